@@ -0,0 +1,777 @@
+//! Trait-based view of the SignalWire REST API.
+//!
+//! Application code that depends on `SignalWireApi` instead of `SignalWireClient` can swap the
+//! real client for a mock or fake in unit tests.
+
+use async_trait::async_trait;
+
+use crate::{
+    client::SignalWireClient,
+    errors::SignalWireError,
+    swml::{Ai, Swml},
+    types::*,
+};
+
+/// Builds the error returned by default method implementations.
+fn not_implemented(method: &str) -> SignalWireError {
+    SignalWireError::Unexpected(format!("`{}` is not implemented by this SignalWireApi implementation", method))
+}
+
+/// The operations exposed by `SignalWireClient`, as an object-safe async trait.
+///
+/// Every method has a default implementation returning `SignalWireError::Unexpected`, so test
+/// doubles only need to override the calls they exercise.
+///
+/// ```rust,no_run
+/// use async_trait::async_trait;
+/// use signalwire::{api::SignalWireApi, errors::SignalWireError, types::*};
+///
+/// struct FakeSms;
+///
+/// #[async_trait]
+/// impl SignalWireApi for FakeSms {
+///     async fn send_sms(&self, message: &SmsMessage) -> Result<SmsResponse, SignalWireError> {
+///         Ok(SmsResponse {
+///             sid: "SM0123456789abcdef0123456789abcdef".parse().unwrap(),
+///             to: message.to.clone(),
+///             from: message.from.clone(),
+///             body: message.body.clone(),
+///             status: "queued".to_string(),
+///             ..Default::default()
+///         })
+///     }
+/// }
+/// ```
+///
+/// On `wasm32`, where the client's futures aren't `Send`, the trait is declared with `#[async_trait(?Send)]`, and so
+/// must its implementations be.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait SignalWireApi: Send + Sync {
+    /// Retrieves a JWT and refresh token for Relay authentication.
+    async fn get_jwt(&self) -> Result<JwtResponse, SignalWireError> {
+        Err(not_implemented("get_jwt"))
+    }
+
+    /// Fetches phone numbers available for purchase in `iso_country`.
+    async fn get_phone_numbers_available(&self, _iso_country: &str, _query_params: &[(String, String)]) -> Result<PhoneNumbersAvailableResponse, SignalWireError> {
+        Err(not_implemented("get_phone_numbers_available"))
+    }
+
+    /// Lists phone numbers owned by the project.
+    async fn get_phone_numbers_owned(&self, _query_params: &[(String, String)]) -> Result<PhoneNumbersOwnedResponse, SignalWireError> {
+        Err(not_implemented("get_phone_numbers_owned"))
+    }
+
+    /// Buys `phone_number`.
+    async fn buy_phone_number(&self, _phone_number: &str) -> Result<BuyPhoneNumberResponse, SignalWireError> {
+        Err(not_implemented("buy_phone_number"))
+    }
+
+    /// Updates the configuration of the owned phone number `id`.
+    async fn update_phone_number(&self, _id: &PhoneNumberId, _request: &UpdatePhoneNumberRequest) -> Result<BuyPhoneNumberResponse, SignalWireError> {
+        Err(not_implemented("update_phone_number"))
+    }
+
+    /// Sends an SMS message.
+    async fn send_sms(&self, _message: &SmsMessage) -> Result<SmsResponse, SignalWireError> {
+        Err(not_implemented("send_sms"))
+    }
+
+    /// Gets a previously sent message by SID.
+    async fn get_message_status(&self, _message_sid: &MessageSid) -> Result<SmsResponse, SignalWireError> {
+        Err(not_implemented("get_message_status"))
+    }
+
+    /// Lists the project and its subprojects.
+    async fn list_subprojects(&self, _query_params: &[(String, String)]) -> Result<SubprojectsListResponse, SignalWireError> {
+        Err(not_implemented("list_subprojects"))
+    }
+
+    /// Gets a subproject by SID.
+    async fn get_subproject(&self, _subproject_sid: &AccountSid) -> Result<SubprojectResponse, SignalWireError> {
+        Err(not_implemented("get_subproject"))
+    }
+
+    /// Creates a subproject.
+    async fn create_subproject(&self, _friendly_name: &str) -> Result<SubprojectResponse, SignalWireError> {
+        Err(not_implemented("create_subproject"))
+    }
+
+    /// Updates a subproject's name and, optionally, its status.
+    async fn update_subproject(&self, _subproject_sid: &AccountSid, _friendly_name: &str, _status: Option<&str>) -> Result<SubprojectResponse, SignalWireError> {
+        Err(not_implemented("update_subproject"))
+    }
+
+    /// Deletes a subproject.
+    async fn delete_subproject(&self, _subproject_sid: &AccountSid) -> Result<(), SignalWireError> {
+        Err(not_implemented("delete_subproject"))
+    }
+
+    /// Lists phone numbers owned by a subproject.
+    async fn get_subproject_phone_numbers(&self, _subproject_sid: &AccountSid, _query_params: &[(String, String)]) -> Result<SubprojectPhoneNumbersResponse, SignalWireError> {
+        Err(not_implemented("get_subproject_phone_numbers"))
+    }
+
+    /// Looks up and validates a phone number.
+    async fn lookup_phone_number(&self, _phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        Err(not_implemented("lookup_phone_number"))
+    }
+
+    /// Looks up a phone number including carrier information.
+    async fn lookup_phone_number_with_carrier(&self, _phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        Err(not_implemented("lookup_phone_number_with_carrier"))
+    }
+
+    /// Looks up a phone number including caller name (CNAM) information.
+    async fn lookup_phone_number_with_caller_name(&self, _phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        Err(not_implemented("lookup_phone_number_with_caller_name"))
+    }
+
+    /// Lists the SWML scripts hosted in the space.
+    async fn list_swml_scripts(&self, _query_params: &[(String, String)]) -> Result<SwmlScriptsListResponse, SignalWireError> {
+        Err(not_implemented("list_swml_scripts"))
+    }
+
+    /// Gets a hosted SWML script by ID.
+    async fn get_swml_script(&self, _id: &str) -> Result<SwmlScriptResponse, SignalWireError> {
+        Err(not_implemented("get_swml_script"))
+    }
+
+    /// Uploads a SWML document as a hosted script.
+    async fn create_swml_script(&self, _name: &str, _swml: &Swml) -> Result<SwmlScriptResponse, SignalWireError> {
+        Err(not_implemented("create_swml_script"))
+    }
+
+    /// Renames a hosted SWML script and/or replaces its document.
+    async fn update_swml_script(&self, _id: &str, _name: Option<&str>, _swml: Option<&Swml>) -> Result<SwmlScriptResponse, SignalWireError> {
+        Err(not_implemented("update_swml_script"))
+    }
+
+    /// Deletes a hosted SWML script.
+    async fn delete_swml_script(&self, _id: &str) -> Result<(), SignalWireError> {
+        Err(not_implemented("delete_swml_script"))
+    }
+
+    /// Sends a task to the Relay clients receiving on a context.
+    async fn send_task(&self, _context: &str, _message: &serde_json::Value) -> Result<(), SignalWireError> {
+        Err(not_implemented("send_task"))
+    }
+
+    /// Creates a token for a browser participant to join a video room.
+    async fn create_room_token(&self, _room_name: &str, _member_name: &str, _permissions: &RoomPermissions) -> Result<RoomTokenResponse, SignalWireError> {
+        Err(not_implemented("create_room_token"))
+    }
+
+    /// Lists the recordings of every video room session.
+    async fn list_room_recordings(&self, _query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        Err(not_implemented("list_room_recordings"))
+    }
+
+    /// Lists the recordings of one video room session.
+    async fn list_room_session_recordings(&self, _room_session_id: &str, _query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        Err(not_implemented("list_room_session_recordings"))
+    }
+
+    /// Gets a video room recording by ID.
+    async fn get_room_recording(&self, _id: &str) -> Result<RoomRecording, SignalWireError> {
+        Err(not_implemented("get_room_recording"))
+    }
+
+    /// Deletes a video room recording.
+    async fn delete_room_recording(&self, _id: &str) -> Result<(), SignalWireError> {
+        Err(not_implemented("delete_room_recording"))
+    }
+
+    /// Lists video room sessions.
+    async fn list_room_sessions(&self, _query_params: &[(String, String)]) -> Result<RoomSessionsListResponse, SignalWireError> {
+        Err(not_implemented("list_room_sessions"))
+    }
+
+    /// Gets a video room session by ID.
+    async fn get_room_session(&self, _id: &str) -> Result<RoomSession, SignalWireError> {
+        Err(not_implemented("get_room_session"))
+    }
+
+    /// Lists the members of a video room session.
+    async fn list_room_session_members(&self, _room_session_id: &str, _query_params: &[(String, String)]) -> Result<RoomMembersListResponse, SignalWireError> {
+        Err(not_implemented("list_room_session_members"))
+    }
+
+    /// Creates a token for a chat member.
+    async fn create_chat_token(
+        &self,
+        _ttl: u32,
+        _channels: &std::collections::BTreeMap<String, ChatChannelPermissions>,
+        _member_id: Option<&str>,
+        _state: Option<&serde_json::Value>,
+    ) -> Result<ChatTokenResponse, SignalWireError> {
+        Err(not_implemented("create_chat_token"))
+    }
+
+    /// Lists the Fabric subscribers of the space.
+    async fn list_fabric_subscribers(&self, _query_params: &[(String, String)]) -> Result<FabricSubscribersListResponse, SignalWireError> {
+        Err(not_implemented("list_fabric_subscribers"))
+    }
+
+    /// Gets a Fabric subscriber by ID.
+    async fn get_fabric_subscriber(&self, _id: &str) -> Result<FabricSubscriberResponse, SignalWireError> {
+        Err(not_implemented("get_fabric_subscriber"))
+    }
+
+    /// Creates a Fabric subscriber.
+    async fn create_fabric_subscriber(&self, _request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        Err(not_implemented("create_fabric_subscriber"))
+    }
+
+    /// Updates a Fabric subscriber.
+    async fn update_fabric_subscriber(&self, _id: &str, _request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        Err(not_implemented("update_fabric_subscriber"))
+    }
+
+    /// Deletes a Fabric subscriber.
+    async fn delete_fabric_subscriber(&self, _id: &str) -> Result<(), SignalWireError> {
+        Err(not_implemented("delete_fabric_subscriber"))
+    }
+
+    /// Lists the Fabric addresses of the space.
+    async fn list_fabric_addresses(&self, _query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        Err(not_implemented("list_fabric_addresses"))
+    }
+
+    /// Gets a Fabric address by ID.
+    async fn get_fabric_address(&self, _id: &str) -> Result<FabricAddress, SignalWireError> {
+        Err(not_implemented("get_fabric_address"))
+    }
+
+    /// Resolves a Fabric address by name.
+    async fn resolve_fabric_address(&self, _name: &str, _address_type: Option<FabricAddressType>) -> Result<FabricAddress, SignalWireError> {
+        Err(not_implemented("resolve_fabric_address"))
+    }
+
+    /// Lists the Fabric resources of the space.
+    async fn list_fabric_resources(&self, _query_params: &[(String, String)]) -> Result<FabricResourcesListResponse, SignalWireError> {
+        Err(not_implemented("list_fabric_resources"))
+    }
+
+    /// Gets a Fabric resource by ID.
+    async fn get_fabric_resource(&self, _id: &str) -> Result<FabricResource, SignalWireError> {
+        Err(not_implemented("get_fabric_resource"))
+    }
+
+    /// Creates a Fabric resource.
+    async fn create_fabric_resource(&self, _resource_type: FabricResourceType, _fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        Err(not_implemented("create_fabric_resource"))
+    }
+
+    /// Updates a Fabric resource.
+    async fn update_fabric_resource(&self, _id: &str, _resource_type: FabricResourceType, _fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        Err(not_implemented("update_fabric_resource"))
+    }
+
+    /// Deletes a Fabric resource.
+    async fn delete_fabric_resource(&self, _id: &str) -> Result<(), SignalWireError> {
+        Err(not_implemented("delete_fabric_resource"))
+    }
+
+    /// Lists the addresses of a Fabric resource.
+    async fn list_fabric_resource_addresses(&self, _id: &str, _query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        Err(not_implemented("list_fabric_resource_addresses"))
+    }
+
+    /// Routes a phone number to a Fabric resource.
+    async fn assign_fabric_resource_phone_number(&self, _id: &str, _phone_number_id: &PhoneNumberId, _handler: FabricHandler) -> Result<FabricAddress, SignalWireError> {
+        Err(not_implemented("assign_fabric_resource_phone_number"))
+    }
+
+    /// Creates a token for a Fabric subscriber.
+    async fn create_fabric_subscriber_token(&self, _reference: &str, _ttl: std::time::Duration) -> Result<FabricSubscriberTokenResponse, SignalWireError> {
+        Err(not_implemented("create_fabric_subscriber_token"))
+    }
+
+    /// Creates a token for a guest, scoped to a set of addresses.
+    async fn create_fabric_guest_token(&self, _allowed_addresses: &[&str], _ttl: std::time::Duration) -> Result<FabricGuestTokenResponse, SignalWireError> {
+        Err(not_implemented("create_fabric_guest_token"))
+    }
+
+    /// Lists the hosted AI agents.
+    async fn list_ai_agents(&self, _query_params: &[(String, String)]) -> Result<AiAgentsListResponse, SignalWireError> {
+        Err(not_implemented("list_ai_agents"))
+    }
+
+    /// Gets a hosted AI agent by ID.
+    async fn get_ai_agent(&self, _id: &str) -> Result<AiAgentResponse, SignalWireError> {
+        Err(not_implemented("get_ai_agent"))
+    }
+
+    /// Creates a hosted AI agent.
+    async fn create_ai_agent(&self, _name: &str, _agent: &Ai) -> Result<AiAgentResponse, SignalWireError> {
+        Err(not_implemented("create_ai_agent"))
+    }
+
+    /// Updates a hosted AI agent.
+    async fn update_ai_agent(&self, _id: &str, _name: Option<&str>, _agent: Option<&Ai>) -> Result<AiAgentResponse, SignalWireError> {
+        Err(not_implemented("update_ai_agent"))
+    }
+
+    /// Deletes a hosted AI agent.
+    async fn delete_ai_agent(&self, _id: &str) -> Result<(), SignalWireError> {
+        Err(not_implemented("delete_ai_agent"))
+    }
+
+    /// Uploads a document to the Datasphere.
+    async fn upload_datasphere_document(&self, _filename: &str, _contents: Vec<u8>, _options: &DatasphereUploadOptions) -> Result<DatasphereDocument, SignalWireError> {
+        Err(not_implemented("upload_datasphere_document"))
+    }
+
+    /// Searches the Datasphere.
+    async fn datasphere_search(&self, _query: &str, _filters: &DatasphereSearchFilters) -> Result<DatasphereSearchResponse, SignalWireError> {
+        Err(not_implemented("datasphere_search"))
+    }
+
+    /// Lists the documents of the Datasphere.
+    async fn list_datasphere_documents(&self, _query_params: &[(String, String)]) -> Result<DatasphereDocumentsListResponse, SignalWireError> {
+        Err(not_implemented("list_datasphere_documents"))
+    }
+
+    /// Gets a Datasphere document by ID.
+    async fn get_datasphere_document(&self, _id: &str) -> Result<DatasphereDocument, SignalWireError> {
+        Err(not_implemented("get_datasphere_document"))
+    }
+
+    /// Lists the chunks of a Datasphere document.
+    async fn list_datasphere_chunks(&self, _document_id: &str, _query_params: &[(String, String)]) -> Result<DatasphereChunksListResponse, SignalWireError> {
+        Err(not_implemented("list_datasphere_chunks"))
+    }
+
+    /// Gets a chunk of a Datasphere document.
+    async fn get_datasphere_chunk(&self, _document_id: &str, _id: &str) -> Result<DatasphereChunk, SignalWireError> {
+        Err(not_implemented("get_datasphere_chunk"))
+    }
+
+    /// Lists the message logs of the space.
+    async fn list_message_logs(&self, _query: &LogQuery) -> Result<MessageLogsListResponse, SignalWireError> {
+        Err(not_implemented("list_message_logs"))
+    }
+
+    /// Gets a message log by ID.
+    async fn get_message_log(&self, _id: &str) -> Result<MessageLog, SignalWireError> {
+        Err(not_implemented("get_message_log"))
+    }
+
+    /// Lists the call logs of the space.
+    async fn list_voice_logs(&self, _query: &LogQuery) -> Result<VoiceLogsListResponse, SignalWireError> {
+        Err(not_implemented("list_voice_logs"))
+    }
+
+    /// Gets a call log by ID.
+    async fn get_voice_log(&self, _id: &str) -> Result<VoiceLog, SignalWireError> {
+        Err(not_implemented("get_voice_log"))
+    }
+
+    /// Lists the SIP endpoints of the space.
+    async fn list_sip_endpoints(&self, _query_params: &[(String, String)]) -> Result<SipEndpointsListResponse, SignalWireError> {
+        Err(not_implemented("list_sip_endpoints"))
+    }
+
+    /// Gets a SIP endpoint by ID.
+    async fn get_sip_endpoint(&self, _id: &str) -> Result<SipEndpoint, SignalWireError> {
+        Err(not_implemented("get_sip_endpoint"))
+    }
+
+    /// Creates a SIP endpoint.
+    async fn create_sip_endpoint(&self, _request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        Err(not_implemented("create_sip_endpoint"))
+    }
+
+    /// Updates a SIP endpoint.
+    async fn update_sip_endpoint(&self, _id: &str, _request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        Err(not_implemented("update_sip_endpoint"))
+    }
+
+    /// Deletes a SIP endpoint.
+    async fn delete_sip_endpoint(&self, _id: &str) -> Result<(), SignalWireError> {
+        Err(not_implemented("delete_sip_endpoint"))
+    }
+
+    /// Lists the SIP domain applications of the space.
+    async fn list_domain_applications(&self, _query_params: &[(String, String)]) -> Result<DomainApplicationsListResponse, SignalWireError> {
+        Err(not_implemented("list_domain_applications"))
+    }
+
+    /// Gets a SIP domain application by ID.
+    async fn get_domain_application(&self, _id: &str) -> Result<DomainApplication, SignalWireError> {
+        Err(not_implemented("get_domain_application"))
+    }
+
+    /// Creates a SIP domain application.
+    async fn create_domain_application(&self, _request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        Err(not_implemented("create_domain_application"))
+    }
+
+    /// Updates a SIP domain application.
+    async fn update_domain_application(&self, _id: &str, _request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        Err(not_implemented("update_domain_application"))
+    }
+
+    /// Deletes a SIP domain application.
+    async fn delete_domain_application(&self, _id: &str) -> Result<(), SignalWireError> {
+        Err(not_implemented("delete_domain_application"))
+    }
+
+    /// Sends a one-time code by SMS.
+    async fn mfa_send_sms(&self, _to: &str, _options: &MfaOptions) -> Result<MfaResponse, SignalWireError> {
+        Err(not_implemented("mfa_send_sms"))
+    }
+
+    /// Sends a one-time code by voice call.
+    async fn mfa_send_call(&self, _to: &str) -> Result<MfaResponse, SignalWireError> {
+        Err(not_implemented("mfa_send_call"))
+    }
+
+    /// Checks a one-time code.
+    async fn mfa_verify(&self, _request_id: &str, _token: &str) -> Result<MfaVerification, SignalWireError> {
+        Err(not_implemented("mfa_verify"))
+    }
+
+    /// Lists the messages of the project.
+    async fn list_messages(&self, _query_params: &[(String, String)]) -> Result<MessagesListResponse, SignalWireError> {
+        Err(not_implemented("list_messages"))
+    }
+
+    /// Places a call to a phone number or a SIP URI.
+    async fn create_call(&self, _call: &NewCall) -> Result<CallResponse, SignalWireError> {
+        Err(not_implemented("create_call"))
+    }
+
+    /// Lists the calls of the project.
+    async fn list_calls(&self, _query_params: &[(String, String)]) -> Result<CallsListResponse, SignalWireError> {
+        Err(not_implemented("list_calls"))
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl SignalWireApi for SignalWireClient {
+    async fn get_jwt(&self) -> Result<JwtResponse, SignalWireError> {
+        SignalWireClient::get_jwt(self).await
+    }
+
+    async fn get_phone_numbers_available(&self, iso_country: &str, query_params: &[(String, String)]) -> Result<PhoneNumbersAvailableResponse, SignalWireError> {
+        SignalWireClient::get_phone_numbers_available(self, iso_country, query_params).await
+    }
+
+    async fn get_phone_numbers_owned(&self, query_params: &[(String, String)]) -> Result<PhoneNumbersOwnedResponse, SignalWireError> {
+        SignalWireClient::get_phone_numbers_owned(self, query_params).await
+    }
+
+    async fn buy_phone_number(&self, phone_number: &str) -> Result<BuyPhoneNumberResponse, SignalWireError> {
+        SignalWireClient::buy_phone_number(self, phone_number).await
+    }
+
+    async fn update_phone_number(&self, id: &PhoneNumberId, request: &UpdatePhoneNumberRequest) -> Result<BuyPhoneNumberResponse, SignalWireError> {
+        SignalWireClient::update_phone_number(self, id, request).await
+    }
+
+    async fn send_sms(&self, message: &SmsMessage) -> Result<SmsResponse, SignalWireError> {
+        SignalWireClient::send_sms(self, message).await
+    }
+
+    async fn get_message_status(&self, message_sid: &MessageSid) -> Result<SmsResponse, SignalWireError> {
+        SignalWireClient::get_message_status(self, message_sid).await
+    }
+
+    async fn list_subprojects(&self, query_params: &[(String, String)]) -> Result<SubprojectsListResponse, SignalWireError> {
+        SignalWireClient::list_subprojects(self, query_params).await
+    }
+
+    async fn get_subproject(&self, subproject_sid: &AccountSid) -> Result<SubprojectResponse, SignalWireError> {
+        SignalWireClient::get_subproject(self, subproject_sid).await
+    }
+
+    async fn create_subproject(&self, friendly_name: &str) -> Result<SubprojectResponse, SignalWireError> {
+        SignalWireClient::create_subproject(self, friendly_name).await
+    }
+
+    async fn update_subproject(&self, subproject_sid: &AccountSid, friendly_name: &str, status: Option<&str>) -> Result<SubprojectResponse, SignalWireError> {
+        SignalWireClient::update_subproject(self, subproject_sid, friendly_name, status).await
+    }
+
+    async fn delete_subproject(&self, subproject_sid: &AccountSid) -> Result<(), SignalWireError> {
+        SignalWireClient::delete_subproject(self, subproject_sid).await
+    }
+
+    async fn get_subproject_phone_numbers(&self, subproject_sid: &AccountSid, query_params: &[(String, String)]) -> Result<SubprojectPhoneNumbersResponse, SignalWireError> {
+        SignalWireClient::get_subproject_phone_numbers(self, subproject_sid, query_params).await
+    }
+
+    async fn lookup_phone_number(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        SignalWireClient::lookup_phone_number(self, phone_number).await
+    }
+
+    async fn lookup_phone_number_with_carrier(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        SignalWireClient::lookup_phone_number_with_carrier(self, phone_number).await
+    }
+
+    async fn lookup_phone_number_with_caller_name(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        SignalWireClient::lookup_phone_number_with_caller_name(self, phone_number).await
+    }
+
+    async fn list_swml_scripts(&self, query_params: &[(String, String)]) -> Result<SwmlScriptsListResponse, SignalWireError> {
+        SignalWireClient::list_swml_scripts(self, query_params).await
+    }
+
+    async fn get_swml_script(&self, id: &str) -> Result<SwmlScriptResponse, SignalWireError> {
+        SignalWireClient::get_swml_script(self, id).await
+    }
+
+    async fn create_swml_script(&self, name: &str, swml: &Swml) -> Result<SwmlScriptResponse, SignalWireError> {
+        SignalWireClient::create_swml_script(self, name, swml).await
+    }
+
+    async fn update_swml_script(&self, id: &str, name: Option<&str>, swml: Option<&Swml>) -> Result<SwmlScriptResponse, SignalWireError> {
+        SignalWireClient::update_swml_script(self, id, name, swml).await
+    }
+
+    async fn delete_swml_script(&self, id: &str) -> Result<(), SignalWireError> {
+        SignalWireClient::delete_swml_script(self, id).await
+    }
+
+    async fn send_task(&self, context: &str, message: &serde_json::Value) -> Result<(), SignalWireError> {
+        SignalWireClient::send_task(self, context, message).await
+    }
+
+    async fn create_room_token(&self, room_name: &str, member_name: &str, permissions: &RoomPermissions) -> Result<RoomTokenResponse, SignalWireError> {
+        SignalWireClient::create_room_token(self, room_name, member_name, permissions).await
+    }
+
+    async fn list_room_recordings(&self, query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        SignalWireClient::list_room_recordings(self, query_params).await
+    }
+
+    async fn list_room_session_recordings(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        SignalWireClient::list_room_session_recordings(self, room_session_id, query_params).await
+    }
+
+    async fn get_room_recording(&self, id: &str) -> Result<RoomRecording, SignalWireError> {
+        SignalWireClient::get_room_recording(self, id).await
+    }
+
+    async fn delete_room_recording(&self, id: &str) -> Result<(), SignalWireError> {
+        SignalWireClient::delete_room_recording(self, id).await
+    }
+
+    async fn list_room_sessions(&self, query_params: &[(String, String)]) -> Result<RoomSessionsListResponse, SignalWireError> {
+        SignalWireClient::list_room_sessions(self, query_params).await
+    }
+
+    async fn get_room_session(&self, id: &str) -> Result<RoomSession, SignalWireError> {
+        SignalWireClient::get_room_session(self, id).await
+    }
+
+    async fn list_room_session_members(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<RoomMembersListResponse, SignalWireError> {
+        SignalWireClient::list_room_session_members(self, room_session_id, query_params).await
+    }
+
+    async fn create_chat_token(
+        &self,
+        ttl: u32,
+        channels: &std::collections::BTreeMap<String, ChatChannelPermissions>,
+        member_id: Option<&str>,
+        state: Option<&serde_json::Value>,
+    ) -> Result<ChatTokenResponse, SignalWireError> {
+        SignalWireClient::create_chat_token(self, ttl, channels, member_id, state).await
+    }
+
+    async fn list_fabric_subscribers(&self, query_params: &[(String, String)]) -> Result<FabricSubscribersListResponse, SignalWireError> {
+        SignalWireClient::list_fabric_subscribers(self, query_params).await
+    }
+
+    async fn get_fabric_subscriber(&self, id: &str) -> Result<FabricSubscriberResponse, SignalWireError> {
+        SignalWireClient::get_fabric_subscriber(self, id).await
+    }
+
+    async fn create_fabric_subscriber(&self, request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        SignalWireClient::create_fabric_subscriber(self, request).await
+    }
+
+    async fn update_fabric_subscriber(&self, id: &str, request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        SignalWireClient::update_fabric_subscriber(self, id, request).await
+    }
+
+    async fn delete_fabric_subscriber(&self, id: &str) -> Result<(), SignalWireError> {
+        SignalWireClient::delete_fabric_subscriber(self, id).await
+    }
+
+    async fn list_fabric_addresses(&self, query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        SignalWireClient::list_fabric_addresses(self, query_params).await
+    }
+
+    async fn get_fabric_address(&self, id: &str) -> Result<FabricAddress, SignalWireError> {
+        SignalWireClient::get_fabric_address(self, id).await
+    }
+
+    async fn resolve_fabric_address(&self, name: &str, address_type: Option<FabricAddressType>) -> Result<FabricAddress, SignalWireError> {
+        SignalWireClient::resolve_fabric_address(self, name, address_type).await
+    }
+
+    async fn list_fabric_resources(&self, query_params: &[(String, String)]) -> Result<FabricResourcesListResponse, SignalWireError> {
+        SignalWireClient::list_fabric_resources(self, query_params).await
+    }
+
+    async fn get_fabric_resource(&self, id: &str) -> Result<FabricResource, SignalWireError> {
+        SignalWireClient::get_fabric_resource(self, id).await
+    }
+
+    async fn create_fabric_resource(&self, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        SignalWireClient::create_fabric_resource(self, resource_type, fields).await
+    }
+
+    async fn update_fabric_resource(&self, id: &str, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        SignalWireClient::update_fabric_resource(self, id, resource_type, fields).await
+    }
+
+    async fn delete_fabric_resource(&self, id: &str) -> Result<(), SignalWireError> {
+        SignalWireClient::delete_fabric_resource(self, id).await
+    }
+
+    async fn list_fabric_resource_addresses(&self, id: &str, query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        SignalWireClient::list_fabric_resource_addresses(self, id, query_params).await
+    }
+
+    async fn assign_fabric_resource_phone_number(&self, id: &str, phone_number_id: &PhoneNumberId, handler: FabricHandler) -> Result<FabricAddress, SignalWireError> {
+        SignalWireClient::assign_fabric_resource_phone_number(self, id, phone_number_id, handler).await
+    }
+
+    async fn create_fabric_subscriber_token(&self, reference: &str, ttl: std::time::Duration) -> Result<FabricSubscriberTokenResponse, SignalWireError> {
+        SignalWireClient::create_fabric_subscriber_token(self, reference, ttl).await
+    }
+
+    async fn create_fabric_guest_token(&self, allowed_addresses: &[&str], ttl: std::time::Duration) -> Result<FabricGuestTokenResponse, SignalWireError> {
+        SignalWireClient::create_fabric_guest_token(self, allowed_addresses, ttl).await
+    }
+
+    async fn list_ai_agents(&self, query_params: &[(String, String)]) -> Result<AiAgentsListResponse, SignalWireError> {
+        SignalWireClient::list_ai_agents(self, query_params).await
+    }
+
+    async fn get_ai_agent(&self, id: &str) -> Result<AiAgentResponse, SignalWireError> {
+        SignalWireClient::get_ai_agent(self, id).await
+    }
+
+    async fn create_ai_agent(&self, name: &str, agent: &Ai) -> Result<AiAgentResponse, SignalWireError> {
+        SignalWireClient::create_ai_agent(self, name, agent).await
+    }
+
+    async fn update_ai_agent(&self, id: &str, name: Option<&str>, agent: Option<&Ai>) -> Result<AiAgentResponse, SignalWireError> {
+        SignalWireClient::update_ai_agent(self, id, name, agent).await
+    }
+
+    async fn delete_ai_agent(&self, id: &str) -> Result<(), SignalWireError> {
+        SignalWireClient::delete_ai_agent(self, id).await
+    }
+
+    async fn upload_datasphere_document(&self, filename: &str, contents: Vec<u8>, options: &DatasphereUploadOptions) -> Result<DatasphereDocument, SignalWireError> {
+        SignalWireClient::upload_datasphere_document(self, filename, contents, options).await
+    }
+
+    async fn datasphere_search(&self, query: &str, filters: &DatasphereSearchFilters) -> Result<DatasphereSearchResponse, SignalWireError> {
+        SignalWireClient::datasphere_search(self, query, filters).await
+    }
+
+    async fn list_datasphere_documents(&self, query_params: &[(String, String)]) -> Result<DatasphereDocumentsListResponse, SignalWireError> {
+        SignalWireClient::list_datasphere_documents(self, query_params).await
+    }
+
+    async fn get_datasphere_document(&self, id: &str) -> Result<DatasphereDocument, SignalWireError> {
+        SignalWireClient::get_datasphere_document(self, id).await
+    }
+
+    async fn list_datasphere_chunks(&self, document_id: &str, query_params: &[(String, String)]) -> Result<DatasphereChunksListResponse, SignalWireError> {
+        SignalWireClient::list_datasphere_chunks(self, document_id, query_params).await
+    }
+
+    async fn get_datasphere_chunk(&self, document_id: &str, id: &str) -> Result<DatasphereChunk, SignalWireError> {
+        SignalWireClient::get_datasphere_chunk(self, document_id, id).await
+    }
+
+    async fn list_message_logs(&self, query: &LogQuery) -> Result<MessageLogsListResponse, SignalWireError> {
+        SignalWireClient::list_message_logs(self, query).await
+    }
+
+    async fn get_message_log(&self, id: &str) -> Result<MessageLog, SignalWireError> {
+        SignalWireClient::get_message_log(self, id).await
+    }
+
+    async fn list_voice_logs(&self, query: &LogQuery) -> Result<VoiceLogsListResponse, SignalWireError> {
+        SignalWireClient::list_voice_logs(self, query).await
+    }
+
+    async fn get_voice_log(&self, id: &str) -> Result<VoiceLog, SignalWireError> {
+        SignalWireClient::get_voice_log(self, id).await
+    }
+
+    async fn list_sip_endpoints(&self, query_params: &[(String, String)]) -> Result<SipEndpointsListResponse, SignalWireError> {
+        SignalWireClient::list_sip_endpoints(self, query_params).await
+    }
+
+    async fn get_sip_endpoint(&self, id: &str) -> Result<SipEndpoint, SignalWireError> {
+        SignalWireClient::get_sip_endpoint(self, id).await
+    }
+
+    async fn create_sip_endpoint(&self, request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        SignalWireClient::create_sip_endpoint(self, request).await
+    }
+
+    async fn update_sip_endpoint(&self, id: &str, request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        SignalWireClient::update_sip_endpoint(self, id, request).await
+    }
+
+    async fn delete_sip_endpoint(&self, id: &str) -> Result<(), SignalWireError> {
+        SignalWireClient::delete_sip_endpoint(self, id).await
+    }
+
+    async fn list_domain_applications(&self, query_params: &[(String, String)]) -> Result<DomainApplicationsListResponse, SignalWireError> {
+        SignalWireClient::list_domain_applications(self, query_params).await
+    }
+
+    async fn get_domain_application(&self, id: &str) -> Result<DomainApplication, SignalWireError> {
+        SignalWireClient::get_domain_application(self, id).await
+    }
+
+    async fn create_domain_application(&self, request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        SignalWireClient::create_domain_application(self, request).await
+    }
+
+    async fn update_domain_application(&self, id: &str, request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        SignalWireClient::update_domain_application(self, id, request).await
+    }
+
+    async fn delete_domain_application(&self, id: &str) -> Result<(), SignalWireError> {
+        SignalWireClient::delete_domain_application(self, id).await
+    }
+
+    async fn mfa_send_sms(&self, to: &str, options: &MfaOptions) -> Result<MfaResponse, SignalWireError> {
+        SignalWireClient::mfa_send_sms(self, to, options).await
+    }
+
+    async fn mfa_send_call(&self, to: &str) -> Result<MfaResponse, SignalWireError> {
+        SignalWireClient::mfa_send_call(self, to).await
+    }
+
+    async fn mfa_verify(&self, request_id: &str, token: &str) -> Result<MfaVerification, SignalWireError> {
+        SignalWireClient::mfa_verify(self, request_id, token).await
+    }
+
+    async fn list_messages(&self, query_params: &[(String, String)]) -> Result<MessagesListResponse, SignalWireError> {
+        SignalWireClient::list_messages(self, query_params).await
+    }
+
+    async fn create_call(&self, call: &NewCall) -> Result<CallResponse, SignalWireError> {
+        SignalWireClient::create_call(self, call).await
+    }
+
+    async fn list_calls(&self, query_params: &[(String, String)]) -> Result<CallsListResponse, SignalWireError> {
+        SignalWireClient::list_calls(self, query_params).await
+    }
+}
@@ -0,0 +1,302 @@
+//! Running one API call per item of a batch, a few at a time.
+//!
+//! `BatchExecutor` caps how many calls are in flight, retries the failures its `RetryPolicy` deems transient, and
+//! reports the result of every item, in the order of the batch, with totals. The bulk client methods
+//! (`send_sms_batch`, `lookup_phone_numbers`, `update_phone_numbers`) run on it, and so can any other call:
+//!
+//! ```rust,no_run
+//! use signalwire::{batch::BatchExecutor, client::SignalWireClient, types::AccountSid};
+//!
+//! # async fn example(client: SignalWireClient, subprojects: Vec<AccountSid>) {
+//! let report = BatchExecutor::new(4)
+//!     .run(subprojects, |sid| {
+//!         let client = &client;
+//!         async move { client.get_subproject(&sid).await }
+//!     })
+//!     .await;
+//! println!(
+//!     "{} fetched, {} failed after {} retries",
+//!     report.metrics.succeeded, report.metrics.failed, report.metrics.retries
+//! );
+//! # }
+//! ```
+
+use std::{future::Future, sync::Mutex, time::Duration};
+
+use futures_util::{stream, StreamExt};
+
+use crate::{
+    errors::SignalWireError,
+    rt::{self, Instant},
+};
+
+/// Which failures of a batch item are retried, how many times and how long apart.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Attempts per item, the first included; 1 never retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled before each following one.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Whether a failure is worth retrying.
+    pub retry_if: fn(&SignalWireError) -> bool,
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, 200ms then 400ms apart, retrying only failures to reach the API.
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            retry_if: is_transient,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A single attempt per item.
+    pub fn none() -> Self {
+        RetryPolicy { max_attempts: 1, ..Default::default() }
+    }
+
+    /// The delay before the retry following `attempt`.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1))).min(self.max_backoff)
+    }
+}
+
+/// Whether the request failed on the way, rather than being answered with an error.
+pub fn is_transient(error: &SignalWireError) -> bool {
+    matches!(error, SignalWireError::HttpError(_))
+}
+
+/// The result of one item of a batch.
+#[derive(Debug)]
+pub struct BatchOutcome<I, T> {
+    pub item: I,
+    /// The result of the last attempt.
+    pub result: Result<T, SignalWireError>,
+    pub attempts: u32,
+    /// From the first attempt to the last one's answer, backoff included.
+    pub elapsed: Duration,
+}
+
+/// The totals of a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BatchMetrics {
+    pub succeeded: usize,
+    pub failed: usize,
+    /// Items never run, the budget being spent.
+    pub skipped: usize,
+    /// Attempts beyond the first, over all items.
+    pub retries: usize,
+    pub elapsed: Duration,
+}
+
+/// What a batch run with `BatchExecutor::run_with_budget` spent.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BatchSpend {
+    pub max_spend: f64,
+    /// The prices of the items that succeeded, or their estimates where the response has none yet.
+    pub spent: f64,
+}
+
+/// The outcomes of a batch, in the order of its items, and their totals.
+#[derive(Debug)]
+pub struct BatchReport<I, T> {
+    /// The outcomes of the items run.
+    pub outcomes: Vec<BatchOutcome<I, T>>,
+    /// The items never run, the budget being spent, in the order of the batch.
+    pub skipped: Vec<I>,
+    pub metrics: BatchMetrics,
+    /// The spending of a batch with a budget.
+    pub spend: Option<BatchSpend>,
+}
+
+impl<I, T> BatchReport<I, T> {
+    /// Whether every item ran and succeeded.
+    pub fn is_success(&self) -> bool {
+        self.metrics.failed == 0 && self.metrics.skipped == 0
+    }
+
+    /// The items that succeeded, with their results.
+    pub fn successes(&self) -> impl Iterator<Item = (&I, &T)> {
+        self.outcomes.iter().filter_map(|outcome| outcome.result.as_ref().ok().map(|value| (&outcome.item, value)))
+    }
+
+    /// The items that failed, with their last error.
+    pub fn failures(&self) -> impl Iterator<Item = (&I, &SignalWireError)> {
+        self.outcomes.iter().filter_map(|outcome| outcome.result.as_ref().err().map(|error| (&outcome.item, error)))
+    }
+
+    /// The results alone, in the order of the items.
+    pub fn into_results(self) -> Vec<Result<T, SignalWireError>> {
+        self.outcomes.into_iter().map(|outcome| outcome.result).collect()
+    }
+}
+
+/// Runs a call per item of a batch, at most `concurrency` at a time, see the module documentation.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchExecutor {
+    concurrency: usize,
+    retry: RetryPolicy,
+}
+
+impl Default for BatchExecutor {
+    /// Eight calls at a time, with the default `RetryPolicy`.
+    fn default() -> Self {
+        BatchExecutor::new(8)
+    }
+}
+
+impl BatchExecutor {
+    /// An executor running at most `concurrency` calls at a time (at least one), with the default `RetryPolicy`.
+    pub fn new(concurrency: usize) -> Self {
+        BatchExecutor {
+            concurrency: concurrency.max(1),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    pub fn retry(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
+    /// Calls `call` with each item, a clone of it for every attempt, and waits for all of them.
+    ///
+    /// A failure never stops the batch: it is reported with the item, and the other items carry on.
+    pub async fn run<I, T, F, Fut>(&self, items: impl IntoIterator<Item = I>, call: F) -> BatchReport<I, T>
+    where
+        I: Clone,
+        F: Fn(I) -> Fut,
+        Fut: Future<Output = Result<T, SignalWireError>>,
+    {
+        self.execute(items, call, None).await
+    }
+
+    /// Same as `run`, but stops starting items once the next one would take the spending beyond `max_spend`,
+    /// reporting the rest as skipped.
+    ///
+    /// An item's `estimate` is set aside when it starts, then replaced by its `price` once it succeeded, or by
+    /// nothing if it failed; a success without a price yet, as sends still queued, keeps costing its estimate.
+    pub async fn run_with_budget<I, T, F, Fut>(&self, items: impl IntoIterator<Item = I>, max_spend: f64, estimate: impl Fn(&I) -> f64, price: impl Fn(&T) -> Option<f64>, call: F) -> BatchReport<I, T>
+    where
+        I: Clone,
+        F: Fn(I) -> Fut,
+        Fut: Future<Output = Result<T, SignalWireError>>,
+    {
+        let budget = Budget {
+            max_spend,
+            estimate: &estimate,
+            price: &price,
+            state: Mutex::new(BudgetState::default()),
+        };
+        self.execute(items, call, Some(&budget)).await
+    }
+
+    async fn execute<I, T, F, Fut>(&self, items: impl IntoIterator<Item = I>, call: F, budget: Option<&Budget<'_, I, T>>) -> BatchReport<I, T>
+    where
+        I: Clone,
+        F: Fn(I) -> Fut,
+        Fut: Future<Output = Result<T, SignalWireError>>,
+    {
+        let started = Instant::now();
+        let call = &call;
+        let mut outcomes: Vec<_> = stream::iter(items.into_iter().enumerate())
+            .map(|(index, item)| async move {
+                let reserved = match budget.map(|budget| budget.reserve(&item)) {
+                    Some(None) => return (index, Err(item)),
+                    Some(Some(reserved)) => reserved,
+                    None => 0.0,
+                };
+
+                let started = Instant::now();
+                let mut attempts = 0;
+                let result = loop {
+                    attempts += 1;
+                    match call(item.clone()).await {
+                        Err(error) if attempts < self.retry.max_attempts && (self.retry.retry_if)(&error) => rt::sleep(self.retry.backoff(attempts)).await,
+                        result => break result,
+                    }
+                };
+                let elapsed = started.elapsed();
+                if let Some(budget) = budget {
+                    budget.settle(reserved, result.as_ref().ok());
+                }
+                (index, Ok(BatchOutcome { item, result, attempts, elapsed }))
+            })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+        outcomes.sort_by_key(|(index, _)| *index);
+
+        let (mut ran, mut skipped) = (Vec::new(), Vec::new());
+        for (_, outcome) in outcomes {
+            match outcome {
+                Ok(outcome) => ran.push(outcome),
+                Err(item) => skipped.push(item),
+            }
+        }
+        let succeeded = ran.iter().filter(|outcome| outcome.result.is_ok()).count();
+        let metrics = BatchMetrics {
+            succeeded,
+            failed: ran.len() - succeeded,
+            skipped: skipped.len(),
+            retries: ran.iter().map(|outcome| (outcome.attempts - 1) as usize).sum(),
+            elapsed: started.elapsed(),
+        };
+        let spend = budget.map(|budget| BatchSpend {
+            max_spend: budget.max_spend,
+            spent: budget.state.lock().unwrap().spent,
+        });
+        BatchReport { outcomes: ran, skipped, metrics, spend }
+    }
+}
+
+/// The spending limit of `BatchExecutor::run_with_budget`.
+struct Budget<'a, I, T> {
+    max_spend: f64,
+    estimate: &'a dyn Fn(&I) -> f64,
+    price: &'a dyn Fn(&T) -> Option<f64>,
+    state: Mutex<BudgetState>,
+}
+
+#[derive(Default)]
+struct BudgetState {
+    /// Spent on the items done, and set aside for those running.
+    spent: f64,
+    exhausted: bool,
+}
+
+impl<I, T> Budget<'_, I, T> {
+    /// Sets the estimate of `item` aside, `None` once the budget doesn't allow it: no item starts from then on.
+    fn reserve(&self, item: &I) -> Option<f64> {
+        let estimate = (self.estimate)(item);
+        let mut state = self.state.lock().unwrap();
+        if state.exhausted || state.spent + estimate > self.max_spend {
+            state.exhausted = true;
+            return None;
+        }
+        state.spent += estimate;
+        Some(estimate)
+    }
+
+    /// Replaces the estimate `reserved` with the price of `value`, nothing for a failure.
+    fn settle(&self, reserved: f64, value: Option<&T>) {
+        let spent = match value {
+            Some(value) => (self.price)(value).map_or(reserved, f64::abs),
+            None => 0.0,
+        };
+        self.state.lock().unwrap().spent += spent - reserved;
+    }
+}
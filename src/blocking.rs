@@ -0,0 +1,689 @@
+//! A synchronous client, enabled by the `blocking` feature.
+//!
+//! Every call runs on one runtime shared by the whole process, started on first use, so connections are
+//! pooled across calls instead of being torn down with a runtime per request.
+//!
+//! Like `reqwest::blocking`, these methods must not be called from async code: they panic inside a
+//! Tokio runtime. Use `SignalWireClient` there.
+
+use std::{future::Future, sync::OnceLock};
+
+use tokio::runtime::Runtime;
+
+use crate::{
+    batch::{BatchExecutor, BatchReport},
+    client::SignalWireClient,
+    errors::SignalWireError,
+    swml::{Ai, Swml},
+    types::*,
+};
+
+/// Runs `future` to completion on the shared runtime.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+    RUNTIME
+        .get_or_init(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(1)
+                .thread_name("signalwire-blocking")
+                .enable_all()
+                .build()
+                .expect("Failed to start the SignalWire blocking runtime")
+        })
+        .block_on(future)
+}
+
+/// The blocking counterpart of `SignalWireClient`, with the same methods minus the `_blocking` suffix.
+///
+/// ```no_run
+/// use signalwire::{blocking::BlockingSignalWireClient, client::SignalWireClient};
+///
+/// let client = BlockingSignalWireClient::new("your-space", "your-project-id", "your-api-key");
+/// let jwt = client.get_jwt()?;
+///
+/// // Configure the async client first to change its defaults
+/// let client = BlockingSignalWireClient::from(
+///     SignalWireClient::new("your-space", "your-project-id", "your-api-key").dry_run(true),
+/// );
+/// # Ok::<(), signalwire::errors::SignalWireError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct BlockingSignalWireClient {
+    client: SignalWireClient,
+}
+
+impl BlockingSignalWireClient {
+    /// Same as `SignalWireClient::new`.
+    pub fn new(space_name: &str, project_id: &str, api_key: &str) -> Self {
+        BlockingSignalWireClient {
+            client: SignalWireClient::new(space_name, project_id, api_key),
+        }
+    }
+
+    /// The async client behind this one, e.g. for `Page::next_page_blocking`.
+    pub fn client(&self) -> &SignalWireClient {
+        &self.client
+    }
+
+    pub fn into_async(self) -> SignalWireClient {
+        self.client
+    }
+
+    /// Blocking version of `SignalWireClient::get_jwt`.
+    pub fn get_jwt(&self) -> Result<JwtResponse, SignalWireError> {
+        block_on(self.client.get_jwt())
+    }
+
+    /// Blocking version of `SignalWireClient::get_phone_numbers_available`.
+    pub fn get_phone_numbers_available(&self, iso_country: &str, query_params: &[(String, String)]) -> Result<PhoneNumbersAvailableResponse, SignalWireError> {
+        block_on(self.client.get_phone_numbers_available(iso_country, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_phone_numbers_owned`.
+    pub fn get_phone_numbers_owned(&self, query_params: &[(String, String)]) -> Result<PhoneNumbersOwnedResponse, SignalWireError> {
+        block_on(self.client.get_phone_numbers_owned(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_phone_numbers_owned_all`.
+    pub fn get_phone_numbers_owned_all(&self, query_params: &[(String, String)]) -> Result<Vec<OwnedPhoneNumber>, SignalWireError> {
+        block_on(self.client.get_phone_numbers_owned_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::buy_phone_number`.
+    pub fn buy_phone_number(&self, phone_number: &str) -> Result<BuyPhoneNumberResponse, SignalWireError> {
+        block_on(self.client.buy_phone_number(phone_number))
+    }
+
+    /// Blocking version of `SignalWireClient::update_phone_number`.
+    pub fn update_phone_number(&self, id: &PhoneNumberId, request: &UpdatePhoneNumberRequest) -> Result<BuyPhoneNumberResponse, SignalWireError> {
+        block_on(self.client.update_phone_number(id, request))
+    }
+
+    /// Blocking version of `SignalWireClient::update_phone_numbers`.
+    pub fn update_phone_numbers(&self, updates: Vec<(PhoneNumberId, UpdatePhoneNumberRequest)>, executor: &BatchExecutor) -> BatchReport<(PhoneNumberId, UpdatePhoneNumberRequest), BuyPhoneNumberResponse> {
+        block_on(self.client.update_phone_numbers(updates, executor))
+    }
+
+    /// Blocking version of `SignalWireClient::send_sms`.
+    pub fn send_sms(&self, message: &SmsMessage) -> Result<SmsResponse, SignalWireError> {
+        block_on(self.client.send_sms(message))
+    }
+
+    /// Blocking version of `SignalWireClient::send_sms_batch`.
+    pub fn send_sms_batch(&self, messages: Vec<SmsMessage>, executor: &BatchExecutor) -> BatchReport<SmsMessage, SmsResponse> {
+        block_on(self.client.send_sms_batch(messages, executor))
+    }
+
+    /// Blocking version of `SignalWireClient::send_sms_batch_with_budget`.
+    pub fn send_sms_batch_with_budget(&self, messages: Vec<SmsMessage>, executor: &BatchExecutor, max_spend: f64, price_per_segment: f64) -> BatchReport<SmsMessage, SmsResponse> {
+        block_on(self.client.send_sms_batch_with_budget(messages, executor, max_spend, price_per_segment))
+    }
+
+    /// Blocking version of `SignalWireClient::get_message_status`.
+    pub fn get_message_status(&self, message_sid: &MessageSid) -> Result<SmsResponse, SignalWireError> {
+        block_on(self.client.get_message_status(message_sid))
+    }
+
+    /// Blocking version of `SignalWireClient::list_messages`.
+    pub fn list_messages(&self, query_params: &[(String, String)]) -> Result<MessagesListResponse, SignalWireError> {
+        block_on(self.client.list_messages(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_messages_all`.
+    pub fn list_messages_all(&self, query_params: &[(String, String)]) -> Result<Vec<SmsResponse>, SignalWireError> {
+        block_on(self.client.list_messages_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_short_codes`.
+    pub fn list_short_codes(&self, query_params: &[(String, String)]) -> Result<ShortCodesListResponse, SignalWireError> {
+        block_on(self.client.list_short_codes(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_short_codes_all`.
+    pub fn list_short_codes_all(&self, query_params: &[(String, String)]) -> Result<Vec<ShortCode>, SignalWireError> {
+        block_on(self.client.list_short_codes_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_short_code`.
+    pub fn get_short_code(&self, short_code_sid: &ShortCodeSid) -> Result<ShortCode, SignalWireError> {
+        block_on(self.client.get_short_code(short_code_sid))
+    }
+
+    /// Blocking version of `SignalWireClient::update_short_code`.
+    pub fn update_short_code(&self, short_code_sid: &ShortCodeSid, update: &ShortCodeUpdate) -> Result<ShortCode, SignalWireError> {
+        block_on(self.client.update_short_code(short_code_sid, update))
+    }
+
+    /// Blocking version of `SignalWireClient::create_call`.
+    pub fn create_call(&self, call: &NewCall) -> Result<CallResponse, SignalWireError> {
+        block_on(self.client.create_call(call))
+    }
+
+    /// Blocking version of `SignalWireClient::list_calls`.
+    pub fn list_calls(&self, query_params: &[(String, String)]) -> Result<CallsListResponse, SignalWireError> {
+        block_on(self.client.list_calls(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_calls_all`.
+    pub fn list_calls_all(&self, query_params: &[(String, String)]) -> Result<Vec<CallResponse>, SignalWireError> {
+        block_on(self.client.list_calls_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_conference_participants`.
+    pub fn list_conference_participants(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<ConferenceParticipantsListResponse, SignalWireError> {
+        block_on(self.client.list_conference_participants(conference_sid, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_conference_participants_all`.
+    pub fn list_conference_participants_all(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<Vec<ConferenceParticipant>, SignalWireError> {
+        block_on(self.client.list_conference_participants_all(conference_sid, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_conference_participant`.
+    pub fn get_conference_participant(&self, conference_sid: &ConferenceSid, call_sid: &CallSid) -> Result<ConferenceParticipant, SignalWireError> {
+        block_on(self.client.get_conference_participant(conference_sid, call_sid))
+    }
+
+    /// Blocking version of `SignalWireClient::update_conference_participant`.
+    pub fn update_conference_participant(&self, conference_sid: &ConferenceSid, call_sid: &CallSid, update: &ParticipantUpdate) -> Result<ConferenceParticipant, SignalWireError> {
+        block_on(self.client.update_conference_participant(conference_sid, call_sid, update))
+    }
+
+    /// Blocking version of `SignalWireClient::list_conference_recordings`.
+    pub fn list_conference_recordings(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<ConferenceRecordingsListResponse, SignalWireError> {
+        block_on(self.client.list_conference_recordings(conference_sid, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_conference_recordings_all`.
+    pub fn list_conference_recordings_all(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<Vec<ConferenceRecording>, SignalWireError> {
+        block_on(self.client.list_conference_recordings_all(conference_sid, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::start_conference_recording`.
+    pub fn start_conference_recording(&self, conference_sid: &ConferenceSid, status_callback: Option<&str>) -> Result<ConferenceRecording, SignalWireError> {
+        block_on(self.client.start_conference_recording(conference_sid, status_callback))
+    }
+
+    /// Blocking version of `SignalWireClient::get_conference_recording`.
+    pub fn get_conference_recording(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid) -> Result<ConferenceRecording, SignalWireError> {
+        block_on(self.client.get_conference_recording(conference_sid, recording_sid))
+    }
+
+    /// Blocking version of `SignalWireClient::update_conference_recording`.
+    pub fn update_conference_recording(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid, status: ConferenceRecordingStatus) -> Result<ConferenceRecording, SignalWireError> {
+        block_on(self.client.update_conference_recording(conference_sid, recording_sid, status))
+    }
+
+    /// Blocking version of `SignalWireClient::stop_conference_recording`.
+    pub fn stop_conference_recording(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid) -> Result<ConferenceRecording, SignalWireError> {
+        block_on(self.client.stop_conference_recording(conference_sid, recording_sid))
+    }
+
+    /// Blocking version of `SignalWireClient::delete_conference_recording`.
+    pub fn delete_conference_recording(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid) -> Result<(), SignalWireError> {
+        block_on(self.client.delete_conference_recording(conference_sid, recording_sid))
+    }
+
+    /// Blocking version of `SignalWireClient::download_recording`.
+    pub fn download_recording<W: std::io::Write>(&self, recording_sid: &RecordingSid, format: RecordingFormat, writer: &mut W) -> Result<u64, SignalWireError> {
+        block_on(self.client.write_recording(recording_sid, format, writer))
+    }
+
+    /// Blocking version of `SignalWireClient::list_subprojects`.
+    pub fn list_subprojects(&self, query_params: &[(String, String)]) -> Result<SubprojectsListResponse, SignalWireError> {
+        block_on(self.client.list_subprojects(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_subprojects_all`.
+    pub fn list_subprojects_all(&self, query_params: &[(String, String)]) -> Result<Vec<SubprojectResponse>, SignalWireError> {
+        block_on(self.client.list_subprojects_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_subproject`.
+    pub fn get_subproject(&self, subproject_sid: &AccountSid) -> Result<SubprojectResponse, SignalWireError> {
+        block_on(self.client.get_subproject(subproject_sid))
+    }
+
+    /// Blocking version of `SignalWireClient::create_subproject`.
+    pub fn create_subproject(&self, friendly_name: &str) -> Result<SubprojectResponse, SignalWireError> {
+        block_on(self.client.create_subproject(friendly_name))
+    }
+
+    /// Blocking version of `SignalWireClient::update_subproject`.
+    pub fn update_subproject(&self, subproject_sid: &AccountSid, friendly_name: &str, status: Option<&str>) -> Result<SubprojectResponse, SignalWireError> {
+        block_on(self.client.update_subproject(subproject_sid, friendly_name, status))
+    }
+
+    /// Blocking version of `SignalWireClient::delete_subproject`.
+    pub fn delete_subproject(&self, subproject_sid: &AccountSid) -> Result<(), SignalWireError> {
+        block_on(self.client.delete_subproject(subproject_sid))
+    }
+
+    /// Blocking version of `SignalWireClient::get_subproject_phone_numbers`.
+    pub fn get_subproject_phone_numbers(&self, subproject_sid: &AccountSid, query_params: &[(String, String)]) -> Result<SubprojectPhoneNumbersResponse, SignalWireError> {
+        block_on(self.client.get_subproject_phone_numbers(subproject_sid, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_subproject_phone_numbers_all`.
+    pub fn get_subproject_phone_numbers_all(&self, subproject_sid: &AccountSid, query_params: &[(String, String)]) -> Result<Vec<SubprojectPhoneNumber>, SignalWireError> {
+        block_on(self.client.get_subproject_phone_numbers_all(subproject_sid, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::lookup_phone_number`.
+    pub fn lookup_phone_number(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        block_on(self.client.lookup_phone_number(phone_number))
+    }
+
+    /// Blocking version of `SignalWireClient::lookup_phone_numbers`.
+    pub fn lookup_phone_numbers(&self, phone_numbers: Vec<String>, executor: &BatchExecutor) -> BatchReport<String, PhoneLookupResponse> {
+        block_on(self.client.lookup_phone_numbers(phone_numbers, executor))
+    }
+
+    /// Blocking version of `SignalWireClient::lookup_phone_number_with_carrier`.
+    pub fn lookup_phone_number_with_carrier(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        block_on(self.client.lookup_phone_number_with_carrier(phone_number))
+    }
+
+    /// Blocking version of `SignalWireClient::lookup_phone_number_with_caller_name`.
+    pub fn lookup_phone_number_with_caller_name(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        block_on(self.client.lookup_phone_number_with_caller_name(phone_number))
+    }
+
+    /// Blocking version of `SignalWireClient::list_swml_scripts`.
+    pub fn list_swml_scripts(&self, query_params: &[(String, String)]) -> Result<SwmlScriptsListResponse, SignalWireError> {
+        block_on(self.client.list_swml_scripts(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_swml_scripts_all`.
+    pub fn list_swml_scripts_all(&self, query_params: &[(String, String)]) -> Result<Vec<SwmlScriptResponse>, SignalWireError> {
+        block_on(self.client.list_swml_scripts_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_swml_script`.
+    pub fn get_swml_script(&self, id: &str) -> Result<SwmlScriptResponse, SignalWireError> {
+        block_on(self.client.get_swml_script(id))
+    }
+
+    /// Blocking version of `SignalWireClient::create_swml_script`.
+    pub fn create_swml_script(&self, name: &str, swml: &Swml) -> Result<SwmlScriptResponse, SignalWireError> {
+        block_on(self.client.create_swml_script(name, swml))
+    }
+
+    /// Blocking version of `SignalWireClient::update_swml_script`.
+    pub fn update_swml_script(&self, id: &str, name: Option<&str>, swml: Option<&Swml>) -> Result<SwmlScriptResponse, SignalWireError> {
+        block_on(self.client.update_swml_script(id, name, swml))
+    }
+
+    /// Blocking version of `SignalWireClient::delete_swml_script`.
+    pub fn delete_swml_script(&self, id: &str) -> Result<(), SignalWireError> {
+        block_on(self.client.delete_swml_script(id))
+    }
+
+    /// Blocking version of `SignalWireClient::send_task`.
+    pub fn send_task(&self, context: &str, message: &serde_json::Value) -> Result<(), SignalWireError> {
+        block_on(self.client.send_task(context, message))
+    }
+
+    /// Blocking version of `SignalWireClient::create_room_token`.
+    pub fn create_room_token(&self, room_name: &str, member_name: &str, permissions: &RoomPermissions) -> Result<RoomTokenResponse, SignalWireError> {
+        block_on(self.client.create_room_token(room_name, member_name, permissions))
+    }
+
+    /// Blocking version of `SignalWireClient::list_room_recordings`.
+    pub fn list_room_recordings(&self, query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        block_on(self.client.list_room_recordings(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_room_recordings_all`.
+    pub fn list_room_recordings_all(&self, query_params: &[(String, String)]) -> Result<Vec<RoomRecording>, SignalWireError> {
+        block_on(self.client.list_room_recordings_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_room_session_recordings`.
+    pub fn list_room_session_recordings(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        block_on(self.client.list_room_session_recordings(room_session_id, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_room_session_recordings_all`.
+    pub fn list_room_session_recordings_all(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<Vec<RoomRecording>, SignalWireError> {
+        block_on(self.client.list_room_session_recordings_all(room_session_id, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_room_recording`.
+    pub fn get_room_recording(&self, id: &str) -> Result<RoomRecording, SignalWireError> {
+        block_on(self.client.get_room_recording(id))
+    }
+
+    /// Blocking version of `SignalWireClient::delete_room_recording`.
+    pub fn delete_room_recording(&self, id: &str) -> Result<(), SignalWireError> {
+        block_on(self.client.delete_room_recording(id))
+    }
+
+    /// Blocking version of `SignalWireClient::download_room_recording`.
+    pub fn download_room_recording<W: std::io::Write>(&self, id: &str, writer: &mut W) -> Result<u64, SignalWireError> {
+        block_on(self.client.write_room_recording(id, writer))
+    }
+
+    /// Blocking version of `SignalWireClient::list_room_sessions`.
+    pub fn list_room_sessions(&self, query_params: &[(String, String)]) -> Result<RoomSessionsListResponse, SignalWireError> {
+        block_on(self.client.list_room_sessions(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_room_sessions_all`.
+    pub fn list_room_sessions_all(&self, query_params: &[(String, String)]) -> Result<Vec<RoomSession>, SignalWireError> {
+        block_on(self.client.list_room_sessions_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_room_session`.
+    pub fn get_room_session(&self, id: &str) -> Result<RoomSession, SignalWireError> {
+        block_on(self.client.get_room_session(id))
+    }
+
+    /// Blocking version of `SignalWireClient::list_room_session_members`.
+    pub fn list_room_session_members(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<RoomMembersListResponse, SignalWireError> {
+        block_on(self.client.list_room_session_members(room_session_id, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_room_session_members_all`.
+    pub fn list_room_session_members_all(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<Vec<RoomMember>, SignalWireError> {
+        block_on(self.client.list_room_session_members_all(room_session_id, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::create_chat_token`.
+    pub fn create_chat_token(
+        &self,
+        ttl: u32,
+        channels: &std::collections::BTreeMap<String, ChatChannelPermissions>,
+        member_id: Option<&str>,
+        state: Option<&serde_json::Value>,
+    ) -> Result<ChatTokenResponse, SignalWireError> {
+        block_on(self.client.create_chat_token(ttl, channels, member_id, state))
+    }
+
+    /// Blocking version of `SignalWireClient::list_fabric_subscribers`.
+    pub fn list_fabric_subscribers(&self, query_params: &[(String, String)]) -> Result<FabricSubscribersListResponse, SignalWireError> {
+        block_on(self.client.list_fabric_subscribers(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_fabric_subscribers_all`.
+    pub fn list_fabric_subscribers_all(&self, query_params: &[(String, String)]) -> Result<Vec<FabricSubscriberResponse>, SignalWireError> {
+        block_on(self.client.list_fabric_subscribers_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_fabric_subscriber`.
+    pub fn get_fabric_subscriber(&self, id: &str) -> Result<FabricSubscriberResponse, SignalWireError> {
+        block_on(self.client.get_fabric_subscriber(id))
+    }
+
+    /// Blocking version of `SignalWireClient::create_fabric_subscriber`.
+    pub fn create_fabric_subscriber(&self, request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        block_on(self.client.create_fabric_subscriber(request))
+    }
+
+    /// Blocking version of `SignalWireClient::update_fabric_subscriber`.
+    pub fn update_fabric_subscriber(&self, id: &str, request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        block_on(self.client.update_fabric_subscriber(id, request))
+    }
+
+    /// Blocking version of `SignalWireClient::delete_fabric_subscriber`.
+    pub fn delete_fabric_subscriber(&self, id: &str) -> Result<(), SignalWireError> {
+        block_on(self.client.delete_fabric_subscriber(id))
+    }
+
+    /// Blocking version of `SignalWireClient::list_fabric_addresses`.
+    pub fn list_fabric_addresses(&self, query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        block_on(self.client.list_fabric_addresses(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_fabric_addresses_all`.
+    pub fn list_fabric_addresses_all(&self, query_params: &[(String, String)]) -> Result<Vec<FabricAddress>, SignalWireError> {
+        block_on(self.client.list_fabric_addresses_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_fabric_address`.
+    pub fn get_fabric_address(&self, id: &str) -> Result<FabricAddress, SignalWireError> {
+        block_on(self.client.get_fabric_address(id))
+    }
+
+    /// Blocking version of `SignalWireClient::resolve_fabric_address`.
+    pub fn resolve_fabric_address(&self, name: &str, address_type: Option<FabricAddressType>) -> Result<FabricAddress, SignalWireError> {
+        block_on(self.client.resolve_fabric_address(name, address_type))
+    }
+
+    /// Blocking version of `SignalWireClient::list_fabric_resources`.
+    pub fn list_fabric_resources(&self, query_params: &[(String, String)]) -> Result<FabricResourcesListResponse, SignalWireError> {
+        block_on(self.client.list_fabric_resources(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_fabric_resources_all`.
+    pub fn list_fabric_resources_all(&self, query_params: &[(String, String)]) -> Result<Vec<FabricResource>, SignalWireError> {
+        block_on(self.client.list_fabric_resources_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_fabric_resource`.
+    pub fn get_fabric_resource(&self, id: &str) -> Result<FabricResource, SignalWireError> {
+        block_on(self.client.get_fabric_resource(id))
+    }
+
+    /// Blocking version of `SignalWireClient::create_fabric_resource`.
+    pub fn create_fabric_resource(&self, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        block_on(self.client.create_fabric_resource(resource_type, fields))
+    }
+
+    /// Blocking version of `SignalWireClient::update_fabric_resource`.
+    pub fn update_fabric_resource(&self, id: &str, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        block_on(self.client.update_fabric_resource(id, resource_type, fields))
+    }
+
+    /// Blocking version of `SignalWireClient::delete_fabric_resource`.
+    pub fn delete_fabric_resource(&self, id: &str) -> Result<(), SignalWireError> {
+        block_on(self.client.delete_fabric_resource(id))
+    }
+
+    /// Blocking version of `SignalWireClient::list_fabric_resource_addresses`.
+    pub fn list_fabric_resource_addresses(&self, id: &str, query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        block_on(self.client.list_fabric_resource_addresses(id, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_fabric_resource_addresses_all`.
+    pub fn list_fabric_resource_addresses_all(&self, id: &str, query_params: &[(String, String)]) -> Result<Vec<FabricAddress>, SignalWireError> {
+        block_on(self.client.list_fabric_resource_addresses_all(id, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::assign_fabric_resource_phone_number`.
+    pub fn assign_fabric_resource_phone_number(&self, id: &str, phone_number_id: &PhoneNumberId, handler: FabricHandler) -> Result<FabricAddress, SignalWireError> {
+        block_on(self.client.assign_fabric_resource_phone_number(id, phone_number_id, handler))
+    }
+
+    /// Blocking version of `SignalWireClient::create_fabric_subscriber_token`.
+    pub fn create_fabric_subscriber_token(&self, reference: &str, ttl: std::time::Duration) -> Result<FabricSubscriberTokenResponse, SignalWireError> {
+        block_on(self.client.create_fabric_subscriber_token(reference, ttl))
+    }
+
+    /// Blocking version of `SignalWireClient::create_fabric_guest_token`.
+    pub fn create_fabric_guest_token(&self, allowed_addresses: &[&str], ttl: std::time::Duration) -> Result<FabricGuestTokenResponse, SignalWireError> {
+        block_on(self.client.create_fabric_guest_token(allowed_addresses, ttl))
+    }
+
+    /// Blocking version of `SignalWireClient::list_ai_agents`.
+    pub fn list_ai_agents(&self, query_params: &[(String, String)]) -> Result<AiAgentsListResponse, SignalWireError> {
+        block_on(self.client.list_ai_agents(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_ai_agents_all`.
+    pub fn list_ai_agents_all(&self, query_params: &[(String, String)]) -> Result<Vec<AiAgentResponse>, SignalWireError> {
+        block_on(self.client.list_ai_agents_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_ai_agent`.
+    pub fn get_ai_agent(&self, id: &str) -> Result<AiAgentResponse, SignalWireError> {
+        block_on(self.client.get_ai_agent(id))
+    }
+
+    /// Blocking version of `SignalWireClient::create_ai_agent`.
+    pub fn create_ai_agent(&self, name: &str, agent: &Ai) -> Result<AiAgentResponse, SignalWireError> {
+        block_on(self.client.create_ai_agent(name, agent))
+    }
+
+    /// Blocking version of `SignalWireClient::update_ai_agent`.
+    pub fn update_ai_agent(&self, id: &str, name: Option<&str>, agent: Option<&Ai>) -> Result<AiAgentResponse, SignalWireError> {
+        block_on(self.client.update_ai_agent(id, name, agent))
+    }
+
+    /// Blocking version of `SignalWireClient::delete_ai_agent`.
+    pub fn delete_ai_agent(&self, id: &str) -> Result<(), SignalWireError> {
+        block_on(self.client.delete_ai_agent(id))
+    }
+
+    /// Blocking version of `SignalWireClient::upload_datasphere_document`.
+    pub fn upload_datasphere_document(&self, filename: &str, contents: Vec<u8>, options: &DatasphereUploadOptions) -> Result<DatasphereDocument, SignalWireError> {
+        block_on(self.client.upload_datasphere_document(filename, contents, options))
+    }
+
+    /// Blocking version of `SignalWireClient::datasphere_search`.
+    pub fn datasphere_search(&self, query: &str, filters: &DatasphereSearchFilters) -> Result<DatasphereSearchResponse, SignalWireError> {
+        block_on(self.client.datasphere_search(query, filters))
+    }
+
+    /// Blocking version of `SignalWireClient::list_datasphere_documents`.
+    pub fn list_datasphere_documents(&self, query_params: &[(String, String)]) -> Result<DatasphereDocumentsListResponse, SignalWireError> {
+        block_on(self.client.list_datasphere_documents(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_datasphere_documents_all`.
+    pub fn list_datasphere_documents_all(&self, query_params: &[(String, String)]) -> Result<Vec<DatasphereDocument>, SignalWireError> {
+        block_on(self.client.list_datasphere_documents_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_datasphere_document`.
+    pub fn get_datasphere_document(&self, id: &str) -> Result<DatasphereDocument, SignalWireError> {
+        block_on(self.client.get_datasphere_document(id))
+    }
+
+    /// Blocking version of `SignalWireClient::list_datasphere_chunks`.
+    pub fn list_datasphere_chunks(&self, document_id: &str, query_params: &[(String, String)]) -> Result<DatasphereChunksListResponse, SignalWireError> {
+        block_on(self.client.list_datasphere_chunks(document_id, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_datasphere_chunks_all`.
+    pub fn list_datasphere_chunks_all(&self, document_id: &str, query_params: &[(String, String)]) -> Result<Vec<DatasphereChunk>, SignalWireError> {
+        block_on(self.client.list_datasphere_chunks_all(document_id, query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_datasphere_chunk`.
+    pub fn get_datasphere_chunk(&self, document_id: &str, id: &str) -> Result<DatasphereChunk, SignalWireError> {
+        block_on(self.client.get_datasphere_chunk(document_id, id))
+    }
+
+    /// Blocking version of `SignalWireClient::list_message_logs`.
+    pub fn list_message_logs(&self, query: &LogQuery) -> Result<MessageLogsListResponse, SignalWireError> {
+        block_on(self.client.list_message_logs(query))
+    }
+
+    /// Blocking version of `SignalWireClient::list_message_logs_all`.
+    pub fn list_message_logs_all(&self, query: &LogQuery) -> Result<Vec<MessageLog>, SignalWireError> {
+        block_on(self.client.list_message_logs_all(query))
+    }
+
+    /// Blocking version of `SignalWireClient::get_message_log`.
+    pub fn get_message_log(&self, id: &str) -> Result<MessageLog, SignalWireError> {
+        block_on(self.client.get_message_log(id))
+    }
+
+    /// Blocking version of `SignalWireClient::list_voice_logs`.
+    pub fn list_voice_logs(&self, query: &LogQuery) -> Result<VoiceLogsListResponse, SignalWireError> {
+        block_on(self.client.list_voice_logs(query))
+    }
+
+    /// Blocking version of `SignalWireClient::list_voice_logs_all`.
+    pub fn list_voice_logs_all(&self, query: &LogQuery) -> Result<Vec<VoiceLog>, SignalWireError> {
+        block_on(self.client.list_voice_logs_all(query))
+    }
+
+    /// Blocking version of `SignalWireClient::get_voice_log`.
+    pub fn get_voice_log(&self, id: &str) -> Result<VoiceLog, SignalWireError> {
+        block_on(self.client.get_voice_log(id))
+    }
+
+    /// Blocking version of `SignalWireClient::list_sip_endpoints`.
+    pub fn list_sip_endpoints(&self, query_params: &[(String, String)]) -> Result<SipEndpointsListResponse, SignalWireError> {
+        block_on(self.client.list_sip_endpoints(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_sip_endpoints_all`.
+    pub fn list_sip_endpoints_all(&self, query_params: &[(String, String)]) -> Result<Vec<SipEndpoint>, SignalWireError> {
+        block_on(self.client.list_sip_endpoints_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_sip_endpoint`.
+    pub fn get_sip_endpoint(&self, id: &str) -> Result<SipEndpoint, SignalWireError> {
+        block_on(self.client.get_sip_endpoint(id))
+    }
+
+    /// Blocking version of `SignalWireClient::create_sip_endpoint`.
+    pub fn create_sip_endpoint(&self, request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        block_on(self.client.create_sip_endpoint(request))
+    }
+
+    /// Blocking version of `SignalWireClient::update_sip_endpoint`.
+    pub fn update_sip_endpoint(&self, id: &str, request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        block_on(self.client.update_sip_endpoint(id, request))
+    }
+
+    /// Blocking version of `SignalWireClient::delete_sip_endpoint`.
+    pub fn delete_sip_endpoint(&self, id: &str) -> Result<(), SignalWireError> {
+        block_on(self.client.delete_sip_endpoint(id))
+    }
+
+    /// Blocking version of `SignalWireClient::list_domain_applications`.
+    pub fn list_domain_applications(&self, query_params: &[(String, String)]) -> Result<DomainApplicationsListResponse, SignalWireError> {
+        block_on(self.client.list_domain_applications(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::list_domain_applications_all`.
+    pub fn list_domain_applications_all(&self, query_params: &[(String, String)]) -> Result<Vec<DomainApplication>, SignalWireError> {
+        block_on(self.client.list_domain_applications_all(query_params))
+    }
+
+    /// Blocking version of `SignalWireClient::get_domain_application`.
+    pub fn get_domain_application(&self, id: &str) -> Result<DomainApplication, SignalWireError> {
+        block_on(self.client.get_domain_application(id))
+    }
+
+    /// Blocking version of `SignalWireClient::create_domain_application`.
+    pub fn create_domain_application(&self, request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        block_on(self.client.create_domain_application(request))
+    }
+
+    /// Blocking version of `SignalWireClient::update_domain_application`.
+    pub fn update_domain_application(&self, id: &str, request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        block_on(self.client.update_domain_application(id, request))
+    }
+
+    /// Blocking version of `SignalWireClient::delete_domain_application`.
+    pub fn delete_domain_application(&self, id: &str) -> Result<(), SignalWireError> {
+        block_on(self.client.delete_domain_application(id))
+    }
+
+    /// Blocking version of `SignalWireClient::mfa_send_sms`.
+    pub fn mfa_send_sms(&self, to: &str, options: &MfaOptions) -> Result<MfaResponse, SignalWireError> {
+        block_on(self.client.mfa_send_sms(to, options))
+    }
+
+    /// Blocking version of `SignalWireClient::mfa_send_call`.
+    pub fn mfa_send_call(&self, to: &str) -> Result<MfaResponse, SignalWireError> {
+        block_on(self.client.mfa_send_call(to))
+    }
+
+    /// Blocking version of `SignalWireClient::mfa_verify`.
+    pub fn mfa_verify(&self, request_id: &str, token: &str) -> Result<MfaVerification, SignalWireError> {
+        block_on(self.client.mfa_verify(request_id, token))
+    }
+}
+
+impl From<SignalWireClient> for BlockingSignalWireClient {
+    fn from(client: SignalWireClient) -> Self {
+        BlockingSignalWireClient { client }
+    }
+}
@@ -0,0 +1,95 @@
+//! Opt-in caching of slow-changing resources, see `SignalWireClient::with_response_cache`.
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use crate::{rt::Instant, sansio::HttpResponse};
+
+/// How long and how many responses are cached, see `SignalWireClient::with_response_cache`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheOptions {
+    pub ttl: Duration,
+    /// Once full, expired responses are dropped first, then the oldest.
+    pub max_entries: usize,
+}
+
+impl Default for CacheOptions {
+    /// One minute, at most 1,000 responses.
+    fn default() -> Self {
+        CacheOptions {
+            ttl: Duration::from_secs(60),
+            max_entries: 1_000,
+        }
+    }
+}
+
+impl CacheOptions {
+    pub fn new(ttl: Duration) -> Self {
+        CacheOptions { ttl, ..Default::default() }
+    }
+}
+
+// The operations whose responses are cached
+const CACHED: &[&str] = &["get_phone_numbers_owned", "list_subprojects", "get_subproject", "get_subproject_phone_numbers"];
+
+// The operations changing them, which clear the cache
+const INVALIDATING: &[&str] = &[
+    "buy_phone_number",
+    "update_phone_number",
+    "assign_fabric_resource_phone_number",
+    "create_subproject",
+    "update_subproject",
+    "delete_subproject",
+];
+
+/// Successful responses of the `CACHED` operations, by project and URL.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    options: CacheOptions,
+    entries: Mutex<HashMap<String, (Instant, HttpResponse)>>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(options: CacheOptions) -> Self {
+        ResponseCache { options, entries: Mutex::default() }
+    }
+
+    pub(crate) fn caches(operation: &str) -> bool {
+        CACHED.contains(&operation)
+    }
+
+    /// The cached response of `key`, unless it expired.
+    pub(crate) fn get(&self, key: &str) -> Option<HttpResponse> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((stored, response)) if stored.elapsed() < self.options.ttl => Some(response.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Caches the successful responses of the cached operations, and clears everything after a change.
+    pub(crate) fn record(&self, operation: &str, key: &str, response: &HttpResponse) {
+        if INVALIDATING.contains(&operation) {
+            self.clear();
+        } else if Self::caches(operation) && (200..300).contains(&response.status) && self.options.max_entries > 0 {
+            let mut entries = self.entries.lock().unwrap();
+            if entries.len() >= self.options.max_entries {
+                let ttl = self.options.ttl;
+                entries.retain(|_, (stored, _)| stored.elapsed() < ttl);
+            }
+            if entries.len() >= self.options.max_entries {
+                if let Some(oldest) = entries.iter().min_by_key(|(_, (stored, _))| *stored).map(|(key, _)| key.clone()) {
+                    entries.remove(&oldest);
+                }
+            }
+            entries.insert(key.to_string(), (Instant::now(), response.clone()));
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
@@ -0,0 +1,288 @@
+//! VCR-style record/replay of SignalWire API interactions.
+//!
+//! In `Record` mode every request goes to the real API and the response is appended to the
+//! cassette. In `Replay` mode nothing leaves the process: requests are matched by method and URL
+//! against the recorded interactions, in order, and the recorded responses are returned.
+//!
+//! The base URL, space name, project ID and API key of the recording client are replaced with
+//! placeholders before anything is written, and put back (with the replaying client's values)
+//! on replay, so cassettes can be committed and replayed in CI with dummy credentials.
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//!
+//! use signalwire::{cassette::Cassette, client::SignalWireClient};
+//!
+//! # async fn example() -> Result<(), signalwire::errors::SignalWireError> {
+//! // Records when SIGNALWIRE_CASSETTE_MODE=record, replays otherwise.
+//! let cassette = Arc::new(Cassette::from_env("tests/fixtures/lookup.json")?);
+//! let client = SignalWireClient::new("space", "project", "key").with_cassette(cassette.clone());
+//!
+//! client.lookup_phone_number("+12065550100").await?;
+//! cassette.save()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{client::SignalWireClient, errors::SignalWireError};
+
+/// The environment variable read by `Cassette::from_env`.
+pub const CASSETTE_MODE_ENV: &str = "SIGNALWIRE_CASSETTE_MODE";
+
+const BASE_URL_PLACEHOLDER: &str = "{SIGNALWIRE_BASE_URL}";
+const SPACE_NAME_PLACEHOLDER: &str = "{SIGNALWIRE_SPACE_NAME}";
+const PROJECT_ID_PLACEHOLDER: &str = "{SIGNALWIRE_PROJECT_ID}";
+const API_KEY_PLACEHOLDER: &str = "{SIGNALWIRE_API_KEY}";
+
+/// Form fields holding secrets of the request rather than of the client, replaced whatever their value.
+const SECRET_FIELDS: &[&str] = &["SipAuthPassword"];
+const SECRET_FIELD_PLACEHOLDER: &str = "{REDACTED}";
+
+/// Whether a cassette talks to the real API or serves recorded responses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+/// A single recorded request/response pair.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub operation: String,
+    pub method: String,
+    pub url: String,
+    pub request_body: Option<String>,
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Default)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+    dirty: bool,
+}
+
+/// A fixture file of recorded interactions.
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<CassetteState>,
+}
+
+impl Cassette {
+    /// Creates an empty cassette that will record to `path`.
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Record,
+            state: Mutex::new(CassetteState::default()),
+        }
+    }
+
+    /// Loads the interactions recorded in `path` for replay.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unexpected` if the file can't be read or parsed.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, SignalWireError> {
+        let contents = fs::read_to_string(path.as_ref()).map_err(|e| SignalWireError::Unexpected(format!("Failed to read cassette {}: {}", path.as_ref().display(), e)))?;
+        let file: CassetteFile = serde_json::from_str(&contents).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse cassette {}: {}", path.as_ref().display(), e)))?;
+        let used = vec![false; file.interactions.len()];
+
+        Ok(Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Replay,
+            state: Mutex::new(CassetteState {
+                interactions: file.interactions,
+                used,
+                dirty: false,
+            }),
+        })
+    }
+
+    /// Records if `SIGNALWIRE_CASSETTE_MODE` is `record`, replays from `path` otherwise.
+    pub fn from_env(path: impl AsRef<Path>) -> Result<Self, SignalWireError> {
+        match env::var(CASSETTE_MODE_ENV) {
+            Ok(mode) if mode.eq_ignore_ascii_case("record") => Ok(Cassette::record(path)),
+            _ => Cassette::replay(path),
+        }
+    }
+
+    /// The mode this cassette was opened in.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// A copy of the interactions recorded so far (or loaded for replay).
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state.lock().unwrap().interactions.clone()
+    }
+
+    /// Writes the recorded interactions to the cassette file, creating parent directories as needed.
+    ///
+    /// This is a no-op in `Replay` mode. Recording cassettes are also saved when dropped.
+    pub fn save(&self) -> Result<(), SignalWireError> {
+        if self.mode == CassetteMode::Replay {
+            return Ok(());
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let file = CassetteFile { interactions: state.interactions.clone() };
+        let contents = serde_json::to_string_pretty(&file).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+
+        if let Some(parent) = self.path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| SignalWireError::Unexpected(format!("Failed to create cassette directory: {}", e)))?;
+        }
+        fs::write(&self.path, contents).map_err(|e| SignalWireError::Unexpected(format!("Failed to write cassette {}: {}", self.path.display(), e)))?;
+        state.dirty = false;
+
+        Ok(())
+    }
+
+    /// Records or replays `request` on behalf of `client`.
+    pub(crate) async fn intercept(&self, client: &SignalWireClient, operation: &'static str, request: reqwest::Request) -> Result<reqwest::Response, SignalWireError> {
+        let scrubber = Scrubber::for_client(client);
+        let method = request.method().to_string();
+        let url = scrubber.scrub(request.url().as_str());
+        let request_body = request.body().and_then(|body| body.as_bytes()).map(|bytes| scrubber.scrub(&String::from_utf8_lossy(bytes)));
+
+        match self.mode {
+            CassetteMode::Replay => {
+                let interaction = self.next_match(&method, &url)?;
+                let headers: Vec<(String, String)> = interaction.headers.iter().map(|(name, value)| (name.clone(), scrubber.restore(value))).collect();
+                build_response(interaction.status, &headers, scrubber.restore(&interaction.body))
+            }
+            CassetteMode::Record => {
+                let response = client.dispatch(operation, request).await?;
+                let status = response.status().as_u16();
+                let headers: Vec<(String, String)> = response
+                    .headers()
+                    .iter()
+                    .filter(|(name, _)| *name != reqwest::header::SET_COOKIE)
+                    .map(|(name, value)| (name.as_str().to_string(), String::from_utf8_lossy(value.as_bytes()).into_owned()))
+                    .collect();
+                let body = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+
+                let interaction = Interaction {
+                    operation: operation.to_string(),
+                    method,
+                    url,
+                    request_body,
+                    status,
+                    headers: headers.iter().map(|(name, value)| (name.clone(), scrubber.scrub(value))).collect(),
+                    body: scrubber.scrub(&body),
+                };
+
+                let mut state = self.state.lock().unwrap();
+                state.interactions.push(interaction);
+                state.used.push(true);
+                state.dirty = true;
+                drop(state);
+
+                build_response(status, &headers, body)
+            }
+        }
+    }
+
+    /// Finds the first unused interaction with the same method and scrubbed URL.
+    fn next_match(&self, method: &str, url: &str) -> Result<Interaction, SignalWireError> {
+        let mut state = self.state.lock().unwrap();
+        let index = (0..state.interactions.len())
+            .find(|&i| !state.used[i] && state.interactions[i].method == method && state.interactions[i].url == url)
+            .ok_or_else(|| SignalWireError::Unexpected(format!("No recorded interaction for {} {} in cassette {}", method, url, self.path.display())))?;
+        state.used[index] = true;
+
+        Ok(state.interactions[index].clone())
+    }
+}
+
+impl Drop for Cassette {
+    fn drop(&mut self) {
+        let dirty = self.state.lock().map(|state| state.dirty).unwrap_or(false);
+        if dirty {
+            let _ = self.save();
+        }
+    }
+}
+
+/// Replaces credentials with placeholders, and back.
+struct Scrubber {
+    secrets: Vec<(String, &'static str)>,
+}
+
+impl Scrubber {
+    fn for_client(client: &SignalWireClient) -> Self {
+        // The base URL goes first since it usually contains the space name, and the API key before the
+        // project ID so a key that happens to contain the project ID is still fully replaced.
+        let secrets = [
+            (client.base_url.clone(), BASE_URL_PLACEHOLDER),
+            (client.api_key.clone(), API_KEY_PLACEHOLDER),
+            (client.project_id.clone(), PROJECT_ID_PLACEHOLDER),
+            (client.space_name.clone(), SPACE_NAME_PLACEHOLDER),
+        ];
+
+        Scrubber {
+            secrets: secrets.into_iter().filter(|(secret, _)| !secret.is_empty()).collect(),
+        }
+    }
+
+    fn scrub(&self, value: &str) -> String {
+        let value = self.secrets.iter().fold(value.to_string(), |value, (secret, placeholder)| value.replace(secret.as_str(), placeholder));
+        scrub_fields(&value)
+    }
+
+    fn restore(&self, value: &str) -> String {
+        self.secrets.iter().fold(value.to_string(), |value, (secret, placeholder)| value.replace(placeholder, secret))
+    }
+}
+
+/// `value` with the values of the `SECRET_FIELDS` of a form, or of a query string, replaced.
+fn scrub_fields(value: &str) -> String {
+    let (prefix, form) = value.split_once('?').unwrap_or(("", value));
+    if !SECRET_FIELDS.iter().any(|field| form.contains(&format!("{}=", field))) {
+        return value.to_string();
+    }
+
+    let fields: Vec<String> = form
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((name, _)) if SECRET_FIELDS.contains(&name) => format!("{}={}", name, SECRET_FIELD_PLACEHOLDER),
+            _ => pair.to_string(),
+        })
+        .collect();
+    if value.contains('?') {
+        format!("{}?{}", prefix, fields.join("&"))
+    } else {
+        fields.join("&")
+    }
+}
+
+fn build_response(status: u16, headers: &[(String, String)], body: String) -> Result<reqwest::Response, SignalWireError> {
+    let mut builder = http::Response::builder().status(status);
+    for (name, value) in headers {
+        // The body is stored decoded, so the original encoding and length no longer apply.
+        if name.eq_ignore_ascii_case("content-encoding") || name.eq_ignore_ascii_case("content-length") || name.eq_ignore_ascii_case("transfer-encoding") {
+            continue;
+        }
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    let response = builder.body(body).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+
+    Ok(reqwest::Response::from(response))
+}
@@ -1,13 +1,92 @@
-use reqwest::{Client as HttpClient, Url};
-
-use crate::{errors::SignalWireError, types::*};
-
-#[derive(Debug)]
+use std::sync::Arc;
+
+use futures_util::Stream;
+use reqwest::Client as HttpClient;
+
+use crate::{
+    batch::{is_transient, BatchExecutor, BatchReport},
+    cache::{CacheOptions, ResponseCache},
+    coalesce::RequestCoalescer,
+    dry_run, endpoint,
+    errors::SignalWireError,
+    media::{ByteRange, MediaDownload},
+    messaging::{self, estimate_segments, BroadcastContent, BroadcastOptions, BroadcastRecipient, BroadcastReport, DedupeDecision, DuplicateGuard, MessageTemplate},
+    pagination::{self, CollectLimits, Paginated},
+    sansio::*,
+    scheduler::{Priority, RequestScheduler, SchedulerOptions},
+    streaming,
+    swml::{Ai, Swml},
+    transport::{self, HttpOptions},
+    types::*,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+    cassette::Cassette,
+    transport::{HttpTransport, ReqwestTransport},
+};
+
+#[derive(Debug, Clone)]
 pub struct SignalWireClient {
     pub project_id: String,
     pub api_key: String,
     pub space_name: String,
+    pub base_url: String,
     pub http_client: HttpClient,
+    #[cfg(not(target_arch = "wasm32"))]
+    transport: Arc<dyn HttpTransport>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub cassette: Option<Arc<Cassette>>,
+    pub dry_run: bool,
+    pub collect_limits: CollectLimits,
+    pub http_options: HttpOptions,
+    coalescer: Option<Arc<RequestCoalescer>>,
+    cache: Option<Arc<ResponseCache>>,
+    scheduler: Option<Arc<RequestScheduler>>,
+    priority: Priority,
+    duplicate_guard: Option<Arc<DuplicateGuard>>,
+}
+
+/// Reads the status, headers and body of a response.
+async fn read_response(response: reqwest::Response) -> Result<HttpResponse, SignalWireError> {
+    let status = response.status().as_u16();
+    let headers = response
+        .headers()
+        .iter()
+        .map(|(name, value)| (name.as_str().to_string(), String::from_utf8_lossy(value.as_bytes()).into_owned()))
+        .collect();
+    let body = response.text().await.map_err(|e| SignalWireError::Unexpected(transport::body_error(&e)))?;
+
+    Ok(HttpResponse { status, headers, body })
+}
+
+/// Passes a successful response on unread, to stream its body, and reads and maps any other with `check_status`.
+async fn checked_response(response: reqwest::Response, not_found: Option<String>) -> Result<reqwest::Response, SignalWireError> {
+    if response.status().is_success() {
+        return Ok(response);
+    }
+
+    let response = read_response(response).await?;
+    check_status(&response, not_found)?;
+    Err(SignalWireError::Unexpected(response.body))
+}
+
+/// The Unix timestamp `ttl` from now.
+fn expire_at(ttl: std::time::Duration) -> i64 {
+    i64::try_from(crate::rt::since_epoch().as_secs().saturating_add(ttl.as_secs())).unwrap_or(i64::MAX)
+}
+
+/// The size of the chunks uploads are streamed in, and progress reported at.
+#[cfg(not(target_arch = "wasm32"))]
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The MIME type of a Datasphere document, after its extension.
+fn document_content_type(filename: &str) -> &'static str {
+    match filename.rsplit_once('.').map(|(_, extension)| extension.to_lowercase()).as_deref() {
+        Some("pdf") => "application/pdf",
+        Some("md") => "text/markdown",
+        Some("txt") => "text/plain",
+        _ => "application/octet-stream",
+    }
 }
 
 impl SignalWireClient {
@@ -23,12 +102,333 @@ impl SignalWireClient {
     ///
     /// A new instance of `SignalWireClient`.
     pub fn new(space_name: &str, project_id: &str, api_key: &str) -> Self {
+        let http_client = HttpClient::new();
         SignalWireClient {
             space_name: space_name.to_string(),
             project_id: project_id.to_string(),
             api_key: api_key.to_string(),
-            http_client: HttpClient::new(),
+            base_url: endpoint::space_base_url(space_name),
+            #[cfg(not(target_arch = "wasm32"))]
+            transport: Arc::new(ReqwestTransport::new(http_client.clone())),
+            http_client,
+            #[cfg(not(target_arch = "wasm32"))]
+            cassette: None,
+            dry_run: false,
+            collect_limits: CollectLimits::default(),
+            http_options: HttpOptions::default(),
+            coalescer: None,
+            cache: None,
+            scheduler: None,
+            duplicate_guard: None,
+            priority: Priority::Normal,
+        }
+    }
+
+    /// Enables or disables dry-run mode.
+    ///
+    /// In dry-run mode billable operations (`send_sms`, `buy_phone_number`) validate their input and
+    /// build the request as usual, but return a synthesized response instead of calling SignalWire.
+    /// Other operations are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether billable operations should be short-circuited.
+    pub fn dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled;
+        self
+    }
+
+    /// Overrides the base URL requests are sent to, instead of `https://{space_name}.signalwire.com`.
+    ///
+    /// This is mostly useful for pointing the client at a local mock server or an egress proxy.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The scheme and host (and optionally port) to use, without a trailing slash.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Overrides how many items and pages the `*_all` methods collect before failing with
+    /// `SignalWireError::CollectLimit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - The caps, `CollectLimits::unbounded()` to collect whole lists whatever their size.
+    pub fn with_collect_limits(mut self, limits: CollectLimits) -> Self {
+        self.collect_limits = limits;
+        self
+    }
+
+    /// Rebuilds the HTTP client with the connection pool and timeout settings of `options`.
+    ///
+    /// Requests then go through a new `ReqwestTransport` on that client, replacing any transport set with
+    /// `with_transport`.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The settings, unset ones keep reqwest's defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::HttpError` if the HTTP client can't be built.
+    pub fn with_http_options(mut self, options: HttpOptions) -> Result<Self, SignalWireError> {
+        self.http_client = options.build_client()?;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.transport = Arc::new(ReqwestTransport::new(self.http_client.clone()));
+        }
+        self.http_options = options;
+        Ok(self)
+    }
+
+    /// Sends every request through `transport` instead of reqwest, e.g. another HTTP stack or an in-memory fake.
+    ///
+    /// Requests are still built with `http_client`, but its settings (timeouts, pool) only apply to transports
+    /// running on it.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - What sends the requests and brings the responses back.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Enables or disables sharing identical GET requests in flight.
+    ///
+    /// When enabled, concurrent GETs of the same URL (e.g. many tasks looking up one number or polling one
+    /// message status) send a single request and all receive its response. Clones of the client share the
+    /// requests in flight. Nothing is cached: a GET made after the response arrived is sent again.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to coalesce GET requests.
+    pub fn with_request_coalescing(mut self, enabled: bool) -> Self {
+        self.coalescer = enabled.then(Arc::default);
+        self
+    }
+
+    /// Caches owned phone numbers, subprojects and subproject numbers for `options.ttl`.
+    ///
+    /// Buying or updating a number and creating, updating or deleting a subproject through this client (or
+    /// one of its clones) clears the cache. Changes made elsewhere, e.g. in the dashboard, show up once the
+    /// cached responses expire, or after `clear_response_cache`.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - How long and how many responses to keep.
+    pub fn with_response_cache(mut self, options: CacheOptions) -> Self {
+        self.cache = Some(Arc::new(ResponseCache::new(options)));
+        self
+    }
+
+    /// Forgets every cached response, see `with_response_cache`.
+    pub fn clear_response_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Limits the requests of this client and its clones to `options`, queueing the others by priority.
+    ///
+    /// Under the limits requests start right away. Beyond them, the queued requests of the highest priority start
+    /// first, see `with_priority`.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - How many requests run at once, and how many start per second.
+    pub fn with_scheduler(mut self, options: SchedulerOptions) -> Self {
+        self.scheduler = Some(Arc::new(RequestScheduler::new(options)));
+        self
+    }
+
+    /// Checks every message of `send_sms` and the batch sends against `guard`, failing the duplicates with
+    /// `SignalWireError::DuplicateSuppressed` instead of sending them. Clones share the guard.
+    ///
+    /// A message whose send was answered with an error is forgotten, so it can be sent again; one whose request
+    /// failed on the way may have been sent, and stays recorded.
+    pub fn with_duplicate_guard(mut self, guard: DuplicateGuard) -> Self {
+        self.duplicate_guard = Some(Arc::new(guard));
+        self
+    }
+
+    /// Tags the requests of this client with `priority`, for the scheduler of `with_scheduler`.
+    ///
+    /// Clones share the scheduler, so a clone can carry interactive traffic at `Priority::High` while another
+    /// runs a bulk job at `Priority::Low`. Without a scheduler the priority has no effect.
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Records every API interaction to, or replays them from, `cassette`.
+    ///
+    /// See the `cassette` module for how credentials are scrubbed from the recorded fixtures.
+    ///
+    /// # Arguments
+    ///
+    /// * `cassette` - The cassette to record to or replay from.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_cassette(mut self, cassette: Arc<Cassette>) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Sends a prepared request and maps transport failures to `SignalWireError::HttpError`.
+    ///
+    /// When a cassette is attached the request is recorded or replayed instead of being sent as-is.
+    async fn send(&self, operation: &'static str, request: reqwest::RequestBuilder) -> Result<reqwest::Response, SignalWireError> {
+        let request = request.build().map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(cassette) = &self.cassette {
+            return cassette.intercept(self, operation, request).await;
+        }
+        self.dispatch(operation, request).await
+    }
+
+    /// The credentials and base URL operations are described against.
+    pub fn credentials(&self) -> Credentials {
+        Credentials {
+            project_id: self.project_id.clone(),
+            api_key: self.api_key.clone(),
+            base_url: self.base_url.clone(),
+        }
+    }
+
+    /// Executes any `sansio::Operation` with this client.
+    ///
+    /// Every endpoint method is a thin wrapper around this, so it can also be used to run
+    /// operations built elsewhere.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever error the operation maps the response to, or `SignalWireError::HttpError`
+    /// if the request can't be sent.
+    pub async fn execute<O: Operation>(&self, operation: &O) -> Result<O::Output, SignalWireError> {
+        self.execute_with_meta(operation).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `execute`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn execute_with_meta<O: Operation>(&self, operation: &O) -> Result<ResponseEnvelope<O::Output>, SignalWireError> {
+        let described = operation.request(&self.credentials())?;
+        self.execute_described(operation, described).await
+    }
+
+    /// Builds and signs the request of any `sansio::Operation` with this client's credentials, without sending it.
+    ///
+    /// See `PreparedRequest` for what can be done with it; `execute_prepared` sends it.
+    pub fn prepare<O: Operation>(&self, operation: O) -> Result<PreparedRequest<O>, SignalWireError> {
+        PreparedRequest::new(operation, &self.credentials())
+    }
+
+    /// Sends a request built by `prepare`, changes included, and parses its response.
+    ///
+    /// It goes through the same steps as `execute`: dry-run, caching, coalescing and the scheduler.
+    pub async fn execute_prepared<O: Operation>(&self, prepared: PreparedRequest<O>) -> Result<O::Output, SignalWireError> {
+        self.execute_prepared_with_meta(prepared).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `execute_prepared`, but also returns the HTTP status and response headers.
+    pub async fn execute_prepared_with_meta<O: Operation>(&self, prepared: PreparedRequest<O>) -> Result<ResponseEnvelope<O::Output>, SignalWireError> {
+        let (operation, request) = prepared.into_parts();
+        self.execute_described(&operation, request).await
+    }
+
+    async fn execute_described<O: Operation>(&self, operation: &O, described: HttpRequest) -> Result<ResponseEnvelope<O::Output>, SignalWireError> {
+        let credentials = self.credentials();
+        if self.dry_run {
+            if let Some(data) = operation.dry_run(&credentials) {
+                return Ok(ResponseEnvelope {
+                    data: data?,
+                    meta: dry_run::response_meta(),
+                });
+            }
+        }
+
+        let key = format!("{} {}", self.project_id, described.url);
+        if let Some(response) = self.cache.as_ref().filter(|_| ResponseCache::caches(O::NAME)).and_then(|cache| cache.get(&key)) {
+            let meta = response.meta();
+            return Ok(ResponseEnvelope { data: operation.parse(response)?, meta });
+        }
+
+        let coalescer = self.coalescer.clone().filter(|_| described.method == reqwest::Method::GET);
+        let mut request = self.request_builder(described);
+        // Only bodies read whole are worth compressing, streamed ones stay in constant memory
+        if let Some(encodings) = transport::accept_encoding() {
+            request = request.header(reqwest::header::ACCEPT_ENCODING, encodings);
+        }
+
+        let response = match coalescer {
+            Some(coalescer) => coalescer.run(key.clone(), self.fetch(O::NAME, request)).await?,
+            None => self.fetch(O::NAME, request).await?,
+        };
+        if let Some(cache) = &self.cache {
+            cache.record(O::NAME, &key, &response);
         }
+        let meta = response.meta();
+
+        Ok(ResponseEnvelope { data: operation.parse(response)?, meta })
+    }
+
+    /// Sends the request of an operation and returns the response unread, to stream its body.
+    ///
+    /// Error statuses are read and mapped with `check_status`. Dry-run, caching and coalescing don't apply.
+    pub(crate) async fn open(&self, operation: &'static str, request: HttpRequest) -> Result<reqwest::Response, SignalWireError> {
+        checked_response(self.send(operation, self.request_builder(request)).await?, None).await
+    }
+
+    fn request_builder(&self, described: HttpRequest) -> reqwest::RequestBuilder {
+        let mut request = self.http_client.request(described.method, &described.url);
+        for (name, value) in &described.headers {
+            request = request.header(name, value);
+        }
+        if let Some(body) = described.body {
+            request = request.body(body);
+        }
+        request
+    }
+
+    /// Sends a request and reads the whole response.
+    async fn fetch(&self, operation: &'static str, request: reqwest::RequestBuilder) -> Result<HttpResponse, SignalWireError> {
+        read_response(self.send(operation, request).await?).await
+    }
+
+    /// Executes a request on the network.
+    ///
+    /// With the `otel` feature enabled, every call is wrapped in a client span named after
+    /// `operation` and the current trace context is injected into the outgoing headers. With a
+    /// scheduler, the request first waits for its turn.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) async fn dispatch(&self, operation: &'static str, request: reqwest::Request) -> Result<reqwest::Response, SignalWireError> {
+        let _permit = match &self.scheduler {
+            Some(scheduler) => Some(scheduler.acquire(self.priority).await),
+            None => None,
+        };
+
+        let request = http::Request::try_from(request).map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+
+        #[cfg(feature = "otel")]
+        let response = crate::otel::instrumented_send(self.transport.as_ref(), operation, request).await?;
+
+        #[cfg(not(feature = "otel"))]
+        let response = self.transport.execute(operation, request).await?;
+
+        transport::decode_response(response.into()).await
+    }
+
+    /// Executes a request with the `fetch` API of the host, after waiting for its turn with a scheduler.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) async fn dispatch(&self, _operation: &'static str, request: reqwest::Request) -> Result<reqwest::Response, SignalWireError> {
+        let _permit = match &self.scheduler {
+            Some(scheduler) => Some(scheduler.acquire(self.priority).await),
+            None => None,
+        };
+
+        self.http_client.execute(request).await.map_err(|e| SignalWireError::HttpError(e.to_string()))
     }
 
     /// Retrieves a JSON Web Token (JWT) and a refresh token for authentication.
@@ -47,27 +447,17 @@ impl SignalWireClient {
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
     pub async fn get_jwt(&self) -> Result<JwtResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/relay/rest/jwt", self.space_name);
-        let response = self
-            .http_client
-            .post(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .header("Content-Length", "0")
-            .body("")
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        }
+        self.get_jwt_with_meta().await.map(ResponseEnvelope::into_inner)
+    }
 
-        let jwt_response: JwtResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+    /// Same as `get_jwt`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_jwt_with_meta(&self) -> Result<ResponseEnvelope<JwtResponse>, SignalWireError> {
+        self.execute_with_meta(&GetJwt).await
+    }
 
-        Ok(jwt_response)
+    /// Same as `get_jwt`, but returns the request instead of sending it, see `prepare`.
+    pub fn prepare_get_jwt(&self) -> Result<PreparedRequest<GetJwt>, SignalWireError> {
+        self.prepare(GetJwt)
     }
 
     /// Blocking version of `get_jwt`.
@@ -82,11 +472,11 @@ impl SignalWireClient {
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-
     #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_jwt`.")]
     #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_jwt` instead")]
     pub fn get_jwt_blocking(&self) -> Result<JwtResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.get_jwt())
+        crate::blocking::block_on(self.get_jwt())
     }
 
     /// Fetches available phone numbers for a given country.
@@ -101,32 +491,12 @@ impl SignalWireClient {
     ///
     /// A `Result` containing either an `PhoneNumbersAvailableResponse` or a `SignalWireError`.
     pub async fn get_phone_numbers_available(&self, iso_country: &str, query_params: &[(String, String)]) -> Result<PhoneNumbersAvailableResponse, SignalWireError> {
-        let url = format!(
-            "https://{}.signalwire.com/api/laml/2010-04-01/Accounts/{}/AvailablePhoneNumbers/{}/Local",
-            self.space_name, self.project_id, iso_country
-        );
-        println!("URL: {}", url);
-
-        let url = Url::parse_with_params(&url, query_params).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        let response = self
-            .http_client
-            .get(url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
-
-        let phone_numbers_response: PhoneNumbersAvailableResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+        self.get_phone_numbers_available_with_meta(iso_country, query_params).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(phone_numbers_response)
+    /// Same as `get_phone_numbers_available`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_phone_numbers_available_with_meta(&self, iso_country: &str, query_params: &[(String, String)]) -> Result<ResponseEnvelope<PhoneNumbersAvailableResponse>, SignalWireError> {
+        self.execute_with_meta(&GetPhoneNumbersAvailable { iso_country, query_params }).await
     }
 
     /// Blocking version of `get_phone_numbers_available`.
@@ -139,15 +509,18 @@ impl SignalWireClient {
     /// # Returns
     ///
     /// A `Result` containing either an `PhoneNumbersAvailableResponse` or a `SignalWireError`.
-
     #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_phone_numbers_available`.")]
     #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_phone_numbers_available` instead")]
     pub fn get_phone_numbers_available_blocking(&self, iso_country: &str, query_params: &[(String, String)]) -> Result<PhoneNumbersAvailableResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.get_phone_numbers_available(iso_country, query_params))
+        crate::blocking::block_on(self.get_phone_numbers_available(iso_country, query_params))
     }
 
     /// Retrieves a list of phone numbers owned by the client.
     ///
+    /// This is a single page, `get_phone_numbers_owned_all` and `get_phone_numbers_owned_stream` follow `links.next`
+    /// through every page.
+    ///
     /// # Arguments
     ///
     /// * `query_params` - Additional query parameters as key-value pairs.
@@ -163,30 +536,17 @@ impl SignalWireClient {
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
     pub async fn get_phone_numbers_owned(&self, query_params: &[(String, String)]) -> Result<PhoneNumbersOwnedResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/relay/rest/phone_numbers", self.space_name);
-
-        let url = Url::parse_with_params(&url, query_params).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        let response = self
-            .http_client
-            .get(url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+        self.get_phone_numbers_owned_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
 
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        } else {
-            let phone_numbers_response: PhoneNumbersOwnedResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+    /// Same as `get_phone_numbers_owned`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_phone_numbers_owned_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<PhoneNumbersOwnedResponse>, SignalWireError> {
+        self.execute_with_meta(&GetPhoneNumbersOwned { query_params }).await
+    }
 
-            Ok(phone_numbers_response)
-        }
+    /// Same as `get_phone_numbers_owned`, but returns the request instead of sending it, see `prepare`.
+    pub fn prepare_get_phone_numbers_owned<'a>(&self, query_params: &'a [(String, String)]) -> Result<PreparedRequest<GetPhoneNumbersOwned<'a>>, SignalWireError> {
+        self.prepare(GetPhoneNumbersOwned { query_params })
     }
 
     /// Blocking version of `get_phone_numbers_owned`.
@@ -207,8 +567,54 @@ impl SignalWireClient {
     /// Other `SignalWireError` variants may be returned for unexpected issues.
     #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_phone_numbers_owned`.")]
     #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_phone_numbers_owned` instead")]
     pub fn get_phone_numbers_owned_blocking(&self, query_params: &[(String, String)]) -> Result<PhoneNumbersOwnedResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.get_phone_numbers_owned(query_params))
+        crate::blocking::block_on(self.get_phone_numbers_owned(query_params))
+    }
+
+    /// Streams every item of `get_phone_numbers_owned`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn get_phone_numbers_owned_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<OwnedPhoneNumber, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.get_phone_numbers_owned(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Streams every item of `get_phone_numbers_owned`, decoding each page as it arrives rather than once read whole.
+    ///
+    /// Unlike `get_phone_numbers_owned_stream`, at most one number's JSON is held in memory, for exports of large
+    /// inventories. Dry-run and the response cache don't apply. Pin the stream to read it.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page or item, then ends.
+    pub fn get_phone_numbers_owned_streaming<'a>(&'a self, query_params: &[(String, String)]) -> impl Stream<Item = Result<OwnedPhoneNumber, SignalWireError>> + 'a {
+        let operation = GetPhoneNumbersOwned { query_params };
+        streaming::stream_list::<PhoneNumbersOwnedResponse>(self, GetPhoneNumbersOwned::NAME, operation.request(&self.credentials()))
+    }
+
+    /// Collects every item of `get_phone_numbers_owned` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn get_phone_numbers_owned_all(&self, query_params: &[(String, String)]) -> Result<Vec<OwnedPhoneNumber>, SignalWireError> {
+        pagination::collect_items(self, async { self.get_phone_numbers_owned(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `get_phone_numbers_owned_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `get_phone_numbers_owned_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_phone_numbers_owned_all` instead")]
+    pub fn get_phone_numbers_owned_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<OwnedPhoneNumber>, SignalWireError> {
+        crate::blocking::block_on(self.get_phone_numbers_owned_all(query_params))
     }
 
     /// Buy a phone number.
@@ -227,29 +633,13 @@ impl SignalWireClient {
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-
     pub async fn buy_phone_number(&self, phone_number: &str) -> Result<BuyPhoneNumberResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/relay/rest/phone_numbers", self.space_name);
-
-        let response = self
-            .http_client
-            .post(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .json(&BuyPhoneNumberRequest { number: phone_number.to_string() })
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
-
-        let buy_phone_number_response: BuyPhoneNumberResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+        self.buy_phone_number_with_meta(phone_number).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(buy_phone_number_response)
+    /// Same as `buy_phone_number`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn buy_phone_number_with_meta(&self, phone_number: &str) -> Result<ResponseEnvelope<BuyPhoneNumberResponse>, SignalWireError> {
+        self.execute_with_meta(&BuyPhoneNumber { phone_number }).await
     }
 
     /// Updates an existing phone number's configuration.
@@ -272,32 +662,23 @@ impl SignalWireClient {
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn update_phone_number(&self, id: &str, request: &UpdatePhoneNumberRequest) -> Result<BuyPhoneNumberResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/relay/rest/phone_numbers/{}", self.space_name, id);
-
-        let response = self
-            .http_client
-            .put(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .header("Accept", "application/json")
-            .json(request)
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
+    pub async fn update_phone_number(&self, id: &PhoneNumberId, request: &UpdatePhoneNumberRequest) -> Result<BuyPhoneNumberResponse, SignalWireError> {
+        self.update_phone_number_with_meta(id, request).await.map(ResponseEnvelope::into_inner)
+    }
 
-        let phone_number_response: BuyPhoneNumberResponse =
-            serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    /// Same as `update_phone_number`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_phone_number_with_meta(&self, id: &PhoneNumberId, request: &UpdatePhoneNumberRequest) -> Result<ResponseEnvelope<BuyPhoneNumberResponse>, SignalWireError> {
+        self.execute_with_meta(&UpdatePhoneNumber { id: id.as_str(), request }).await
+    }
 
-        Ok(phone_number_response)
+    /// Applies every update of `updates` with `executor`, reporting the result of each.
+    ///
+    /// # Arguments
+    ///
+    /// * `updates` - The ID of each phone number, with its new settings
+    /// * `executor` - How many updates run at a time, and how failures are retried
+    pub async fn update_phone_numbers(&self, updates: Vec<(PhoneNumberId, UpdatePhoneNumberRequest)>, executor: &BatchExecutor) -> BatchReport<(PhoneNumberId, UpdatePhoneNumberRequest), BuyPhoneNumberResponse> {
+        executor.run(updates, |(id, request)| async move { self.update_phone_number(&id, &request).await }).await
     }
 
     /// Blocking version of `buy_phone_number`.
@@ -316,11 +697,11 @@ impl SignalWireClient {
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-
     #[cfg_attr(feature = "blocking", doc = "Blocking version of `buy_phone_number`.")]
     #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::buy_phone_number` instead")]
     pub fn buy_phone_number_blocking(&self, phone_number: &str) -> Result<BuyPhoneNumberResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.buy_phone_number(phone_number))
+        crate::blocking::block_on(self.buy_phone_number(phone_number))
     }
 
     /// Blocking version of `update_phone_number`.
@@ -342,8 +723,9 @@ impl SignalWireClient {
     /// Other `SignalWireError` variants may be returned for unexpected issues.
     #[cfg_attr(feature = "blocking", doc = "Blocking version of `update_phone_number`.")]
     #[cfg(feature = "blocking")]
-    pub fn update_phone_number_blocking(&self, id: &str, request: &UpdatePhoneNumberRequest) -> Result<BuyPhoneNumberResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.update_phone_number(id, request))
+    #[deprecated(note = "use `BlockingSignalWireClient::update_phone_number` instead")]
+    pub fn update_phone_number_blocking(&self, id: &PhoneNumberId, request: &UpdatePhoneNumberRequest) -> Result<BuyPhoneNumberResponse, SignalWireError> {
+        crate::blocking::block_on(self.update_phone_number(id, request))
     }
 
     /// Sends an SMS message using the SignalWire API.
@@ -363,31 +745,94 @@ impl SignalWireClient {
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
     pub async fn send_sms(&self, message: &SmsMessage) -> Result<SmsResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/laml/2010-04-01/Accounts/{}/Messages", self.space_name, self.project_id);
+        self.send_sms_with_meta(message).await.map(ResponseEnvelope::into_inner)
+    }
 
-        let form = [("From", &message.from), ("To", &message.to), ("Body", &message.body)];
+    /// Same as `send_sms`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn send_sms_with_meta(&self, message: &SmsMessage) -> Result<ResponseEnvelope<SmsResponse>, SignalWireError> {
+        let Some(guard) = &self.duplicate_guard else {
+            return self.execute_with_meta(&SendSms { message }).await;
+        };
+        if let DedupeDecision::Suppressed { since } = guard.check(message) {
+            return Err(SignalWireError::DuplicateSuppressed { to: message.to.clone(), since });
+        }
 
-        let response = self
-            .http_client
-            .post(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .form(&form)
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+        let result = self.execute_with_meta(&SendSms { message }).await;
+        if result.as_ref().is_err_and(|error| !is_transient(error)) {
+            guard.forget(message);
+        }
+        result
+    }
 
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+    /// Same as `send_sms`, but returns the request instead of sending it, see `prepare`.
+    ///
+    /// ```rust
+    /// use signalwire::{client::SignalWireClient, types::SmsMessage};
+    ///
+    /// let client = SignalWireClient::new("example", "project", "key");
+    /// let message = SmsMessage {
+    ///     from: "+15551234567".to_string(),
+    ///     to: "+15557654321".to_string(),
+    ///     body: "Hi".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let prepared = client.prepare_send_sms(&message).unwrap();
+    /// assert_eq!(prepared.request().method, "POST");
+    /// assert_eq!(
+    ///     prepared.request().body.as_deref(),
+    ///     Some("From=%2B15551234567&To=%2B15557654321&Body=Hi")
+    /// );
+    /// ```
+    pub fn prepare_send_sms<'a>(&self, message: &'a SmsMessage) -> Result<PreparedRequest<SendSms<'a>>, SignalWireError> {
+        self.prepare(SendSms { message })
+    }
 
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
+    /// Sends every message of `messages` with `executor`, reporting the result of each.
+    ///
+    /// A message whose request failed on the way may have been sent all the same, so retrying it can send it
+    /// twice: use `RetryPolicy::none()` where duplicates matter.
+    pub async fn send_sms_batch(&self, messages: Vec<SmsMessage>, executor: &BatchExecutor) -> BatchReport<SmsMessage, SmsResponse> {
+        executor.run(messages, |message| async move { self.send_sms(&message).await }).await
+    }
 
-        let sms_response: SmsResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    /// Same as `send_sms_batch`, but stops sending once the next message would take the spending beyond
+    /// `max_spend`, reporting the messages left unsent as skipped, see `BatchExecutor::run_with_budget`.
+    ///
+    /// A message is estimated at `price_per_segment` for each of its segments (see `estimate_segments`) until
+    /// SignalWire reports its price.
+    pub async fn send_sms_batch_with_budget(&self, messages: Vec<SmsMessage>, executor: &BatchExecutor, max_spend: f64, price_per_segment: f64) -> BatchReport<SmsMessage, SmsResponse> {
+        executor
+            .run_with_budget(
+                messages,
+                max_spend,
+                |message| estimate_segments(&message.body).segments as f64 * price_per_segment,
+                |response: &SmsResponse| response.price,
+                |message| async move { self.send_sms(&message).await },
+            )
+            .await
+    }
+
+    /// Renders `template` with the values of every recipient of `recipients`, and sends the messages from `from`
+    /// with `executor`, see `send_sms_batch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first message that can't be rendered or isn't valid, before sending any.
+    pub async fn send_template_batch(
+        &self,
+        template: &MessageTemplate,
+        from: &str,
+        recipients: Vec<(String, Vec<(String, String)>)>,
+        executor: &BatchExecutor,
+    ) -> Result<BatchReport<SmsMessage, SmsResponse>, SignalWireError> {
+        let messages = recipients.iter().map(|(to, values)| template.message(from, to, values)).collect::<Result<Vec<_>, _>>()?;
+        Ok(self.send_sms_batch(messages, executor).await)
+    }
 
-        Ok(sms_response)
+    /// Sends `content`, a body or a `MessageTemplate`, to every recipient of `recipients`, skipping those who opted
+    /// out, from the number or pool of `options`, within its rate limit and retries, see `messaging::broadcast`.
+    pub async fn broadcast(&self, content: impl Into<BroadcastContent>, recipients: impl IntoIterator<Item = impl Into<BroadcastRecipient>>, options: &BroadcastOptions<'_>) -> BroadcastReport {
+        messaging::broadcast(self, content, recipients, options).await
     }
 
     /// Blocking version of `send_sms`.
@@ -408,8 +853,9 @@ impl SignalWireClient {
     /// Other `SignalWireError` variants may be returned for unexpected issues.
     #[cfg_attr(feature = "blocking", doc = "Blocking version of `send_sms`.")]
     #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::send_sms` instead")]
     pub fn send_sms_blocking(&self, message: &SmsMessage) -> Result<SmsResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.send_sms(message))
+        crate::blocking::block_on(self.send_sms(message))
     }
 
     /// Get the status of a message by its SID (message identifier).
@@ -432,31 +878,18 @@ impl SignalWireClient {
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Returns `SignalWireError::NotFound` if the message SID doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn get_message_status(&self, message_sid: &str) -> Result<SmsResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/laml/2010-04-01/Accounts/{}/Messages/{}", self.space_name, self.project_id, message_sid);
-
-        let response = self
-            .http_client
-            .get(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status == reqwest::StatusCode::NOT_FOUND {
-            return Err(SignalWireError::NotFound(format!("Message with SID {} not found", message_sid)));
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
+    pub async fn get_message_status(&self, message_sid: &MessageSid) -> Result<SmsResponse, SignalWireError> {
+        self.get_message_status_with_meta(message_sid).await.map(ResponseEnvelope::into_inner)
+    }
 
-        let sms_response: SmsResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    /// Same as `get_message_status`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_message_status_with_meta(&self, message_sid: &MessageSid) -> Result<ResponseEnvelope<SmsResponse>, SignalWireError> {
+        self.execute_with_meta(&GetMessageStatus { message_sid: message_sid.as_str() }).await
+    }
 
-        Ok(sms_response)
+    /// Same as `get_message_status`, but returns the request instead of sending it, see `prepare`.
+    pub fn prepare_get_message_status<'a>(&self, message_sid: &'a MessageSid) -> Result<PreparedRequest<GetMessageStatus<'a>>, SignalWireError> {
+        self.prepare(GetMessageStatus { message_sid: message_sid.as_str() })
     }
 
     /// Blocking version of `get_message_status`.
@@ -478,62 +911,566 @@ impl SignalWireClient {
     /// Other `SignalWireError` variants may be returned for unexpected issues.
     #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_message_status`.")]
     #[cfg(feature = "blocking")]
-    pub fn get_message_status_blocking(&self, message_sid: &str) -> Result<SmsResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.get_message_status(message_sid))
+    #[deprecated(note = "use `BlockingSignalWireClient::get_message_status` instead")]
+    pub fn get_message_status_blocking(&self, message_sid: &MessageSid) -> Result<SmsResponse, SignalWireError> {
+        crate::blocking::block_on(self.get_message_status(message_sid))
     }
 
-    // ---------- Subproject (Account) Methods ----------
-
-    /// Lists all subprojects (accounts) for the current project.
-    ///
-    /// This method returns a list that contains the current Project and any subprojects.
+    /// Lists the messages of the project, newest first. `Paginated::into_page` walks the following pages.
     ///
     /// # Arguments
     ///
-    /// * `query_params` - Optional query parameters for filtering subprojects
+    /// * `query_params` - Filters and page size, see `MessageListParams`
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `SubprojectsListResponse` with the list of subprojects if successful
+    /// - `MessagesListResponse` with the first page of messages
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn list_subprojects(&self, query_params: &[(String, String)]) -> Result<SubprojectsListResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/laml/2010-04-01/Accounts", self.space_name);
-
-        let url = Url::parse_with_params(&url, query_params).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        let response = self
-            .http_client
-            .get(url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
-
-        let subprojects_response: SubprojectsListResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    pub async fn list_messages(&self, query_params: &[(String, String)]) -> Result<MessagesListResponse, SignalWireError> {
+        self.list_messages_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(subprojects_response)
+    /// Same as `list_messages`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_messages_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<MessagesListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListMessages { query_params }).await
     }
 
-    /// Blocking version of `list_subprojects`.
+    /// Blocking version of `list_messages`.
     ///
     /// # Arguments
     ///
-    /// * `query_params` - Optional query parameters for filtering subprojects
+    /// * `query_params` - Filters and page size, see `MessageListParams`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `MessagesListResponse` with the first page of messages
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_messages`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_messages` instead")]
+    pub fn list_messages_blocking(&self, query_params: &[(String, String)]) -> Result<MessagesListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_messages(query_params))
+    }
+
+    /// Streams every item of `list_messages`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_messages_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<SmsResponse, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_messages(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Streams every item of `list_messages`, decoding each page as it arrives rather than once read whole.
+    ///
+    /// Unlike `list_messages_stream`, at most one message's JSON is held in memory, for exports of long message
+    /// histories. Dry-run doesn't apply. Pin the stream to read it.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page or item, then ends.
+    pub fn list_messages_streaming<'a>(&'a self, query_params: &[(String, String)]) -> impl Stream<Item = Result<SmsResponse, SignalWireError>> + 'a {
+        let operation = ListMessages { query_params };
+        streaming::stream_list::<MessagesListResponse>(self, ListMessages::NAME, operation.request(&self.credentials()))
+    }
+
+    /// Collects every item of `list_messages` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_messages_all(&self, query_params: &[(String, String)]) -> Result<Vec<SmsResponse>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_messages(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_messages_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_messages_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_messages_all` instead")]
+    pub fn list_messages_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<SmsResponse>, SignalWireError> {
+        crate::blocking::block_on(self.list_messages_all(query_params))
+    }
+
+    /// Lists the short codes provisioned on the project, see `ShortCodeListParams` for the filters.
+    /// `Paginated::into_page` walks the following pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_short_codes(&self, query_params: &[(String, String)]) -> Result<ShortCodesListResponse, SignalWireError> {
+        self.list_short_codes_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_short_codes`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_short_codes_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<ShortCodesListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListShortCodes { query_params }).await
+    }
+
+    /// Collects every item of `list_short_codes` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the short codes go beyond the limits, or the error of the failing page.
+    pub async fn list_short_codes_all(&self, query_params: &[(String, String)]) -> Result<Vec<ShortCode>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_short_codes(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Gets a short code by SID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the short code doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_short_code(&self, short_code_sid: &ShortCodeSid) -> Result<ShortCode, SignalWireError> {
+        self.get_short_code_with_meta(short_code_sid).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_short_code`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_short_code_with_meta(&self, short_code_sid: &ShortCodeSid) -> Result<ResponseEnvelope<ShortCode>, SignalWireError> {
+        self.execute_with_meta(&GetShortCode { short_code_sid: short_code_sid.as_str() }).await
+    }
+
+    /// Updates the name of a short code, and where its inbound messages are posted to, as for a phone number.
+    ///
+    /// ```rust,no_run
+    /// use signalwire::{
+    ///     client::SignalWireClient,
+    ///     types::{ShortCodeSid, ShortCodeUpdate},
+    /// };
+    ///
+    /// # async fn example(client: SignalWireClient, short_code: ShortCodeSid) -> Result<(), signalwire::errors::SignalWireError> {
+    /// let update = ShortCodeUpdate {
+    ///     sms_url: Some("https://example.com/sms/inbound".to_string()),
+    ///     sms_method: Some("POST".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// client.update_short_code(&short_code, &update).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the short code doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn update_short_code(&self, short_code_sid: &ShortCodeSid, update: &ShortCodeUpdate) -> Result<ShortCode, SignalWireError> {
+        self.update_short_code_with_meta(short_code_sid, update).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `update_short_code`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_short_code_with_meta(&self, short_code_sid: &ShortCodeSid, update: &ShortCodeUpdate) -> Result<ResponseEnvelope<ShortCode>, SignalWireError> {
+        self.execute_with_meta(&UpdateShortCode {
+            short_code_sid: short_code_sid.as_str(),
+            update,
+        })
+        .await
+    }
+
+    /// Places a call to a phone number or a SIP URI, see `NewCall::builder`.
+    ///
+    /// To a SIP URI, the SIP headers of the call are appended to it, and its credentials answer the destination's
+    /// authentication challenge, so calls can be bridged into an existing PBX or trunk.
+    ///
+    /// ```rust,no_run
+    /// use signalwire::{client::SignalWireClient, types::NewCall};
+    ///
+    /// # async fn example(client: SignalWireClient) -> Result<(), signalwire::errors::SignalWireError> {
+    /// let call = NewCall::builder()
+    ///     .from("+15551234567")
+    ///     .to("sip:frontdesk@pbx.example.com")
+    ///     .url("https://example.com/laml/bridge.xml")
+    ///     .sip_header("X-Ticket-Id", "4521")
+    ///     .sip_auth("trunk-user", "trunk-password")
+    ///     .build()?;
+    /// let response = client.create_call(&call).await?;
+    /// println!("{}: {}", response.sid, response.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_call(&self, call: &NewCall) -> Result<CallResponse, SignalWireError> {
+        self.create_call_with_meta(call).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_call`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_call_with_meta(&self, call: &NewCall) -> Result<ResponseEnvelope<CallResponse>, SignalWireError> {
+        self.execute_with_meta(&CreateCall { call }).await
+    }
+
+    /// Lists the calls of the project, newest first. `Paginated::into_page` walks the following pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Filters and page size, see `CallListParams`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `CallsListResponse` with the first page of calls
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_calls(&self, query_params: &[(String, String)]) -> Result<CallsListResponse, SignalWireError> {
+        self.list_calls_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_calls`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_calls_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<CallsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListCalls { query_params }).await
+    }
+
+    /// Blocking version of `list_calls`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Filters and page size, see `CallListParams`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `CallsListResponse` with the first page of calls
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_calls`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_calls` instead")]
+    pub fn list_calls_blocking(&self, query_params: &[(String, String)]) -> Result<CallsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_calls(query_params))
+    }
+
+    /// Streams every item of `list_calls`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_calls_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<CallResponse, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_calls(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_calls` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_calls_all(&self, query_params: &[(String, String)]) -> Result<Vec<CallResponse>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_calls(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_calls_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_calls_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_calls_all` instead")]
+    pub fn list_calls_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<CallResponse>, SignalWireError> {
+        crate::blocking::block_on(self.list_calls_all(query_params))
+    }
+
+    /// Lists the participants of a conference. `Paginated::into_page` walks the following pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the conference doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_conference_participants(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<ConferenceParticipantsListResponse, SignalWireError> {
+        self.list_conference_participants_with_meta(conference_sid, query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_conference_participants`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_conference_participants_with_meta(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<ResponseEnvelope<ConferenceParticipantsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListConferenceParticipants {
+            conference_sid: conference_sid.as_str(),
+            query_params,
+        })
+        .await
+    }
+
+    /// Collects every item of `list_conference_participants` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the participants go beyond the limits, or the error of the failing page.
+    pub async fn list_conference_participants_all(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<Vec<ConferenceParticipant>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_conference_participants(conference_sid, query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Gets the participant of a conference on the call `call_sid`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the call isn't in the conference.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_conference_participant(&self, conference_sid: &ConferenceSid, call_sid: &CallSid) -> Result<ConferenceParticipant, SignalWireError> {
+        self.get_conference_participant_with_meta(conference_sid, call_sid).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_conference_participant`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_conference_participant_with_meta(&self, conference_sid: &ConferenceSid, call_sid: &CallSid) -> Result<ResponseEnvelope<ConferenceParticipant>, SignalWireError> {
+        self.execute_with_meta(&GetConferenceParticipant {
+            conference_sid: conference_sid.as_str(),
+            call_sid: call_sid.as_str(),
+        })
+        .await
+    }
+
+    /// Updates the participant of a conference on the call `call_sid`: mutes or holds it, or makes it coach another
+    /// participant or barge in, see `ParticipantUpdate`.
+    ///
+    /// ```rust,no_run
+    /// use signalwire::{
+    ///     client::SignalWireClient,
+    ///     types::{CallSid, ConferenceSid, ParticipantUpdate},
+    /// };
+    ///
+    /// # async fn example(client: SignalWireClient, conference: ConferenceSid, supervisor: CallSid, agent: CallSid) -> Result<(), signalwire::errors::SignalWireError> {
+    /// // Whisper to the agent, then join the conversation
+    /// client.update_conference_participant(&conference, &supervisor, &ParticipantUpdate::coach(&agent)).await?;
+    /// client.update_conference_participant(&conference, &supervisor, &ParticipantUpdate::barge()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the call isn't in the conference.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn update_conference_participant(&self, conference_sid: &ConferenceSid, call_sid: &CallSid, update: &ParticipantUpdate) -> Result<ConferenceParticipant, SignalWireError> {
+        self.update_conference_participant_with_meta(conference_sid, call_sid, update).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `update_conference_participant`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_conference_participant_with_meta(&self, conference_sid: &ConferenceSid, call_sid: &CallSid, update: &ParticipantUpdate) -> Result<ResponseEnvelope<ConferenceParticipant>, SignalWireError> {
+        self.execute_with_meta(&UpdateConferenceParticipant {
+            conference_sid: conference_sid.as_str(),
+            call_sid: call_sid.as_str(),
+            update,
+        })
+        .await
+    }
+
+    /// Lists the recordings of a conference, newest first. `Paginated::into_page` walks the following pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the conference doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_conference_recordings(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<ConferenceRecordingsListResponse, SignalWireError> {
+        self.list_conference_recordings_with_meta(conference_sid, query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_conference_recordings`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_conference_recordings_with_meta(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<ResponseEnvelope<ConferenceRecordingsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListConferenceRecordings {
+            conference_sid: conference_sid.as_str(),
+            query_params,
+        })
+        .await
+    }
+
+    /// Collects every item of `list_conference_recordings` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the recordings go beyond the limits, or the error of the failing page.
+    pub async fn list_conference_recordings_all(&self, conference_sid: &ConferenceSid, query_params: &[(String, String)]) -> Result<Vec<ConferenceRecording>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_conference_recordings(conference_sid, query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Starts recording a conference in progress, posting the status changes of the recording to `status_callback`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the conference doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues, e.g. a conference already over.
+    pub async fn start_conference_recording(&self, conference_sid: &ConferenceSid, status_callback: Option<&str>) -> Result<ConferenceRecording, SignalWireError> {
+        self.start_conference_recording_with_meta(conference_sid, status_callback).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `start_conference_recording`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn start_conference_recording_with_meta(&self, conference_sid: &ConferenceSid, status_callback: Option<&str>) -> Result<ResponseEnvelope<ConferenceRecording>, SignalWireError> {
+        self.execute_with_meta(&StartConferenceRecording {
+            conference_sid: conference_sid.as_str(),
+            status_callback,
+        })
+        .await
+    }
+
+    /// Gets a recording of a conference.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_conference_recording(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid) -> Result<ConferenceRecording, SignalWireError> {
+        self.get_conference_recording_with_meta(conference_sid, recording_sid).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_conference_recording`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_conference_recording_with_meta(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid) -> Result<ResponseEnvelope<ConferenceRecording>, SignalWireError> {
+        self.execute_with_meta(&GetConferenceRecording {
+            conference_sid: conference_sid.as_str(),
+            recording_sid: recording_sid.as_str(),
+        })
+        .await
+    }
+
+    /// Sets the status of a recording of a conference in progress: `Paused`, `InProgress` to resume it, or `Stopped`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues, e.g. a recording already stopped.
+    pub async fn update_conference_recording(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid, status: ConferenceRecordingStatus) -> Result<ConferenceRecording, SignalWireError> {
+        self.update_conference_recording_with_meta(conference_sid, recording_sid, status).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `update_conference_recording`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_conference_recording_with_meta(
+        &self,
+        conference_sid: &ConferenceSid,
+        recording_sid: &RecordingSid,
+        status: ConferenceRecordingStatus,
+    ) -> Result<ResponseEnvelope<ConferenceRecording>, SignalWireError> {
+        self.execute_with_meta(&UpdateConferenceRecording {
+            conference_sid: conference_sid.as_str(),
+            recording_sid: recording_sid.as_str(),
+            status: &status.to_string(),
+        })
+        .await
+    }
+
+    /// Stops a recording of a conference in progress, see `update_conference_recording`.
+    pub async fn stop_conference_recording(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid) -> Result<ConferenceRecording, SignalWireError> {
+        self.update_conference_recording(conference_sid, recording_sid, ConferenceRecordingStatus::Stopped).await
+    }
+
+    /// Deletes a recording of a conference.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn delete_conference_recording(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid) -> Result<(), SignalWireError> {
+        self.delete_conference_recording_with_meta(conference_sid, recording_sid).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `delete_conference_recording`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn delete_conference_recording_with_meta(&self, conference_sid: &ConferenceSid, recording_sid: &RecordingSid) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&DeleteConferenceRecording {
+            conference_sid: conference_sid.as_str(),
+            recording_sid: recording_sid.as_str(),
+        })
+        .await
+    }
+
+    /// Starts downloading the audio of a completed recording, call or conference, in `format`, or the `range` of its
+    /// bytes.
+    ///
+    /// The body is read from the returned `MediaDownload` chunk by chunk, as `Bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Returns `SignalWireError::Unexpected` if the download is refused, e.g. for a recording still in progress or a
+    /// range past its end.
+    pub async fn get_recording_media(&self, recording_sid: &RecordingSid, format: RecordingFormat, range: Option<ByteRange>) -> Result<MediaDownload, SignalWireError> {
+        let path = endpoint::Endpoint::Recording {
+            account_sid: &self.project_id,
+            recording_sid: recording_sid.as_str(),
+        }
+        .path();
+        self.get_media(&format!("{}.{}", path, format.extension()), range).await
+    }
+
+    /// Downloads the audio of a completed recording into `writer`, chunk by chunk, without holding it in memory.
+    ///
+    /// Enabled by the `tokio` feature; on other runtimes, write the chunks of `get_recording_media` instead.
+    ///
+    /// # Errors
+    ///
+    /// Same as `get_recording_media`, and `SignalWireError::Unexpected` if writing fails.
+    #[cfg(any(test, feature = "tokio"))]
+    pub async fn download_recording<W: tokio::io::AsyncWrite + Unpin>(&self, recording_sid: &RecordingSid, format: RecordingFormat, writer: &mut W) -> Result<u64, SignalWireError> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut download = self.get_recording_media(recording_sid, format, None).await?;
+        let mut written = 0;
+        while let Some(chunk) = download.chunk().await? {
+            writer.write_all(&chunk).await.map_err(|e| SignalWireError::Unexpected(format!("Failed to write the recording: {}", e)))?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await.map_err(|e| SignalWireError::Unexpected(format!("Failed to write the recording: {}", e)))?;
+
+        Ok(written)
+    }
+
+    /// Downloads a recording into a synchronous writer, for the blocking API.
+    #[cfg(feature = "blocking")]
+    pub(crate) async fn write_recording<W: std::io::Write>(&self, recording_sid: &RecordingSid, format: RecordingFormat, writer: &mut W) -> Result<u64, SignalWireError> {
+        let mut download = self.get_recording_media(recording_sid, format, None).await?;
+        let mut written = 0;
+        while let Some(chunk) = download.chunk().await? {
+            writer.write_all(&chunk).map_err(|e| SignalWireError::Unexpected(format!("Failed to write the recording: {}", e)))?;
+            written += chunk.len() as u64;
+        }
+
+        Ok(written)
+    }
+
+    // ---------- Subproject (Account) Methods ----------
+
+    /// Lists all subprojects (accounts) for the current project.
+    ///
+    /// This method returns a list that contains the current Project and any subprojects.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters for filtering subprojects
     ///
     /// # Returns
     ///
@@ -545,572 +1482,4363 @@ impl SignalWireClient {
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_subprojects`.")]
+    pub async fn list_subprojects(&self, query_params: &[(String, String)]) -> Result<SubprojectsListResponse, SignalWireError> {
+        self.list_subprojects_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_subprojects`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_subprojects_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<SubprojectsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListSubprojects { query_params }).await
+    }
+
+    /// Blocking version of `list_subprojects`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters for filtering subprojects
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SubprojectsListResponse` with the list of subprojects if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_subprojects`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_subprojects` instead")]
+    pub fn list_subprojects_blocking(&self, query_params: &[(String, String)]) -> Result<SubprojectsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_subprojects(query_params))
+    }
+
+    /// Streams every item of `list_subprojects`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_subprojects_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<SubprojectResponse, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_subprojects(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_subprojects` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_subprojects_all(&self, query_params: &[(String, String)]) -> Result<Vec<SubprojectResponse>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_subprojects(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_subprojects_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_subprojects_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_subprojects_all` instead")]
+    pub fn list_subprojects_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<SubprojectResponse>, SignalWireError> {
+        crate::blocking::block_on(self.list_subprojects_all(query_params))
+    }
+
+    /// Get details for a specific subproject (account).
+    ///
+    /// # Arguments
+    ///
+    /// * `subproject_sid` - The SID (unique identifier) of the subproject to retrieve
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SubprojectResponse` with the subproject details if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_subproject(&self, subproject_sid: &AccountSid) -> Result<SubprojectResponse, SignalWireError> {
+        self.get_subproject_with_meta(subproject_sid).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_subproject`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_subproject_with_meta(&self, subproject_sid: &AccountSid) -> Result<ResponseEnvelope<SubprojectResponse>, SignalWireError> {
+        self.execute_with_meta(&GetSubproject { subproject_sid: subproject_sid.as_str() }).await
+    }
+
+    /// Blocking version of `get_subproject`.
+    ///
+    /// # Arguments
+    ///
+    /// * `subproject_sid` - The SID (unique identifier) of the subproject to retrieve
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SubprojectResponse` with the subproject details if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_subproject`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_subproject` instead")]
+    pub fn get_subproject_blocking(&self, subproject_sid: &AccountSid) -> Result<SubprojectResponse, SignalWireError> {
+        crate::blocking::block_on(self.get_subproject(subproject_sid))
+    }
+
+    /// Creates a new subproject (account) within the current project.
+    ///
+    /// # Arguments
+    ///
+    /// * `friendly_name` - A human-readable name for the subproject
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SubprojectResponse` with the details of the created subproject if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_subproject(&self, friendly_name: &str) -> Result<SubprojectResponse, SignalWireError> {
+        self.create_subproject_with_meta(friendly_name).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_subproject`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_subproject_with_meta(&self, friendly_name: &str) -> Result<ResponseEnvelope<SubprojectResponse>, SignalWireError> {
+        self.execute_with_meta(&CreateSubproject { friendly_name }).await
+    }
+
+    /// Blocking version of `create_subproject`.
+    ///
+    /// # Arguments
+    ///
+    /// * `friendly_name` - A human-readable name for the subproject
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SubprojectResponse` with the details of the created subproject if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_subproject`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::create_subproject` instead")]
+    pub fn create_subproject_blocking(&self, friendly_name: &str) -> Result<SubprojectResponse, SignalWireError> {
+        crate::blocking::block_on(self.create_subproject(friendly_name))
+    }
+
+    /// Updates an existing subproject (account).
+    ///
+    /// # Arguments
+    ///
+    /// * `subproject_sid` - The SID (unique identifier) of the subproject to update
+    /// * `friendly_name` - A new human-readable name for the subproject
+    /// * `status` - Optional status to set for the subproject ("active" or "suspended")
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SubprojectResponse` with the updated subproject details if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn update_subproject(&self, subproject_sid: &AccountSid, friendly_name: &str, status: Option<&str>) -> Result<SubprojectResponse, SignalWireError> {
+        self.update_subproject_with_meta(subproject_sid, friendly_name, status).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `update_subproject`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_subproject_with_meta(&self, subproject_sid: &AccountSid, friendly_name: &str, status: Option<&str>) -> Result<ResponseEnvelope<SubprojectResponse>, SignalWireError> {
+        self.execute_with_meta(&UpdateSubproject {
+            subproject_sid: subproject_sid.as_str(),
+            friendly_name,
+            status,
+        })
+        .await
+    }
+
+    /// Blocking version of `update_subproject`.
+    ///
+    /// # Arguments
+    ///
+    /// * `subproject_sid` - The SID (unique identifier) of the subproject to update
+    /// * `friendly_name` - A new human-readable name for the subproject
+    /// * `status` - Optional status to set for the subproject ("active" or "suspended")
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SubprojectResponse` with the updated subproject details if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `update_subproject`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::update_subproject` instead")]
+    pub fn update_subproject_blocking(&self, subproject_sid: &AccountSid, friendly_name: &str, status: Option<&str>) -> Result<SubprojectResponse, SignalWireError> {
+        crate::blocking::block_on(self.update_subproject(subproject_sid, friendly_name, status))
+    }
+
+    /// Deletes a subproject (account).
+    ///
+    /// # Arguments
+    ///
+    /// * `subproject_sid` - The SID (unique identifier) of the subproject to delete
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the subproject was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn delete_subproject(&self, subproject_sid: &AccountSid) -> Result<(), SignalWireError> {
+        self.delete_subproject_with_meta(subproject_sid).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `delete_subproject`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn delete_subproject_with_meta(&self, subproject_sid: &AccountSid) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&DeleteSubproject { subproject_sid: subproject_sid.as_str() }).await
+    }
+
+    /// Blocking version of `delete_subproject`.
+    ///
+    /// # Arguments
+    ///
+    /// * `subproject_sid` - The SID (unique identifier) of the subproject to delete
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the subproject was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `delete_subproject`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::delete_subproject` instead")]
+    pub fn delete_subproject_blocking(&self, subproject_sid: &AccountSid) -> Result<(), SignalWireError> {
+        crate::blocking::block_on(self.delete_subproject(subproject_sid))
+    }
+
+    // ---------- Subproject Resource Methods ----------
+
+    /// Lists phone numbers owned by a specific subproject.
+    ///
+    /// This method allows you to retrieve all phone numbers that belong to a specific subproject
+    /// using your main account's credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `subproject_sid` - The SID (unique identifier) of the subproject
+    /// * `query_params` - Filters and paging, see `SubprojectPhoneNumberParams`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SubprojectPhoneNumbersResponse` with detailed phone number info if successful.
+    /// - `SignalWireError` if the request fails or is unauthorized.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_subproject_phone_numbers(&self, subproject_sid: &AccountSid, query_params: &[(String, String)]) -> Result<SubprojectPhoneNumbersResponse, SignalWireError> {
+        self.get_subproject_phone_numbers_with_meta(subproject_sid, query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_subproject_phone_numbers`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_subproject_phone_numbers_with_meta(&self, subproject_sid: &AccountSid, query_params: &[(String, String)]) -> Result<ResponseEnvelope<SubprojectPhoneNumbersResponse>, SignalWireError> {
+        // First check if the subproject exists
+        self.get_subproject(subproject_sid).await?;
+
+        self.execute_with_meta(&GetSubprojectPhoneNumbers {
+            subproject_sid: subproject_sid.as_str(),
+            query_params,
+        })
+        .await
+    }
+
+    /// Blocking version of `get_subproject_phone_numbers`.
+    ///
+    /// # Arguments
+    ///
+    /// * `subproject_sid` - The SID (unique identifier) of the subproject
+    /// * `query_params` - Additional query parameters as key-value pairs.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SubprojectPhoneNumbersResponse` with detailed phone number info if successful.
+    /// - `SignalWireError` if the request fails or is unauthorized.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_subproject_phone_numbers`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_subproject_phone_numbers` instead")]
+    pub fn get_subproject_phone_numbers_blocking(&self, subproject_sid: &AccountSid, query_params: &[(String, String)]) -> Result<SubprojectPhoneNumbersResponse, SignalWireError> {
+        crate::blocking::block_on(self.get_subproject_phone_numbers(subproject_sid, query_params))
+    }
+
+    /// Streams every item of `get_subproject_phone_numbers`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn get_subproject_phone_numbers_stream<'a>(&'a self, subproject_sid: &'a AccountSid, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<SubprojectPhoneNumber, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.get_subproject_phone_numbers(subproject_sid, query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `get_subproject_phone_numbers` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn get_subproject_phone_numbers_all(&self, subproject_sid: &AccountSid, query_params: &[(String, String)]) -> Result<Vec<SubprojectPhoneNumber>, SignalWireError> {
+        pagination::collect_items(self, async { self.get_subproject_phone_numbers(subproject_sid, query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `get_subproject_phone_numbers_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `get_subproject_phone_numbers_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_subproject_phone_numbers_all` instead")]
+    pub fn get_subproject_phone_numbers_all_blocking(&self, subproject_sid: &AccountSid, query_params: &[(String, String)]) -> Result<Vec<SubprojectPhoneNumber>, SignalWireError> {
+        crate::blocking::block_on(self.get_subproject_phone_numbers_all(subproject_sid, query_params))
+    }
+
+    // ---------- Phone Number Lookup & Validation Methods ----------
+
+    /// Looks up and validates a phone number.
+    ///
+    /// This method validates a phone number to ensure it is valid and properly formatted.
+    /// It returns basic information about the number such as country code and formatting.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_number` - The phone number to lookup and validate
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `PhoneLookupResponse` with information about the phone number if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn lookup_phone_number(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        self.lookup_phone_number_with_meta(phone_number).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Looks up every number of `phone_numbers` with `executor`, reporting the result of each.
+    pub async fn lookup_phone_numbers(&self, phone_numbers: Vec<String>, executor: &BatchExecutor) -> BatchReport<String, PhoneLookupResponse> {
+        executor.run(phone_numbers, |phone_number| async move { self.lookup_phone_number(&phone_number).await }).await
+    }
+
+    /// Same as `lookup_phone_number`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn lookup_phone_number_with_meta(&self, phone_number: &str) -> Result<ResponseEnvelope<PhoneLookupResponse>, SignalWireError> {
+        self.execute_with_meta(&LookupPhoneNumber { phone_number, query_params: &[] }).await
+    }
+
+    /// Same as `lookup_phone_number`, but returns the request instead of sending it, see `prepare`.
+    pub fn prepare_lookup_phone_number<'a>(&self, phone_number: &'a str) -> Result<PreparedRequest<LookupPhoneNumber<'a>>, SignalWireError> {
+        self.prepare(LookupPhoneNumber { phone_number, query_params: &[] })
+    }
+
+    /// Blocking version of `lookup_phone_number`.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_number` - The phone number to lookup and validate
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `PhoneLookupResponse` with information about the phone number if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `lookup_phone_number`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::lookup_phone_number` instead")]
+    pub fn lookup_phone_number_blocking(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        crate::blocking::block_on(self.lookup_phone_number(phone_number))
+    }
+
+    /// Looks up a phone number with carrier information.
+    ///
+    /// This method validates a phone number and returns carrier information about the number,
+    /// including the carrier name and whether it's a mobile, landline, or VoIP number.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_number` - The phone number to lookup and validate
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `PhoneLookupResponse` with information about the phone number and carrier if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn lookup_phone_number_with_carrier(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        self.lookup_phone_number_with_carrier_with_meta(phone_number).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `lookup_phone_number_with_carrier`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn lookup_phone_number_with_carrier_with_meta(&self, phone_number: &str) -> Result<ResponseEnvelope<PhoneLookupResponse>, SignalWireError> {
+        let params = PhoneLookupParams::new().with_carrier().build();
+
+        self.execute_with_meta(&LookupPhoneNumber { phone_number, query_params: &params }).await
+    }
+
+    /// Blocking version of `lookup_phone_number_with_carrier`.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_number` - The phone number to lookup and validate
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `PhoneLookupResponse` with information about the phone number and carrier if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `lookup_phone_number_with_carrier`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::lookup_phone_number_with_carrier` instead")]
+    pub fn lookup_phone_number_with_carrier_blocking(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        crate::blocking::block_on(self.lookup_phone_number_with_carrier(phone_number))
+    }
+
+    /// Looks up a phone number with caller name (CNAM) information.
+    ///
+    /// This method validates a phone number and returns caller name information,
+    /// which provides the registered name of the phone number owner if available.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_number` - The phone number to lookup and validate
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `PhoneLookupResponse` with information about the phone number and caller name if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn lookup_phone_number_with_caller_name(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        self.lookup_phone_number_with_caller_name_with_meta(phone_number).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `lookup_phone_number_with_caller_name`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn lookup_phone_number_with_caller_name_with_meta(&self, phone_number: &str) -> Result<ResponseEnvelope<PhoneLookupResponse>, SignalWireError> {
+        let params = PhoneLookupParams::new().with_caller_name().build();
+
+        self.execute_with_meta(&LookupPhoneNumber { phone_number, query_params: &params }).await
+    }
+
+    /// Blocking version of `lookup_phone_number_with_caller_name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_number` - The phone number to lookup and validate
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `PhoneLookupResponse` with information about the phone number and caller name if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `lookup_phone_number_with_caller_name`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::lookup_phone_number_with_caller_name` instead")]
+    pub fn lookup_phone_number_with_caller_name_blocking(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        crate::blocking::block_on(self.lookup_phone_number_with_caller_name(phone_number))
+    }
+
+    // ---------- SWML Script Methods ----------
+
+    /// Lists the SWML scripts hosted in the space.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SwmlScriptsListResponse` with one page of scripts if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_swml_scripts(&self, query_params: &[(String, String)]) -> Result<SwmlScriptsListResponse, SignalWireError> {
+        self.list_swml_scripts_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_swml_scripts`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_swml_scripts_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<SwmlScriptsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListSwmlScripts { query_params }).await
+    }
+
+    /// Blocking version of `list_swml_scripts`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SwmlScriptsListResponse` with one page of scripts if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_swml_scripts`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_swml_scripts` instead")]
+    pub fn list_swml_scripts_blocking(&self, query_params: &[(String, String)]) -> Result<SwmlScriptsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_swml_scripts(query_params))
+    }
+
+    /// Streams every item of `list_swml_scripts`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_swml_scripts_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<SwmlScriptResponse, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_swml_scripts(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_swml_scripts` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_swml_scripts_all(&self, query_params: &[(String, String)]) -> Result<Vec<SwmlScriptResponse>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_swml_scripts(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_swml_scripts_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_swml_scripts_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_swml_scripts_all` instead")]
+    pub fn list_swml_scripts_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<SwmlScriptResponse>, SignalWireError> {
+        crate::blocking::block_on(self.list_swml_scripts_all(query_params))
+    }
+
+    /// Gets a hosted SWML script, including its contents and request URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the script resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SwmlScriptResponse` with the script details if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the script ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_swml_script(&self, id: &str) -> Result<SwmlScriptResponse, SignalWireError> {
+        self.get_swml_script_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_swml_script`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_swml_script_with_meta(&self, id: &str) -> Result<ResponseEnvelope<SwmlScriptResponse>, SignalWireError> {
+        self.execute_with_meta(&GetSwmlScript { id }).await
+    }
+
+    /// Blocking version of `get_swml_script`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the script resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SwmlScriptResponse` with the script details if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the script ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_swml_script`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_swml_script` instead")]
+    pub fn get_swml_script_blocking(&self, id: &str) -> Result<SwmlScriptResponse, SignalWireError> {
+        crate::blocking::block_on(self.get_swml_script(id))
+    }
+
+    /// Uploads a SWML document as a hosted script.
+    ///
+    /// Point a phone number at the returned `swml_script.request_url` (with the `relay_script` call handler) to run it
+    /// on incoming calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A display name for the script
+    /// * `swml` - The document to upload, e.g. built with `Swml::new`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SwmlScriptResponse` with the created script if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Swml` if the document can't be serialized.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_swml_script(&self, name: &str, swml: &Swml) -> Result<SwmlScriptResponse, SignalWireError> {
+        self.create_swml_script_with_meta(name, swml).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_swml_script`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_swml_script_with_meta(&self, name: &str, swml: &Swml) -> Result<ResponseEnvelope<SwmlScriptResponse>, SignalWireError> {
+        self.execute_with_meta(&CreateSwmlScript { name, swml }).await
+    }
+
+    /// Blocking version of `create_swml_script`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A display name for the script
+    /// * `swml` - The document to upload, e.g. built with `Swml::new`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SwmlScriptResponse` with the created script if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Swml` if the document can't be serialized.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_swml_script`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::create_swml_script` instead")]
+    pub fn create_swml_script_blocking(&self, name: &str, swml: &Swml) -> Result<SwmlScriptResponse, SignalWireError> {
+        crate::blocking::block_on(self.create_swml_script(name, swml))
+    }
+
+    /// Renames a hosted SWML script and/or replaces its document. `None` leaves the field unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the script resource
+    /// * `name` - A new display name
+    /// * `swml` - The new document
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SwmlScriptResponse` with the updated script if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the script ID doesn't exist.
+    /// Returns `SignalWireError::Swml` if the document can't be serialized.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn update_swml_script(&self, id: &str, name: Option<&str>, swml: Option<&Swml>) -> Result<SwmlScriptResponse, SignalWireError> {
+        self.update_swml_script_with_meta(id, name, swml).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `update_swml_script`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_swml_script_with_meta(&self, id: &str, name: Option<&str>, swml: Option<&Swml>) -> Result<ResponseEnvelope<SwmlScriptResponse>, SignalWireError> {
+        self.execute_with_meta(&UpdateSwmlScript { id, name, swml }).await
+    }
+
+    /// Blocking version of `update_swml_script`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the script resource
+    /// * `name` - A new display name
+    /// * `swml` - The new document
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SwmlScriptResponse` with the updated script if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the script ID doesn't exist.
+    /// Returns `SignalWireError::Swml` if the document can't be serialized.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `update_swml_script`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::update_swml_script` instead")]
+    pub fn update_swml_script_blocking(&self, id: &str, name: Option<&str>, swml: Option<&Swml>) -> Result<SwmlScriptResponse, SignalWireError> {
+        crate::blocking::block_on(self.update_swml_script(id, name, swml))
+    }
+
+    /// Deletes a hosted SWML script.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the script resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the script was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the script ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn delete_swml_script(&self, id: &str) -> Result<(), SignalWireError> {
+        self.delete_swml_script_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `delete_swml_script`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn delete_swml_script_with_meta(&self, id: &str) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&DeleteSwmlScript { id }).await
+    }
+
+    /// Blocking version of `delete_swml_script`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the script resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the script was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the script ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `delete_swml_script`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::delete_swml_script` instead")]
+    pub fn delete_swml_script_blocking(&self, id: &str) -> Result<(), SignalWireError> {
+        crate::blocking::block_on(self.delete_swml_script(id))
+    }
+
+    // ---------- Relay Task Methods ----------
+
+    /// Sends a task to the Relay clients receiving on `context` (see `relay::Tasking::receive`).
+    ///
+    /// Tasks are fire-and-forget: SignalWire delivers them to whichever clients are connected, nothing is queued for
+    /// clients that aren't.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context the receiving Relay clients subscribed to
+    /// * `message` - The JSON payload of the task
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the task was accepted for delivery
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn send_task(&self, context: &str, message: &serde_json::Value) -> Result<(), SignalWireError> {
+        self.send_task_with_meta(context, message).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `send_task`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn send_task_with_meta(&self, context: &str, message: &serde_json::Value) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&SendRelayTask { context, message }).await
+    }
+
+    /// Blocking version of `send_task`.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context the receiving Relay clients subscribed to
+    /// * `message` - The JSON payload of the task
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the task was accepted for delivery
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `send_task`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::send_task` instead")]
+    pub fn send_task_blocking(&self, context: &str, message: &serde_json::Value) -> Result<(), SignalWireError> {
+        crate::blocking::block_on(self.send_task(context, message))
+    }
+
+    // ---------- Video Methods ----------
+
+    /// Creates a token for a browser participant to join a video room.
+    ///
+    /// # Arguments
+    ///
+    /// * `room_name` - The room to join, created on first join if it doesn't exist
+    /// * `member_name` - The name the participant is shown with
+    /// * `permissions` - What the participant may do in the room
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomTokenResponse` with the token if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_room_token(&self, room_name: &str, member_name: &str, permissions: &RoomPermissions) -> Result<RoomTokenResponse, SignalWireError> {
+        self.create_room_token_with_meta(room_name, member_name, permissions).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_room_token`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_room_token_with_meta(&self, room_name: &str, member_name: &str, permissions: &RoomPermissions) -> Result<ResponseEnvelope<RoomTokenResponse>, SignalWireError> {
+        self.execute_with_meta(&CreateRoomToken {
+            room_name,
+            user_name: member_name,
+            permissions,
+        })
+        .await
+    }
+
+    /// Blocking version of `create_room_token`.
+    ///
+    /// # Arguments
+    ///
+    /// * `room_name` - The room to join, created on first join if it doesn't exist
+    /// * `member_name` - The name the participant is shown with
+    /// * `permissions` - What the participant may do in the room
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomTokenResponse` with the token if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_room_token`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::create_room_token` instead")]
+    pub fn create_room_token_blocking(&self, room_name: &str, member_name: &str, permissions: &RoomPermissions) -> Result<RoomTokenResponse, SignalWireError> {
+        crate::blocking::block_on(self.create_room_token(room_name, member_name, permissions))
+    }
+
+    /// Lists the recordings of every video room session.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomRecordingsListResponse` with one page of recordings if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_room_recordings(&self, query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        self.list_room_recordings_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_room_recordings`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_room_recordings_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<RoomRecordingsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListRoomRecordings { room_session_id: None, query_params }).await
+    }
+
+    /// Blocking version of `list_room_recordings`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomRecordingsListResponse` with one page of recordings if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_room_recordings`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_room_recordings` instead")]
+    pub fn list_room_recordings_blocking(&self, query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_room_recordings(query_params))
+    }
+
+    /// Streams every item of `list_room_recordings`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_room_recordings_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<RoomRecording, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_room_recordings(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_room_recordings` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_room_recordings_all(&self, query_params: &[(String, String)]) -> Result<Vec<RoomRecording>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_room_recordings(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_room_recordings_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_room_recordings_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_room_recordings_all` instead")]
+    pub fn list_room_recordings_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<RoomRecording>, SignalWireError> {
+        crate::blocking::block_on(self.list_room_recordings_all(query_params))
+    }
+
+    /// Lists the recordings of one video room session.
+    ///
+    /// # Arguments
+    ///
+    /// * `room_session_id` - The ID of the room session
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomRecordingsListResponse` with one page of recordings if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the room session doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_room_session_recordings(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        self.list_room_session_recordings_with_meta(room_session_id, query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_room_session_recordings`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_room_session_recordings_with_meta(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<ResponseEnvelope<RoomRecordingsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListRoomRecordings {
+            room_session_id: Some(room_session_id),
+            query_params,
+        })
+        .await
+    }
+
+    /// Blocking version of `list_room_session_recordings`.
+    ///
+    /// # Arguments
+    ///
+    /// * `room_session_id` - The ID of the room session
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomRecordingsListResponse` with one page of recordings if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the room session doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_room_session_recordings`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_room_session_recordings` instead")]
+    pub fn list_room_session_recordings_blocking(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<RoomRecordingsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_room_session_recordings(room_session_id, query_params))
+    }
+
+    /// Streams every item of `list_room_session_recordings`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_room_session_recordings_stream<'a>(&'a self, room_session_id: &'a str, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<RoomRecording, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_room_session_recordings(room_session_id, query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_room_session_recordings` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_room_session_recordings_all(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<Vec<RoomRecording>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_room_session_recordings(room_session_id, query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_room_session_recordings_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_room_session_recordings_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_room_session_recordings_all` instead")]
+    pub fn list_room_session_recordings_all_blocking(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<Vec<RoomRecording>, SignalWireError> {
+        crate::blocking::block_on(self.list_room_session_recordings_all(room_session_id, query_params))
+    }
+
+    /// Gets a video room recording by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the recording
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomRecording` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_room_recording(&self, id: &str) -> Result<RoomRecording, SignalWireError> {
+        self.get_room_recording_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_room_recording`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_room_recording_with_meta(&self, id: &str) -> Result<ResponseEnvelope<RoomRecording>, SignalWireError> {
+        self.execute_with_meta(&GetRoomRecording { id }).await
+    }
+
+    /// Blocking version of `get_room_recording`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the recording
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomRecording` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_room_recording`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_room_recording` instead")]
+    pub fn get_room_recording_blocking(&self, id: &str) -> Result<RoomRecording, SignalWireError> {
+        crate::blocking::block_on(self.get_room_recording(id))
+    }
+
+    /// Deletes a video room recording.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the recording
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the recording was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn delete_room_recording(&self, id: &str) -> Result<(), SignalWireError> {
+        self.delete_room_recording_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `delete_room_recording`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn delete_room_recording_with_meta(&self, id: &str) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&DeleteRoomRecording { id }).await
+    }
+
+    /// Blocking version of `delete_room_recording`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the recording
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the recording was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `delete_room_recording`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::delete_room_recording` instead")]
+    pub fn delete_room_recording_blocking(&self, id: &str) -> Result<(), SignalWireError> {
+        crate::blocking::block_on(self.delete_room_recording(id))
+    }
+
+    /// Downloads a completed video room recording into `writer`, chunk by chunk, without holding it in memory.
+    ///
+    /// Enabled by the `tokio` feature; on other runtimes, write the chunks of `get_room_recording_media` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the recording
+    /// * `writer` - Where to write the recording, e.g. a `tokio::fs::File`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - The number of bytes written if successful
+    /// - `SignalWireError` if the request or a write fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Returns `SignalWireError::Unexpected` if the recording isn't completed yet, or if writing fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg(any(test, feature = "tokio"))]
+    pub async fn download_room_recording<W: tokio::io::AsyncWrite + Unpin>(&self, id: &str, writer: &mut W) -> Result<u64, SignalWireError> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut download = self.get_room_recording_media(id, None).await?;
+        let mut written = 0;
+        while let Some(chunk) = download.chunk().await? {
+            writer.write_all(&chunk).await.map_err(|e| SignalWireError::Unexpected(format!("Failed to write the recording: {}", e)))?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await.map_err(|e| SignalWireError::Unexpected(format!("Failed to write the recording: {}", e)))?;
+
+        Ok(written)
+    }
+
+    /// Blocking version of `download_room_recording`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the recording
+    /// * `writer` - Where to write the recording, e.g. a `std::fs::File`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - The number of bytes written if successful
+    /// - `SignalWireError` if the request or a write fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Returns `SignalWireError::Unexpected` if the recording isn't completed yet, or if writing fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `download_room_recording`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::download_room_recording` instead")]
+    pub fn download_room_recording_blocking<W: std::io::Write>(&self, id: &str, writer: &mut W) -> Result<u64, SignalWireError> {
+        crate::blocking::block_on(self.write_room_recording(id, writer))
+    }
+
+    /// Downloads a recording into a synchronous writer, for the blocking API.
+    #[cfg(feature = "blocking")]
+    pub(crate) async fn write_room_recording<W: std::io::Write>(&self, id: &str, writer: &mut W) -> Result<u64, SignalWireError> {
+        let mut download = self.get_room_recording_media(id, None).await?;
+        let mut written = 0;
+        while let Some(chunk) = download.chunk().await? {
+            writer.write_all(&chunk).map_err(|e| SignalWireError::Unexpected(format!("Failed to write the recording: {}", e)))?;
+            written += chunk.len() as u64;
+        }
+
+        Ok(written)
+    }
+
+    /// Starts downloading a recording, or the `range` of its bytes, from the URL its metadata points to.
+    ///
+    /// The body is read from the returned `MediaDownload` chunk by chunk, as `Bytes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the recording
+    /// * `range` - The bytes to download, e.g. `ByteRange::from(written)` to resume; `None` for the whole recording
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::NotFound` if the recording doesn't exist.
+    /// Returns `SignalWireError::Unexpected` if the recording isn't completed yet, or if the download is refused, e.g.
+    /// for a range past its end.
+    pub async fn get_room_recording_media(&self, id: &str, range: Option<ByteRange>) -> Result<MediaDownload, SignalWireError> {
+        let recording = self.get_room_recording(id).await?;
+        let uri = recording
+            .uri
+            .filter(|uri| !uri.is_empty())
+            .ok_or_else(|| SignalWireError::Unexpected(format!("Room recording {} is {}, it can't be downloaded yet", id, recording.status)))?;
+
+        // The URL is pre-signed, it must not get our credentials.
+        self.download("download_room_recording", self.http_client.get(&uri), range).await
+    }
+
+    /// Starts downloading media the API links to, e.g. the `uri` of an MMS media (without `.json`) or the `media_url`
+    /// of a fax, or the `range` of its bytes.
+    ///
+    /// Links relative to the space, or pointing at it, are fetched with the client's credentials; others (pre-signed
+    /// storage URLs) without. The body is read from the returned `MediaDownload` chunk by chunk, as `Bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if there is no media at `uri`.
+    /// Returns `SignalWireError::Unexpected` if the download is refused, e.g. for a range past its end.
+    pub async fn get_media(&self, uri: &str, range: Option<ByteRange>) -> Result<MediaDownload, SignalWireError> {
+        let on_space = |url: &str| url.starts_with(&self.base_url) || url.starts_with(&endpoint::space_base_url(&self.space_name));
+        let request = if uri.starts_with('/') {
+            let url = format!("{}{}", self.base_url, uri);
+            self.http_client.get(url).header(reqwest::header::AUTHORIZATION, self.credentials().authorization_header())
+        } else if on_space(uri) {
+            self.http_client.get(uri).header(reqwest::header::AUTHORIZATION, self.credentials().authorization_header())
+        } else {
+            self.http_client.get(uri)
+        };
+
+        self.download("get_media", request, range).await
+    }
+
+    async fn download(&self, operation: &'static str, mut request: reqwest::RequestBuilder, range: Option<ByteRange>) -> Result<MediaDownload, SignalWireError> {
+        // Media are read in constant memory, and ranges count the bytes of the media itself
+        request = request.header(reqwest::header::ACCEPT_ENCODING, "identity");
+        if let Some(range) = range {
+            request = request.header(reqwest::header::RANGE, range.header_value());
+        }
+        let request = request.build().map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+
+        // The path only, the query of a pre-signed URL holds its signature
+        let not_found = format!("Media {} not found", request.url().path());
+        Ok(MediaDownload::new(checked_response(self.dispatch(operation, request).await?, Some(not_found)).await?))
+    }
+
+    /// Lists video room sessions, most recent first.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `status` or `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomSessionsListResponse` with one page of room sessions if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_room_sessions(&self, query_params: &[(String, String)]) -> Result<RoomSessionsListResponse, SignalWireError> {
+        self.list_room_sessions_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_room_sessions`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_room_sessions_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<RoomSessionsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListRoomSessions { query_params }).await
+    }
+
+    /// Blocking version of `list_room_sessions`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `status` or `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomSessionsListResponse` with one page of room sessions if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_room_sessions`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_room_sessions` instead")]
+    pub fn list_room_sessions_blocking(&self, query_params: &[(String, String)]) -> Result<RoomSessionsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_room_sessions(query_params))
+    }
+
+    /// Streams every item of `list_room_sessions`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_room_sessions_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<RoomSession, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_room_sessions(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_room_sessions` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_room_sessions_all(&self, query_params: &[(String, String)]) -> Result<Vec<RoomSession>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_room_sessions(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_room_sessions_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_room_sessions_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_room_sessions_all` instead")]
+    pub fn list_room_sessions_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<RoomSession>, SignalWireError> {
+        crate::blocking::block_on(self.list_room_sessions_all(query_params))
+    }
+
+    /// Gets a video room session by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the room session
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomSession` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the room session doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_room_session(&self, id: &str) -> Result<RoomSession, SignalWireError> {
+        self.get_room_session_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_room_session`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_room_session_with_meta(&self, id: &str) -> Result<ResponseEnvelope<RoomSession>, SignalWireError> {
+        self.execute_with_meta(&GetRoomSession { id }).await
+    }
+
+    /// Blocking version of `get_room_session`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the room session
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomSession` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the room session doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_room_session`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_room_session` instead")]
+    pub fn get_room_session_blocking(&self, id: &str) -> Result<RoomSession, SignalWireError> {
+        crate::blocking::block_on(self.get_room_session(id))
+    }
+
+    /// Lists the members of a video room session, including the ones who left, see `RoomMember::is_present`.
+    ///
+    /// # Arguments
+    ///
+    /// * `room_session_id` - The ID of the room session
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomMembersListResponse` with one page of members if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the room session doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_room_session_members(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<RoomMembersListResponse, SignalWireError> {
+        self.list_room_session_members_with_meta(room_session_id, query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_room_session_members`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_room_session_members_with_meta(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<ResponseEnvelope<RoomMembersListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListRoomSessionMembers { room_session_id, query_params }).await
+    }
+
+    /// Blocking version of `list_room_session_members`.
+    ///
+    /// # Arguments
+    ///
+    /// * `room_session_id` - The ID of the room session
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `RoomMembersListResponse` with one page of members if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the room session doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_room_session_members`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_room_session_members` instead")]
+    pub fn list_room_session_members_blocking(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<RoomMembersListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_room_session_members(room_session_id, query_params))
+    }
+
+    /// Streams every item of `list_room_session_members`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_room_session_members_stream<'a>(&'a self, room_session_id: &'a str, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<RoomMember, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_room_session_members(room_session_id, query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_room_session_members` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_room_session_members_all(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<Vec<RoomMember>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_room_session_members(room_session_id, query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_room_session_members_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_room_session_members_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_room_session_members_all` instead")]
+    pub fn list_room_session_members_all_blocking(&self, room_session_id: &str, query_params: &[(String, String)]) -> Result<Vec<RoomMember>, SignalWireError> {
+        crate::blocking::block_on(self.list_room_session_members_all(room_session_id, query_params))
+    }
+
+    // ---------- Chat Methods ----------
+
+    /// Creates a token for a chat member, scoped to the channels it can read or write.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - How long the token is valid, in minutes
+    /// * `channels` - The channels the member can use, by name, with what it can do on each
+    /// * `member_id` - The ID of the member, generated by SignalWire if `None`
+    /// * `state` - Optional state of the member, visible to the other members
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `ChatTokenResponse` with the token to hand to the browser if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because `channels` is empty.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_chat_token(
+        &self,
+        ttl: u32,
+        channels: &std::collections::BTreeMap<String, ChatChannelPermissions>,
+        member_id: Option<&str>,
+        state: Option<&serde_json::Value>,
+    ) -> Result<ChatTokenResponse, SignalWireError> {
+        self.create_chat_token_with_meta(ttl, channels, member_id, state).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_chat_token`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_chat_token_with_meta(
+        &self,
+        ttl: u32,
+        channels: &std::collections::BTreeMap<String, ChatChannelPermissions>,
+        member_id: Option<&str>,
+        state: Option<&serde_json::Value>,
+    ) -> Result<ResponseEnvelope<ChatTokenResponse>, SignalWireError> {
+        self.execute_with_meta(&CreateChatToken { ttl, channels, member_id, state }).await
+    }
+
+    /// Blocking version of `create_chat_token`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - How long the token is valid, in minutes
+    /// * `channels` - The channels the member can use, by name, with what it can do on each
+    /// * `member_id` - The ID of the member, generated by SignalWire if `None`
+    /// * `state` - Optional state of the member, visible to the other members
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `ChatTokenResponse` with the token to hand to the browser if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because `channels` is empty.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_chat_token`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::create_chat_token` instead")]
+    pub fn create_chat_token_blocking(
+        &self,
+        ttl: u32,
+        channels: &std::collections::BTreeMap<String, ChatChannelPermissions>,
+        member_id: Option<&str>,
+        state: Option<&serde_json::Value>,
+    ) -> Result<ChatTokenResponse, SignalWireError> {
+        crate::blocking::block_on(self.create_chat_token(ttl, channels, member_id, state))
+    }
+
+    // ---------- Fabric Subscriber Methods ----------
+
+    /// Lists the Fabric subscribers of the space.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscribersListResponse` with one page of subscribers if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_fabric_subscribers(&self, query_params: &[(String, String)]) -> Result<FabricSubscribersListResponse, SignalWireError> {
+        self.list_fabric_subscribers_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_fabric_subscribers`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_fabric_subscribers_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<FabricSubscribersListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListFabricSubscribers { query_params }).await
+    }
+
+    /// Blocking version of `list_fabric_subscribers`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscribersListResponse` with one page of subscribers if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_fabric_subscribers`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_fabric_subscribers` instead")]
+    pub fn list_fabric_subscribers_blocking(&self, query_params: &[(String, String)]) -> Result<FabricSubscribersListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_fabric_subscribers(query_params))
+    }
+
+    /// Streams every item of `list_fabric_subscribers`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_fabric_subscribers_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<FabricSubscriberResponse, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_fabric_subscribers(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_fabric_subscribers` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_fabric_subscribers_all(&self, query_params: &[(String, String)]) -> Result<Vec<FabricSubscriberResponse>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_fabric_subscribers(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_fabric_subscribers_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_fabric_subscribers_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_fabric_subscribers_all` instead")]
+    pub fn list_fabric_subscribers_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<FabricSubscriberResponse>, SignalWireError> {
+        crate::blocking::block_on(self.list_fabric_subscribers_all(query_params))
+    }
+
+    /// Gets a Fabric subscriber by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the subscriber
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscriberResponse` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subscriber doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_fabric_subscriber(&self, id: &str) -> Result<FabricSubscriberResponse, SignalWireError> {
+        self.get_fabric_subscriber_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_fabric_subscriber`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_fabric_subscriber_with_meta(&self, id: &str) -> Result<ResponseEnvelope<FabricSubscriberResponse>, SignalWireError> {
+        self.execute_with_meta(&GetFabricSubscriber { id }).await
+    }
+
+    /// Blocking version of `get_fabric_subscriber`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the subscriber
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscriberResponse` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subscriber doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_fabric_subscriber`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_fabric_subscriber` instead")]
+    pub fn get_fabric_subscriber_blocking(&self, id: &str) -> Result<FabricSubscriberResponse, SignalWireError> {
+        crate::blocking::block_on(self.get_fabric_subscriber(id))
+    }
+
+    /// Creates a Fabric subscriber, who can then sign in to calling apps with their email and password.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The subscriber's fields; `email` is required
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscriberResponse` with the new subscriber if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because the email is already taken.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_fabric_subscriber(&self, request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        self.create_fabric_subscriber_with_meta(request).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_fabric_subscriber`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_fabric_subscriber_with_meta(&self, request: &FabricSubscriberRequest) -> Result<ResponseEnvelope<FabricSubscriberResponse>, SignalWireError> {
+        self.execute_with_meta(&CreateFabricSubscriber { request }).await
+    }
+
+    /// Blocking version of `create_fabric_subscriber`.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The subscriber's fields; `email` is required
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscriberResponse` with the new subscriber if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because the email is already taken.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_fabric_subscriber`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::create_fabric_subscriber` instead")]
+    pub fn create_fabric_subscriber_blocking(&self, request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        crate::blocking::block_on(self.create_fabric_subscriber(request))
+    }
+
+    /// Updates a Fabric subscriber, e.g. its display name or password.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the subscriber
+    /// * `request` - The fields to change; fields left `None` are kept
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscriberResponse` with the updated subscriber if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subscriber doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn update_fabric_subscriber(&self, id: &str, request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        self.update_fabric_subscriber_with_meta(id, request).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `update_fabric_subscriber`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_fabric_subscriber_with_meta(&self, id: &str, request: &FabricSubscriberRequest) -> Result<ResponseEnvelope<FabricSubscriberResponse>, SignalWireError> {
+        self.execute_with_meta(&UpdateFabricSubscriber { id, request }).await
+    }
+
+    /// Blocking version of `update_fabric_subscriber`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the subscriber
+    /// * `request` - The fields to change; fields left `None` are kept
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscriberResponse` with the updated subscriber if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subscriber doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `update_fabric_subscriber`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::update_fabric_subscriber` instead")]
+    pub fn update_fabric_subscriber_blocking(&self, id: &str, request: &FabricSubscriberRequest) -> Result<FabricSubscriberResponse, SignalWireError> {
+        crate::blocking::block_on(self.update_fabric_subscriber(id, request))
+    }
+
+    /// Deletes a Fabric subscriber.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the subscriber
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the subscriber was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subscriber doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn delete_fabric_subscriber(&self, id: &str) -> Result<(), SignalWireError> {
+        self.delete_fabric_subscriber_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `delete_fabric_subscriber`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn delete_fabric_subscriber_with_meta(&self, id: &str) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&DeleteFabricSubscriber { id }).await
+    }
+
+    /// Blocking version of `delete_fabric_subscriber`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the subscriber
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the subscriber was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subscriber doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `delete_fabric_subscriber`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::delete_fabric_subscriber` instead")]
+    pub fn delete_fabric_subscriber_blocking(&self, id: &str) -> Result<(), SignalWireError> {
+        crate::blocking::block_on(self.delete_fabric_subscriber(id))
+    }
+
+    // ---------- Fabric Address Methods ----------
+
+    /// Lists the Fabric addresses of the space: the rooms, apps and subscribers that can be called.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `type` or `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddressesListResponse` with one page of addresses if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_fabric_addresses(&self, query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        self.list_fabric_addresses_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_fabric_addresses`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_fabric_addresses_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<FabricAddressesListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListFabricAddresses { query_params }).await
+    }
+
+    /// Blocking version of `list_fabric_addresses`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `type` or `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddressesListResponse` with one page of addresses if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_fabric_addresses`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_fabric_addresses` instead")]
+    pub fn list_fabric_addresses_blocking(&self, query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_fabric_addresses(query_params))
+    }
+
+    /// Streams every item of `list_fabric_addresses`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_fabric_addresses_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<FabricAddress, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_fabric_addresses(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_fabric_addresses` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_fabric_addresses_all(&self, query_params: &[(String, String)]) -> Result<Vec<FabricAddress>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_fabric_addresses(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_fabric_addresses_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_fabric_addresses_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_fabric_addresses_all` instead")]
+    pub fn list_fabric_addresses_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<FabricAddress>, SignalWireError> {
+        crate::blocking::block_on(self.list_fabric_addresses_all(query_params))
+    }
+
+    /// Gets a Fabric address by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the address
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddress` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the address doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_fabric_address(&self, id: &str) -> Result<FabricAddress, SignalWireError> {
+        self.get_fabric_address_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_fabric_address`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_fabric_address_with_meta(&self, id: &str) -> Result<ResponseEnvelope<FabricAddress>, SignalWireError> {
+        self.execute_with_meta(&GetFabricAddress { id }).await
+    }
+
+    /// Blocking version of `get_fabric_address`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the address
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddress` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the address doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_fabric_address`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_fabric_address` instead")]
+    pub fn get_fabric_address_blocking(&self, id: &str) -> Result<FabricAddress, SignalWireError> {
+        crate::blocking::block_on(self.get_fabric_address(id))
+    }
+
+    /// Resolves a Fabric address by name, e.g. before dialing it or scoping a token to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the address, e.g. `standup` for `/public/standup`
+    /// * `address_type` - Only an address of this type, if the name is shared by several
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddress` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if no address has this name and type.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn resolve_fabric_address(&self, name: &str, address_type: Option<FabricAddressType>) -> Result<FabricAddress, SignalWireError> {
+        let mut query_params = vec![("name".to_string(), name.to_string())];
+        if let Some(address_type) = &address_type {
+            query_params.push(("type".to_string(), address_type.to_string()));
+        }
+
+        // The filters are applied server side, the match is checked anyway as they are loose (case, prefixes).
+        let addresses = self.list_fabric_addresses(&query_params).await?;
+        addresses
+            .data
+            .into_iter()
+            .find(|address| address.name == name && address_type.as_ref().is_none_or(|address_type| address.get_type() == *address_type))
+            .ok_or_else(|| SignalWireError::NotFound(format!("Fabric address named {} not found", name)))
+    }
+
+    /// Blocking version of `resolve_fabric_address`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the address, e.g. `standup` for `/public/standup`
+    /// * `address_type` - Only an address of this type, if the name is shared by several
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddress` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if no address has this name and type.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `resolve_fabric_address`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::resolve_fabric_address` instead")]
+    pub fn resolve_fabric_address_blocking(&self, name: &str, address_type: Option<FabricAddressType>) -> Result<FabricAddress, SignalWireError> {
+        crate::blocking::block_on(self.resolve_fabric_address(name, address_type))
+    }
+
+    // ---------- Fabric Resource Methods ----------
+
+    /// Lists the Fabric resources of the space, of every type.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `type` or `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricResourcesListResponse` with one page of resources if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_fabric_resources(&self, query_params: &[(String, String)]) -> Result<FabricResourcesListResponse, SignalWireError> {
+        self.list_fabric_resources_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_fabric_resources`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_fabric_resources_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<FabricResourcesListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListFabricResources { query_params }).await
+    }
+
+    /// Blocking version of `list_fabric_resources`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `type` or `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricResourcesListResponse` with one page of resources if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_fabric_resources`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_fabric_resources` instead")]
+    pub fn list_fabric_resources_blocking(&self, query_params: &[(String, String)]) -> Result<FabricResourcesListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_fabric_resources(query_params))
+    }
+
+    /// Streams every item of `list_fabric_resources`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_fabric_resources_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<FabricResource, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_fabric_resources(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_fabric_resources` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_fabric_resources_all(&self, query_params: &[(String, String)]) -> Result<Vec<FabricResource>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_fabric_resources(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_fabric_resources_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_fabric_resources_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_fabric_resources_all` instead")]
+    pub fn list_fabric_resources_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<FabricResource>, SignalWireError> {
+        crate::blocking::block_on(self.list_fabric_resources_all(query_params))
+    }
+
+    /// Gets a Fabric resource of any type by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricResource` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_fabric_resource(&self, id: &str) -> Result<FabricResource, SignalWireError> {
+        self.get_fabric_resource_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_fabric_resource`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_fabric_resource_with_meta(&self, id: &str) -> Result<ResponseEnvelope<FabricResource>, SignalWireError> {
+        self.execute_with_meta(&GetFabricResource { id }).await
+    }
+
+    /// Blocking version of `get_fabric_resource`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricResource` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_fabric_resource`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_fabric_resource` instead")]
+    pub fn get_fabric_resource_blocking(&self, id: &str) -> Result<FabricResource, SignalWireError> {
+        crate::blocking::block_on(self.get_fabric_resource(id))
+    }
+
+    /// Creates a Fabric resource, e.g. a SWML script, AI agent or LaML application. Its addresses are created along with it.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_type` - The type of the resource
+    /// * `fields` - The fields of the resource, as documented for its type
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricResource` with the new resource if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the fields are rejected.
+    /// Returns `SignalWireError::Unexpected` for `FabricResourceType::Unknown`.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_fabric_resource(&self, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        self.create_fabric_resource_with_meta(resource_type, fields).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_fabric_resource`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_fabric_resource_with_meta(&self, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<ResponseEnvelope<FabricResource>, SignalWireError> {
+        self.execute_with_meta(&CreateFabricResource { resource_type: &resource_type, fields }).await
+    }
+
+    /// Blocking version of `create_fabric_resource`.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_type` - The type of the resource
+    /// * `fields` - The fields of the resource, as documented for its type
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricResource` with the new resource if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the fields are rejected.
+    /// Returns `SignalWireError::Unexpected` for `FabricResourceType::Unknown`.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_fabric_resource`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::create_fabric_resource` instead")]
+    pub fn create_fabric_resource_blocking(&self, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        crate::blocking::block_on(self.create_fabric_resource(resource_type, fields))
+    }
+
+    /// Updates the fields of a Fabric resource.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    /// * `resource_type` - The type of the resource
+    /// * `fields` - The fields to change, as documented for its type
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricResource` with the updated resource if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Returns `SignalWireError::Unexpected` for `FabricResourceType::Unknown`.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn update_fabric_resource(&self, id: &str, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        self.update_fabric_resource_with_meta(id, resource_type, fields).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `update_fabric_resource`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_fabric_resource_with_meta(&self, id: &str, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<ResponseEnvelope<FabricResource>, SignalWireError> {
+        self.execute_with_meta(&UpdateFabricResource {
+            id,
+            resource_type: &resource_type,
+            fields,
+        })
+        .await
+    }
+
+    /// Blocking version of `update_fabric_resource`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    /// * `resource_type` - The type of the resource
+    /// * `fields` - The fields to change, as documented for its type
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricResource` with the updated resource if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Returns `SignalWireError::Unexpected` for `FabricResourceType::Unknown`.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `update_fabric_resource`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::update_fabric_resource` instead")]
+    pub fn update_fabric_resource_blocking(&self, id: &str, resource_type: FabricResourceType, fields: &serde_json::Value) -> Result<FabricResource, SignalWireError> {
+        crate::blocking::block_on(self.update_fabric_resource(id, resource_type, fields))
+    }
+
+    /// Deletes a Fabric resource of any type, along with its addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the resource was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn delete_fabric_resource(&self, id: &str) -> Result<(), SignalWireError> {
+        self.delete_fabric_resource_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `delete_fabric_resource`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn delete_fabric_resource_with_meta(&self, id: &str) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&DeleteFabricResource { id }).await
+    }
+
+    /// Blocking version of `delete_fabric_resource`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the resource was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `delete_fabric_resource`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::delete_fabric_resource` instead")]
+    pub fn delete_fabric_resource_blocking(&self, id: &str) -> Result<(), SignalWireError> {
+        crate::blocking::block_on(self.delete_fabric_resource(id))
+    }
+
+    /// Lists the addresses a Fabric resource can be called at.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddressesListResponse` with one page of addresses if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_fabric_resource_addresses(&self, id: &str, query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        self.list_fabric_resource_addresses_with_meta(id, query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_fabric_resource_addresses`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_fabric_resource_addresses_with_meta(&self, id: &str, query_params: &[(String, String)]) -> Result<ResponseEnvelope<FabricAddressesListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListFabricResourceAddresses { id, query_params }).await
+    }
+
+    /// Blocking version of `list_fabric_resource_addresses`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddressesListResponse` with one page of addresses if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_fabric_resource_addresses`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_fabric_resource_addresses` instead")]
+    pub fn list_fabric_resource_addresses_blocking(&self, id: &str, query_params: &[(String, String)]) -> Result<FabricAddressesListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_fabric_resource_addresses(id, query_params))
+    }
+
+    /// Streams every item of `list_fabric_resource_addresses`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_fabric_resource_addresses_stream<'a>(&'a self, id: &'a str, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<FabricAddress, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_fabric_resource_addresses(id, query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_fabric_resource_addresses` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_fabric_resource_addresses_all(&self, id: &str, query_params: &[(String, String)]) -> Result<Vec<FabricAddress>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_fabric_resource_addresses(id, query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_fabric_resource_addresses_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_fabric_resource_addresses_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_fabric_resource_addresses_all` instead")]
+    pub fn list_fabric_resource_addresses_all_blocking(&self, id: &str, query_params: &[(String, String)]) -> Result<Vec<FabricAddress>, SignalWireError> {
+        crate::blocking::block_on(self.list_fabric_resource_addresses_all(id, query_params))
+    }
+
+    /// Routes the calls or messages of an owned phone number to a Fabric resource.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    /// * `phone_number_id` - The ID of the phone number, see `get_phone_numbers_owned`
+    /// * `handler` - Whether calls or messages are routed
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddress` of the phone number if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn assign_fabric_resource_phone_number(&self, id: &str, phone_number_id: &PhoneNumberId, handler: FabricHandler) -> Result<FabricAddress, SignalWireError> {
+        self.assign_fabric_resource_phone_number_with_meta(id, phone_number_id, handler).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `assign_fabric_resource_phone_number`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn assign_fabric_resource_phone_number_with_meta(&self, id: &str, phone_number_id: &PhoneNumberId, handler: FabricHandler) -> Result<ResponseEnvelope<FabricAddress>, SignalWireError> {
+        self.execute_with_meta(&AssignFabricResourcePhoneNumber {
+            id,
+            phone_number_id: phone_number_id.as_str(),
+            handler,
+        })
+        .await
+    }
+
+    /// Blocking version of `assign_fabric_resource_phone_number`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the resource
+    /// * `phone_number_id` - The ID of the phone number, see `get_phone_numbers_owned`
+    /// * `handler` - Whether calls or messages are routed
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricAddress` of the phone number if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the resource doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `assign_fabric_resource_phone_number`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::assign_fabric_resource_phone_number` instead")]
+    pub fn assign_fabric_resource_phone_number_blocking(&self, id: &str, phone_number_id: &PhoneNumberId, handler: FabricHandler) -> Result<FabricAddress, SignalWireError> {
+        crate::blocking::block_on(self.assign_fabric_resource_phone_number(id, phone_number_id, handler))
+    }
+
+    // ---------- Fabric Token Methods ----------
+
+    /// Creates a short-lived token for a Fabric subscriber to register a web or mobile client with.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The email or ID of the subscriber
+    /// * `ttl` - How long the token is valid
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscriberTokenResponse` with the token and its refresh token if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subscriber doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_fabric_subscriber_token(&self, reference: &str, ttl: std::time::Duration) -> Result<FabricSubscriberTokenResponse, SignalWireError> {
+        self.create_fabric_subscriber_token_with_meta(reference, ttl).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_fabric_subscriber_token`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_fabric_subscriber_token_with_meta(&self, reference: &str, ttl: std::time::Duration) -> Result<ResponseEnvelope<FabricSubscriberTokenResponse>, SignalWireError> {
+        self.execute_with_meta(&CreateFabricSubscriberToken { reference, expire_at: expire_at(ttl) }).await
+    }
+
+    /// Blocking version of `create_fabric_subscriber_token`.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The email or ID of the subscriber
+    /// * `ttl` - How long the token is valid
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricSubscriberTokenResponse` with the token and its refresh token if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the subscriber doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_fabric_subscriber_token`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::create_fabric_subscriber_token` instead")]
+    pub fn create_fabric_subscriber_token_blocking(&self, reference: &str, ttl: std::time::Duration) -> Result<FabricSubscriberTokenResponse, SignalWireError> {
+        crate::blocking::block_on(self.create_fabric_subscriber_token(reference, ttl))
+    }
+
+    /// Creates a short-lived token for a guest, who can only call the given addresses, e.g. a support line.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowed_addresses` - The IDs of the addresses the guest can call, see `resolve_fabric_address`
+    /// * `ttl` - How long the token is valid
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricGuestTokenResponse` with the token if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because an address doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_fabric_guest_token(&self, allowed_addresses: &[&str], ttl: std::time::Duration) -> Result<FabricGuestTokenResponse, SignalWireError> {
+        self.create_fabric_guest_token_with_meta(allowed_addresses, ttl).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_fabric_guest_token`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_fabric_guest_token_with_meta(&self, allowed_addresses: &[&str], ttl: std::time::Duration) -> Result<ResponseEnvelope<FabricGuestTokenResponse>, SignalWireError> {
+        self.execute_with_meta(&CreateFabricGuestToken {
+            allowed_addresses,
+            expire_at: expire_at(ttl),
+        })
+        .await
+    }
+
+    /// Blocking version of `create_fabric_guest_token`.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowed_addresses` - The IDs of the addresses the guest can call, see `resolve_fabric_address`
+    /// * `ttl` - How long the token is valid
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `FabricGuestTokenResponse` with the token if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because an address doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_fabric_guest_token`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::create_fabric_guest_token` instead")]
+    pub fn create_fabric_guest_token_blocking(&self, allowed_addresses: &[&str], ttl: std::time::Duration) -> Result<FabricGuestTokenResponse, SignalWireError> {
+        crate::blocking::block_on(self.create_fabric_guest_token(allowed_addresses, ttl))
+    }
+
+    // ---------- AI Agent Methods ----------
+
+    /// Lists the AI agents hosted in the space.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `AiAgentsListResponse` with one page of agents if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_ai_agents(&self, query_params: &[(String, String)]) -> Result<AiAgentsListResponse, SignalWireError> {
+        self.list_ai_agents_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_ai_agents`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_ai_agents_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<AiAgentsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListAiAgents { query_params }).await
+    }
+
+    /// Blocking version of `list_ai_agents`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `AiAgentsListResponse` with one page of agents if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_ai_agents`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_ai_agents` instead")]
+    pub fn list_ai_agents_blocking(&self, query_params: &[(String, String)]) -> Result<AiAgentsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_ai_agents(query_params))
+    }
+
+    /// Streams every item of `list_ai_agents`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_ai_agents_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<AiAgentResponse, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_ai_agents(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_ai_agents` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_ai_agents_all(&self, query_params: &[(String, String)]) -> Result<Vec<AiAgentResponse>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_ai_agents(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_ai_agents_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_ai_agents_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_ai_agents_all` instead")]
+    pub fn list_ai_agents_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<AiAgentResponse>, SignalWireError> {
+        crate::blocking::block_on(self.list_ai_agents_all(query_params))
+    }
+
+    /// Gets a hosted AI agent by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the agent resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `AiAgentResponse` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the agent ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_ai_agent(&self, id: &str) -> Result<AiAgentResponse, SignalWireError> {
+        self.get_ai_agent_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_ai_agent`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_ai_agent_with_meta(&self, id: &str) -> Result<ResponseEnvelope<AiAgentResponse>, SignalWireError> {
+        self.execute_with_meta(&GetAiAgent { id }).await
+    }
+
+    /// Blocking version of `get_ai_agent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the agent resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `AiAgentResponse` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the agent ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_ai_agent`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_ai_agent` instead")]
+    pub fn get_ai_agent_blocking(&self, id: &str) -> Result<AiAgentResponse, SignalWireError> {
+        crate::blocking::block_on(self.get_ai_agent(id))
+    }
+
+    /// Hosts an AI agent in the space, configured as for the SWML `ai` instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A display name for the agent
+    /// * `agent` - The prompt, languages and voices, SWAIG functions and post-prompt webhook
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `AiAgentResponse` with the new agent if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the configuration is rejected.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_ai_agent(&self, name: &str, agent: &Ai) -> Result<AiAgentResponse, SignalWireError> {
+        self.create_ai_agent_with_meta(name, agent).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_ai_agent`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_ai_agent_with_meta(&self, name: &str, agent: &Ai) -> Result<ResponseEnvelope<AiAgentResponse>, SignalWireError> {
+        self.execute_with_meta(&CreateAiAgent { name, agent }).await
+    }
+
+    /// Blocking version of `create_ai_agent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A display name for the agent
+    /// * `agent` - The prompt, languages and voices, SWAIG functions and post-prompt webhook
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `AiAgentResponse` with the new agent if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the configuration is rejected.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_ai_agent`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::create_ai_agent` instead")]
+    pub fn create_ai_agent_blocking(&self, name: &str, agent: &Ai) -> Result<AiAgentResponse, SignalWireError> {
+        crate::blocking::block_on(self.create_ai_agent(name, agent))
+    }
+
+    /// Renames a hosted AI agent and/or replaces its configuration. `None` leaves the field unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the agent resource
+    /// * `name` - A new display name
+    /// * `agent` - The new configuration
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `AiAgentResponse` with the updated agent if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the agent ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn update_ai_agent(&self, id: &str, name: Option<&str>, agent: Option<&Ai>) -> Result<AiAgentResponse, SignalWireError> {
+        self.update_ai_agent_with_meta(id, name, agent).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `update_ai_agent`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_ai_agent_with_meta(&self, id: &str, name: Option<&str>, agent: Option<&Ai>) -> Result<ResponseEnvelope<AiAgentResponse>, SignalWireError> {
+        self.execute_with_meta(&UpdateAiAgent { id, name, agent }).await
+    }
+
+    /// Blocking version of `update_ai_agent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the agent resource
+    /// * `name` - A new display name
+    /// * `agent` - The new configuration
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `AiAgentResponse` with the updated agent if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the agent ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `update_ai_agent`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::update_ai_agent` instead")]
+    pub fn update_ai_agent_blocking(&self, id: &str, name: Option<&str>, agent: Option<&Ai>) -> Result<AiAgentResponse, SignalWireError> {
+        crate::blocking::block_on(self.update_ai_agent(id, name, agent))
+    }
+
+    /// Deletes a hosted AI agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the agent resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the agent was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the agent ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn delete_ai_agent(&self, id: &str) -> Result<(), SignalWireError> {
+        self.delete_ai_agent_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `delete_ai_agent`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn delete_ai_agent_with_meta(&self, id: &str) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&DeleteAiAgent { id }).await
+    }
+
+    /// Blocking version of `delete_ai_agent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the agent resource
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the agent was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the agent ID doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `delete_ai_agent`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::delete_ai_agent` instead")]
+    pub fn delete_ai_agent_blocking(&self, id: &str) -> Result<(), SignalWireError> {
+        crate::blocking::block_on(self.delete_ai_agent(id))
+    }
+
+    // ---------- Datasphere Methods ----------
+
+    /// Uploads a document (PDF or text) to the Datasphere, where it's split into chunks for AI agents to search.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - The name of the document; its extension sets the content type
+    /// * `contents` - The document
+    /// * `options` - The chunking strategy and tags
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereDocument` with the `submitted` document if successful; it's searchable once `completed`
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the document is rejected, e.g. because of its type.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn upload_datasphere_document(&self, filename: &str, contents: Vec<u8>, options: &DatasphereUploadOptions) -> Result<DatasphereDocument, SignalWireError> {
+        self.upload_datasphere_document_with_progress(filename, contents, options, |_| {}).await
+    }
+
+    /// Same as `upload_datasphere_document`, calling `progress` as the document is streamed, chunk by chunk.
+    pub async fn upload_datasphere_document_with_progress(
+        &self,
+        filename: &str,
+        contents: Vec<u8>,
+        options: &DatasphereUploadOptions,
+        progress: impl Fn(UploadProgress) + Send + Sync + 'static,
+    ) -> Result<DatasphereDocument, SignalWireError> {
+        let total = contents.len() as u64;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let file = {
+            use futures_util::StreamExt;
+
+            let chunks: Vec<Vec<u8>> = contents.chunks(UPLOAD_CHUNK_SIZE).map(<[u8]>::to_vec).collect();
+            let mut sent = 0;
+            let body = futures_util::stream::iter(chunks).map(move |chunk| {
+                sent += chunk.len() as u64;
+                progress(UploadProgress { sent, total });
+                Ok::<_, std::io::Error>(chunk)
+            });
+            reqwest::multipart::Part::stream_with_length(reqwest::Body::wrap_stream(body), total)
+        };
+
+        // fetch can't stream a request body, the document is sent in one piece
+        #[cfg(target_arch = "wasm32")]
+        let file = {
+            progress(UploadProgress { sent: total, total });
+            reqwest::multipart::Part::bytes(contents)
+        };
+
+        let file = file
+            .file_name(filename.to_string())
+            .mime_str(document_content_type(filename))
+            .map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+        let mut form = reqwest::multipart::Form::new().part("file", file);
+        for (name, value) in options.chunking.fields() {
+            form = form.text(name, value);
+        }
+        for tag in &options.tags {
+            form = form.text("tags[]", tag.clone());
+        }
+
+        // The body is streamed once, so it can't go through a cassette or a sansio operation.
+        let request = self
+            .http_client
+            .post(endpoint::Endpoint::DatasphereDocuments.url(&self.base_url))
+            .header("Authorization", self.credentials().authorization_header())
+            .multipart(form)
+            .build()
+            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+        let response = self.dispatch("upload_datasphere_document", request).await?;
+        let status = response.status().as_u16();
+        let body = response.text().await.map_err(|e| SignalWireError::Unexpected(transport::body_error(&e)))?;
+
+        let response = HttpResponse { status, headers: vec![], body };
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+
+    /// Blocking version of `upload_datasphere_document`.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - The name of the document; its extension sets the content type
+    /// * `contents` - The document
+    /// * `options` - The chunking strategy and tags
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereDocument` with the `submitted` document if successful; it's searchable once `completed`
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the document is rejected, e.g. because of its type.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `upload_datasphere_document`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::upload_datasphere_document` instead")]
+    pub fn upload_datasphere_document_blocking(&self, filename: &str, contents: Vec<u8>, options: &DatasphereUploadOptions) -> Result<DatasphereDocument, SignalWireError> {
+        crate::blocking::block_on(self.upload_datasphere_document(filename, contents, options))
+    }
+
+    /// Searches the Datasphere for the chunks closest to `query`, as AI agents do.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - What to search for, in natural language
+    /// * `filters` - What to restrict the search to, e.g. documents with given tags
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereSearchResponse` with the matching chunks, most relevant first, if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn datasphere_search(&self, query: &str, filters: &DatasphereSearchFilters) -> Result<DatasphereSearchResponse, SignalWireError> {
+        self.datasphere_search_with_meta(query, filters).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `datasphere_search`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn datasphere_search_with_meta(&self, query: &str, filters: &DatasphereSearchFilters) -> Result<ResponseEnvelope<DatasphereSearchResponse>, SignalWireError> {
+        self.execute_with_meta(&SearchDatasphere { query, filters }).await
+    }
+
+    /// Blocking version of `datasphere_search`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - What to search for, in natural language
+    /// * `filters` - What to restrict the search to, e.g. documents with given tags
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereSearchResponse` with the matching chunks, most relevant first, if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `datasphere_search`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::datasphere_search` instead")]
+    pub fn datasphere_search_blocking(&self, query: &str, filters: &DatasphereSearchFilters) -> Result<DatasphereSearchResponse, SignalWireError> {
+        crate::blocking::block_on(self.datasphere_search(query, filters))
+    }
+
+    /// Lists the documents of the Datasphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereDocumentsListResponse` with one page of documents if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_datasphere_documents(&self, query_params: &[(String, String)]) -> Result<DatasphereDocumentsListResponse, SignalWireError> {
+        self.list_datasphere_documents_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_datasphere_documents`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_datasphere_documents_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<DatasphereDocumentsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListDatasphereDocuments { query_params }).await
+    }
+
+    /// Blocking version of `list_datasphere_documents`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereDocumentsListResponse` with one page of documents if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_datasphere_documents`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_datasphere_documents` instead")]
+    pub fn list_datasphere_documents_blocking(&self, query_params: &[(String, String)]) -> Result<DatasphereDocumentsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_datasphere_documents(query_params))
+    }
+
+    /// Streams every item of `list_datasphere_documents`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_datasphere_documents_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<DatasphereDocument, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_datasphere_documents(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_datasphere_documents` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_datasphere_documents_all(&self, query_params: &[(String, String)]) -> Result<Vec<DatasphereDocument>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_datasphere_documents(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_datasphere_documents_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_datasphere_documents_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_datasphere_documents_all` instead")]
+    pub fn list_datasphere_documents_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<DatasphereDocument>, SignalWireError> {
+        crate::blocking::block_on(self.list_datasphere_documents_all(query_params))
+    }
+
+    /// Gets a Datasphere document by ID, e.g. to check whether it was processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the document
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereDocument` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the document doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_datasphere_document(&self, id: &str) -> Result<DatasphereDocument, SignalWireError> {
+        self.get_datasphere_document_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_datasphere_document`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_datasphere_document_with_meta(&self, id: &str) -> Result<ResponseEnvelope<DatasphereDocument>, SignalWireError> {
+        self.execute_with_meta(&GetDatasphereDocument { id }).await
+    }
+
+    /// Blocking version of `get_datasphere_document`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the document
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereDocument` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the document doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_datasphere_document`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_datasphere_document` instead")]
+    pub fn get_datasphere_document_blocking(&self, id: &str) -> Result<DatasphereDocument, SignalWireError> {
+        crate::blocking::block_on(self.get_datasphere_document(id))
+    }
+
+    /// Lists the chunks a Datasphere document was split into.
+    ///
+    /// # Arguments
+    ///
+    /// * `document_id` - The ID of the document
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereChunksListResponse` with one page of chunks if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the document doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_datasphere_chunks(&self, document_id: &str, query_params: &[(String, String)]) -> Result<DatasphereChunksListResponse, SignalWireError> {
+        self.list_datasphere_chunks_with_meta(document_id, query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_datasphere_chunks`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_datasphere_chunks_with_meta(&self, document_id: &str, query_params: &[(String, String)]) -> Result<ResponseEnvelope<DatasphereChunksListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListDatasphereChunks { document_id, query_params }).await
+    }
+
+    /// Blocking version of `list_datasphere_chunks`.
+    ///
+    /// # Arguments
+    ///
+    /// * `document_id` - The ID of the document
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereChunksListResponse` with one page of chunks if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the document doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_datasphere_chunks`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_datasphere_chunks` instead")]
+    pub fn list_datasphere_chunks_blocking(&self, document_id: &str, query_params: &[(String, String)]) -> Result<DatasphereChunksListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_datasphere_chunks(document_id, query_params))
+    }
+
+    /// Streams every item of `list_datasphere_chunks`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_datasphere_chunks_stream<'a>(&'a self, document_id: &'a str, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<DatasphereChunk, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_datasphere_chunks(document_id, query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_datasphere_chunks` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_datasphere_chunks_all(&self, document_id: &str, query_params: &[(String, String)]) -> Result<Vec<DatasphereChunk>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_datasphere_chunks(document_id, query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_datasphere_chunks_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_datasphere_chunks_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_datasphere_chunks_all` instead")]
+    pub fn list_datasphere_chunks_all_blocking(&self, document_id: &str, query_params: &[(String, String)]) -> Result<Vec<DatasphereChunk>, SignalWireError> {
+        crate::blocking::block_on(self.list_datasphere_chunks_all(document_id, query_params))
+    }
+
+    /// Gets a chunk of a Datasphere document.
+    ///
+    /// # Arguments
+    ///
+    /// * `document_id` - The ID of the document
+    /// * `id` - The ID of the chunk
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereChunk` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the chunk doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_datasphere_chunk(&self, document_id: &str, id: &str) -> Result<DatasphereChunk, SignalWireError> {
+        self.get_datasphere_chunk_with_meta(document_id, id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_datasphere_chunk`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_datasphere_chunk_with_meta(&self, document_id: &str, id: &str) -> Result<ResponseEnvelope<DatasphereChunk>, SignalWireError> {
+        self.execute_with_meta(&GetDatasphereChunk { document_id, id }).await
+    }
+
+    /// Blocking version of `get_datasphere_chunk`.
+    ///
+    /// # Arguments
+    ///
+    /// * `document_id` - The ID of the document
+    /// * `id` - The ID of the chunk
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DatasphereChunk` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the chunk doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_datasphere_chunk`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_datasphere_chunk` instead")]
+    pub fn get_datasphere_chunk_blocking(&self, document_id: &str, id: &str) -> Result<DatasphereChunk, SignalWireError> {
+        crate::blocking::block_on(self.get_datasphere_chunk(document_id, id))
+    }
+
+    // ---------- Space Log Methods ----------
+
+    /// Lists the messages logged for the whole space, with their charges; richer than the Compatibility message list.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The time range and page to list, see `LogQuery::next_page`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `MessageLogsListResponse` with one page of logs if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_message_logs(&self, query: &LogQuery) -> Result<MessageLogsListResponse, SignalWireError> {
+        self.list_message_logs_with_meta(query).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_message_logs`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_message_logs_with_meta(&self, query: &LogQuery) -> Result<ResponseEnvelope<MessageLogsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListMessageLogs { query }).await
+    }
+
+    /// Blocking version of `list_message_logs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The time range and page to list, see `LogQuery::next_page`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `MessageLogsListResponse` with one page of logs if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_message_logs`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_message_logs` instead")]
+    pub fn list_message_logs_blocking(&self, query: &LogQuery) -> Result<MessageLogsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_message_logs(query))
+    }
+
+    /// Streams every item of `list_message_logs`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_message_logs_stream<'a>(&'a self, query: &'a LogQuery) -> impl Stream<Item = Result<MessageLog, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_message_logs(query).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_message_logs` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_message_logs_all(&self, query: &LogQuery) -> Result<Vec<MessageLog>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_message_logs(query).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_message_logs_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_message_logs_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_message_logs_all` instead")]
+    pub fn list_message_logs_all_blocking(&self, query: &LogQuery) -> Result<Vec<MessageLog>, SignalWireError> {
+        crate::blocking::block_on(self.list_message_logs_all(query))
+    }
+
+    /// Gets a message log by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the message
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `MessageLog` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the log doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_message_log(&self, id: &str) -> Result<MessageLog, SignalWireError> {
+        self.get_message_log_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_message_log`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_message_log_with_meta(&self, id: &str) -> Result<ResponseEnvelope<MessageLog>, SignalWireError> {
+        self.execute_with_meta(&GetMessageLog { id }).await
+    }
+
+    /// Blocking version of `get_message_log`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the message
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `MessageLog` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the log doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_message_log`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_message_log` instead")]
+    pub fn get_message_log_blocking(&self, id: &str) -> Result<MessageLog, SignalWireError> {
+        crate::blocking::block_on(self.get_message_log(id))
+    }
+
+    /// Lists the calls logged for the whole space, with their charges; richer than the Compatibility call list.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The time range and page to list, see `LogQuery::next_page`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `VoiceLogsListResponse` with one page of logs if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_voice_logs(&self, query: &LogQuery) -> Result<VoiceLogsListResponse, SignalWireError> {
+        self.list_voice_logs_with_meta(query).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_voice_logs`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_voice_logs_with_meta(&self, query: &LogQuery) -> Result<ResponseEnvelope<VoiceLogsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListVoiceLogs { query }).await
+    }
+
+    /// Blocking version of `list_voice_logs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The time range and page to list, see `LogQuery::next_page`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `VoiceLogsListResponse` with one page of logs if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_voice_logs`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_voice_logs` instead")]
+    pub fn list_voice_logs_blocking(&self, query: &LogQuery) -> Result<VoiceLogsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_voice_logs(query))
+    }
+
+    /// Streams every item of `list_voice_logs`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_voice_logs_stream<'a>(&'a self, query: &'a LogQuery) -> impl Stream<Item = Result<VoiceLog, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_voice_logs(query).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_voice_logs` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_voice_logs_all(&self, query: &LogQuery) -> Result<Vec<VoiceLog>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_voice_logs(query).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_voice_logs_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_voice_logs_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_voice_logs_all` instead")]
+    pub fn list_voice_logs_all_blocking(&self, query: &LogQuery) -> Result<Vec<VoiceLog>, SignalWireError> {
+        crate::blocking::block_on(self.list_voice_logs_all(query))
+    }
+
+    /// Gets a call log by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the call
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `VoiceLog` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the log doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_voice_log(&self, id: &str) -> Result<VoiceLog, SignalWireError> {
+        self.get_voice_log_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_voice_log`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_voice_log_with_meta(&self, id: &str) -> Result<ResponseEnvelope<VoiceLog>, SignalWireError> {
+        self.execute_with_meta(&GetVoiceLog { id }).await
+    }
+
+    /// Blocking version of `get_voice_log`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the call
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `VoiceLog` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the log doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_voice_log`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_voice_log` instead")]
+    pub fn get_voice_log_blocking(&self, id: &str) -> Result<VoiceLog, SignalWireError> {
+        crate::blocking::block_on(self.get_voice_log(id))
+    }
+
+    // ---------- SIP Endpoint Methods ----------
+
+    /// Lists the SIP endpoints of the space.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SipEndpointsListResponse` with one page of SIP endpoints if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn list_sip_endpoints(&self, query_params: &[(String, String)]) -> Result<SipEndpointsListResponse, SignalWireError> {
+        self.list_sip_endpoints_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `list_sip_endpoints`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_sip_endpoints_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<SipEndpointsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListSipEndpoints { query_params }).await
+    }
+
+    /// Blocking version of `list_sip_endpoints`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SipEndpointsListResponse` with one page of SIP endpoints if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_sip_endpoints`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_sip_endpoints` instead")]
+    pub fn list_sip_endpoints_blocking(&self, query_params: &[(String, String)]) -> Result<SipEndpointsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_sip_endpoints(query_params))
+    }
+
+    /// Streams every item of `list_sip_endpoints`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_sip_endpoints_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<SipEndpoint, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_sip_endpoints(query_params).await.map(Paginated::into_page) })
+    }
+
+    /// Collects every item of `list_sip_endpoints` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_sip_endpoints_all(&self, query_params: &[(String, String)]) -> Result<Vec<SipEndpoint>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_sip_endpoints(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_sip_endpoints_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_sip_endpoints_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_sip_endpoints_all` instead")]
+    pub fn list_sip_endpoints_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<SipEndpoint>, SignalWireError> {
+        crate::blocking::block_on(self.list_sip_endpoints_all(query_params))
+    }
+
+    /// Gets a SIP endpoint by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the SIP endpoint
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SipEndpoint` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the SIP endpoint doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_sip_endpoint(&self, id: &str) -> Result<SipEndpoint, SignalWireError> {
+        self.get_sip_endpoint_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `get_sip_endpoint`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_sip_endpoint_with_meta(&self, id: &str) -> Result<ResponseEnvelope<SipEndpoint>, SignalWireError> {
+        self.execute_with_meta(&GetSipEndpoint { id }).await
+    }
+
+    /// Blocking version of `get_sip_endpoint`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the SIP endpoint
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SipEndpoint` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the SIP endpoint doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_sip_endpoint`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::get_sip_endpoint` instead")]
+    pub fn get_sip_endpoint_blocking(&self, id: &str) -> Result<SipEndpoint, SignalWireError> {
+        crate::blocking::block_on(self.get_sip_endpoint(id))
+    }
+
+    /// Creates a SIP endpoint for a softphone or PBX to register with.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The endpoint's fields; `username` and `password` are required
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SipEndpoint` with the new endpoint if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because the username is taken.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn create_sip_endpoint(&self, request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        self.create_sip_endpoint_with_meta(request).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `create_sip_endpoint`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_sip_endpoint_with_meta(&self, request: &SipEndpointRequest) -> Result<ResponseEnvelope<SipEndpoint>, SignalWireError> {
+        self.execute_with_meta(&CreateSipEndpoint { request }).await
+    }
+
+    /// Blocking version of `create_sip_endpoint`.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The endpoint's fields; `username` and `password` are required
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `SipEndpoint` with the new endpoint if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because the username is taken.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_sip_endpoint`.")]
     #[cfg(feature = "blocking")]
-    pub fn list_subprojects_blocking(&self, query_params: &[(String, String)]) -> Result<SubprojectsListResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.list_subprojects(query_params))
+    #[deprecated(note = "use `BlockingSignalWireClient::create_sip_endpoint` instead")]
+    pub fn create_sip_endpoint_blocking(&self, request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        crate::blocking::block_on(self.create_sip_endpoint(request))
     }
 
-    /// Get details for a specific subproject (account).
+    /// Updates a SIP endpoint, e.g. its password or codecs.
     ///
     /// # Arguments
     ///
-    /// * `subproject_sid` - The SID (unique identifier) of the subproject to retrieve
+    /// * `id` - The ID of the SIP endpoint
+    /// * `request` - The fields to change; fields left `None` are kept
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `SubprojectResponse` with the subproject details if successful
+    /// - `SipEndpoint` with the updated endpoint if successful
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
-    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Returns `SignalWireError::NotFound` if the SIP endpoint doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn get_subproject(&self, subproject_sid: &str) -> Result<SubprojectResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/laml/2010-04-01/Accounts/{}", self.space_name, subproject_sid);
-
-        let response = self
-            .http_client
-            .get(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status == reqwest::StatusCode::NOT_FOUND {
-            return Err(SignalWireError::NotFound(format!("Subproject with SID {} not found", subproject_sid)));
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
-
-        let subproject_response: SubprojectResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    pub async fn update_sip_endpoint(&self, id: &str, request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        self.update_sip_endpoint_with_meta(id, request).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(subproject_response)
+    /// Same as `update_sip_endpoint`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_sip_endpoint_with_meta(&self, id: &str, request: &SipEndpointRequest) -> Result<ResponseEnvelope<SipEndpoint>, SignalWireError> {
+        self.execute_with_meta(&UpdateSipEndpoint { id, request }).await
     }
 
-    /// Blocking version of `get_subproject`.
+    /// Blocking version of `update_sip_endpoint`.
     ///
     /// # Arguments
     ///
-    /// * `subproject_sid` - The SID (unique identifier) of the subproject to retrieve
+    /// * `id` - The ID of the SIP endpoint
+    /// * `request` - The fields to change; fields left `None` are kept
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `SubprojectResponse` with the subproject details if successful
+    /// - `SipEndpoint` with the updated endpoint if successful
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
-    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Returns `SignalWireError::NotFound` if the SIP endpoint doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_subproject`.")]
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `update_sip_endpoint`.")]
     #[cfg(feature = "blocking")]
-    pub fn get_subproject_blocking(&self, subproject_sid: &str) -> Result<SubprojectResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.get_subproject(subproject_sid))
+    #[deprecated(note = "use `BlockingSignalWireClient::update_sip_endpoint` instead")]
+    pub fn update_sip_endpoint_blocking(&self, id: &str, request: &SipEndpointRequest) -> Result<SipEndpoint, SignalWireError> {
+        crate::blocking::block_on(self.update_sip_endpoint(id, request))
     }
 
-    /// Creates a new subproject (account) within the current project.
+    /// Deletes a SIP endpoint.
     ///
     /// # Arguments
     ///
-    /// * `friendly_name` - A human-readable name for the subproject
+    /// * `id` - The ID of the SIP endpoint
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `SubprojectResponse` with the details of the created subproject if successful
+    /// - `()` if the SIP endpoint was successfully deleted
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the SIP endpoint doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn create_subproject(&self, friendly_name: &str) -> Result<SubprojectResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/laml/2010-04-01/Accounts", self.space_name);
-
-        let form = [("FriendlyName", friendly_name)];
-
-        let response = self
-            .http_client
-            .post(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .form(&form)
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
-
-        let subproject_response: SubprojectResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    pub async fn delete_sip_endpoint(&self, id: &str) -> Result<(), SignalWireError> {
+        self.delete_sip_endpoint_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(subproject_response)
+    /// Same as `delete_sip_endpoint`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn delete_sip_endpoint_with_meta(&self, id: &str) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&DeleteSipEndpoint { id }).await
     }
 
-    /// Blocking version of `create_subproject`.
+    /// Blocking version of `delete_sip_endpoint`.
     ///
     /// # Arguments
     ///
-    /// * `friendly_name` - A human-readable name for the subproject
+    /// * `id` - The ID of the SIP endpoint
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `SubprojectResponse` with the details of the created subproject if successful
+    /// - `()` if the SIP endpoint was successfully deleted
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the SIP endpoint doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_subproject`.")]
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `delete_sip_endpoint`.")]
     #[cfg(feature = "blocking")]
-    pub fn create_subproject_blocking(&self, friendly_name: &str) -> Result<SubprojectResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.create_subproject(friendly_name))
+    #[deprecated(note = "use `BlockingSignalWireClient::delete_sip_endpoint` instead")]
+    pub fn delete_sip_endpoint_blocking(&self, id: &str) -> Result<(), SignalWireError> {
+        crate::blocking::block_on(self.delete_sip_endpoint(id))
     }
 
-    /// Updates an existing subproject (account).
+    // ---------- Domain Application Methods ----------
+
+    /// Lists the SIP domain applications of the space.
     ///
     /// # Arguments
     ///
-    /// * `subproject_sid` - The SID (unique identifier) of the subproject to update
-    /// * `friendly_name` - A new human-readable name for the subproject
-    /// * `status` - Optional status to set for the subproject ("active" or "suspended")
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `SubprojectResponse` with the updated subproject details if successful
+    /// - `DomainApplicationsListResponse` with one page of domain applications if successful
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
-    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn update_subproject(&self, subproject_sid: &str, friendly_name: &str, status: Option<&str>) -> Result<SubprojectResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/laml/2010-04-01/Accounts/{}", self.space_name, subproject_sid);
+    pub async fn list_domain_applications(&self, query_params: &[(String, String)]) -> Result<DomainApplicationsListResponse, SignalWireError> {
+        self.list_domain_applications_with_meta(query_params).await.map(ResponseEnvelope::into_inner)
+    }
 
-        let mut form = vec![("FriendlyName", friendly_name)];
-        if let Some(status_value) = status {
-            form.push(("Status", status_value));
-        }
+    /// Same as `list_domain_applications`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn list_domain_applications_with_meta(&self, query_params: &[(String, String)]) -> Result<ResponseEnvelope<DomainApplicationsListResponse>, SignalWireError> {
+        self.execute_with_meta(&ListDomainApplications { query_params }).await
+    }
 
-        let response = self
-            .http_client
-            .post(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .form(&form)
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+    /// Blocking version of `list_domain_applications`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_params` - Optional query parameters, e.g. `page_size`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DomainApplicationsListResponse` with one page of domain applications if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `list_domain_applications`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_domain_applications` instead")]
+    pub fn list_domain_applications_blocking(&self, query_params: &[(String, String)]) -> Result<DomainApplicationsListResponse, SignalWireError> {
+        crate::blocking::block_on(self.list_domain_applications(query_params))
+    }
 
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+    /// Streams every item of `list_domain_applications`, fetching the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the error of the first failing page, then ends.
+    pub fn list_domain_applications_stream<'a>(&'a self, query_params: &'a [(String, String)]) -> impl Stream<Item = Result<DomainApplication, SignalWireError>> + 'a {
+        pagination::stream_items(self, async move { self.list_domain_applications(query_params).await.map(Paginated::into_page) })
+    }
 
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status == reqwest::StatusCode::NOT_FOUND {
-            return Err(SignalWireError::NotFound(format!("Subproject with SID {} not found", subproject_sid)));
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
+    /// Collects every item of `list_domain_applications` into a `Vec`, within the client's `CollectLimits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn list_domain_applications_all(&self, query_params: &[(String, String)]) -> Result<Vec<DomainApplication>, SignalWireError> {
+        pagination::collect_items(self, async { self.list_domain_applications(query_params).await.map(Paginated::into_page) }).await
+    }
+
+    /// Blocking version of `list_domain_applications_all`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `list_domain_applications_all`.
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::list_domain_applications_all` instead")]
+    pub fn list_domain_applications_all_blocking(&self, query_params: &[(String, String)]) -> Result<Vec<DomainApplication>, SignalWireError> {
+        crate::blocking::block_on(self.list_domain_applications_all(query_params))
+    }
 
-        let subproject_response: SubprojectResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    /// Gets a SIP domain application by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the domain application
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `DomainApplication` if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the domain application doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn get_domain_application(&self, id: &str) -> Result<DomainApplication, SignalWireError> {
+        self.get_domain_application_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(subproject_response)
+    /// Same as `get_domain_application`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn get_domain_application_with_meta(&self, id: &str) -> Result<ResponseEnvelope<DomainApplication>, SignalWireError> {
+        self.execute_with_meta(&GetDomainApplication { id }).await
     }
 
-    /// Blocking version of `update_subproject`.
+    /// Blocking version of `get_domain_application`.
     ///
     /// # Arguments
     ///
-    /// * `subproject_sid` - The SID (unique identifier) of the subproject to update
-    /// * `friendly_name` - A new human-readable name for the subproject
-    /// * `status` - Optional status to set for the subproject ("active" or "suspended")
+    /// * `id` - The ID of the domain application
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `SubprojectResponse` with the updated subproject details if successful
+    /// - `DomainApplication` if successful
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
-    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Returns `SignalWireError::NotFound` if the domain application doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    #[cfg_attr(feature = "blocking", doc = "Blocking version of `update_subproject`.")]
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_domain_application`.")]
     #[cfg(feature = "blocking")]
-    pub fn update_subproject_blocking(&self, subproject_sid: &str, friendly_name: &str, status: Option<&str>) -> Result<SubprojectResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.update_subproject(subproject_sid, friendly_name, status))
+    #[deprecated(note = "use `BlockingSignalWireClient::get_domain_application` instead")]
+    pub fn get_domain_application_blocking(&self, id: &str) -> Result<DomainApplication, SignalWireError> {
+        crate::blocking::block_on(self.get_domain_application(id))
     }
 
-    /// Deletes a subproject (account).
+    /// Creates a SIP domain application, e.g. for an external SIP trunk to send calls to.
     ///
     /// # Arguments
     ///
-    /// * `subproject_sid` - The SID (unique identifier) of the subproject to delete
+    /// * `request` - The application's fields; `identifier` and `call_handler` are required
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `()` if the subproject was successfully deleted
+    /// - `DomainApplication` with the new application if successful
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
-    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because the identifier is taken.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn delete_subproject(&self, subproject_sid: &str) -> Result<(), SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/laml/2010-04-01/Accounts/{}", self.space_name, subproject_sid);
-
-        let response = self
-            .http_client
-            .delete(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status == reqwest::StatusCode::NOT_FOUND {
-            return Err(SignalWireError::NotFound(format!("Subproject with SID {} not found", subproject_sid)));
-        } else if status.is_client_error() || status.is_server_error() {
-            let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-            return Err(SignalWireError::Unexpected(response_text));
-        }
+    pub async fn create_domain_application(&self, request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        self.create_domain_application_with_meta(request).await.map(ResponseEnvelope::into_inner)
+    }
 
-        // Success, return empty result
-        Ok(())
+    /// Same as `create_domain_application`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn create_domain_application_with_meta(&self, request: &DomainApplicationRequest) -> Result<ResponseEnvelope<DomainApplication>, SignalWireError> {
+        self.execute_with_meta(&CreateDomainApplication { request }).await
     }
 
-    /// Blocking version of `delete_subproject`.
+    /// Blocking version of `create_domain_application`.
     ///
     /// # Arguments
     ///
-    /// * `subproject_sid` - The SID (unique identifier) of the subproject to delete
+    /// * `request` - The application's fields; `identifier` and `call_handler` are required
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `()` if the subproject was successfully deleted
+    /// - `DomainApplication` with the new application if successful
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
-    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Returns `SignalWireError::Unexpected` if the request is rejected, e.g. because the identifier is taken.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    #[cfg_attr(feature = "blocking", doc = "Blocking version of `delete_subproject`.")]
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `create_domain_application`.")]
     #[cfg(feature = "blocking")]
-    pub fn delete_subproject_blocking(&self, subproject_sid: &str) -> Result<(), SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.delete_subproject(subproject_sid))
+    #[deprecated(note = "use `BlockingSignalWireClient::create_domain_application` instead")]
+    pub fn create_domain_application_blocking(&self, request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        crate::blocking::block_on(self.create_domain_application(request))
     }
 
-    // ---------- Subproject Resource Methods ----------
-
-    /// Lists phone numbers owned by a specific subproject.
-    ///
-    /// This method allows you to retrieve all phone numbers that belong to a specific subproject
-    /// using your main account's credentials.
+    /// Updates a SIP domain application, e.g. its allowed IP addresses or call handler.
     ///
     /// # Arguments
     ///
-    /// * `subproject_sid` - The SID (unique identifier) of the subproject
-    /// * `query_params` - Additional query parameters as key-value pairs.
+    /// * `id` - The ID of the domain application
+    /// * `request` - The fields to change; fields left `None` are kept
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `SubprojectPhoneNumbersResponse` with detailed phone number info if successful.
-    /// - `SignalWireError` if the request fails or is unauthorized.
+    /// - `DomainApplication` with the updated application if successful
+    /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
-    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Returns `SignalWireError::NotFound` if the domain application doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn get_subproject_phone_numbers(&self, subproject_sid: &str, query_params: &[(String, String)]) -> Result<SubprojectPhoneNumbersResponse, SignalWireError> {
-        // First check if the subproject exists
-        self.get_subproject(subproject_sid).await?;
-
-        // URL to get phone numbers from a specific subproject
-        let url = format!("https://{}.signalwire.com/api/laml/2010-04-01/Accounts/{}/IncomingPhoneNumbers", self.space_name, subproject_sid);
-
-        let url = Url::parse_with_params(&url, query_params).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        let response = self
-            .http_client
-            .get(url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status == reqwest::StatusCode::NOT_FOUND {
-            return Err(SignalWireError::NotFound(format!("Subproject with SID {} not found", subproject_sid)));
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
-
-        let phone_numbers_response: SubprojectPhoneNumbersResponse =
-            serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    pub async fn update_domain_application(&self, id: &str, request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        self.update_domain_application_with_meta(id, request).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(phone_numbers_response)
+    /// Same as `update_domain_application`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn update_domain_application_with_meta(&self, id: &str, request: &DomainApplicationRequest) -> Result<ResponseEnvelope<DomainApplication>, SignalWireError> {
+        self.execute_with_meta(&UpdateDomainApplication { id, request }).await
     }
 
-    /// Blocking version of `get_subproject_phone_numbers`.
+    /// Blocking version of `update_domain_application`.
     ///
     /// # Arguments
     ///
-    /// * `subproject_sid` - The SID (unique identifier) of the subproject
-    /// * `query_params` - Additional query parameters as key-value pairs.
+    /// * `id` - The ID of the domain application
+    /// * `request` - The fields to change; fields left `None` are kept
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `SubprojectPhoneNumbersResponse` with detailed phone number info if successful.
-    /// - `SignalWireError` if the request fails or is unauthorized.
+    /// - `DomainApplication` with the updated application if successful
+    /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
-    /// Returns `SignalWireError::NotFound` if the subproject SID doesn't exist.
+    /// Returns `SignalWireError::NotFound` if the domain application doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    #[cfg_attr(feature = "blocking", doc = "Blocking version of `get_subproject_phone_numbers`.")]
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `update_domain_application`.")]
     #[cfg(feature = "blocking")]
-    pub fn get_subproject_phone_numbers_blocking(&self, subproject_sid: &str, query_params: &[(String, String)]) -> Result<SubprojectPhoneNumbersResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.get_subproject_phone_numbers(subproject_sid, query_params))
+    #[deprecated(note = "use `BlockingSignalWireClient::update_domain_application` instead")]
+    pub fn update_domain_application_blocking(&self, id: &str, request: &DomainApplicationRequest) -> Result<DomainApplication, SignalWireError> {
+        crate::blocking::block_on(self.update_domain_application(id, request))
     }
 
-    // ---------- Phone Number Lookup & Validation Methods ----------
-
-    /// Looks up and validates a phone number.
-    ///
-    /// This method validates a phone number to ensure it is valid and properly formatted.
-    /// It returns basic information about the number such as country code and formatting.
+    /// Deletes a SIP domain application.
     ///
     /// # Arguments
     ///
-    /// * `phone_number` - The phone number to lookup and validate
+    /// * `id` - The ID of the domain application
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `PhoneLookupResponse` with information about the phone number if successful
+    /// - `()` if the domain application was successfully deleted
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the domain application doesn't exist.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn lookup_phone_number(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/relay/rest/lookup/phone_number/{}", self.space_name, phone_number);
+    pub async fn delete_domain_application(&self, id: &str) -> Result<(), SignalWireError> {
+        self.delete_domain_application_with_meta(id).await.map(ResponseEnvelope::into_inner)
+    }
 
-        let response = self
-            .http_client
-            .get(&url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+    /// Same as `delete_domain_application`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn delete_domain_application_with_meta(&self, id: &str) -> Result<ResponseEnvelope<()>, SignalWireError> {
+        self.execute_with_meta(&DeleteDomainApplication { id }).await
+    }
 
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+    /// Blocking version of `delete_domain_application`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the domain application
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `()` if the domain application was successfully deleted
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::NotFound` if the domain application doesn't exist.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `delete_domain_application`.")]
+    #[cfg(feature = "blocking")]
+    #[deprecated(note = "use `BlockingSignalWireClient::delete_domain_application` instead")]
+    pub fn delete_domain_application_blocking(&self, id: &str) -> Result<(), SignalWireError> {
+        crate::blocking::block_on(self.delete_domain_application(id))
+    }
 
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
+    // ---------- MFA Methods ----------
 
-        let lookup_response: PhoneLookupResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    /// Sends a one-time code by SMS, to be checked with `mfa_verify`.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - The E.164 number to send the code to
+    /// * `options` - The sender, text, code length, attempts and validity
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either:
+    /// - `MfaResponse` with the ID of the request if successful
+    /// - `SignalWireError` if the request fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Validation` in dry-run mode if `to` isn't an E.164 number.
+    /// Other `SignalWireError` variants may be returned for unexpected issues.
+    pub async fn mfa_send_sms(&self, to: &str, options: &MfaOptions) -> Result<MfaResponse, SignalWireError> {
+        self.mfa_send_sms_with_meta(to, options).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(lookup_response)
+    /// Same as `mfa_send_sms`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn mfa_send_sms_with_meta(&self, to: &str, options: &MfaOptions) -> Result<ResponseEnvelope<MfaResponse>, SignalWireError> {
+        self.execute_with_meta(&SendMfaSms { to, options }).await
     }
 
-    /// Blocking version of `lookup_phone_number`.
+    /// Blocking version of `mfa_send_sms`.
     ///
     /// # Arguments
     ///
-    /// * `phone_number` - The phone number to lookup and validate
+    /// * `to` - The E.164 number to send the code to
+    /// * `options` - The sender, text, code length, attempts and validity
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `PhoneLookupResponse` with information about the phone number if successful
+    /// - `MfaResponse` with the ID of the request if successful
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Validation` in dry-run mode if `to` isn't an E.164 number.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    #[cfg_attr(feature = "blocking", doc = "Blocking version of `lookup_phone_number`.")]
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `mfa_send_sms`.")]
     #[cfg(feature = "blocking")]
-    pub fn lookup_phone_number_blocking(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.lookup_phone_number(phone_number))
+    #[deprecated(note = "use `BlockingSignalWireClient::mfa_send_sms` instead")]
+    pub fn mfa_send_sms_blocking(&self, to: &str, options: &MfaOptions) -> Result<MfaResponse, SignalWireError> {
+        crate::blocking::block_on(self.mfa_send_sms(to, options))
     }
 
-    /// Looks up a phone number with carrier information.
-    ///
-    /// This method validates a phone number and returns carrier information about the number,
-    /// including the carrier name and whether it's a mobile, landline, or VoIP number.
+    /// Sends a one-time code by voice call, read out to the callee, to be checked with `mfa_verify`.
     ///
     /// # Arguments
     ///
-    /// * `phone_number` - The phone number to lookup and validate
+    /// * `to` - The E.164 number to call
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `PhoneLookupResponse` with information about the phone number and carrier if successful
+    /// - `MfaResponse` with the ID of the request if successful
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Validation` in dry-run mode if `to` isn't an E.164 number.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn lookup_phone_number_with_carrier(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/relay/rest/lookup/phone_number/{}", self.space_name, phone_number);
-
-        let params = PhoneLookupParams::new().with_carrier().build();
-        let url = Url::parse_with_params(&url, &params).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        let response = self
-            .http_client
-            .get(url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
-
-        let lookup_response: PhoneLookupResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    pub async fn mfa_send_call(&self, to: &str) -> Result<MfaResponse, SignalWireError> {
+        self.mfa_send_call_with_meta(to).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(lookup_response)
+    /// Same as `mfa_send_call`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn mfa_send_call_with_meta(&self, to: &str) -> Result<ResponseEnvelope<MfaResponse>, SignalWireError> {
+        self.execute_with_meta(&SendMfaCall { to, options: &MfaOptions::default() }).await
     }
 
-    /// Blocking version of `lookup_phone_number_with_carrier`.
+    /// Blocking version of `mfa_send_call`.
     ///
     /// # Arguments
     ///
-    /// * `phone_number` - The phone number to lookup and validate
+    /// * `to` - The E.164 number to call
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `PhoneLookupResponse` with information about the phone number and carrier if successful
+    /// - `MfaResponse` with the ID of the request if successful
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
+    /// Returns `SignalWireError::Validation` in dry-run mode if `to` isn't an E.164 number.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    #[cfg_attr(feature = "blocking", doc = "Blocking version of `lookup_phone_number_with_carrier`.")]
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `mfa_send_call`.")]
     #[cfg(feature = "blocking")]
-    pub fn lookup_phone_number_with_carrier_blocking(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.lookup_phone_number_with_carrier(phone_number))
+    #[deprecated(note = "use `BlockingSignalWireClient::mfa_send_call` instead")]
+    pub fn mfa_send_call_blocking(&self, to: &str) -> Result<MfaResponse, SignalWireError> {
+        crate::blocking::block_on(self.mfa_send_call(to))
     }
 
-    /// Looks up a phone number with caller name (CNAM) information.
-    ///
-    /// This method validates a phone number and returns caller name information,
-    /// which provides the registered name of the phone number owner if available.
+    /// Checks a one-time code sent with `mfa_send_sms` or `mfa_send_call`.
     ///
     /// # Arguments
     ///
-    /// * `phone_number` - The phone number to lookup and validate
+    /// * `request_id` - The `id` of the `MfaResponse`
+    /// * `token` - The code entered by the user
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `PhoneLookupResponse` with information about the phone number and caller name if successful
+    /// - `MfaVerification::Verified`, `Failed` (wrong code) or `Expired` (a new code must be sent)
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    pub async fn lookup_phone_number_with_caller_name(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
-        let url = format!("https://{}.signalwire.com/api/relay/rest/lookup/phone_number/{}", self.space_name, phone_number);
-
-        let params = PhoneLookupParams::new().with_caller_name().build();
-        let url = Url::parse_with_params(&url, &params).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        let response = self
-            .http_client
-            .get(url)
-            .basic_auth(&self.project_id, Some(&self.api_key))
-            .send()
-            .await
-            .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
-
-        let status = response.status();
-        let response_text = response.text().await.map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
-
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(SignalWireError::Unauthorized);
-        } else if status.is_client_error() || status.is_server_error() {
-            return Err(SignalWireError::Unexpected(response_text));
-        }
-
-        let lookup_response: PhoneLookupResponse = serde_json::from_str(&response_text).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response_text)))?;
+    pub async fn mfa_verify(&self, request_id: &str, token: &str) -> Result<MfaVerification, SignalWireError> {
+        self.mfa_verify_with_meta(request_id, token).await.map(ResponseEnvelope::into_inner)
+    }
 
-        Ok(lookup_response)
+    /// Same as `mfa_verify`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn mfa_verify_with_meta(&self, request_id: &str, token: &str) -> Result<ResponseEnvelope<MfaVerification>, SignalWireError> {
+        self.execute_with_meta(&VerifyMfa { request_id, token }).await
     }
 
-    /// Blocking version of `lookup_phone_number_with_caller_name`.
+    /// Blocking version of `mfa_verify`.
     ///
     /// # Arguments
     ///
-    /// * `phone_number` - The phone number to lookup and validate
+    /// * `request_id` - The `id` of the `MfaResponse`
+    /// * `token` - The code entered by the user
     ///
     /// # Returns
     ///
     /// A `Result` containing either:
-    /// - `PhoneLookupResponse` with information about the phone number and caller name if successful
+    /// - `MfaVerification::Verified`, `Failed` (wrong code) or `Expired` (a new code must be sent)
     /// - `SignalWireError` if the request fails
     ///
     /// # Errors
     ///
     /// Returns `SignalWireError::Unauthorized` if authentication fails.
     /// Other `SignalWireError` variants may be returned for unexpected issues.
-    #[cfg_attr(feature = "blocking", doc = "Blocking version of `lookup_phone_number_with_caller_name`.")]
+    #[cfg_attr(feature = "blocking", doc = "Blocking version of `mfa_verify`.")]
     #[cfg(feature = "blocking")]
-    pub fn lookup_phone_number_with_caller_name_blocking(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
-        tokio::runtime::Runtime::new().unwrap().block_on(self.lookup_phone_number_with_caller_name(phone_number))
+    #[deprecated(note = "use `BlockingSignalWireClient::mfa_verify` instead")]
+    pub fn mfa_verify_blocking(&self, request_id: &str, token: &str) -> Result<MfaVerification, SignalWireError> {
+        crate::blocking::block_on(self.mfa_verify(request_id, token))
     }
 }
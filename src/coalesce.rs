@@ -0,0 +1,75 @@
+//! Sharing of identical GET requests in flight, see `SignalWireClient::with_request_coalescing`.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use futures_util::future::{FutureExt, Shared};
+use tokio::sync::oneshot;
+
+use crate::{errors::SignalWireError, sansio::HttpResponse};
+
+type InFlight = Shared<oneshot::Receiver<Result<HttpResponse, SignalWireError>>>;
+
+/// The GET requests in flight, by project and URL.
+///
+/// The first caller of a key sends the request, later callers wait for the same response. A key is
+/// forgotten as soon as its response arrives, so nothing is cached beyond the requests in flight.
+#[derive(Default)]
+pub(crate) struct RequestCoalescer {
+    in_flight: Mutex<HashMap<String, InFlight>>,
+}
+
+/// Forgets the key of the request in flight when it is answered or given up.
+struct Sending<'a> {
+    coalescer: &'a RequestCoalescer,
+    key: &'a str,
+}
+
+impl Drop for Sending<'_> {
+    fn drop(&mut self) {
+        self.coalescer.in_flight.lock().unwrap().remove(self.key);
+    }
+}
+
+impl RequestCoalescer {
+    /// Runs `fetch`, or waits for the request already in flight for `key`.
+    ///
+    /// The response is handed over a channel rather than by sharing `fetch` itself, which needn't be `Send`. If the
+    /// caller sending the request gives up, the ones waiting send their own.
+    pub(crate) async fn run<F>(self: &Arc<Self>, key: String, fetch: F) -> Result<HttpResponse, SignalWireError>
+    where
+        F: Future<Output = Result<HttpResponse, SignalWireError>>,
+    {
+        let sender = loop {
+            let waiting = {
+                let mut in_flight = self.in_flight.lock().unwrap();
+                match in_flight.get(&key) {
+                    Some(request) => request.clone(),
+                    None => {
+                        let (sender, receiver) = oneshot::channel();
+                        in_flight.insert(key.clone(), receiver.shared());
+                        break sender;
+                    }
+                }
+            };
+            if let Ok(response) = waiting.await {
+                return response;
+            }
+        };
+
+        let sending = Sending { coalescer: self, key: &key };
+        let response = fetch.await;
+        drop(sending);
+        let _ = sender.send(response.clone());
+        response
+    }
+}
+
+impl std::fmt::Debug for RequestCoalescer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestCoalescer").field("in_flight", &self.in_flight.lock().unwrap().len()).finish()
+    }
+}
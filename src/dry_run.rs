@@ -0,0 +1,139 @@
+//! Synthesized responses returned by billable operations in dry-run mode.
+
+use std::time::Duration;
+
+use crate::{
+    endpoint::{Endpoint, LAML_API_VERSION},
+    rt,
+    types::*,
+    webhook::CallStatus,
+};
+
+/// The header added to the metadata of every dry-run response.
+#[cfg(any(test, feature = "reqwest"))]
+const DRY_RUN_HEADER: &str = "x-signalwire-dry-run";
+
+#[cfg(any(test, feature = "reqwest"))]
+pub(crate) fn response_meta() -> ResponseMeta {
+    ResponseMeta::new(201, vec![(DRY_RUN_HEADER.to_string(), "true".to_string())])
+}
+
+pub(crate) fn sms_response(message: &SmsMessage, account_sid: &str) -> SmsResponse {
+    let now = Timestamp::now();
+    let sid = format!("SM{:032x}", now.since_epoch.as_nanos());
+    let now = now.rfc2822();
+
+    SmsResponse {
+        uri: Endpoint::Message { account_sid, message_sid: &sid }.path(),
+        sid: MessageSid::unchecked(sid),
+        date_created: now.clone(),
+        date_updated: now,
+        account_sid: AccountSid::unchecked(account_sid),
+        to: message.to.clone(),
+        from: message.from.clone(),
+        messaging_service_sid: message.number_group_id.clone(),
+        body: message.body.clone(),
+        status: MessageStatus::Queued.to_string(),
+        num_segments: 1,
+        direction: "outbound-api".to_string(),
+        api_version: LAML_API_VERSION.to_string(),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn call_response(call: &NewCall, account_sid: &str) -> CallResponse {
+    let now = Timestamp::now();
+    let sid = format!("CA{:032x}", now.since_epoch.as_nanos());
+    let now = now.rfc2822();
+
+    CallResponse {
+        uri: Some(format!("{}/{}", Endpoint::Calls { account_sid }.path(), sid)),
+        sid: CallSid::unchecked(sid),
+        account_sid: AccountSid::unchecked(account_sid),
+        date_created: Some(now.clone()),
+        date_updated: Some(now),
+        to: call.to.clone(),
+        from: call.from.clone(),
+        status: CallStatus::Queued.to_string(),
+        direction: "outbound-api".to_string(),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn buy_phone_number_response(phone_number: &str) -> BuyPhoneNumberResponse {
+    let now = Timestamp::now();
+    // Shaped like the UUIDs the API gives phone numbers
+    let hex = format!("{:032x}", now.since_epoch.as_nanos());
+    let id = format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..]);
+    let now = now.rfc3339();
+
+    BuyPhoneNumberResponse {
+        id: PhoneNumberId::unchecked(id),
+        number: phone_number.to_string(),
+        name: Some(phone_number.to_string()),
+        capabilities: NumberCapability::ALL.to_vec(),
+        created_at: Some(now.clone()),
+        updated_at: Some(now),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn mfa_response(to: &str, channel: &str) -> MfaResponse {
+    MfaResponse {
+        id: format!("dry-run-{:x}", Timestamp::now().since_epoch.as_nanos()),
+        success: true,
+        to: to.to_string(),
+        channel: channel.to_string(),
+    }
+}
+
+/// The current time, formatted the way the API formats dates.
+struct Timestamp {
+    since_epoch: Duration,
+    // The UTC date and time of day
+    year: i64,
+    month: usize,
+    day: i64,
+    seconds: u64,
+}
+
+impl Timestamp {
+    fn now() -> Self {
+        let since_epoch = rt::since_epoch();
+        let days = (since_epoch.as_secs() / 86_400) as i64;
+
+        // The civil date of a day count, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days_from_march = days + 719_468;
+        let era = days_from_march.div_euclid(146_097);
+        let day_of_era = days_from_march.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+
+        Timestamp {
+            since_epoch,
+            year: year_of_era + era * 400 + i64::from(month <= 2),
+            month: month as usize,
+            day: day_of_year - (153 * month_from_march + 2) / 5 + 1,
+            seconds: since_epoch.as_secs() % 86_400,
+        }
+    }
+
+    /// E.g. `Tue, 01 Jul 2003 10:52:37 +0000`, the dates of the Compatibility API.
+    fn rfc2822(&self) -> String {
+        const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+        const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+        let weekday = WEEKDAYS[(self.since_epoch.as_secs() / 86_400 % 7) as usize];
+        format!("{}, {:02} {} {} {} +0000", weekday, self.day, MONTHS[self.month - 1], self.year, self.time())
+    }
+
+    /// E.g. `2003-07-01T10:52:37Z`, the dates of the REST APIs.
+    fn rfc3339(&self) -> String {
+        format!("{}-{:02}-{:02}T{}Z", self.year, self.month, self.day, self.time())
+    }
+
+    fn time(&self) -> String {
+        format!("{:02}:{:02}:{:02}", self.seconds / 3_600, self.seconds / 60 % 60, self.seconds % 60)
+    }
+}
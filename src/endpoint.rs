@@ -0,0 +1,228 @@
+//! URL construction for every SignalWire REST endpoint used by the client.
+//!
+//! All paths live here, so changing an API version or domain is a one-line change and URL
+//! generation can be unit tested without a network.
+
+/// The domain spaces are hosted under.
+pub const DEFAULT_DOMAIN: &str = "signalwire.com";
+
+/// The version segment of the Compatibility (LaML) API.
+pub const LAML_API_VERSION: &str = "2010-04-01";
+
+/// Returns the base URL of a space, e.g. `https://example.signalwire.com`.
+pub fn space_base_url(space_name: &str) -> String {
+    format!("https://{}.{}", space_name, DEFAULT_DOMAIN)
+}
+
+/// Returns the Relay WebSocket URL for a base URL, e.g. `wss://example.signalwire.com` for `https://example.signalwire.com`.
+pub fn relay_url(base_url: &str) -> String {
+    let base_url = base_url.trim_end_matches('/');
+    match base_url.split_once("://") {
+        Some(("http", host)) => format!("ws://{}", host),
+        Some((_, host)) => format!("wss://{}", host),
+        None => format!("wss://{}", base_url),
+    }
+}
+
+/// A SignalWire REST endpoint, together with the identifiers its path needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endpoint<'a> {
+    /// `POST /api/relay/rest/jwt`
+    Jwt,
+    /// `GET /api/laml/2010-04-01/Accounts/{account_sid}/AvailablePhoneNumbers/{iso_country}/Local`
+    AvailablePhoneNumbers { account_sid: &'a str, iso_country: &'a str },
+    /// `GET|POST /api/relay/rest/phone_numbers`
+    PhoneNumbers,
+    /// `GET|PUT|DELETE /api/relay/rest/phone_numbers/{id}`
+    PhoneNumber { id: &'a str },
+    /// `GET|POST /api/laml/2010-04-01/Accounts/{account_sid}/Messages`
+    Messages { account_sid: &'a str },
+    /// `GET /api/laml/2010-04-01/Accounts/{account_sid}/Messages/{message_sid}`
+    Message { account_sid: &'a str, message_sid: &'a str },
+    /// `GET /api/laml/2010-04-01/Accounts/{account_sid}/SMS/ShortCodes`
+    ShortCodes { account_sid: &'a str },
+    /// `GET|POST /api/laml/2010-04-01/Accounts/{account_sid}/SMS/ShortCodes/{short_code_sid}`
+    ShortCode { account_sid: &'a str, short_code_sid: &'a str },
+    /// `GET|POST /api/laml/2010-04-01/Accounts/{account_sid}/Calls`
+    Calls { account_sid: &'a str },
+    /// `GET|POST /api/laml/2010-04-01/Accounts/{account_sid}/Conferences/{conference_sid}/Recordings`
+    ConferenceRecordings { account_sid: &'a str, conference_sid: &'a str },
+    /// `GET|POST|DELETE /api/laml/2010-04-01/Accounts/{account_sid}/Conferences/{conference_sid}/Recordings/{recording_sid}`
+    ConferenceRecording { account_sid: &'a str, conference_sid: &'a str, recording_sid: &'a str },
+    /// `GET /api/laml/2010-04-01/Accounts/{account_sid}/Conferences/{conference_sid}/Participants`
+    ConferenceParticipants { account_sid: &'a str, conference_sid: &'a str },
+    /// `GET|POST /api/laml/2010-04-01/Accounts/{account_sid}/Conferences/{conference_sid}/Participants/{call_sid}`
+    ConferenceParticipant { account_sid: &'a str, conference_sid: &'a str, call_sid: &'a str },
+    /// `GET /api/laml/2010-04-01/Accounts/{account_sid}/Recordings/{recording_sid}`, with `.mp3` or `.wav` for the audio
+    Recording { account_sid: &'a str, recording_sid: &'a str },
+    /// `GET|POST /api/laml/2010-04-01/Accounts`
+    Accounts,
+    /// `GET|POST|DELETE /api/laml/2010-04-01/Accounts/{account_sid}`
+    Account { account_sid: &'a str },
+    /// `GET /api/laml/2010-04-01/Accounts/{account_sid}/IncomingPhoneNumbers`
+    IncomingPhoneNumbers { account_sid: &'a str },
+    /// `GET /api/relay/rest/lookup/phone_number/{phone_number}`
+    PhoneNumberLookup { phone_number: &'a str },
+    /// `GET|POST /api/fabric/resources/swml_scripts`
+    SwmlScripts,
+    /// `GET|PUT|DELETE /api/fabric/resources/swml_scripts/{id}`
+    SwmlScript { id: &'a str },
+    /// `POST /api/relay/rest/tasks`
+    RelayTasks,
+    /// `POST /api/video/room_tokens`
+    RoomTokens,
+    /// `GET /api/video/room_recordings`
+    RoomRecordings,
+    /// `GET|DELETE /api/video/room_recordings/{id}`
+    RoomRecording { id: &'a str },
+    /// `GET /api/video/room_sessions/{id}/recordings`
+    RoomSessionRecordings { id: &'a str },
+    /// `GET`/`POST /api/fabric/resources/subscribers`
+    FabricSubscribers,
+    /// `GET`/`PUT`/`DELETE /api/fabric/resources/subscribers/{id}`
+    FabricSubscriber { id: &'a str },
+    /// `GET /api/fabric/addresses`
+    FabricAddresses,
+    /// `GET /api/fabric/addresses/{id}`
+    FabricAddress { id: &'a str },
+    /// `GET /api/fabric/resources`
+    FabricResources,
+    /// `GET`/`DELETE /api/fabric/resources/{id}`
+    FabricResource { id: &'a str },
+    /// `POST /api/fabric/resources/{collection}`, e.g. `swml_scripts`
+    FabricResourcesOfType { collection: &'a str },
+    /// `PUT /api/fabric/resources/{collection}/{id}`
+    FabricResourceOfType { collection: &'a str, id: &'a str },
+    /// `GET /api/fabric/resources/{id}/addresses`
+    FabricResourceAddresses { id: &'a str },
+    /// `POST /api/fabric/resources/{id}/phone_routes`
+    FabricResourcePhoneRoutes { id: &'a str },
+    /// `POST /api/fabric/subscribers/tokens`
+    FabricSubscriberTokens,
+    /// `POST /api/fabric/guests/tokens`
+    FabricGuestTokens,
+    /// `GET`/`POST /api/fabric/resources/ai_agents`
+    AiAgents,
+    /// `GET`/`PUT`/`DELETE /api/fabric/resources/ai_agents/{id}`
+    AiAgent { id: &'a str },
+    /// `GET`/`POST /api/datasphere/documents`
+    DatasphereDocuments,
+    /// `GET /api/datasphere/documents/{id}`
+    DatasphereDocument { id: &'a str },
+    /// `GET /api/datasphere/documents/{id}/chunks`
+    DatasphereDocumentChunks { id: &'a str },
+    /// `GET /api/datasphere/documents/{document_id}/chunks/{id}`
+    DatasphereDocumentChunk { document_id: &'a str, id: &'a str },
+    /// `POST /api/datasphere/documents/search`
+    DatasphereSearch,
+    /// `GET /api/messaging/logs`
+    MessageLogs,
+    /// `GET /api/messaging/logs/{id}`
+    MessageLog { id: &'a str },
+    /// `GET /api/voice/logs`
+    VoiceLogs,
+    /// `GET /api/voice/logs/{id}`
+    VoiceLog { id: &'a str },
+    /// `GET`/`POST /api/relay/rest/endpoints/sip`
+    SipEndpoints,
+    /// `GET`/`PUT`/`DELETE /api/relay/rest/endpoints/sip/{id}`
+    SipEndpoint { id: &'a str },
+    /// `GET`/`POST /api/relay/rest/domain_applications`
+    DomainApplications,
+    /// `GET`/`PUT`/`DELETE /api/relay/rest/domain_applications/{id}`
+    DomainApplication { id: &'a str },
+    /// `POST /api/relay/rest/mfa/sms`
+    MfaSms,
+    /// `POST /api/relay/rest/mfa/call`
+    MfaCall,
+    /// `POST /api/relay/rest/mfa/{id}/verify`
+    MfaVerify { id: &'a str },
+    /// `POST /api/chat/tokens`
+    ChatTokens,
+    /// `GET /api/video/room_sessions`
+    RoomSessions,
+    /// `GET /api/video/room_sessions/{id}`
+    RoomSession { id: &'a str },
+    /// `GET /api/video/room_sessions/{id}/members`
+    RoomSessionMembers { id: &'a str },
+}
+
+impl Endpoint<'_> {
+    /// The path of the endpoint, starting with `/`.
+    pub fn path(&self) -> String {
+        match self {
+            Endpoint::Jwt => "/api/relay/rest/jwt".to_string(),
+            Endpoint::AvailablePhoneNumbers { account_sid, iso_country } => format!("{}/AvailablePhoneNumbers/{}/Local", laml_account_path(account_sid), iso_country),
+            Endpoint::PhoneNumbers => "/api/relay/rest/phone_numbers".to_string(),
+            Endpoint::PhoneNumber { id } => format!("/api/relay/rest/phone_numbers/{}", id),
+            Endpoint::Messages { account_sid } => format!("{}/Messages", laml_account_path(account_sid)),
+            Endpoint::Message { account_sid, message_sid } => format!("{}/Messages/{}", laml_account_path(account_sid), message_sid),
+            Endpoint::ShortCodes { account_sid } => format!("{}/SMS/ShortCodes", laml_account_path(account_sid)),
+            Endpoint::ShortCode { account_sid, short_code_sid } => format!("{}/SMS/ShortCodes/{}", laml_account_path(account_sid), short_code_sid),
+            Endpoint::Calls { account_sid } => format!("{}/Calls", laml_account_path(account_sid)),
+            Endpoint::ConferenceRecordings { account_sid, conference_sid } => format!("{}/Conferences/{}/Recordings", laml_account_path(account_sid), conference_sid),
+            Endpoint::ConferenceRecording {
+                account_sid,
+                conference_sid,
+                recording_sid,
+            } => format!("{}/Conferences/{}/Recordings/{}", laml_account_path(account_sid), conference_sid, recording_sid),
+            Endpoint::ConferenceParticipants { account_sid, conference_sid } => format!("{}/Conferences/{}/Participants", laml_account_path(account_sid), conference_sid),
+            Endpoint::ConferenceParticipant { account_sid, conference_sid, call_sid } => format!("{}/Conferences/{}/Participants/{}", laml_account_path(account_sid), conference_sid, call_sid),
+            Endpoint::Recording { account_sid, recording_sid } => format!("{}/Recordings/{}", laml_account_path(account_sid), recording_sid),
+            Endpoint::Accounts => format!("/api/laml/{}/Accounts", LAML_API_VERSION),
+            Endpoint::Account { account_sid } => laml_account_path(account_sid),
+            Endpoint::IncomingPhoneNumbers { account_sid } => format!("{}/IncomingPhoneNumbers", laml_account_path(account_sid)),
+            Endpoint::PhoneNumberLookup { phone_number } => format!("/api/relay/rest/lookup/phone_number/{}", phone_number),
+            Endpoint::SwmlScripts => "/api/fabric/resources/swml_scripts".to_string(),
+            Endpoint::SwmlScript { id } => format!("/api/fabric/resources/swml_scripts/{}", id),
+            Endpoint::RelayTasks => "/api/relay/rest/tasks".to_string(),
+            Endpoint::RoomTokens => "/api/video/room_tokens".to_string(),
+            Endpoint::RoomRecordings => "/api/video/room_recordings".to_string(),
+            Endpoint::RoomRecording { id } => format!("/api/video/room_recordings/{}", id),
+            Endpoint::RoomSessionRecordings { id } => format!("/api/video/room_sessions/{}/recordings", id),
+            Endpoint::FabricSubscribers => "/api/fabric/resources/subscribers".to_string(),
+            Endpoint::FabricSubscriber { id } => format!("/api/fabric/resources/subscribers/{}", id),
+            Endpoint::FabricAddresses => "/api/fabric/addresses".to_string(),
+            Endpoint::FabricAddress { id } => format!("/api/fabric/addresses/{}", id),
+            Endpoint::FabricResources => "/api/fabric/resources".to_string(),
+            Endpoint::FabricResource { id } => format!("/api/fabric/resources/{}", id),
+            Endpoint::FabricResourcesOfType { collection } => format!("/api/fabric/resources/{}", collection),
+            Endpoint::FabricResourceOfType { collection, id } => format!("/api/fabric/resources/{}/{}", collection, id),
+            Endpoint::FabricResourceAddresses { id } => format!("/api/fabric/resources/{}/addresses", id),
+            Endpoint::FabricResourcePhoneRoutes { id } => format!("/api/fabric/resources/{}/phone_routes", id),
+            Endpoint::FabricSubscriberTokens => "/api/fabric/subscribers/tokens".to_string(),
+            Endpoint::FabricGuestTokens => "/api/fabric/guests/tokens".to_string(),
+            Endpoint::AiAgents => "/api/fabric/resources/ai_agents".to_string(),
+            Endpoint::AiAgent { id } => format!("/api/fabric/resources/ai_agents/{}", id),
+            Endpoint::DatasphereDocuments => "/api/datasphere/documents".to_string(),
+            Endpoint::DatasphereDocument { id } => format!("/api/datasphere/documents/{}", id),
+            Endpoint::DatasphereDocumentChunks { id } => format!("/api/datasphere/documents/{}/chunks", id),
+            Endpoint::DatasphereDocumentChunk { document_id, id } => format!("/api/datasphere/documents/{}/chunks/{}", document_id, id),
+            Endpoint::DatasphereSearch => "/api/datasphere/documents/search".to_string(),
+            Endpoint::MessageLogs => "/api/messaging/logs".to_string(),
+            Endpoint::MessageLog { id } => format!("/api/messaging/logs/{}", id),
+            Endpoint::VoiceLogs => "/api/voice/logs".to_string(),
+            Endpoint::VoiceLog { id } => format!("/api/voice/logs/{}", id),
+            Endpoint::SipEndpoints => "/api/relay/rest/endpoints/sip".to_string(),
+            Endpoint::SipEndpoint { id } => format!("/api/relay/rest/endpoints/sip/{}", id),
+            Endpoint::DomainApplications => "/api/relay/rest/domain_applications".to_string(),
+            Endpoint::DomainApplication { id } => format!("/api/relay/rest/domain_applications/{}", id),
+            Endpoint::MfaSms => "/api/relay/rest/mfa/sms".to_string(),
+            Endpoint::MfaCall => "/api/relay/rest/mfa/call".to_string(),
+            Endpoint::MfaVerify { id } => format!("/api/relay/rest/mfa/{}/verify", id),
+            Endpoint::ChatTokens => "/api/chat/tokens".to_string(),
+            Endpoint::RoomSessions => "/api/video/room_sessions".to_string(),
+            Endpoint::RoomSession { id } => format!("/api/video/room_sessions/{}", id),
+            Endpoint::RoomSessionMembers { id } => format!("/api/video/room_sessions/{}/members", id),
+        }
+    }
+
+    /// The full URL of the endpoint under `base_url` (e.g. the result of `space_base_url`).
+    pub fn url(&self, base_url: &str) -> String {
+        format!("{}{}", base_url.trim_end_matches('/'), self.path())
+    }
+}
+
+fn laml_account_path(account_sid: &str) -> String {
+    format!("/api/laml/{}/Accounts/{}", LAML_API_VERSION, account_sid)
+}
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum SignalWireError {
     #[error("HTTP request failed with status: {0}")]
     HttpError(String),
@@ -13,4 +13,157 @@ pub enum SignalWireError {
 
     #[error("Unexpected error: {0}")]
     Unexpected(String),
+
+    #[error("Validation failed: {0}")]
+    Validation(#[from] ValidationError),
+
+    #[error("Invalid LaML: {0}")]
+    Laml(#[from] LamlError),
+
+    #[error("Invalid SWML: {0}")]
+    Swml(#[from] SwmlError),
+
+    #[error("Relay error: {0}")]
+    Relay(#[from] RelayError),
+
+    #[error("Collecting the list stopped: {0}")]
+    CollectLimit(#[from] CollectLimitError),
+
+    #[error("Suppressed a duplicate of a message sent to {to} {since:?} ago")]
+    DuplicateSuppressed { to: String, since: std::time::Duration },
+
+    #[error("The sending hours of the timezones of {0} never overlap")]
+    NoSendingTime(String),
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    #[error("`{field}` is not a valid E.164 phone number: {value:?}")]
+    InvalidPhoneNumber { field: &'static str, value: String },
+
+    #[error("Message body must not be empty")]
+    EmptyBody,
+
+    #[error("Message body is {length} characters long, the maximum is {max}")]
+    BodyTooLong { length: usize, max: usize },
+
+    #[error("Not a pagination cursor: {0:?}")]
+    InvalidCursor(String),
+
+    #[error("Not a valid {kind} SID: {value:?}")]
+    InvalidSid { kind: &'static str, value: String },
+
+    #[error("Not a base64 SHA-256 certificate pin: {0:?}")]
+    InvalidPin(String),
+
+    #[error("A sender pool needs at least one number")]
+    EmptySenderPool,
+
+    #[error("Invalid message template: {0}")]
+    InvalidTemplate(String),
+
+    #[error("No value for the template placeholder `{{{0}}}`")]
+    MissingTemplateValue(String),
+
+    #[error("`{field}` is not a valid SIP URI: {value:?}")]
+    InvalidSipUri { field: &'static str, value: String },
+
+    #[error("SIP header names must start with `X-` and contain only letters, digits and dashes: {0:?}")]
+    InvalidSipHeader(String),
+
+    #[error("SIP headers and credentials can only be sent to a SIP URI")]
+    SipOptionsWithoutSipUri,
+
+    #[error("SIP credentials need both a username and a password")]
+    IncompleteSipAuth,
+
+    #[error("A call needs either a `url` or `laml`, not both")]
+    InvalidCallInstructions,
+
+    #[error("A message is sent from either a number or a number group, not both")]
+    SenderAndNumberGroup,
+
+    #[error("Not a number group ID: {0:?}")]
+    InvalidNumberGroup(String),
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum LamlError {
+    #[error("Malformed XML: {0}")]
+    Xml(String),
+
+    #[error("Expected a <Response> root element, found <{0}>")]
+    UnexpectedRoot(String),
+
+    #[error("Unknown verb <{0}>")]
+    UnknownVerb(String),
+
+    #[error("<{parent}> can't contain <{child}>")]
+    UnexpectedChild { parent: String, child: String },
+
+    #[error("Invalid value {value:?} for `{attribute}` on <{verb}>")]
+    InvalidAttribute { verb: String, attribute: String, value: String },
+
+    #[error("{path}: <{parent}> can't contain <{child}>")]
+    IllegalNesting { path: String, parent: String, child: String },
+
+    #[error("{path}: <{verb}> requires `{attribute}`")]
+    MissingAttribute { path: String, verb: String, attribute: String },
+
+    #[error("{path}: invalid value {value:?} for `{attribute}` on <{verb}>, expected {expected}")]
+    InvalidValue {
+        path: String,
+        verb: String,
+        attribute: String,
+        value: String,
+        expected: String,
+    },
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SwmlError {
+    #[error("Failed to serialize SWML: {0}")]
+    Serialization(String),
+
+    #[error("The document has no `main` section")]
+    MissingMainSection,
+
+    #[error("{path}: unknown method `{method}`")]
+    UnknownMethod { path: String, method: String },
+
+    #[error("{path}: `{method}` requires `{field}`")]
+    MissingField { path: String, method: String, field: String },
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum RelayError {
+    #[error("WebSocket error: {0}")]
+    WebSocket(String),
+
+    #[error("Malformed Relay frame: {0}")]
+    Protocol(String),
+
+    #[error("`{method}` failed with code {code}: {message}")]
+    Request { method: String, code: String, message: String },
+
+    #[error("`{0}` timed out")]
+    Timeout(String),
+
+    #[error("The Relay connection is closed")]
+    Closed,
+
+    #[error("Dialing failed: {0}")]
+    DialFailed(String),
+
+    #[error("Call {0} ended")]
+    CallEnded(String),
+}
+/// A `*_all` method stopped before the end of the list, see `CollectLimits`.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum CollectLimitError {
+    #[error("the list has more than {max_items} items")]
+    TooManyItems { max_items: usize },
+
+    #[error("the list has more than {max_pages} pages")]
+    TooManyPages { max_pages: usize },
 }
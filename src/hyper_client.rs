@@ -0,0 +1,135 @@
+//! An async client on `hyper` alone, enabled by the `hyper` feature.
+//!
+//! `HyperClient` runs any `sansio::Operation` over a pooled `hyper` HTTP/1.1 client with `native-tls`, for builds
+//! that keep their dependency tree small, e.g. to audit it or to save binary size. The most common calls also have
+//! methods of their own:
+//!
+//! ```rust,no_run
+//! use signalwire::{hyper_client::HyperClient, types::SmsMessage};
+//!
+//! # async fn example() -> Result<(), signalwire::errors::SignalWireError> {
+//! let client = HyperClient::new("your-space", "your-project-id", "your-api-key");
+//! let message = SmsMessage {
+//!     from: "+15551234567".to_string(),
+//!     to: "+15557654321".to_string(),
+//!     body: "Deploy finished".to_string(),
+//!     ..Default::default()
+//! };
+//! let sent = client.send_sms(&message).await?;
+//! println!("{:?}", client.get_message_status(&sent.sid).await?.status);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Like `ureq_client::UreqClient`, it has none of the client-side features of `SignalWireClient` (dry-run,
+//! cassettes, caching, coalescing, scheduling), nor its reqwest conveniences: no compression, proxies or timeouts.
+//! Wrap calls in `tokio::time::timeout` to bound them.
+
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper_tls::HttpsConnector;
+use hyper_util::{
+    client::legacy::{connect::HttpConnector, Client},
+    rt::TokioExecutor,
+};
+
+use crate::{errors::SignalWireError, sansio::*, types::*};
+
+/// The `hyper` client a `HyperClient` sends its requests with.
+pub type HttpsClient = Client<HttpsConnector<HttpConnector>, Full<Bytes>>;
+
+/// An async SignalWire client on `hyper`, see the module documentation.
+#[derive(Debug, Clone)]
+pub struct HyperClient {
+    credentials: Credentials,
+    client: HttpsClient,
+}
+
+impl HyperClient {
+    /// Creates a client for the space `space_name`, with a default connection pool.
+    pub fn new(space_name: &str, project_id: &str, api_key: &str) -> Self {
+        HyperClient {
+            credentials: Credentials::new(space_name, project_id, api_key),
+            client: Client::builder(TokioExecutor::new()).build(HttpsConnector::new()),
+        }
+    }
+
+    /// Overrides the base URL, e.g. to target a mock server.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.credentials = self.credentials.with_base_url(base_url);
+        self
+    }
+
+    /// Sends the requests through `client`, e.g. one with other pool settings.
+    pub fn with_client(mut self, client: HttpsClient) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+
+    /// Executes any `sansio::Operation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever error the operation maps the response to, or `SignalWireError::HttpError`
+    /// if the request can't be sent.
+    pub async fn execute<O: Operation>(&self, operation: &O) -> Result<O::Output, SignalWireError> {
+        self.execute_with_meta(operation).await.map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `execute`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub async fn execute_with_meta<O: Operation>(&self, operation: &O) -> Result<ResponseEnvelope<O::Output>, SignalWireError> {
+        let response = self.send(operation.request(&self.credentials)?).await?;
+        let meta = response.meta();
+
+        Ok(ResponseEnvelope { data: operation.parse(response)?, meta })
+    }
+
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, SignalWireError> {
+        let mut builder = http::Request::builder().method(request.method).uri(&request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        let request = builder.body(Full::new(Bytes::from(request.body.unwrap_or_default()))).map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+        let response = self.client.request(request).await.map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.as_str().to_string(), String::from_utf8_lossy(value.as_bytes()).into_owned()))
+            .collect();
+        let body = response.into_body().collect().await.map_err(|e| SignalWireError::HttpError(e.to_string()))?.to_bytes();
+        let body = String::from_utf8(body.into()).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+
+        Ok(HttpResponse { status, headers, body })
+    }
+
+    /// Same as `SignalWireClient::get_jwt`.
+    pub async fn get_jwt(&self) -> Result<JwtResponse, SignalWireError> {
+        self.execute(&GetJwt).await
+    }
+
+    /// Same as `SignalWireClient::send_sms`.
+    pub async fn send_sms(&self, message: &SmsMessage) -> Result<SmsResponse, SignalWireError> {
+        self.execute(&SendSms { message }).await
+    }
+
+    /// Same as `SignalWireClient::get_message_status`.
+    pub async fn get_message_status(&self, message_sid: &MessageSid) -> Result<SmsResponse, SignalWireError> {
+        self.execute(&GetMessageStatus { message_sid: message_sid.as_str() }).await
+    }
+
+    /// Same as `SignalWireClient::lookup_phone_number`.
+    pub async fn lookup_phone_number(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        self.execute(&LookupPhoneNumber { phone_number, query_params: &[] }).await
+    }
+
+    /// Same as `SignalWireClient::get_phone_numbers_owned`.
+    pub async fn get_phone_numbers_owned(&self, query_params: &[(String, String)]) -> Result<PhoneNumbersOwnedResponse, SignalWireError> {
+        self.execute(&GetPhoneNumbersOwned { query_params }).await
+    }
+}
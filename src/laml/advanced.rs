@@ -0,0 +1,382 @@
+//! The less common verbs: queues, SIP transfers, media streams and payments.
+
+use super::{element::Element, Sip, Verb};
+use crate::errors::LamlError;
+
+/// Puts the caller in a call queue.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Enqueue {
+    pub name: String,
+    pub action: Option<String>,
+    pub method: Option<String>,
+    /// LaML played to the caller while waiting.
+    pub wait_url: Option<String>,
+    pub wait_url_method: Option<String>,
+}
+
+impl Enqueue {
+    pub fn new(name: &str) -> Self {
+        Enqueue {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn to_element(&self) -> Element {
+        Element::new("Enqueue")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .attr("waitUrl", self.wait_url.as_ref())
+            .attr("waitUrlMethod", self.wait_url_method.as_ref())
+            .text(&self.name)
+    }
+
+    pub(super) fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Enqueue {
+            name: element.text.trim().to_string(),
+            action: element.get("action"),
+            method: element.get("method"),
+            wait_url: element.get("waitUrl"),
+            wait_url_method: element.get("waitUrlMethod"),
+        })
+    }
+}
+
+/// Transfers a SIP call to another SIP endpoint with a SIP REFER.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Refer {
+    pub sip: Sip,
+    pub action: Option<String>,
+    pub method: Option<String>,
+}
+
+impl Refer {
+    pub fn new(sip_uri: &str) -> Self {
+        Refer {
+            sip: Sip::new(sip_uri),
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn to_element(&self) -> Element {
+        Element::new("Refer")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .child(Element::new("Sip").attr("username", self.sip.username.as_ref()).attr("password", self.sip.password.as_ref()).text(&self.sip.uri))
+    }
+
+    pub(super) fn from_element(element: &Element) -> Result<Self, LamlError> {
+        let mut sip = None;
+        for child in &element.children {
+            match child.name.as_str() {
+                "Sip" => {
+                    sip = Some(Sip {
+                        uri: child.text.trim().to_string(),
+                        username: child.get("username"),
+                        password: child.get("password"),
+                    })
+                }
+                name => return Err(unexpected_child(element, name)),
+            }
+        }
+
+        Ok(Refer {
+            sip: sip.unwrap_or_default(),
+            action: element.get("action"),
+            method: element.get("method"),
+        })
+    }
+}
+
+/// Starts background processes on the call, e.g. forking the media to a websocket with `<Stream>`.
+///
+/// The LaML after `<Start>` keeps running while the stream is active.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Start {
+    pub streams: Vec<Stream>,
+}
+
+impl Start {
+    pub fn stream(stream: Stream) -> Self {
+        Start { streams: vec![stream] }
+    }
+
+    pub(super) fn to_element(&self) -> Element {
+        Element::new("Start").children(self.streams.iter().map(Stream::to_element))
+    }
+
+    pub(super) fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Start { streams: streams(element)? })
+    }
+}
+
+/// Connects the call to a bidirectional `<Stream>`; the LaML after `<Connect>` runs once the stream ends.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Connect {
+    pub action: Option<String>,
+    pub method: Option<String>,
+    pub streams: Vec<Stream>,
+}
+
+impl Connect {
+    pub fn stream(stream: Stream) -> Self {
+        Connect {
+            streams: vec![stream],
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn to_element(&self) -> Element {
+        Element::new("Connect")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .children(self.streams.iter().map(Stream::to_element))
+    }
+
+    pub(super) fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Connect {
+            action: element.get("action"),
+            method: element.get("method"),
+            streams: streams(element)?,
+        })
+    }
+}
+
+/// A websocket the call's audio is streamed to.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Stream {
+    /// A `wss://` URL.
+    pub url: String,
+    pub name: Option<String>,
+    /// `inbound_track`, `outbound_track` or `both_tracks`.
+    pub track: Option<String>,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<String>,
+    /// Custom `<Parameter>`s passed to the websocket server in the `start` message.
+    pub parameters: Vec<(String, String)>,
+}
+
+impl Stream {
+    pub fn new(url: &str) -> Self {
+        Stream {
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a custom parameter.
+    pub fn parameter(mut self, name: &str, value: &str) -> Self {
+        self.parameters.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Stream")
+            .attr("url", Some(&self.url))
+            .attr("name", self.name.as_ref())
+            .attr("track", self.track.as_ref())
+            .attr("statusCallback", self.status_callback.as_ref())
+            .attr("statusCallbackMethod", self.status_callback_method.as_ref())
+            .children(parameter_elements(&self.parameters))
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Stream {
+            url: element.get("url").unwrap_or_default(),
+            name: element.get("name"),
+            track: element.get("track"),
+            status_callback: element.get("statusCallback"),
+            status_callback_method: element.get("statusCallbackMethod"),
+            parameters: parameters(element)?,
+        })
+    }
+}
+
+/// Collects a card or bank account payment over DTMF and charges or tokenizes it through a payment connector.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Pay {
+    pub payment_connector_url: Option<String>,
+    pub action: Option<String>,
+    pub status_url: Option<String>,
+    /// The amount to charge; leave empty to only tokenize the payment method.
+    pub charge_amount: Option<String>,
+    pub currency: Option<String>,
+    pub description: Option<String>,
+    /// `credit-card` or `ach-debit`.
+    pub payment_method: Option<String>,
+    /// `one-time` or `reusable`.
+    pub token_type: Option<String>,
+    /// Space separated, e.g. `visa mastercard amex`.
+    pub valid_card_types: Option<String>,
+    pub security_code: Option<bool>,
+    /// `true`, `false` or the postal code to check against.
+    pub postal_code: Option<String>,
+    pub min_postal_code_length: Option<u32>,
+    pub timeout: Option<u32>,
+    pub max_attempts: Option<u32>,
+    pub language: Option<String>,
+    pub voice: Option<String>,
+    pub prompts: Vec<PayPrompt>,
+    /// Custom `<Parameter>`s forwarded to the payment connector.
+    pub parameters: Vec<(String, String)>,
+}
+
+impl Pay {
+    pub fn new(payment_connector_url: &str) -> Self {
+        Pay {
+            payment_connector_url: Some(payment_connector_url.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a custom prompt.
+    pub fn prompt(mut self, prompt: PayPrompt) -> Self {
+        self.prompts.push(prompt);
+        self
+    }
+
+    /// Adds a custom parameter.
+    pub fn parameter(mut self, name: &str, value: &str) -> Self {
+        self.parameters.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub(super) fn to_element(&self) -> Element {
+        Element::new("Pay")
+            .attr("paymentConnectorUrl", self.payment_connector_url.as_ref())
+            .attr("action", self.action.as_ref())
+            .attr("statusUrl", self.status_url.as_ref())
+            .attr("chargeAmount", self.charge_amount.as_ref())
+            .attr("currency", self.currency.as_ref())
+            .attr("description", self.description.as_ref())
+            .attr("paymentMethod", self.payment_method.as_ref())
+            .attr("tokenType", self.token_type.as_ref())
+            .attr("validCardTypes", self.valid_card_types.as_ref())
+            .attr("securityCode", self.security_code)
+            .attr("postalCode", self.postal_code.as_ref())
+            .attr("minPostalCodeLength", self.min_postal_code_length)
+            .attr("timeout", self.timeout)
+            .attr("maxAttempts", self.max_attempts)
+            .attr("language", self.language.as_ref())
+            .attr("voice", self.voice.as_ref())
+            .children(self.prompts.iter().map(PayPrompt::to_element))
+            .children(parameter_elements(&self.parameters))
+    }
+
+    pub(super) fn from_element(element: &Element) -> Result<Self, LamlError> {
+        let mut prompts = Vec::new();
+        for child in &element.children {
+            match child.name.as_str() {
+                "Prompt" => prompts.push(PayPrompt::from_element(child)?),
+                "Parameter" => {}
+                name => return Err(unexpected_child(element, name)),
+            }
+        }
+
+        Ok(Pay {
+            payment_connector_url: element.get("paymentConnectorUrl"),
+            action: element.get("action"),
+            status_url: element.get("statusUrl"),
+            charge_amount: element.get("chargeAmount"),
+            currency: element.get("currency"),
+            description: element.get("description"),
+            payment_method: element.get("paymentMethod"),
+            token_type: element.get("tokenType"),
+            valid_card_types: element.get("validCardTypes"),
+            security_code: element.parse_bool("securityCode")?,
+            postal_code: element.get("postalCode"),
+            min_postal_code_length: element.parse("minPostalCodeLength")?,
+            timeout: element.parse("timeout")?,
+            max_attempts: element.parse("maxAttempts")?,
+            language: element.get("language"),
+            voice: element.get("voice"),
+            prompts,
+            parameters: parameters(element)?,
+        })
+    }
+}
+
+/// Replaces the default prompt of one `<Pay>` step with custom `Say`/`Play`/`Pause` verbs.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct PayPrompt {
+    /// The step, e.g. `payment-card-number`, `expiration-date`, `security-code`, `payment-completed`.
+    pub for_step: String,
+    /// Space separated error types this prompt is used for, e.g. `timeout invalid-card-number`.
+    pub error_type: Option<String>,
+    pub card_type: Option<String>,
+    pub attempt: Option<String>,
+    pub verbs: Vec<Verb>,
+}
+
+impl PayPrompt {
+    pub fn new(for_step: &str) -> Self {
+        PayPrompt {
+            for_step: for_step.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a verb to play for this step.
+    pub fn verb(mut self, verb: impl Into<Verb>) -> Self {
+        self.verbs.push(verb.into());
+        self
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Prompt")
+            .attr("for", Some(&self.for_step))
+            .attr("errorType", self.error_type.as_ref())
+            .attr("cardType", self.card_type.as_ref())
+            .attr("attempt", self.attempt.as_ref())
+            .children(self.verbs.iter().map(Verb::to_element))
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(PayPrompt {
+            for_step: element.get("for").unwrap_or_default(),
+            error_type: element.get("errorType"),
+            card_type: element.get("cardType"),
+            attempt: element.get("attempt"),
+            verbs: element.children.iter().map(Verb::from_element).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+fn unexpected_child(parent: &Element, child: &str) -> LamlError {
+    LamlError::UnexpectedChild {
+        parent: parent.name.clone(),
+        child: child.to_string(),
+    }
+}
+
+fn streams(element: &Element) -> Result<Vec<Stream>, LamlError> {
+    element
+        .children
+        .iter()
+        .map(|child| match child.name.as_str() {
+            "Stream" => Stream::from_element(child),
+            name => Err(unexpected_child(element, name)),
+        })
+        .collect()
+}
+
+fn parameter_elements(parameters: &[(String, String)]) -> impl Iterator<Item = Element> + '_ {
+    parameters.iter().map(|(name, value)| Element::new("Parameter").attr("name", Some(name)).attr("value", Some(value)))
+}
+
+/// The `<Parameter name value/>` children of `element`; other children are left to the caller.
+fn parameters(element: &Element) -> Result<Vec<(String, String)>, LamlError> {
+    element
+        .children
+        .iter()
+        .filter(|child| child.name == "Parameter")
+        .map(|child| match (child.get("name"), child.get("value")) {
+            (Some(name), Some(value)) => Ok((name, value)),
+            _ => Err(LamlError::InvalidAttribute {
+                verb: "Parameter".to_string(),
+                attribute: "name".to_string(),
+                value: String::new(),
+            }),
+        })
+        .collect()
+}
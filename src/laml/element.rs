@@ -0,0 +1,162 @@
+//! A minimal XML element tree, the intermediate form between LaML text and the typed verbs.
+
+use std::str::FromStr;
+
+use quick_xml::{
+    escape::escape,
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use crate::errors::LamlError;
+
+/// An XML element with its attributes, text and child elements.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub(crate) struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub text: String,
+    pub children: Vec<Element>,
+}
+
+impl Element {
+    pub fn new(name: &str) -> Self {
+        Element {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds an attribute if `value` is set.
+    pub fn attr<T: ToString>(mut self, name: &str, value: Option<T>) -> Self {
+        if let Some(value) = value {
+            self.attributes.push((name.to_string(), value.to_string()));
+        }
+        self
+    }
+
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    pub fn child(mut self, child: Element) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoIterator<Item = Element>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// The raw value of an attribute.
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.attributes.iter().find(|(key, _)| key == name).map(|(_, value)| value.clone())
+    }
+
+    /// Parses an attribute, reporting unparsable values as `LamlError::InvalidAttribute`.
+    pub fn parse<T: FromStr>(&self, name: &str) -> Result<Option<T>, LamlError> {
+        self.get(name)
+            .map(|value| {
+                value.trim().parse().map_err(|_| LamlError::InvalidAttribute {
+                    verb: self.name.clone(),
+                    attribute: name.to_string(),
+                    value,
+                })
+            })
+            .transpose()
+    }
+
+    /// Parses a `true`/`false` attribute.
+    pub fn parse_bool(&self, name: &str) -> Result<Option<bool>, LamlError> {
+        self.get(name)
+            .map(|value| match value.trim().to_ascii_lowercase().as_str() {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err(LamlError::InvalidAttribute {
+                    verb: self.name.clone(),
+                    attribute: name.to_string(),
+                    value,
+                }),
+            })
+            .transpose()
+    }
+
+    /// Parses a whole document into its root element.
+    pub fn parse_document(xml: &str) -> Result<Element, LamlError> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+
+        let mut stack: Vec<Element> = Vec::new();
+        loop {
+            match reader.read_event().map_err(|e| LamlError::Xml(e.to_string()))? {
+                Event::Start(start) => stack.push(Element::from_start(&start)?),
+                Event::Empty(start) => {
+                    let element = Element::from_start(&start)?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                Event::End(_) => {
+                    let element = stack.pop().ok_or_else(|| LamlError::Xml("Unexpected closing tag".to_string()))?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                Event::Text(text) => {
+                    let text = text.unescape().map_err(|e| LamlError::Xml(e.to_string()))?;
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&text);
+                    }
+                }
+                Event::CData(data) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&String::from_utf8_lossy(&data));
+                    }
+                }
+                Event::Eof => return Err(LamlError::Xml("Unexpected end of document".to_string())),
+                _ => {}
+            }
+        }
+    }
+
+    fn from_start(start: &BytesStart) -> Result<Element, LamlError> {
+        let mut element = Element::new(&String::from_utf8_lossy(start.name().as_ref()));
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| LamlError::Xml(e.to_string()))?;
+            let value = attribute.unescape_value().map_err(|e| LamlError::Xml(e.to_string()))?;
+            element.attributes.push((String::from_utf8_lossy(attribute.key.as_ref()).into_owned(), value.into_owned()));
+        }
+
+        Ok(element)
+    }
+
+    /// Writes the element as indented XML, two spaces per level.
+    pub fn write(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        out.push_str(&indent);
+        out.push('<');
+        out.push_str(&self.name);
+        for (name, value) in &self.attributes {
+            out.push_str(&format!(" {}=\"{}\"", name, escape(value.as_str())));
+        }
+
+        if self.text.is_empty() && self.children.is_empty() {
+            out.push_str("/>\n");
+        } else if self.children.is_empty() {
+            out.push_str(&format!(">{}</{}>\n", escape(self.text.as_str()), self.name));
+        } else {
+            out.push_str(">\n");
+            if !self.text.is_empty() {
+                out.push_str(&format!("{}  {}\n", indent, escape(self.text.as_str())));
+            }
+            for child in &self.children {
+                child.write(out, depth + 1);
+            }
+            out.push_str(&format!("{}</{}>\n", indent, self.name));
+        }
+    }
+}
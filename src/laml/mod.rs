@@ -0,0 +1,155 @@
+//! Typed LaML (cXML) documents: build them in Rust, or parse existing ones.
+//!
+//! LaML is the XML dialect SignalWire fetches from your webhooks to control calls and messages.
+//! A document is a `<Response>` holding a list of verbs, executed in order.
+//!
+//! ```rust
+//! use signalwire::laml::{Dial, Gather, Response, Say};
+//!
+//! let response = Response::new()
+//!     .verb(
+//!         Gather {
+//!             action: Some("/menu".to_string()),
+//!             num_digits: Some(1),
+//!             ..Gather::new()
+//!         }
+//!         .verb(Say::new("Press 1 for sales.")),
+//!     )
+//!     .verb(Dial::number("+15551234567"));
+//!
+//! let xml = response.to_xml();
+//! assert_eq!(Response::parse(&xml).unwrap(), response);
+//! ```
+
+mod advanced;
+mod element;
+mod patterns;
+mod validate;
+mod verbs;
+
+use std::{fmt, str::FromStr};
+
+pub use advanced::*;
+use element::Element;
+pub use patterns::*;
+pub use verbs::*;
+
+use crate::errors::LamlError;
+
+/// A LaML document.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Response {
+    pub verbs: Vec<Verb>,
+}
+
+impl Response {
+    pub fn new() -> Self {
+        Response::default()
+    }
+
+    /// Appends a verb.
+    pub fn verb(mut self, verb: impl Into<Verb>) -> Self {
+        self.verbs.push(verb.into());
+        self
+    }
+
+    /// Appends several verbs, e.g. the `verbs()` of a prebuilt pattern like `Forward`.
+    pub fn extend(mut self, verbs: impl IntoIterator<Item = Verb>) -> Self {
+        self.verbs.extend(verbs);
+        self
+    }
+
+    /// Appends a `<Say>` with default voice and language.
+    pub fn say(self, text: &str) -> Self {
+        self.verb(Say::new(text))
+    }
+
+    /// Appends a `<Play>` of the audio file at `url`.
+    pub fn play(self, url: &str) -> Self {
+        self.verb(Play::new(url))
+    }
+
+    /// Appends a `<Pause>` of `length` seconds.
+    pub fn pause(self, length: u32) -> Self {
+        self.verb(Pause::new(length))
+    }
+
+    /// Appends a `<Dial>` to a single number.
+    pub fn dial(self, number: &str) -> Self {
+        self.verb(Dial::number(number))
+    }
+
+    /// Appends a `<Redirect>` to `url`.
+    pub fn redirect(self, url: &str) -> Self {
+        self.verb(Redirect::new(url))
+    }
+
+    /// Appends a `<Message>` replying with `body`.
+    pub fn message(self, body: &str) -> Self {
+        self.verb(Message::new(body))
+    }
+
+    /// Appends a `<Hangup>`.
+    pub fn hangup(self) -> Self {
+        self.verb(Verb::Hangup)
+    }
+
+    /// Appends a `<Reject>`.
+    pub fn reject(self) -> Self {
+        self.verb(Reject::new())
+    }
+
+    /// Renders the document, with an XML declaration, ready to be served to SignalWire.
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        Element::new("Response").children(self.verbs.iter().map(Verb::to_element)).write(&mut xml, 0);
+        xml
+    }
+
+    /// Checks the document against the LaML schema: illegal nestings (e.g. a `Dial` inside a `Gather`), missing
+    /// required attributes and attribute values outside of their allowed set.
+    ///
+    /// # Errors
+    ///
+    /// Returns every problem found, each with the path of the offending verb (e.g. `verbs[1].verbs[0]`).
+    pub fn validate(&self) -> Result<(), Vec<LamlError>> {
+        let errors = validate::validate(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Parses a LaML document.
+    ///
+    /// Unknown attributes are ignored; unknown verbs and malformed attribute values are errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `LamlError` describing the first problem found.
+    pub fn parse(xml: &str) -> Result<Self, LamlError> {
+        let root = Element::parse_document(xml)?;
+        if root.name != "Response" {
+            return Err(LamlError::UnexpectedRoot(root.name));
+        }
+
+        Ok(Response {
+            verbs: root.children.iter().map(Verb::from_element).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl FromStr for Response {
+    type Err = LamlError;
+
+    fn from_str(xml: &str) -> Result<Self, Self::Err> {
+        Response::parse(xml)
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_xml())
+    }
+}
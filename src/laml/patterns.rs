@@ -0,0 +1,323 @@
+//! Complete LaML for common call flows, built from the typed verbs.
+//!
+//! Each pattern renders to a `Response` or, through `verbs()`, to verbs that can be spliced into a larger document
+//! with `Response::extend`.
+
+use super::{Dial, DialNoun, Gather, Number, Record, Redirect, Response, Say, Verb};
+
+/// Forwards the call to a number, optionally whispering a message to the callee before connecting.
+///
+/// ```rust
+/// use signalwire::laml::Forward;
+///
+/// let forward = Forward::new("+15551234567")
+///     .whisper_url("https://example.com/whisper")
+///     .timeout(20)
+///     .fallback_say("Nobody is available.");
+/// let xml = forward.to_response().to_xml();
+/// // Serve at https://example.com/whisper:
+/// let whisper = Forward::whisper("Call from the website, connecting now.").to_xml();
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Forward {
+    pub to: String,
+    pub caller_id: Option<String>,
+    pub timeout: Option<u32>,
+    /// LaML played to the callee before the calls are connected, see `Forward::whisper`.
+    pub whisper_url: Option<String>,
+    /// `record-from-answer` etc., see `Dial::record`.
+    pub record: Option<String>,
+    /// Verbs run when the callee doesn't answer, is busy or the call fails.
+    pub fallback: Vec<Verb>,
+}
+
+impl Forward {
+    pub fn new(to: &str) -> Self {
+        Forward { to: to.to_string(), ..Default::default() }
+    }
+
+    pub fn caller_id(mut self, caller_id: &str) -> Self {
+        self.caller_id = Some(caller_id.to_string());
+        self
+    }
+
+    /// Seconds to ring before giving up.
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn whisper_url(mut self, url: &str) -> Self {
+        self.whisper_url = Some(url.to_string());
+        self
+    }
+
+    pub fn record(mut self, record: &str) -> Self {
+        self.record = Some(record.to_string());
+        self
+    }
+
+    /// Adds a verb run when the forward doesn't connect.
+    pub fn fallback(mut self, verb: impl Into<Verb>) -> Self {
+        self.fallback.push(verb.into());
+        self
+    }
+
+    /// Says `text` when the forward doesn't connect.
+    pub fn fallback_say(self, text: &str) -> Self {
+        self.fallback(Say::new(text))
+    }
+
+    /// The document to serve at `whisper_url`: the callee hears `message`, then the calls are connected.
+    pub fn whisper(message: &str) -> Response {
+        Response::new().say(message)
+    }
+
+    pub fn verbs(&self) -> Vec<Verb> {
+        let number = Number {
+            url: self.whisper_url.clone(),
+            ..Number::new(&self.to)
+        };
+        let dial = Dial {
+            caller_id: self.caller_id.clone(),
+            timeout: self.timeout,
+            record: self.record.clone(),
+            ..Dial::new()
+        }
+        .noun(DialNoun::Number(number));
+
+        // Without an `action`, `<Dial>` falls through to the next verb once the call ends or fails.
+        let mut verbs = vec![Verb::Dial(dial)];
+        verbs.extend(self.fallback.iter().cloned());
+        verbs
+    }
+
+    pub fn to_response(&self) -> Response {
+        Response::new().extend(self.verbs())
+    }
+}
+
+/// Plays a greeting, records a message with transcription and hangs up.
+///
+/// ```rust
+/// use signalwire::laml::Voicemail;
+///
+/// let voicemail = Voicemail::new("Leave a message after the beep.")
+///     .transcribe_callback("https://example.com/transcription")
+///     .max_length(120);
+/// let xml = voicemail.to_response().to_xml();
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Voicemail {
+    /// Played before the beep, usually a `Say` or a `Play`.
+    pub greeting: Vec<Verb>,
+    /// Receives the recording once the caller is done; without it the call hangs up after recording.
+    pub action: Option<String>,
+    pub recording_status_callback: Option<String>,
+    /// Receives the transcription text. Transcription is enabled whenever this is set.
+    pub transcribe_callback: Option<String>,
+    /// Maximum message length in seconds.
+    pub max_length: Option<u32>,
+    pub finish_on_key: Option<String>,
+    /// Said when the caller hangs up or stays silent without leaving a message.
+    pub goodbye: Option<String>,
+}
+
+impl Voicemail {
+    /// A voicemail greeting the caller with `greeting`.
+    pub fn new(greeting: &str) -> Self {
+        Voicemail {
+            greeting: vec![Verb::Say(Say::new(greeting))],
+            ..Default::default()
+        }
+    }
+
+    /// A voicemail with a custom greeting, e.g. a `Play` of a recorded message.
+    pub fn with_greeting(greeting: impl Into<Verb>) -> Self {
+        Voicemail {
+            greeting: vec![greeting.into()],
+            ..Default::default()
+        }
+    }
+
+    pub fn action(mut self, url: &str) -> Self {
+        self.action = Some(url.to_string());
+        self
+    }
+
+    pub fn recording_status_callback(mut self, url: &str) -> Self {
+        self.recording_status_callback = Some(url.to_string());
+        self
+    }
+
+    pub fn transcribe_callback(mut self, url: &str) -> Self {
+        self.transcribe_callback = Some(url.to_string());
+        self
+    }
+
+    pub fn max_length(mut self, seconds: u32) -> Self {
+        self.max_length = Some(seconds);
+        self
+    }
+
+    pub fn finish_on_key(mut self, key: &str) -> Self {
+        self.finish_on_key = Some(key.to_string());
+        self
+    }
+
+    pub fn goodbye(mut self, text: &str) -> Self {
+        self.goodbye = Some(text.to_string());
+        self
+    }
+
+    pub fn verbs(&self) -> Vec<Verb> {
+        let record = Record {
+            action: self.action.clone(),
+            max_length: self.max_length,
+            finish_on_key: self.finish_on_key.clone(),
+            play_beep: Some(true),
+            transcribe: self.transcribe_callback.as_ref().map(|_| true),
+            transcribe_callback: self.transcribe_callback.clone(),
+            recording_status_callback: self.recording_status_callback.clone(),
+            ..Record::new()
+        };
+
+        let mut verbs = self.greeting.clone();
+        verbs.push(Verb::Record(record));
+        if let Some(goodbye) = &self.goodbye {
+            verbs.push(Verb::Say(Say::new(goodbye)));
+        }
+        verbs.push(Verb::Hangup);
+        verbs
+    }
+
+    pub fn to_response(&self) -> Response {
+        Response::new().extend(self.verbs())
+    }
+}
+
+/// A single-digit IVR menu.
+///
+/// Serve `to_response()` at the menu URL and `route(digits)` at `action`, with the `Digits` of the gather webhook.
+/// Invalid or missing input replays the menu until the caller picks a valid option or hangs up.
+///
+/// ```rust
+/// use signalwire::laml::Menu;
+///
+/// let menu = Menu::new("https://example.com/ivr", "https://example.com/ivr/route")
+///     .greeting("Thanks for calling Acme.")
+///     .option("1", "sales", "https://example.com/sales")
+///     .option("2", "support", "https://example.com/support");
+///
+/// let prompt = menu.to_response().to_xml(); // "Press 1 for sales. Press 2 for support."
+/// let routed = menu.route("2").to_xml(); // <Redirect>https://example.com/support</Redirect>
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Menu {
+    /// Where this menu is served, used to replay it.
+    pub url: String,
+    /// Receives the pressed digit (see `Menu::route`).
+    pub action: String,
+    pub greeting: Option<String>,
+    pub options: Vec<MenuOption>,
+    /// Seconds to wait for a key press.
+    pub timeout: Option<u32>,
+    /// Said before replaying the menu after invalid or missing input.
+    pub invalid_input: String,
+}
+
+/// A choice of a `Menu`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct MenuOption {
+    pub digit: String,
+    /// Read as "Press {digit} for {label}."
+    pub label: String,
+    /// The LaML the caller is redirected to.
+    pub url: String,
+}
+
+impl Menu {
+    pub fn new(url: &str, action: &str) -> Self {
+        Menu {
+            url: url.to_string(),
+            action: action.to_string(),
+            invalid_input: "Sorry, I didn't get that.".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Said before the options.
+    pub fn greeting(mut self, text: &str) -> Self {
+        self.greeting = Some(text.to_string());
+        self
+    }
+
+    pub fn option(mut self, digit: &str, label: &str, url: &str) -> Self {
+        self.options.push(MenuOption {
+            digit: digit.to_string(),
+            label: label.to_string(),
+            url: url.to_string(),
+        });
+        self
+    }
+
+    pub fn timeout(mut self, seconds: u32) -> Self {
+        self.timeout = Some(seconds);
+        self
+    }
+
+    pub fn invalid_input(mut self, text: &str) -> Self {
+        self.invalid_input = text.to_string();
+        self
+    }
+
+    /// The menu prompt, ending with a replay of the menu when no key is pressed.
+    pub fn verbs(&self) -> Vec<Verb> {
+        let mut gather = Gather {
+            action: Some(self.action.clone()),
+            method: Some("POST".to_string()),
+            input: Some("dtmf".to_string()),
+            num_digits: Some(1),
+            timeout: self.timeout,
+            ..Gather::new()
+        };
+        if let Some(greeting) = &self.greeting {
+            gather = gather.verb(Say::new(greeting));
+        }
+        for option in &self.options {
+            gather = gather.verb(Say::new(&format!("Press {} for {}.", option.digit, option.label)));
+        }
+
+        vec![Verb::Gather(gather), Verb::Say(Say::new(&self.invalid_input)), Verb::Redirect(Redirect::new(&self.url))]
+    }
+
+    pub fn to_response(&self) -> Response {
+        Response::new().extend(self.verbs())
+    }
+
+    /// The document to serve at `action` for the pressed `digits`: a redirect to the chosen option, or the menu again.
+    pub fn route(&self, digits: &str) -> Response {
+        match self.options.iter().find(|option| option.digit == digits.trim()) {
+            Some(option) => Response::new().redirect(&option.url),
+            None => Response::new().say(&self.invalid_input).redirect(&self.url),
+        }
+    }
+}
+
+impl From<Forward> for Response {
+    fn from(forward: Forward) -> Self {
+        forward.to_response()
+    }
+}
+
+impl From<Voicemail> for Response {
+    fn from(voicemail: Voicemail) -> Self {
+        voicemail.to_response()
+    }
+}
+
+impl From<Menu> for Response {
+    fn from(menu: Menu) -> Self {
+        menu.to_response()
+    }
+}
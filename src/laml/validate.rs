@@ -0,0 +1,264 @@
+use super::{Connect, Dial, DialNoun, Gather, Pay, Response, Stream, Verb};
+use crate::errors::LamlError;
+
+/// The verbs allowed inside `<Gather>` and `<Pay><Prompt>`.
+const PROMPT_VERBS: &[&str] = &["Say", "Play", "Pause"];
+
+const METHODS: &[&str] = &["GET", "POST"];
+const DIAL_RECORD: &[&str] = &["do-not-record", "record-from-answer", "record-from-ringing", "record-from-answer-dual", "record-from-ringing-dual"];
+const CONFERENCE_BEEP: &[&str] = &["true", "false", "onEnter", "onExit"];
+const CONFERENCE_RECORD: &[&str] = &["do-not-record", "record-from-start"];
+const RECORD_TRIM: &[&str] = &["trim-silence", "do-not-trim"];
+const REJECT_REASONS: &[&str] = &["rejected", "busy"];
+const STREAM_TRACKS: &[&str] = &["inbound_track", "outbound_track", "both_tracks"];
+const PAYMENT_METHODS: &[&str] = &["credit-card", "ach-debit"];
+const TOKEN_TYPES: &[&str] = &["one-time", "reusable"];
+const CARD_TYPES: &[&str] = &["visa", "mastercard", "amex", "maestro", "discover", "optima", "jcb", "diners-club", "enroute"];
+
+/// Collects every problem of `response`.
+pub(super) fn validate(response: &Response) -> Vec<LamlError> {
+    let mut validator = Validator::default();
+    for (index, verb) in response.verbs.iter().enumerate() {
+        validator.verb(&format!("verbs[{}]", index), verb);
+    }
+
+    validator.errors
+}
+
+#[derive(Default)]
+struct Validator {
+    errors: Vec<LamlError>,
+}
+
+impl Validator {
+    fn verb(&mut self, path: &str, verb: &Verb) {
+        let name = verb.name();
+        match verb {
+            Verb::Say(say) => self.required(path, name, "text", &say.text),
+            Verb::Play(play) => {
+                if play.url.trim().is_empty() && play.digits.is_none() {
+                    self.missing(path, name, "url` or `digits");
+                }
+                self.check(path, name, "digits", play.digits.as_deref(), is_dtmf, "digits, `*`, `#` and `w`");
+            }
+            Verb::Pause(_) | Verb::Hangup => {}
+            Verb::Gather(gather) => self.gather(path, gather),
+            Verb::Dial(dial) => self.dial(path, dial),
+            Verb::Record(record) => {
+                self.one_of(path, name, "method", record.method.as_deref(), METHODS);
+                self.one_of(path, name, "trim", record.trim.as_deref(), RECORD_TRIM);
+                self.check(path, name, "finishOnKey", record.finish_on_key.as_deref(), is_dtmf, "digits, `*` and `#`");
+            }
+            Verb::Redirect(redirect) => {
+                self.required(path, name, "url", &redirect.url);
+                self.one_of(path, name, "method", redirect.method.as_deref(), METHODS);
+            }
+            Verb::Reject(reject) => self.one_of(path, name, "reason", reject.reason.as_deref(), REJECT_REASONS),
+            Verb::Message(message) => {
+                if message.body.trim().is_empty() && message.media.is_empty() {
+                    self.missing(path, name, "body` or `Media");
+                }
+                self.one_of(path, name, "method", message.method.as_deref(), METHODS);
+            }
+            Verb::Enqueue(enqueue) => {
+                self.required(path, name, "name", &enqueue.name);
+                self.one_of(path, name, "method", enqueue.method.as_deref(), METHODS);
+                self.one_of(path, name, "waitUrlMethod", enqueue.wait_url_method.as_deref(), METHODS);
+            }
+            Verb::Refer(refer) => {
+                self.required(&format!("{}.sip", path), "Sip", "uri", &refer.sip.uri);
+                self.one_of(path, name, "method", refer.method.as_deref(), METHODS);
+            }
+            Verb::Start(start) => self.streams(path, name, &start.streams),
+            Verb::Connect(connect) => self.connect(path, connect),
+            Verb::Pay(pay) => self.pay(path, pay),
+        }
+    }
+
+    fn gather(&mut self, path: &str, gather: &Gather) {
+        self.one_of(path, "Gather", "method", gather.method.as_deref(), METHODS);
+        self.check(
+            path,
+            "Gather",
+            "input",
+            gather.input.as_deref(),
+            |input| input.split_whitespace().all(|input| input == "dtmf" || input == "speech") && !input.trim().is_empty(),
+            "`dtmf`, `speech` or `dtmf speech`",
+        );
+        self.check(
+            path,
+            "Gather",
+            "finishOnKey",
+            gather.finish_on_key.as_deref(),
+            |key| key.len() <= 1 && is_dtmf(key),
+            "a single digit, `*`, `#` or nothing",
+        );
+        self.check(
+            path,
+            "Gather",
+            "speechTimeout",
+            gather.speech_timeout.as_deref(),
+            |timeout| timeout == "auto" || timeout.parse::<u32>().is_ok(),
+            "a number of seconds or `auto`",
+        );
+        if gather.num_digits == Some(0) {
+            self.invalid(path, "Gather", "numDigits", "0", "at least 1");
+        }
+        self.prompt_verbs(path, "Gather", &gather.verbs);
+    }
+
+    fn dial(&mut self, path: &str, dial: &Dial) {
+        self.one_of(path, "Dial", "method", dial.method.as_deref(), METHODS);
+        self.one_of(path, "Dial", "record", dial.record.as_deref(), DIAL_RECORD);
+        if dial.nouns.is_empty() {
+            self.missing(path, "Dial", "a number, SIP endpoint, conference or queue");
+        }
+
+        for (index, noun) in dial.nouns.iter().enumerate() {
+            let path = format!("{}.nouns[{}]", path, index);
+            match noun {
+                DialNoun::Number(number) => {
+                    self.required(&path, "Number", "number", &number.number);
+                    self.check(&path, "Number", "sendDigits", number.send_digits.as_deref(), is_dtmf, "digits, `*`, `#` and `w`");
+                    self.one_of(&path, "Number", "method", number.method.as_deref(), METHODS);
+                }
+                DialNoun::Sip(sip) => self.required(&path, "Sip", "uri", &sip.uri),
+                DialNoun::Conference(conference) => {
+                    self.required(&path, "Conference", "name", &conference.name);
+                    self.one_of(&path, "Conference", "beep", conference.beep.as_deref(), CONFERENCE_BEEP);
+                    self.one_of(&path, "Conference", "record", conference.record.as_deref(), CONFERENCE_RECORD);
+                }
+                DialNoun::Queue(queue) => self.required(&path, "Queue", "name", &queue.name),
+            }
+        }
+
+        // Numbers and SIP endpoints ring in parallel, but a conference or queue must be dialed alone.
+        if dial.nouns.len() > 1 {
+            for noun in dial.nouns.iter().filter(|noun| matches!(noun, DialNoun::Conference(_) | DialNoun::Queue(_))) {
+                self.nesting(path, "Dial", noun_name(noun));
+            }
+        }
+    }
+
+    fn connect(&mut self, path: &str, connect: &Connect) {
+        self.one_of(path, "Connect", "method", connect.method.as_deref(), METHODS);
+        self.streams(path, "Connect", &connect.streams);
+    }
+
+    fn streams(&mut self, path: &str, verb: &str, streams: &[Stream]) {
+        if streams.is_empty() {
+            self.missing(path, verb, "Stream");
+        }
+
+        for (index, stream) in streams.iter().enumerate() {
+            let path = format!("{}.streams[{}]", path, index);
+            self.check(&path, "Stream", "url", Some(&stream.url), |url| url.starts_with("wss://") || url.starts_with("ws://"), "a `wss://` URL");
+            self.one_of(&path, "Stream", "track", stream.track.as_deref(), STREAM_TRACKS);
+            self.one_of(&path, "Stream", "statusCallbackMethod", stream.status_callback_method.as_deref(), METHODS);
+        }
+    }
+
+    fn pay(&mut self, path: &str, pay: &Pay) {
+        if pay.payment_connector_url.as_deref().is_none_or(|url| url.trim().is_empty()) {
+            self.missing(path, "Pay", "paymentConnectorUrl");
+        }
+        self.check(
+            path,
+            "Pay",
+            "chargeAmount",
+            pay.charge_amount.as_deref(),
+            |amount| amount.parse::<f64>().is_ok_and(|amount| amount >= 0.0),
+            "a non-negative amount",
+        );
+        self.one_of(path, "Pay", "paymentMethod", pay.payment_method.as_deref(), PAYMENT_METHODS);
+        self.one_of(path, "Pay", "tokenType", pay.token_type.as_deref(), TOKEN_TYPES);
+        self.check(
+            path,
+            "Pay",
+            "validCardTypes",
+            pay.valid_card_types.as_deref(),
+            |types| types.split_whitespace().all(|card| CARD_TYPES.contains(&card)),
+            &expected_one_of(CARD_TYPES),
+        );
+        if pay.max_attempts == Some(0) {
+            self.invalid(path, "Pay", "maxAttempts", "0", "at least 1");
+        }
+
+        for (index, prompt) in pay.prompts.iter().enumerate() {
+            let path = format!("{}.prompts[{}]", path, index);
+            self.required(&path, "Prompt", "for", &prompt.for_step);
+            self.prompt_verbs(&path, "Prompt", &prompt.verbs);
+        }
+    }
+
+    /// Validates the verbs nested in a prompt, flagging anything but `Say`, `Play` and `Pause`.
+    fn prompt_verbs(&mut self, path: &str, parent: &str, verbs: &[Verb]) {
+        for (index, verb) in verbs.iter().enumerate() {
+            let path = format!("{}.verbs[{}]", path, index);
+            if PROMPT_VERBS.contains(&verb.name()) {
+                self.verb(&path, verb);
+            } else {
+                self.nesting(&path, parent, verb.name());
+            }
+        }
+    }
+
+    fn required(&mut self, path: &str, verb: &str, attribute: &str, value: &str) {
+        if value.trim().is_empty() {
+            self.missing(path, verb, attribute);
+        }
+    }
+
+    fn one_of(&mut self, path: &str, verb: &str, attribute: &str, value: Option<&str>, allowed: &[&str]) {
+        self.check(path, verb, attribute, value, |value| allowed.contains(&value), &expected_one_of(allowed));
+    }
+
+    fn check(&mut self, path: &str, verb: &str, attribute: &str, value: Option<&str>, valid: impl Fn(&str) -> bool, expected: &str) {
+        if let Some(value) = value.filter(|value| !valid(value)) {
+            self.invalid(path, verb, attribute, value, expected);
+        }
+    }
+
+    fn missing(&mut self, path: &str, verb: &str, attribute: &str) {
+        self.errors.push(LamlError::MissingAttribute {
+            path: path.to_string(),
+            verb: verb.to_string(),
+            attribute: attribute.to_string(),
+        });
+    }
+
+    fn invalid(&mut self, path: &str, verb: &str, attribute: &str, value: &str, expected: &str) {
+        self.errors.push(LamlError::InvalidValue {
+            path: path.to_string(),
+            verb: verb.to_string(),
+            attribute: attribute.to_string(),
+            value: value.to_string(),
+            expected: expected.to_string(),
+        });
+    }
+
+    fn nesting(&mut self, path: &str, parent: &str, child: &str) {
+        self.errors.push(LamlError::IllegalNesting {
+            path: path.to_string(),
+            parent: parent.to_string(),
+            child: child.to_string(),
+        });
+    }
+}
+
+fn noun_name(noun: &DialNoun) -> &'static str {
+    match noun {
+        DialNoun::Number(_) => "Number",
+        DialNoun::Sip(_) => "Sip",
+        DialNoun::Conference(_) => "Conference",
+        DialNoun::Queue(_) => "Queue",
+    }
+}
+
+fn is_dtmf(digits: &str) -> bool {
+    digits.chars().all(|c| c.is_ascii_digit() || matches!(c, '*' | '#' | 'w' | 'W'))
+}
+
+fn expected_one_of(allowed: &[&str]) -> String {
+    allowed.iter().map(|value| format!("`{}`", value)).collect::<Vec<_>>().join(", ")
+}
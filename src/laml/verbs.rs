@@ -0,0 +1,623 @@
+use super::{element::Element, Connect, Enqueue, Pay, Refer, Start};
+use crate::errors::LamlError;
+
+/// A LaML verb, i.e. one instruction of a `<Response>`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verb {
+    Say(Say),
+    Play(Play),
+    Pause(Pause),
+    Gather(Gather),
+    Dial(Dial),
+    Record(Record),
+    Redirect(Redirect),
+    Hangup,
+    Reject(Reject),
+    Message(Message),
+    Enqueue(Enqueue),
+    Refer(Refer),
+    Start(Start),
+    Connect(Connect),
+    Pay(Pay),
+}
+
+macro_rules! impl_from_verb {
+    ($($verb:ident),*) => {
+        $(
+            impl From<$verb> for Verb {
+                fn from(verb: $verb) -> Self {
+                    Verb::$verb(verb)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_verb!(Say, Play, Pause, Gather, Dial, Record, Redirect, Reject, Message, Enqueue, Refer, Start, Connect, Pay);
+
+impl Verb {
+    /// The XML tag of the verb.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verb::Say(_) => "Say",
+            Verb::Play(_) => "Play",
+            Verb::Pause(_) => "Pause",
+            Verb::Gather(_) => "Gather",
+            Verb::Dial(_) => "Dial",
+            Verb::Record(_) => "Record",
+            Verb::Redirect(_) => "Redirect",
+            Verb::Hangup => "Hangup",
+            Verb::Reject(_) => "Reject",
+            Verb::Message(_) => "Message",
+            Verb::Enqueue(_) => "Enqueue",
+            Verb::Refer(_) => "Refer",
+            Verb::Start(_) => "Start",
+            Verb::Connect(_) => "Connect",
+            Verb::Pay(_) => "Pay",
+        }
+    }
+
+    pub(crate) fn to_element(&self) -> Element {
+        match self {
+            Verb::Say(say) => say.to_element(),
+            Verb::Play(play) => play.to_element(),
+            Verb::Pause(pause) => pause.to_element(),
+            Verb::Gather(gather) => gather.to_element(),
+            Verb::Dial(dial) => dial.to_element(),
+            Verb::Record(record) => record.to_element(),
+            Verb::Redirect(redirect) => redirect.to_element(),
+            Verb::Hangup => Element::new("Hangup"),
+            Verb::Reject(reject) => reject.to_element(),
+            Verb::Message(message) => message.to_element(),
+            Verb::Enqueue(enqueue) => enqueue.to_element(),
+            Verb::Refer(refer) => refer.to_element(),
+            Verb::Start(start) => start.to_element(),
+            Verb::Connect(connect) => connect.to_element(),
+            Verb::Pay(pay) => pay.to_element(),
+        }
+    }
+
+    pub(crate) fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(match element.name.as_str() {
+            "Say" => Verb::Say(Say::from_element(element)?),
+            "Play" => Verb::Play(Play::from_element(element)?),
+            "Pause" => Verb::Pause(Pause::from_element(element)?),
+            "Gather" => Verb::Gather(Gather::from_element(element)?),
+            "Dial" => Verb::Dial(Dial::from_element(element)?),
+            "Record" => Verb::Record(Record::from_element(element)?),
+            "Redirect" => Verb::Redirect(Redirect::from_element(element)?),
+            "Hangup" => Verb::Hangup,
+            "Reject" => Verb::Reject(Reject::from_element(element)?),
+            "Message" | "Sms" => Verb::Message(Message::from_element(element)?),
+            "Enqueue" => Verb::Enqueue(Enqueue::from_element(element)?),
+            "Refer" => Verb::Refer(Refer::from_element(element)?),
+            "Start" => Verb::Start(Start::from_element(element)?),
+            "Connect" => Verb::Connect(Connect::from_element(element)?),
+            "Pay" => Verb::Pay(Pay::from_element(element)?),
+            name => return Err(LamlError::UnknownVerb(name.to_string())),
+        })
+    }
+}
+
+/// Reads text to the caller with text-to-speech.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Say {
+    pub text: String,
+    pub voice: Option<String>,
+    pub language: Option<String>,
+    pub loop_count: Option<u32>,
+}
+
+impl Say {
+    pub fn new(text: &str) -> Self {
+        Say {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Say")
+            .attr("voice", self.voice.as_ref())
+            .attr("language", self.language.as_ref())
+            .attr("loop", self.loop_count)
+            .text(&self.text)
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Say {
+            text: element.text.clone(),
+            voice: element.get("voice"),
+            language: element.get("language"),
+            loop_count: element.parse("loop")?,
+        })
+    }
+}
+
+/// Plays an audio file to the caller.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Play {
+    pub url: String,
+    pub loop_count: Option<u32>,
+    /// DTMF tones to play instead of audio (then `url` is empty).
+    pub digits: Option<String>,
+}
+
+impl Play {
+    pub fn new(url: &str) -> Self {
+        Play {
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Play").attr("loop", self.loop_count).attr("digits", self.digits.as_ref()).text(&self.url)
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Play {
+            url: element.text.clone(),
+            loop_count: element.parse("loop")?,
+            digits: element.get("digits"),
+        })
+    }
+}
+
+/// Waits silently.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Pause {
+    /// The length of the pause in seconds (1 by default).
+    pub length: Option<u32>,
+}
+
+impl Pause {
+    pub fn new(length: u32) -> Self {
+        Pause { length: Some(length) }
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Pause").attr("length", self.length)
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Pause { length: element.parse("length")? })
+    }
+}
+
+/// Collects DTMF digits or speech, playing the nested `Say`/`Play`/`Pause` verbs as a prompt.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Gather {
+    pub action: Option<String>,
+    pub method: Option<String>,
+    /// `dtmf`, `speech` or `dtmf speech`.
+    pub input: Option<String>,
+    pub timeout: Option<u32>,
+    pub finish_on_key: Option<String>,
+    pub num_digits: Option<u32>,
+    /// A number of seconds or `auto`.
+    pub speech_timeout: Option<String>,
+    pub hints: Option<String>,
+    pub language: Option<String>,
+    pub verbs: Vec<Verb>,
+}
+
+impl Gather {
+    pub fn new() -> Self {
+        Gather::default()
+    }
+
+    /// Adds a nested verb, played while waiting for input.
+    pub fn verb(mut self, verb: impl Into<Verb>) -> Self {
+        self.verbs.push(verb.into());
+        self
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Gather")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .attr("input", self.input.as_ref())
+            .attr("timeout", self.timeout)
+            .attr("finishOnKey", self.finish_on_key.as_ref())
+            .attr("numDigits", self.num_digits)
+            .attr("speechTimeout", self.speech_timeout.as_ref())
+            .attr("hints", self.hints.as_ref())
+            .attr("language", self.language.as_ref())
+            .children(self.verbs.iter().map(Verb::to_element))
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Gather {
+            action: element.get("action"),
+            method: element.get("method"),
+            input: element.get("input"),
+            timeout: element.parse("timeout")?,
+            finish_on_key: element.get("finishOnKey"),
+            num_digits: element.parse("numDigits")?,
+            speech_timeout: element.get("speechTimeout"),
+            hints: element.get("hints"),
+            language: element.get("language"),
+            verbs: element.children.iter().map(Verb::from_element).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Connects the caller to another party.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Dial {
+    pub action: Option<String>,
+    pub method: Option<String>,
+    pub timeout: Option<u32>,
+    pub caller_id: Option<String>,
+    /// `do-not-record`, `record-from-answer`, `record-from-ringing`, ...
+    pub record: Option<String>,
+    pub time_limit: Option<u32>,
+    pub hangup_on_star: Option<bool>,
+    pub answer_on_bridge: Option<bool>,
+    pub ring_tone: Option<String>,
+    pub nouns: Vec<DialNoun>,
+}
+
+impl Dial {
+    pub fn new() -> Self {
+        Dial::default()
+    }
+
+    /// Dials a single phone number.
+    pub fn number(number: &str) -> Self {
+        Dial::new().noun(DialNoun::Number(Number::new(number)))
+    }
+
+    /// Adds a party to dial. Several numbers or SIP endpoints are dialed simultaneously.
+    pub fn noun(mut self, noun: DialNoun) -> Self {
+        self.nouns.push(noun);
+        self
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Dial")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .attr("timeout", self.timeout)
+            .attr("callerId", self.caller_id.as_ref())
+            .attr("record", self.record.as_ref())
+            .attr("timeLimit", self.time_limit)
+            .attr("hangupOnStar", self.hangup_on_star)
+            .attr("answerOnBridge", self.answer_on_bridge)
+            .attr("ringTone", self.ring_tone.as_ref())
+            .children(self.nouns.iter().map(DialNoun::to_element))
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        let mut nouns = element.children.iter().map(DialNoun::from_element).collect::<Result<Vec<_>, _>>()?;
+        // `<Dial>+15551234567</Dial>` is shorthand for a single `<Number>`.
+        if !element.text.trim().is_empty() {
+            nouns.insert(0, DialNoun::Number(Number::new(element.text.trim())));
+        }
+
+        Ok(Dial {
+            action: element.get("action"),
+            method: element.get("method"),
+            timeout: element.parse("timeout")?,
+            caller_id: element.get("callerId"),
+            record: element.get("record"),
+            time_limit: element.parse("timeLimit")?,
+            hangup_on_star: element.parse_bool("hangupOnStar")?,
+            answer_on_bridge: element.parse_bool("answerOnBridge")?,
+            ring_tone: element.get("ringTone"),
+            nouns,
+        })
+    }
+}
+
+/// Who or what a `Dial` connects to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DialNoun {
+    Number(Number),
+    Sip(Sip),
+    Conference(Conference),
+    Queue(Queue),
+}
+
+impl DialNoun {
+    fn to_element(&self) -> Element {
+        match self {
+            DialNoun::Number(number) => Element::new("Number")
+                .attr("sendDigits", number.send_digits.as_ref())
+                .attr("url", number.url.as_ref())
+                .attr("method", number.method.as_ref())
+                .attr("statusCallback", number.status_callback.as_ref())
+                .text(&number.number),
+            DialNoun::Sip(sip) => Element::new("Sip").attr("username", sip.username.as_ref()).attr("password", sip.password.as_ref()).text(&sip.uri),
+            DialNoun::Conference(conference) => Element::new("Conference")
+                .attr("muted", conference.muted)
+                .attr("beep", conference.beep.as_ref())
+                .attr("startConferenceOnEnter", conference.start_conference_on_enter)
+                .attr("endConferenceOnExit", conference.end_conference_on_exit)
+                .attr("waitUrl", conference.wait_url.as_ref())
+                .attr("maxParticipants", conference.max_participants)
+                .attr("record", conference.record.as_ref())
+                .attr("statusCallback", conference.status_callback.as_ref())
+                .text(&conference.name),
+            DialNoun::Queue(queue) => Element::new("Queue").attr("url", queue.url.as_ref()).text(&queue.name),
+        }
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(match element.name.as_str() {
+            "Number" => DialNoun::Number(Number {
+                number: element.text.trim().to_string(),
+                send_digits: element.get("sendDigits"),
+                url: element.get("url"),
+                method: element.get("method"),
+                status_callback: element.get("statusCallback"),
+            }),
+            "Sip" => DialNoun::Sip(Sip {
+                uri: element.text.trim().to_string(),
+                username: element.get("username"),
+                password: element.get("password"),
+            }),
+            "Conference" => DialNoun::Conference(Conference {
+                name: element.text.trim().to_string(),
+                muted: element.parse_bool("muted")?,
+                beep: element.get("beep"),
+                start_conference_on_enter: element.parse_bool("startConferenceOnEnter")?,
+                end_conference_on_exit: element.parse_bool("endConferenceOnExit")?,
+                wait_url: element.get("waitUrl"),
+                max_participants: element.parse("maxParticipants")?,
+                record: element.get("record"),
+                status_callback: element.get("statusCallback"),
+            }),
+            "Queue" => DialNoun::Queue(Queue {
+                name: element.text.trim().to_string(),
+                url: element.get("url"),
+            }),
+            name => {
+                return Err(LamlError::UnexpectedChild {
+                    parent: "Dial".to_string(),
+                    child: name.to_string(),
+                })
+            }
+        })
+    }
+}
+
+/// A phone number to dial.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Number {
+    pub number: String,
+    pub send_digits: Option<String>,
+    /// LaML run on the called party before the calls are connected (e.g. a whisper).
+    pub url: Option<String>,
+    pub method: Option<String>,
+    pub status_callback: Option<String>,
+}
+
+impl Number {
+    pub fn new(number: &str) -> Self {
+        Number {
+            number: number.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A SIP endpoint to dial.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Sip {
+    pub uri: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl Sip {
+    pub fn new(uri: &str) -> Self {
+        Sip {
+            uri: uri.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A conference room to join.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Conference {
+    pub name: String,
+    pub muted: Option<bool>,
+    /// `true`, `false`, `onEnter` or `onExit`.
+    pub beep: Option<String>,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    pub wait_url: Option<String>,
+    pub max_participants: Option<u32>,
+    pub record: Option<String>,
+    pub status_callback: Option<String>,
+}
+
+impl Conference {
+    pub fn new(name: &str) -> Self {
+        Conference {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A call queue to take the next caller from.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Queue {
+    pub name: String,
+    pub url: Option<String>,
+}
+
+impl Queue {
+    pub fn new(name: &str) -> Self {
+        Queue {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Records the caller.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Record {
+    pub action: Option<String>,
+    pub method: Option<String>,
+    pub timeout: Option<u32>,
+    pub finish_on_key: Option<String>,
+    pub max_length: Option<u32>,
+    pub play_beep: Option<bool>,
+    /// `trim-silence` or `do-not-trim`.
+    pub trim: Option<String>,
+    pub transcribe: Option<bool>,
+    pub transcribe_callback: Option<String>,
+    pub recording_status_callback: Option<String>,
+}
+
+impl Record {
+    pub fn new() -> Self {
+        Record::default()
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Record")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .attr("timeout", self.timeout)
+            .attr("finishOnKey", self.finish_on_key.as_ref())
+            .attr("maxLength", self.max_length)
+            .attr("playBeep", self.play_beep)
+            .attr("trim", self.trim.as_ref())
+            .attr("transcribe", self.transcribe)
+            .attr("transcribeCallback", self.transcribe_callback.as_ref())
+            .attr("recordingStatusCallback", self.recording_status_callback.as_ref())
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Record {
+            action: element.get("action"),
+            method: element.get("method"),
+            timeout: element.parse("timeout")?,
+            finish_on_key: element.get("finishOnKey"),
+            max_length: element.parse("maxLength")?,
+            play_beep: element.parse_bool("playBeep")?,
+            trim: element.get("trim"),
+            transcribe: element.parse_bool("transcribe")?,
+            transcribe_callback: element.get("transcribeCallback"),
+            recording_status_callback: element.get("recordingStatusCallback"),
+        })
+    }
+}
+
+/// Continues the call with the LaML at another URL.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Redirect {
+    pub url: String,
+    pub method: Option<String>,
+}
+
+impl Redirect {
+    pub fn new(url: &str) -> Self {
+        Redirect {
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Redirect").attr("method", self.method.as_ref()).text(&self.url)
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Redirect {
+            url: element.text.trim().to_string(),
+            method: element.get("method"),
+        })
+    }
+}
+
+/// Rejects an incoming call without answering it.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Reject {
+    /// `rejected` or `busy`.
+    pub reason: Option<String>,
+}
+
+impl Reject {
+    pub fn new() -> Self {
+        Reject::default()
+    }
+
+    fn to_element(&self) -> Element {
+        Element::new("Reject").attr("reason", self.reason.as_ref())
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        Ok(Reject { reason: element.get("reason") })
+    }
+}
+
+/// Sends a message, or replies to the inbound one when `to` and `from` are omitted.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Message {
+    pub body: String,
+    pub media: Vec<String>,
+    pub to: Option<String>,
+    pub from: Option<String>,
+    pub action: Option<String>,
+    pub method: Option<String>,
+    pub status_callback: Option<String>,
+}
+
+impl Message {
+    pub fn new(body: &str) -> Self {
+        Message {
+            body: body.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn to_element(&self) -> Element {
+        let element = Element::new("Message")
+            .attr("to", self.to.as_ref())
+            .attr("from", self.from.as_ref())
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .attr("statusCallback", self.status_callback.as_ref());
+
+        if self.media.is_empty() {
+            element.text(&self.body)
+        } else {
+            element.child(Element::new("Body").text(&self.body)).children(self.media.iter().map(|url| Element::new("Media").text(url)))
+        }
+    }
+
+    fn from_element(element: &Element) -> Result<Self, LamlError> {
+        let mut message = Message {
+            body: element.text.clone(),
+            to: element.get("to"),
+            from: element.get("from"),
+            action: element.get("action"),
+            method: element.get("method"),
+            status_callback: element.get("statusCallback"),
+            ..Default::default()
+        };
+
+        for child in &element.children {
+            match child.name.as_str() {
+                "Body" => message.body = child.text.clone(),
+                "Media" => message.media.push(child.text.trim().to_string()),
+                name => {
+                    return Err(LamlError::UnexpectedChild {
+                        parent: element.name.clone(),
+                        child: name.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(message)
+    }
+}
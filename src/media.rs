@@ -0,0 +1,167 @@
+//! Downloading media: room recordings, MMS media, faxes.
+//!
+//! A `MediaDownload` hands out the body as the `Bytes` chunks the connection receives, never copying it into a
+//! `String` or a growing `Vec`. Large media can be fetched in parts with a `ByteRange`, e.g. to resume a download
+//! that failed half way:
+//!
+//! ```rust,no_run
+//! use signalwire::{client::SignalWireClient, errors::SignalWireError, media::ByteRange};
+//! use tokio::io::AsyncWriteExt;
+//!
+//! # async fn example(client: SignalWireClient, id: &str) -> Result<(), SignalWireError> {
+//! let mut file = tokio::fs::OpenOptions::new()
+//!     .append(true)
+//!     .create(true)
+//!     .open("recording.mp4")
+//!     .await
+//!     .unwrap();
+//! let written = file.metadata().await.unwrap().len();
+//!
+//! let mut download = client
+//!     .get_room_recording_media(id, Some(ByteRange::from(written)))
+//!     .await?;
+//! if !download.is_partial() {
+//!     // The server sent the whole recording, start over
+//!     file.set_len(0).await.unwrap();
+//! }
+//! while let Some(chunk) = download.chunk().await? {
+//!     file.write_all(&chunk).await.unwrap();
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use bytes::Bytes;
+use futures_util::{Stream, TryStreamExt};
+use reqwest::header::{CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE};
+
+use crate::{
+    errors::SignalWireError,
+    rt::{self, BoxStream},
+    transport,
+};
+
+/// The bytes of a media to download, `start` to `end` included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    /// The last byte, `None` for the end of the media.
+    pub end: Option<u64>,
+}
+
+impl ByteRange {
+    /// The bytes from `start` to `end`, both included.
+    pub fn new(start: u64, end: u64) -> Self {
+        ByteRange { start, end: Some(end) }
+    }
+
+    /// The bytes from `start` to the end of the media, e.g. to resume after `start` bytes.
+    pub fn from(start: u64) -> Self {
+        ByteRange { start, end: None }
+    }
+
+    /// The value of the `Range` header.
+    pub fn header_value(&self) -> String {
+        match self.end {
+            Some(end) => format!("bytes={}-{}", self.start, end),
+            None => format!("bytes={}-", self.start),
+        }
+    }
+}
+
+/// The part of the media a `206 Partial Content` response holds, after its `Content-Range` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentRange {
+    pub start: u64,
+    /// The last byte, included.
+    pub end: u64,
+    /// The size of the whole media, `None` if the server doesn't know it.
+    pub total: Option<u64>,
+}
+
+impl ContentRange {
+    /// Parses a `Content-Range` header, e.g. `bytes 0-1023/4096`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (range, total) = value.trim().strip_prefix("bytes ")?.split_once('/')?;
+        let (start, end) = range.split_once('-')?;
+        Some(ContentRange {
+            start: start.parse().ok()?,
+            end: end.parse().ok()?,
+            total: match total {
+                "*" => None,
+                total => Some(total.parse().ok()?),
+            },
+        })
+    }
+}
+
+/// A media download under way, its body not read yet.
+pub struct MediaDownload {
+    /// The MIME type of the media, e.g. `audio/mpeg`.
+    pub content_type: Option<String>,
+    /// The size of this body, only the requested part for a range.
+    pub content_length: Option<u64>,
+    /// The part of the media sent, `None` if the whole media was.
+    pub content_range: Option<ContentRange>,
+    body: BoxStream<'static, reqwest::Result<Bytes>>,
+}
+
+impl fmt::Debug for MediaDownload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MediaDownload")
+            .field("content_type", &self.content_type)
+            .field("content_length", &self.content_length)
+            .field("content_range", &self.content_range)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MediaDownload {
+    /// Reads the headers of a successful media response, its status checked by the client.
+    pub(crate) fn new(response: reqwest::Response) -> Self {
+        let headers = response.headers();
+        let header = |name| headers.get(name).and_then(|value: &reqwest::header::HeaderValue| value.to_str().ok());
+        MediaDownload {
+            content_type: header(CONTENT_TYPE).map(str::to_string),
+            content_length: header(CONTENT_LENGTH).and_then(|length| length.parse().ok()),
+            content_range: header(CONTENT_RANGE).and_then(ContentRange::parse).filter(|_| response.status() == reqwest::StatusCode::PARTIAL_CONTENT),
+            body: rt::body_stream(response),
+        }
+    }
+
+    /// Whether only a part of the media is sent, as requested with a `ByteRange`.
+    ///
+    /// Servers may ignore ranges and send the whole media instead, which this tells apart.
+    pub fn is_partial(&self) -> bool {
+        self.content_range.is_some()
+    }
+
+    /// The next chunk of the body, as received, or `None` once it is all read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::HttpError` if the connection fails.
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>, SignalWireError> {
+        self.body.try_next().await.map_err(|e| SignalWireError::HttpError(transport::body_error(&e)))
+    }
+
+    /// The chunks of the body as a stream, e.g. to forward them to another body without buffering.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Bytes, SignalWireError>> {
+        self.body.map_err(|e| SignalWireError::HttpError(transport::body_error(&e)))
+    }
+
+    /// Reads the whole body into one buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::HttpError` if the connection fails.
+    pub async fn bytes(self) -> Result<Bytes, SignalWireError> {
+        let mut chunks: Vec<Bytes> = self.into_stream().try_collect().await?;
+        Ok(match chunks.len() {
+            1 => chunks.remove(0),
+            _ => chunks.concat().into(),
+        })
+    }
+}
@@ -0,0 +1,378 @@
+use std::{fmt, sync::Mutex, time::Duration};
+
+use async_trait::async_trait;
+
+use crate::{
+    api::SignalWireApi,
+    batch::BatchExecutor,
+    errors::SignalWireError,
+    messaging::{MessageTemplate, SenderPool, SenderStore, SuppressionStore},
+    rt::{self, Instant},
+    types::{MessageSid, MessageStatus, SmsMessage},
+    validation::validate_sms_message,
+};
+
+/// What a broadcast sends: the same body to everyone, or a template rendered with each recipient's values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BroadcastContent {
+    Body(String),
+    Template(MessageTemplate),
+}
+
+impl From<&str> for BroadcastContent {
+    fn from(body: &str) -> Self {
+        BroadcastContent::Body(body.to_string())
+    }
+}
+
+impl From<String> for BroadcastContent {
+    fn from(body: String) -> Self {
+        BroadcastContent::Body(body)
+    }
+}
+
+impl From<MessageTemplate> for BroadcastContent {
+    fn from(template: MessageTemplate) -> Self {
+        BroadcastContent::Template(template)
+    }
+}
+
+/// A recipient of a broadcast, with the values of its template placeholders.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BroadcastRecipient {
+    pub to: String,
+    pub values: Vec<(String, String)>,
+}
+
+impl BroadcastRecipient {
+    pub fn new(to: impl Into<String>) -> Self {
+        BroadcastRecipient { to: to.into(), values: Vec::new() }
+    }
+
+    /// Sets the placeholder `name` of the template to `value` for this recipient.
+    pub fn value(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl From<&str> for BroadcastRecipient {
+    fn from(to: &str) -> Self {
+        BroadcastRecipient::new(to)
+    }
+}
+
+impl From<String> for BroadcastRecipient {
+    fn from(to: String) -> Self {
+        BroadcastRecipient::new(to)
+    }
+}
+
+/// A `SenderPool` whatever its store, so the options needn't be generic over it.
+#[async_trait]
+trait SenderSource: Send + Sync {
+    fn numbers(&self) -> &[String];
+
+    async fn sender_for(&self, recipient: &str) -> Result<String, SignalWireError>;
+}
+
+#[async_trait]
+impl<S: SenderStore> SenderSource for SenderPool<S> {
+    fn numbers(&self) -> &[String] {
+        SenderPool::numbers(self)
+    }
+
+    async fn sender_for(&self, recipient: &str) -> Result<String, SignalWireError> {
+        SenderPool::sender_for(self, recipient).await
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Sender<'a> {
+    Number(&'a str),
+    Pool(&'a dyn SenderSource),
+    NumberGroup(&'a str),
+}
+
+/// How `broadcast` sends: from which numbers, to whom, and how fast.
+#[derive(Clone, Copy)]
+pub struct BroadcastOptions<'a> {
+    sender: Sender<'a>,
+    suppression: Option<&'a dyn SuppressionStore>,
+    executor: BatchExecutor,
+    interval: Duration,
+}
+
+impl fmt::Debug for BroadcastOptions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("BroadcastOptions");
+        match self.sender {
+            Sender::Number(number) => debug.field("from", &number),
+            Sender::Pool(pool) => debug.field("pool", &pool.numbers()),
+            Sender::NumberGroup(group) => debug.field("number_group", &group),
+        };
+        debug.field("suppression", &self.suppression.is_some()).field("executor", &self.executor).field("interval", &self.interval).finish()
+    }
+}
+
+impl<'a> BroadcastOptions<'a> {
+    /// Sends everything from the E.164 number `from`, with the default `BatchExecutor`, without a rate limit.
+    pub fn from_number(from: &'a str) -> Self {
+        BroadcastOptions::new(Sender::Number(from))
+    }
+
+    /// Sends from the numbers of `pool`, every recipient keeping its own, see `SenderPool`.
+    pub fn from_pool<S: SenderStore>(pool: &'a SenderPool<S>) -> Self {
+        BroadcastOptions::new(Sender::Pool(pool))
+    }
+
+    /// Lets SignalWire pick the sender of every message among the numbers of the number group `number_group_id`,
+    /// as high-throughput senders are advised to.
+    pub fn from_number_group(number_group_id: &'a str) -> Self {
+        BroadcastOptions::new(Sender::NumberGroup(number_group_id))
+    }
+
+    fn new(sender: Sender<'a>) -> Self {
+        BroadcastOptions {
+            sender,
+            suppression: None,
+            executor: BatchExecutor::default(),
+            interval: Duration::ZERO,
+        }
+    }
+
+    /// Skips the recipients who opted out according to `store`, e.g. the store of an `OptOutHandler`.
+    pub fn with_suppression<S: SuppressionStore>(mut self, store: &'a S) -> Self {
+        self.suppression = Some(store);
+        self
+    }
+
+    /// Replaces how many messages are sent at once, and how failed sends are retried.
+    pub fn with_executor(mut self, executor: BatchExecutor) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// Starts at most `per_second` sends a second, retries included, 0 for no limit.
+    pub fn with_rate_limit(mut self, per_second: u32) -> Self {
+        self.interval = if per_second == 0 { Duration::ZERO } else { Duration::from_secs(1) / per_second };
+        self
+    }
+}
+
+/// What became of a recipient of a broadcast.
+#[derive(Debug, Clone)]
+pub enum BroadcastOutcome {
+    /// Accepted by SignalWire.
+    Sent { sid: MessageSid, status: MessageStatus },
+    /// Not sent, the recipient having opted out.
+    Suppressed,
+    /// Not sent, or not accepted by SignalWire: the template missed a value, a number was invalid, a store failed,
+    /// or the last attempt failed.
+    Failed(SignalWireError),
+}
+
+/// The outcome of a recipient of a broadcast.
+#[derive(Debug, Clone)]
+pub struct BroadcastResult {
+    pub to: String,
+    /// The number sent from, `None` if the message didn't get as far as being sent, or was sent from a number group
+    /// and not accepted.
+    pub from: Option<String>,
+    pub outcome: BroadcastOutcome,
+    /// The sends attempted, 0 if the message didn't get as far as being sent.
+    pub attempts: u32,
+}
+
+impl BroadcastResult {
+    /// The SID of the message, if it was sent.
+    pub fn sid(&self) -> Option<&MessageSid> {
+        match &self.outcome {
+            BroadcastOutcome::Sent { sid, .. } => Some(sid),
+            _ => None,
+        }
+    }
+
+    /// The status of the message as SignalWire accepted it, if it was sent.
+    pub fn status(&self) -> Option<&MessageStatus> {
+        match &self.outcome {
+            BroadcastOutcome::Sent { status, .. } => Some(status),
+            _ => None,
+        }
+    }
+
+    /// Why the message wasn't sent, if it failed.
+    pub fn error(&self) -> Option<&SignalWireError> {
+        match &self.outcome {
+            BroadcastOutcome::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The totals of a broadcast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BroadcastSummary {
+    pub total: usize,
+    pub sent: usize,
+    pub suppressed: usize,
+    pub failed: usize,
+    /// Attempts beyond the first, over all recipients.
+    pub retries: usize,
+    pub elapsed: Duration,
+}
+
+/// The outcomes of a broadcast, in the order of its recipients, and their totals.
+#[derive(Debug, Clone)]
+pub struct BroadcastReport {
+    pub results: Vec<BroadcastResult>,
+    pub summary: BroadcastSummary,
+}
+
+impl BroadcastReport {
+    /// Whether no recipient failed; suppressed recipients don't count as failures.
+    pub fn is_success(&self) -> bool {
+        self.summary.failed == 0
+    }
+
+    /// The recipients that failed, with their error.
+    pub fn failures(&self) -> impl Iterator<Item = (&str, &SignalWireError)> {
+        self.results.iter().filter_map(|result| result.error().map(|error| (result.to.as_str(), error)))
+    }
+}
+
+/// Sends `content` to every recipient of `recipients` with `api`, and reports what became of each.
+///
+/// Recipients who opted out are skipped, the others get their template rendered and a sender picked before the
+/// messages are sent by the executor of the options, within their rate limit, and retried as it says. A failure
+/// never stops the broadcast: it is reported with its recipient, and the others carry on.
+///
+/// ```rust,no_run
+/// use signalwire::{
+///     batch::BatchExecutor,
+///     client::SignalWireClient,
+///     messaging::{broadcast, BroadcastOptions, BroadcastRecipient, InMemorySuppressionStore, MessageTemplate},
+/// };
+///
+/// # async fn example(client: SignalWireClient, opted_out: InMemorySuppressionStore) -> Result<(), signalwire::errors::SignalWireError> {
+/// let template = MessageTemplate::parse("Hi {name}, your order has shipped")?;
+/// let options = BroadcastOptions::from_number("+15551234567")
+///     .with_suppression(&opted_out)
+///     .with_executor(BatchExecutor::new(4))
+///     .with_rate_limit(10);
+///
+/// let report = broadcast(
+///     &client,
+///     template,
+///     [BroadcastRecipient::new("+15557654321").value("name", "Ada")],
+///     &options,
+/// )
+/// .await;
+/// for (to, error) in report.failures() {
+///     eprintln!("{}: {}", to, error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn broadcast<A: SignalWireApi + ?Sized>(api: &A, content: impl Into<BroadcastContent>, recipients: impl IntoIterator<Item = impl Into<BroadcastRecipient>>, options: &BroadcastOptions<'_>) -> BroadcastReport {
+    let started = Instant::now();
+    let content = content.into();
+
+    // Suppressed and unsendable recipients are settled here, the others once sent
+    let mut results = Vec::new();
+    let mut messages = Vec::new();
+    for (index, recipient) in recipients.into_iter().map(Into::into).enumerate() {
+        let outcome = match prepare(&content, &recipient, options).await {
+            Ok(Some(message)) => {
+                messages.push((index, message));
+                results.push(None);
+                continue;
+            }
+            Ok(None) => BroadcastOutcome::Suppressed,
+            Err(error) => BroadcastOutcome::Failed(error),
+        };
+        results.push(Some(BroadcastResult {
+            to: recipient.to,
+            from: None,
+            outcome,
+            attempts: 0,
+        }));
+    }
+
+    let next_send = Mutex::new(Instant::now());
+    let next_send = &next_send;
+    let report = options
+        .executor
+        .run(messages, |(_, message): (usize, SmsMessage)| async move {
+            let wait = {
+                let mut next_send = next_send.lock().unwrap();
+                let now = Instant::now();
+                let start = (*next_send).max(now);
+                *next_send = start + options.interval;
+                start - now
+            };
+            rt::sleep(wait).await;
+            api.send_sms(&message).await
+        })
+        .await;
+
+    let mut summary = BroadcastSummary {
+        total: results.len(),
+        retries: report.metrics.retries,
+        ..Default::default()
+    };
+    for outcome in report.outcomes {
+        let (index, message) = outcome.item;
+        let attempts = outcome.attempts;
+        // A number group's sender is only known once SignalWire picked it
+        let (from, outcome) = match outcome.result {
+            Ok(response) => (
+                Some(if message.from.is_empty() { response.from.clone() } else { message.from }),
+                BroadcastOutcome::Sent {
+                    status: response.get_status(),
+                    sid: response.sid,
+                },
+            ),
+            Err(error) => ((!message.from.is_empty()).then_some(message.from), BroadcastOutcome::Failed(error)),
+        };
+        results[index] = Some(BroadcastResult { to: message.to, from, outcome, attempts });
+    }
+
+    let results: Vec<_> = results.into_iter().flatten().collect();
+    for result in &results {
+        match result.outcome {
+            BroadcastOutcome::Sent { .. } => summary.sent += 1,
+            BroadcastOutcome::Suppressed => summary.suppressed += 1,
+            BroadcastOutcome::Failed(_) => summary.failed += 1,
+        }
+    }
+    summary.elapsed = started.elapsed();
+    BroadcastReport { results, summary }
+}
+
+/// The message to send `recipient`, `None` if it opted out.
+async fn prepare(content: &BroadcastContent, recipient: &BroadcastRecipient, options: &BroadcastOptions<'_>) -> Result<Option<SmsMessage>, SignalWireError> {
+    if let Some(store) = options.suppression {
+        if store.is_suppressed(&recipient.to).await? {
+            return Ok(None);
+        }
+    }
+
+    let body = match content {
+        BroadcastContent::Body(body) => body.clone(),
+        BroadcastContent::Template(template) => template.render(&recipient.values)?,
+    };
+    let (from, number_group_id) = match options.sender {
+        Sender::Number(number) => (number.to_string(), None),
+        Sender::Pool(pool) => (pool.sender_for(&recipient.to).await?, None),
+        Sender::NumberGroup(group) => (String::new(), Some(group.to_string())),
+    };
+    let message = SmsMessage {
+        body,
+        from,
+        to: recipient.to.clone(),
+        number_group_id,
+    };
+    validate_sms_message(&message)?;
+    Ok(Some(message))
+}
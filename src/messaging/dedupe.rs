@@ -0,0 +1,124 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Mutex,
+    time::Duration,
+};
+
+use crate::{rt::Instant, types::SmsMessage};
+
+/// Whether `DuplicateGuard::check` lets a message through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupeDecision {
+    /// No identical message went to the recipient within the window.
+    Allowed,
+    /// An identical message went to the recipient `since` ago.
+    Suppressed { since: Duration },
+}
+
+type DecisionHandler = Box<dyn Fn(&SmsMessage, DedupeDecision) + Send + Sync>;
+
+/// Suppresses a message identical to one sent to the same recipient within a window, so application logic run twice
+/// can't text a customer twice.
+///
+/// Messages are identified by their recipient and a hash of their body; the sender isn't part of it.
+/// `SignalWireClient::with_duplicate_guard` checks every `send_sms` against a guard.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use signalwire::{
+///     messaging::{DedupeDecision, DuplicateGuard},
+///     types::SmsMessage,
+/// };
+///
+/// let guard = DuplicateGuard::new(Duration::from_secs(600)).on_decision(|message, decision| {
+///     if let DedupeDecision::Suppressed { since } = decision {
+///         println!(
+///             "Suppressed a duplicate to {}, first sent {:?} ago",
+///             message.to, since
+///         );
+///     }
+/// });
+/// let message = SmsMessage {
+///     from: "+15551234567".to_string(),
+///     to: "+15557654321".to_string(),
+///     body: "Your order has shipped".to_string(),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(guard.check(&message), DedupeDecision::Allowed);
+/// assert!(matches!(
+///     guard.check(&message),
+///     DedupeDecision::Suppressed { .. }
+/// ));
+/// ```
+pub struct DuplicateGuard {
+    window: Duration,
+    sent: Mutex<HashMap<(String, u64), Instant>>,
+    handler: Option<DecisionHandler>,
+}
+
+impl std::fmt::Debug for DuplicateGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DuplicateGuard")
+            .field("window", &self.window)
+            .field("tracked", &self.sent.lock().unwrap().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl DuplicateGuard {
+    /// A guard suppressing the duplicates sent within `window` of each other.
+    pub fn new(window: Duration) -> Self {
+        DuplicateGuard {
+            window,
+            sent: Mutex::new(HashMap::new()),
+            handler: None,
+        }
+    }
+
+    /// Calls `handler` with every decision, e.g. to audit the suppressed messages.
+    pub fn on_decision(mut self, handler: impl Fn(&SmsMessage, DedupeDecision) + Send + Sync + 'static) -> Self {
+        self.handler = Some(Box::new(handler));
+        self
+    }
+
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Whether `message` may be sent, recording it as sent now if so.
+    pub fn check(&self, message: &SmsMessage) -> DedupeDecision {
+        let now = Instant::now();
+        let decision = {
+            let mut sent = self.sent.lock().unwrap();
+            sent.retain(|_, sent_at| now.saturating_duration_since(*sent_at) < self.window);
+            match sent.get(&key(message)) {
+                Some(sent_at) => DedupeDecision::Suppressed {
+                    since: now.saturating_duration_since(*sent_at),
+                },
+                None => {
+                    sent.insert(key(message), now);
+                    DedupeDecision::Allowed
+                }
+            }
+        };
+
+        if let Some(handler) = &self.handler {
+            handler(message, decision);
+        }
+        decision
+    }
+
+    /// Forgets `message`, e.g. once its send failed, so it can be sent again right away.
+    pub fn forget(&self, message: &SmsMessage) {
+        self.sent.lock().unwrap().remove(&key(message));
+    }
+}
+
+fn key(message: &SmsMessage) -> (String, u64) {
+    let mut hasher = DefaultHasher::new();
+    message.body.hash(&mut hasher);
+    (message.to.clone(), hasher.finish())
+}
@@ -0,0 +1,29 @@
+//! Utilities for building compliant, reliable messaging on top of the REST client.
+
+#[cfg(any(test, feature = "reqwest"))]
+mod broadcast;
+mod dedupe;
+mod opt_out;
+#[cfg(any(test, feature = "reqwest"))]
+mod outbox;
+#[cfg(any(test, feature = "quiet-hours"))]
+mod quiet_hours;
+mod sanitize;
+mod segments;
+mod sender_pool;
+mod stats;
+mod template;
+
+#[cfg(any(test, feature = "reqwest"))]
+pub use broadcast::*;
+pub use dedupe::*;
+pub use opt_out::*;
+#[cfg(any(test, feature = "reqwest"))]
+pub use outbox::*;
+#[cfg(any(test, feature = "quiet-hours"))]
+pub use quiet_hours::*;
+pub use sanitize::*;
+pub use segments::*;
+pub use sender_pool::*;
+pub use stats::*;
+pub use template::*;
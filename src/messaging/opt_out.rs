@@ -0,0 +1,205 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use async_trait::async_trait;
+
+use crate::{errors::SignalWireError, webhook::IncomingSmsWebhook};
+
+/// What an inbound message asks for, according to the standard carrier keywords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptOutAction {
+    /// The sender no longer wants to receive messages (`STOP`, `UNSUBSCRIBE`, ...).
+    OptOut,
+    /// The sender wants to receive messages again (`START`, `UNSTOP`, ...).
+    OptIn,
+    /// The sender asked for help (`HELP`, `INFO`, ...).
+    Help,
+}
+
+/// The keywords recognized for each `OptOutAction`.
+///
+/// A message matches when its whole body, ignoring case, surrounding whitespace and trailing
+/// punctuation, is one of the keywords.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptOutKeywords {
+    pub opt_out: Vec<String>,
+    pub opt_in: Vec<String>,
+    pub help: Vec<String>,
+}
+
+impl Default for OptOutKeywords {
+    /// The English keywords required by US carriers.
+    fn default() -> Self {
+        OptOutKeywords::english()
+    }
+}
+
+impl OptOutKeywords {
+    fn from_lists(opt_out: &[&str], opt_in: &[&str], help: &[&str]) -> Self {
+        let to_vec = |keywords: &[&str]| keywords.iter().map(|keyword| keyword.to_string()).collect();
+        OptOutKeywords {
+            opt_out: to_vec(opt_out),
+            opt_in: to_vec(opt_in),
+            help: to_vec(help),
+        }
+    }
+
+    /// The English keywords required by US carriers.
+    pub fn english() -> Self {
+        OptOutKeywords::from_lists(
+            &["STOP", "STOPALL", "UNSUBSCRIBE", "CANCEL", "END", "QUIT", "OPTOUT", "REVOKE"],
+            &["START", "UNSTOP", "YES", "OPTIN"],
+            &["HELP", "INFO"],
+        )
+    }
+
+    /// Spanish keywords.
+    pub fn spanish() -> Self {
+        OptOutKeywords::from_lists(&["ALTO", "PARAR", "DETENER", "CANCELAR", "BAJA"], &["INICIAR", "COMENZAR", "SI", "SÍ"], &["AYUDA", "INFORMACION", "INFORMACIÓN"])
+    }
+
+    /// French keywords.
+    pub fn french() -> Self {
+        OptOutKeywords::from_lists(&["ARRET", "ARRÊT", "ARRETER", "ARRÊTER", "DESABONNER", "DÉSABONNER"], &["DEMARRER", "DÉMARRER", "OUI"], &["AIDE"])
+    }
+
+    /// Adds the keywords of `other` to these.
+    pub fn merge(mut self, other: OptOutKeywords) -> Self {
+        self.opt_out.extend(other.opt_out);
+        self.opt_in.extend(other.opt_in);
+        self.help.extend(other.help);
+        self
+    }
+
+    /// The action requested by a message body, if it is a keyword.
+    pub fn detect(&self, body: &str) -> Option<OptOutAction> {
+        let word = body.trim().trim_end_matches(|c: char| c.is_ascii_punctuation()).trim();
+        let matches = |keywords: &[String]| keywords.iter().any(|keyword| keyword.to_lowercase() == word.to_lowercase());
+
+        if matches(&self.opt_out) {
+            Some(OptOutAction::OptOut)
+        } else if matches(&self.opt_in) {
+            Some(OptOutAction::OptIn)
+        } else if matches(&self.help) {
+            Some(OptOutAction::Help)
+        } else {
+            None
+        }
+    }
+}
+
+/// Detects the action requested by a message body using the default English keywords.
+pub fn detect_opt_out_action(body: &str) -> Option<OptOutAction> {
+    OptOutKeywords::default().detect(body)
+}
+
+/// Where opted-out phone numbers are kept.
+///
+/// Implement this on top of your database; `InMemorySuppressionStore` is provided for tests and
+/// single-process services.
+#[async_trait]
+pub trait SuppressionStore: Send + Sync {
+    /// Whether `phone_number` has opted out.
+    async fn is_suppressed(&self, phone_number: &str) -> Result<bool, SignalWireError>;
+
+    /// Records that `phone_number` opted out.
+    async fn suppress(&self, phone_number: &str) -> Result<(), SignalWireError>;
+
+    /// Records that `phone_number` opted back in.
+    async fn unsuppress(&self, phone_number: &str) -> Result<(), SignalWireError>;
+}
+
+/// A `SuppressionStore` kept in memory, lost on restart.
+#[derive(Debug, Default)]
+pub struct InMemorySuppressionStore {
+    numbers: Mutex<HashSet<String>>,
+}
+
+impl InMemorySuppressionStore {
+    pub fn new() -> Self {
+        InMemorySuppressionStore::default()
+    }
+}
+
+#[async_trait]
+impl SuppressionStore for InMemorySuppressionStore {
+    async fn is_suppressed(&self, phone_number: &str) -> Result<bool, SignalWireError> {
+        Ok(self.numbers.lock().unwrap().contains(phone_number))
+    }
+
+    async fn suppress(&self, phone_number: &str) -> Result<(), SignalWireError> {
+        self.numbers.lock().unwrap().insert(phone_number.to_string());
+        Ok(())
+    }
+
+    async fn unsuppress(&self, phone_number: &str) -> Result<(), SignalWireError> {
+        self.numbers.lock().unwrap().remove(phone_number);
+        Ok(())
+    }
+}
+
+/// Keeps a `SuppressionStore` in sync with the opt-out keywords of inbound messages.
+///
+/// ```rust,no_run
+/// use signalwire::{
+///     messaging::{InMemorySuppressionStore, OptOutAction, OptOutHandler},
+///     webhook::IncomingSmsWebhook,
+/// };
+///
+/// # async fn example(message: IncomingSmsWebhook) -> Result<(), signalwire::errors::SignalWireError> {
+/// let handler = OptOutHandler::new(InMemorySuppressionStore::new());
+///
+/// if handler.handle(&message).await? == Some(OptOutAction::Help) {
+///     // Reply with your help message
+/// }
+/// if handler.can_send("+15557654321").await? {
+///     // Send your message
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct OptOutHandler<S> {
+    keywords: OptOutKeywords,
+    store: S,
+}
+
+impl<S: SuppressionStore> OptOutHandler<S> {
+    /// Creates a handler recognizing the default English keywords.
+    pub fn new(store: S) -> Self {
+        OptOutHandler {
+            keywords: OptOutKeywords::default(),
+            store,
+        }
+    }
+
+    /// Replaces the recognized keywords, e.g. with `OptOutKeywords::english().merge(OptOutKeywords::spanish())`.
+    pub fn with_keywords(mut self, keywords: OptOutKeywords) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// The underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Updates the store according to an inbound message and returns the action it requested.
+    ///
+    /// `OptOut` suppresses the sender, `OptIn` removes the suppression and `Help` leaves the store untouched.
+    pub async fn handle(&self, message: &IncomingSmsWebhook) -> Result<Option<OptOutAction>, SignalWireError> {
+        let action = self.keywords.detect(&message.body);
+
+        match action {
+            Some(OptOutAction::OptOut) => self.store.suppress(&message.from).await?,
+            Some(OptOutAction::OptIn) => self.store.unsuppress(&message.from).await?,
+            Some(OptOutAction::Help) | None => {}
+        }
+
+        Ok(action)
+    }
+
+    /// Whether messages may be sent to `phone_number`.
+    pub async fn can_send(&self, phone_number: &str) -> Result<bool, SignalWireError> {
+        self.store.is_suppressed(phone_number).await.map(|suppressed| !suppressed)
+    }
+}
@@ -0,0 +1,345 @@
+use std::{
+    convert::Infallible,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::SignalWireApi,
+    batch::RetryPolicy,
+    errors::SignalWireError,
+    rt::{self, Instant},
+    types::{MessageSid, MessageStatus, SmsMessage},
+    validation::validate_sms_message,
+    webhook::MessageStatusCallback,
+};
+
+/// Where a message of the outbox stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxState {
+    /// Waiting to be sent, or to be retried.
+    Pending,
+    /// Accepted by SignalWire, waiting for a delivery receipt.
+    Sent,
+    /// Delivered to the recipient.
+    Delivered,
+    /// Given up on, or reported failed or undelivered.
+    Failed,
+}
+
+/// A message of the outbox, with its delivery state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// The outbox's own ID, returned by `Outbox::enqueue`.
+    pub id: String,
+    pub message: SmsMessage,
+    pub state: OutboxState,
+    /// The sends attempted so far.
+    pub attempts: u32,
+    /// When a pending entry may be sent next, in milliseconds since the Unix epoch. A worker that took it pushes it
+    /// back by its lease, so it's sent again if the worker dies before recording the outcome; the value then also
+    /// tells the worker holding the lease apart from one that took the entry over since.
+    pub not_before: u64,
+    /// The SID of the message once SignalWire accepted it.
+    pub message_sid: Option<MessageSid>,
+    /// Why the last attempt or the delivery failed.
+    pub last_error: Option<String>,
+}
+
+/// Where the messages of an `Outbox` are kept.
+///
+/// Implement this on top of your database for messages to survive restarts; `InMemoryOutboxStore` is provided for
+/// tests and single-process services. Entries are serializable, e.g. to store them as JSON.
+#[async_trait]
+pub trait OutboxStore: Send + Sync {
+    /// Adds a new entry.
+    async fn insert(&self, entry: OutboxEntry) -> Result<(), SignalWireError>;
+
+    /// Takes up to `limit` pending entries due at `now`, oldest first, setting their `not_before` to `lease_until`
+    /// so other workers skip them meanwhile. Taking and leasing must be atomic for several workers to share a store.
+    async fn claim_due(&self, now: u64, lease_until: u64, limit: usize) -> Result<Vec<OutboxEntry>, SignalWireError>;
+
+    /// Saves the changes to an entry, and returns whether it was saved.
+    ///
+    /// With `lease_until`, this is a compare-and-set for the worker holding the lease: the entry is only saved if it
+    /// is still pending with its `not_before` at `lease_until`, i.e. no other worker claimed it since the lease ran
+    /// out. Otherwise it's saved unconditionally.
+    async fn update(&self, entry: &OutboxEntry, lease_until: Option<u64>) -> Result<bool, SignalWireError>;
+
+    /// The entry with the outbox ID `id`.
+    async fn get(&self, id: &str) -> Result<Option<OutboxEntry>, SignalWireError>;
+
+    /// The entry sent as `message_sid`.
+    async fn find_by_sid(&self, message_sid: &str) -> Result<Option<OutboxEntry>, SignalWireError>;
+}
+
+/// An `OutboxStore` kept in memory, lost on restart.
+#[derive(Debug, Default)]
+pub struct InMemoryOutboxStore {
+    entries: Mutex<Vec<OutboxEntry>>,
+}
+
+impl InMemoryOutboxStore {
+    pub fn new() -> Self {
+        InMemoryOutboxStore::default()
+    }
+}
+
+#[async_trait]
+impl OutboxStore for InMemoryOutboxStore {
+    async fn insert(&self, entry: OutboxEntry) -> Result<(), SignalWireError> {
+        self.entries.lock().unwrap().push(entry);
+        Ok(())
+    }
+
+    async fn claim_due(&self, now: u64, lease_until: u64, limit: usize) -> Result<Vec<OutboxEntry>, SignalWireError> {
+        let mut entries = self.entries.lock().unwrap();
+        Ok(entries
+            .iter_mut()
+            .filter(|entry| entry.state == OutboxState::Pending && entry.not_before <= now)
+            .take(limit)
+            .map(|entry| {
+                entry.not_before = lease_until;
+                entry.clone()
+            })
+            .collect())
+    }
+
+    async fn update(&self, entry: &OutboxEntry, lease_until: Option<u64>) -> Result<bool, SignalWireError> {
+        let mut entries = self.entries.lock().unwrap();
+        let Some(stored) = entries.iter_mut().find(|stored| stored.id == entry.id) else {
+            return Ok(false);
+        };
+        if lease_until.is_some_and(|lease_until| stored.state != OutboxState::Pending || stored.not_before != lease_until) {
+            return Ok(false);
+        }
+        *stored = entry.clone();
+        Ok(true)
+    }
+
+    async fn get(&self, id: &str) -> Result<Option<OutboxEntry>, SignalWireError> {
+        Ok(self.entries.lock().unwrap().iter().find(|entry| entry.id == id).cloned())
+    }
+
+    async fn find_by_sid(&self, message_sid: &str) -> Result<Option<OutboxEntry>, SignalWireError> {
+        Ok(self.entries.lock().unwrap().iter().find(|entry| entry.message_sid.as_ref().is_some_and(|sid| sid.as_str() == message_sid)).cloned())
+    }
+}
+
+/// What a pass of `Outbox::drain` did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OutboxDrain {
+    /// Messages accepted by SignalWire.
+    pub sent: usize,
+    /// Messages that failed, to be retried later.
+    pub retrying: usize,
+    /// Messages given up on.
+    pub failed: usize,
+}
+
+/// At-least-once sending: messages are stored first, then sent by a worker, retried, and followed up to their
+/// delivery receipts.
+///
+/// A message is only marked sent once SignalWire accepted it, so a worker stopped half way sends it again after a
+/// restart, at the cost of a possible duplicate.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use signalwire::{
+///     client::SignalWireClient,
+///     messaging::{InMemoryOutboxStore, Outbox},
+///     types::SmsMessage,
+///     webhook::MessageStatusCallback,
+/// };
+///
+/// # async fn example(client: SignalWireClient, message: SmsMessage, callback: MessageStatusCallback) -> Result<(), signalwire::errors::SignalWireError> {
+/// let outbox = Outbox::new(InMemoryOutboxStore::new()).with_rate_limit(10);
+/// let id = outbox.enqueue(message).await?;
+///
+/// // In a background task
+/// outbox.run(&client, Duration::from_secs(1)).await?;
+///
+/// // In the status callback handler
+/// outbox.handle_status(&callback).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Outbox<S> {
+    store: S,
+    retry: RetryPolicy,
+    lease: Duration,
+    batch_size: usize,
+    interval: Duration,
+}
+
+impl<S: OutboxStore> Outbox<S> {
+    /// An outbox on `store`, retrying with the default `RetryPolicy`, without a rate limit.
+    pub fn new(store: S) -> Self {
+        Outbox {
+            store,
+            retry: RetryPolicy::default(),
+            lease: Duration::from_secs(60),
+            batch_size: 100,
+            interval: Duration::ZERO,
+        }
+    }
+
+    /// Replaces how failed sends are retried.
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sends at most `per_second` messages a second, 0 for no limit.
+    pub fn with_rate_limit(mut self, per_second: u32) -> Self {
+        self.interval = if per_second == 0 { Duration::ZERO } else { Duration::from_secs(1) / per_second };
+        self
+    }
+
+    /// How long a worker may take to send the messages it took before others take them over, 60 seconds by default.
+    pub fn with_lease(mut self, lease: Duration) -> Self {
+        self.lease = lease;
+        self
+    }
+
+    /// The underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Stores `message` for sending and returns its outbox ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Validation` if the message can't be sent as it is, or the error of the store.
+    pub async fn enqueue(&self, message: SmsMessage) -> Result<String, SignalWireError> {
+        validate_sms_message(&message)?;
+
+        static ENQUEUED: AtomicU64 = AtomicU64::new(0);
+        let now = rt::since_epoch();
+        let id = format!("OB{:x}{:04x}", now.as_nanos(), ENQUEUED.fetch_add(1, Ordering::Relaxed) & 0xffff);
+        self.store
+            .insert(OutboxEntry {
+                id: id.clone(),
+                message,
+                state: OutboxState::Pending,
+                attempts: 0,
+                not_before: millis(now),
+                message_sid: None,
+                last_error: None,
+            })
+            .await?;
+        Ok(id)
+    }
+
+    /// The entry with the outbox ID `id`.
+    pub async fn get(&self, id: &str) -> Result<Option<OutboxEntry>, SignalWireError> {
+        self.store.get(id).await
+    }
+
+    /// Sends the messages due now with `api`, within the rate limit, and records the outcomes.
+    ///
+    /// A worker takes no more messages at once than it can send within its lease at the rate limit, and renews the
+    /// lease of each right before sending it; a message whose lease ran out and was taken over by another worker
+    /// is left to that worker, rather than sent twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the store. Failed sends are recorded on their entries instead.
+    pub async fn drain<A: SignalWireApi + ?Sized>(&self, api: &A) -> Result<OutboxDrain, SignalWireError> {
+        let mut drain = OutboxDrain::default();
+        let mut next_send = Instant::now();
+        let batch_size = match self.lease.as_nanos().checked_div(self.interval.as_nanos()) {
+            Some(sendable) => usize::try_from(sendable).unwrap_or(usize::MAX).clamp(1, self.batch_size),
+            None => self.batch_size,
+        };
+        loop {
+            let now = millis(rt::since_epoch());
+            let entries = self.store.claim_due(now, now.saturating_add(millis(self.lease)), batch_size).await?;
+            if entries.is_empty() {
+                return Ok(drain);
+            }
+
+            for mut entry in entries {
+                rt::sleep(next_send.saturating_duration_since(Instant::now())).await;
+                next_send = Instant::now() + self.interval;
+
+                // Renewed so the lease covers the send, unless another worker took the entry over meanwhile
+                let claimed = entry.not_before;
+                entry.not_before = millis(rt::since_epoch()).saturating_add(millis(self.lease));
+                if !self.store.update(&entry, Some(claimed)).await? {
+                    continue;
+                }
+                let lease_until = entry.not_before;
+
+                entry.attempts += 1;
+                match api.send_sms(&entry.message).await {
+                    Ok(response) => {
+                        entry.state = OutboxState::Sent;
+                        entry.message_sid = Some(response.sid);
+                        entry.last_error = None;
+                        drain.sent += 1;
+                    }
+                    Err(error) => {
+                        entry.last_error = Some(error.to_string());
+                        if entry.attempts < self.retry.max_attempts && (self.retry.retry_if)(&error) {
+                            entry.not_before = millis(rt::since_epoch() + self.retry.backoff(entry.attempts));
+                            drain.retrying += 1;
+                        } else {
+                            entry.state = OutboxState::Failed;
+                            drain.failed += 1;
+                        }
+                    }
+                }
+                self.store.update(&entry, Some(lease_until)).await?;
+            }
+        }
+    }
+
+    /// Drains the outbox every `poll_interval`, until the store fails.
+    pub async fn run<A: SignalWireApi + ?Sized>(&self, api: &A, poll_interval: Duration) -> Result<Infallible, SignalWireError> {
+        loop {
+            self.drain(api).await?;
+            rt::sleep(poll_interval).await;
+        }
+    }
+
+    /// Records a delivery receipt, and returns the entry it's about, `None` for messages sent outside the outbox.
+    ///
+    /// `delivered` marks the entry delivered, `failed` and `undelivered` failed, with the error reported.
+    pub async fn handle_status(&self, callback: &MessageStatusCallback) -> Result<Option<OutboxEntry>, SignalWireError> {
+        let Some(mut entry) = self.store.find_by_sid(&callback.message_sid).await? else {
+            return Ok(None);
+        };
+
+        let status = callback.get_status();
+        if status == MessageStatus::Delivered {
+            entry.state = OutboxState::Delivered;
+        } else if status.is_failure() {
+            entry.state = OutboxState::Failed;
+            entry.last_error = Some(match (&callback.error_code, &callback.error_message) {
+                (Some(code), Some(message)) => format!("{}: {}", code, message),
+                (Some(code), None) => code.clone(),
+                (None, Some(message)) => message.clone(),
+                (None, None) => status.to_string(),
+            });
+        } else {
+            return Ok(Some(entry));
+        }
+        self.store.update(&entry, None).await?;
+        Ok(Some(entry))
+    }
+}
+
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,242 @@
+//! Sending only at decent local hours, see `QuietHoursScheduler`.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use chrono::{DateTime, Duration, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::{
+    api::SignalWireApi,
+    errors::SignalWireError,
+    types::{SmsMessage, SmsResponse},
+};
+
+/// The local hours messages may be sent at, from `start` until `end`.
+///
+/// An `end` before `start` spans midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendingHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl Default for SendingHours {
+    /// 8am to 9pm, the hours the TCPA allows.
+    fn default() -> Self {
+        SendingHours::new(NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(21, 0, 0).unwrap())
+    }
+}
+
+impl SendingHours {
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        SendingHours { start, end }
+    }
+
+    /// Whether `time` is within the hours.
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            self.start <= time || time < self.end
+        }
+    }
+
+    /// The first moment from `after` on within the hours in `timezone`.
+    fn next_start(&self, timezone: Tz, after: DateTime<Utc>) -> DateTime<Utc> {
+        let local = after.with_timezone(&timezone);
+        if self.contains(local.time()) {
+            return after;
+        }
+
+        let date = if local.time() < self.start { local.date_naive() } else { local.date_naive() + Duration::days(1) };
+        let start = date.and_time(self.start);
+        // A start skipped by a DST change moves to the hour after
+        timezone
+            .from_local_datetime(&start)
+            .earliest()
+            .or_else(|| timezone.from_local_datetime(&(start + Duration::hours(1))).earliest())
+            .map_or(after + Duration::hours(1), |start| start.with_timezone(&Utc))
+    }
+}
+
+/// What `QuietHoursScheduler` did with a message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuietHoursEvent {
+    /// The message was held back until `until`, outside of the sending hours of its recipient.
+    Deferred { message: SmsMessage, until: DateTime<Utc> },
+    /// A deferred message is being sent, its recipient's sending hours having come.
+    Released { message: SmsMessage },
+}
+
+/// The outcome of `QuietHoursScheduler::send`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduledSend {
+    /// Sent right away, within the sending hours.
+    Sent(Box<SmsResponse>),
+    /// Held back, to be sent by `QuietHoursScheduler::release_due` from `until` on.
+    Deferred { until: DateTime<Utc> },
+}
+
+type EventHandler = Box<dyn Fn(&QuietHoursEvent) + Send + Sync>;
+
+/// Holds messages back until the sending hours of their recipients, in the timezones `lookup_phone_number` reports.
+///
+/// A number in several timezones, as numbers of an area code spanning them are, only gets messages at hours within
+/// the sending hours of all of them. Numbers the lookup reports no timezone for use the default one, UTC unless set.
+/// Timezones are looked up once per number.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use signalwire::{
+///     client::SignalWireClient,
+///     messaging::{QuietHoursEvent, QuietHoursScheduler, ScheduledSend, SendingHours},
+///     types::SmsMessage,
+/// };
+///
+/// # async fn example(client: SignalWireClient, message: SmsMessage) -> Result<(), signalwire::errors::SignalWireError> {
+/// let scheduler = QuietHoursScheduler::new(SendingHours::default())
+///     .with_default_timezone(chrono_tz::America::New_York)
+///     .on_event(|event| match event {
+///         QuietHoursEvent::Deferred { message, until } => println!("{} deferred until {}", message.to, until),
+///         QuietHoursEvent::Released { message } => println!("{} released", message.to),
+///     });
+///
+/// if let ScheduledSend::Sent(response) = scheduler.send(&client, message).await? {
+///     println!("Sent {}", response.sid);
+/// }
+///
+/// // Every minute or so
+/// for (message, result) in scheduler.release_due(&client).await {
+///     result?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct QuietHoursScheduler {
+    hours: SendingHours,
+    default_timezone: Tz,
+    timezones: Mutex<HashMap<String, Vec<Tz>>>,
+    deferred: Mutex<Vec<(DateTime<Utc>, SmsMessage)>>,
+    handler: Option<EventHandler>,
+}
+
+impl std::fmt::Debug for QuietHoursScheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QuietHoursScheduler")
+            .field("hours", &self.hours)
+            .field("default_timezone", &self.default_timezone)
+            .field("deferred", &self.deferred.lock().unwrap().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl QuietHoursScheduler {
+    pub fn new(hours: SendingHours) -> Self {
+        QuietHoursScheduler {
+            hours,
+            default_timezone: Tz::UTC,
+            timezones: Mutex::new(HashMap::new()),
+            deferred: Mutex::new(Vec::new()),
+            handler: None,
+        }
+    }
+
+    /// The timezone of the numbers the lookup reports none for, UTC by default.
+    pub fn with_default_timezone(mut self, timezone: Tz) -> Self {
+        self.default_timezone = timezone;
+        self
+    }
+
+    /// Calls `handler` whenever a message is deferred or released.
+    pub fn on_event(mut self, handler: impl Fn(&QuietHoursEvent) + Send + Sync + 'static) -> Self {
+        self.handler = Some(Box::new(handler));
+        self
+    }
+
+    /// The timezones of `phone_number`, looked up with `api` the first time.
+    pub async fn timezones<A: SignalWireApi + ?Sized>(&self, api: &A, phone_number: &str) -> Result<Vec<Tz>, SignalWireError> {
+        if let Some(timezones) = self.timezones.lock().unwrap().get(phone_number) {
+            return Ok(timezones.clone());
+        }
+
+        let lookup = api.lookup_phone_number(phone_number).await?;
+        let mut timezones: Vec<Tz> = lookup.timezones.unwrap_or_default().iter().filter_map(|name| name.parse().ok()).collect();
+        if timezones.is_empty() {
+            timezones.push(self.default_timezone);
+        }
+        self.timezones.lock().unwrap().insert(phone_number.to_string(), timezones.clone());
+        Ok(timezones)
+    }
+
+    /// The first moment from `after` on within the sending hours of every timezone of `phone_number`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the timezone lookup, or `SignalWireError::NoSendingTime` if the sending hours of its
+    /// timezones don't overlap: the message can't be sent without reaching someone at night.
+    pub async fn next_send_time<A: SignalWireApi + ?Sized>(&self, api: &A, phone_number: &str, after: DateTime<Utc>) -> Result<DateTime<Utc>, SignalWireError> {
+        let timezones = self.timezones(api, phone_number).await?;
+
+        // Moves to the next start of whichever timezone is outside its hours, until all are within; the hours of
+        // the timezones of a number overlap in practice, the bound stops at those that don't
+        let mut time = after;
+        for _ in 0..8 {
+            match timezones.iter().find(|timezone| !self.hours.contains(time.with_timezone(*timezone).time())) {
+                Some(timezone) => time = self.hours.next_start(*timezone, time),
+                None => return Ok(time),
+            }
+        }
+        Err(SignalWireError::NoSendingTime(phone_number.to_string()))
+    }
+
+    /// Sends `message` with `api` if its recipient is within the sending hours, or holds it back until they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `next_send_time`, or of the send.
+    pub async fn send<A: SignalWireApi + ?Sized>(&self, api: &A, message: SmsMessage) -> Result<ScheduledSend, SignalWireError> {
+        let now = Utc::now();
+        let until = self.next_send_time(api, &message.to, now).await?;
+        if until <= now {
+            return api.send_sms(&message).await.map(|response| ScheduledSend::Sent(Box::new(response)));
+        }
+
+        self.emit(&QuietHoursEvent::Deferred { message: message.clone(), until });
+        self.deferred.lock().unwrap().push((until, message));
+        Ok(ScheduledSend::Deferred { until })
+    }
+
+    /// Sends the deferred messages whose time has come, returning each with the result of its send.
+    pub async fn release_due<A: SignalWireApi + ?Sized>(&self, api: &A) -> Vec<(SmsMessage, Result<SmsResponse, SignalWireError>)> {
+        self.release_due_at(api, Utc::now()).await
+    }
+
+    pub(crate) async fn release_due_at<A: SignalWireApi + ?Sized>(&self, api: &A, now: DateTime<Utc>) -> Vec<(SmsMessage, Result<SmsResponse, SignalWireError>)> {
+        let due: Vec<SmsMessage> = {
+            let mut deferred = self.deferred.lock().unwrap();
+            let (due, waiting) = deferred.drain(..).partition(|(until, _)| *until <= now);
+            *deferred = waiting;
+            due.into_iter().map(|(_, message)| message).collect()
+        };
+
+        let mut results = Vec::with_capacity(due.len());
+        for message in due {
+            self.emit(&QuietHoursEvent::Released { message: message.clone() });
+            let result = api.send_sms(&message).await;
+            results.push((message, result));
+        }
+        results
+    }
+
+    /// The messages held back, with the time each is due.
+    pub fn deferred(&self) -> Vec<(DateTime<Utc>, SmsMessage)> {
+        self.deferred.lock().unwrap().clone()
+    }
+
+    fn emit(&self, event: &QuietHoursEvent) {
+        if let Some(handler) = &self.handler {
+            handler(event);
+        }
+    }
+}
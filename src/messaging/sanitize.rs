@@ -0,0 +1,184 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::messaging::{estimate_segments, SegmentEstimate, SmsEncoding};
+
+/// What `BodySanitizer` does with the characters forcing a body to UCS-2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum UnicodePolicy {
+    /// Leaves them, a single one making every segment hold 70 characters instead of 160.
+    #[default]
+    Keep,
+    /// Replaces the lookalikes of GSM-7 characters: curly quotes, dashes, ellipses, special spaces, accented letters
+    /// missing from GSM-7, and drops the invisible ones (zero-width spaces, soft hyphens). Others, e.g. emoji, stay.
+    Transliterate,
+    /// Transliterates, then removes whatever is still outside GSM-7, so the body is always sent as GSM-7.
+    Strip,
+}
+
+/// A body after `BodySanitizer::sanitize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SanitizedBody {
+    pub body: String,
+    /// Characters replaced or removed to stay in GSM-7.
+    pub replaced: usize,
+    /// Whether the body was cut to fit the segment limit.
+    pub truncated: bool,
+    /// How the resulting body is encoded and split.
+    pub estimate: SegmentEstimate,
+}
+
+/// Keeps message bodies from costing more segments than they should.
+///
+/// ```rust
+/// use signalwire::messaging::{BodySanitizer, SmsEncoding, UnicodePolicy};
+///
+/// let sanitizer = BodySanitizer::new()
+///     .unicode(UnicodePolicy::Transliterate)
+///     .max_segments(1);
+///
+/// let sanitized = sanitizer.sanitize("It’s ready — pick it up at “Main St”");
+/// assert_eq!(sanitized.body, "It's ready - pick it up at \"Main St\"");
+/// assert_eq!(sanitized.estimate.encoding, SmsEncoding::Gsm7);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodySanitizer {
+    unicode: UnicodePolicy,
+    max_segments: Option<usize>,
+    ellipsis: String,
+}
+
+impl Default for BodySanitizer {
+    fn default() -> Self {
+        BodySanitizer::new()
+    }
+}
+
+impl BodySanitizer {
+    /// A sanitizer keeping bodies as they are, see `unicode` and `max_segments`.
+    pub fn new() -> Self {
+        BodySanitizer {
+            unicode: UnicodePolicy::Keep,
+            max_segments: None,
+            ellipsis: "...".to_string(),
+        }
+    }
+
+    /// What to do with the characters forcing UCS-2.
+    pub fn unicode(mut self, policy: UnicodePolicy) -> Self {
+        self.unicode = policy;
+        self
+    }
+
+    /// Cuts bodies longer than `segments` segments, between graphemes so no emoji or accented letter is split.
+    pub fn max_segments(mut self, segments: usize) -> Self {
+        self.max_segments = Some(segments.max(1));
+        self
+    }
+
+    /// What ends a cut body, `...` by default; an empty one cuts without a marker.
+    pub fn ellipsis(mut self, ellipsis: &str) -> Self {
+        self.ellipsis = ellipsis.to_string();
+        self
+    }
+
+    pub fn sanitize(&self, body: &str) -> SanitizedBody {
+        let (body, replaced) = match self.unicode {
+            UnicodePolicy::Keep => (body.to_string(), 0),
+            UnicodePolicy::Transliterate | UnicodePolicy::Strip => transliterate(body, self.unicode == UnicodePolicy::Strip),
+        };
+
+        let estimate = estimate_segments(&body);
+        match self.max_segments {
+            Some(max_segments) if estimate.segments > max_segments => {
+                let body = self.truncate(&body, max_segments);
+                SanitizedBody {
+                    estimate: estimate_segments(&body),
+                    body,
+                    replaced,
+                    truncated: true,
+                }
+            }
+            _ => SanitizedBody {
+                body,
+                replaced,
+                truncated: false,
+                estimate,
+            },
+        }
+    }
+
+    /// The longest start of `body`, up to a grapheme boundary, fitting in `max_segments` with the ellipsis.
+    fn truncate(&self, body: &str, max_segments: usize) -> String {
+        let boundaries: Vec<usize> = body.grapheme_indices(true).map(|(index, _)| index).collect();
+        let cut = |count: usize| {
+            let end = boundaries.get(count).copied().unwrap_or(body.len());
+            format!("{}{}", body[..end].trim_end(), self.ellipsis)
+        };
+
+        // Segments only grow with the graphemes kept, so the longest fitting cut is found by bisection
+        let (mut fits, mut too_long) = (0, boundaries.len());
+        while too_long - fits > 1 {
+            let middle = (fits + too_long) / 2;
+            if estimate_segments(&cut(middle)).segments <= max_segments {
+                fits = middle;
+            } else {
+                too_long = middle;
+            }
+        }
+        cut(fits)
+    }
+}
+
+/// `body` with the lookalikes of GSM-7 characters replaced, and, with `strip`, the rest of the non-GSM-7 characters
+/// removed; with the count of the characters replaced or removed.
+fn transliterate(body: &str, strip: bool) -> (String, usize) {
+    let mut sanitized = String::with_capacity(body.len());
+    let mut replaced = 0;
+    for c in body.chars() {
+        let replacement = match c {
+            '‘' | '’' | '‚' | '‛' | '′' | '`' | '´' => "'",
+            '“' | '”' | '„' | '‟' | '″' | '«' | '»' => "\"",
+            '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => "-",
+            '…' => "...",
+            '•' | '·' => "*",
+            '\t' | '\u{A0}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}' => " ",
+            '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}' | '\u{AD}' => "",
+            'á' | 'â' | 'ã' | 'ā' | 'ă' | 'ą' => "a",
+            'Á' | 'À' | 'Â' | 'Ã' | 'Ā' | 'Ă' | 'Ą' => "A",
+            'ç' | 'ć' | 'č' => "c",
+            'Ć' | 'Č' => "C",
+            'ď' => "d",
+            'Ď' => "D",
+            'ê' | 'ë' | 'ē' | 'ę' | 'ě' => "e",
+            'È' | 'Ê' | 'Ë' | 'Ē' | 'Ę' | 'Ě' => "E",
+            'í' | 'î' | 'ï' | 'ī' | 'ı' => "i",
+            'Í' | 'Ì' | 'Î' | 'Ï' | 'Ī' | 'İ' => "I",
+            'ł' => "l",
+            'Ł' => "L",
+            'ń' | 'ň' => "n",
+            'Ń' | 'Ň' => "N",
+            'ó' | 'ô' | 'õ' | 'ō' | 'ő' => "o",
+            'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ō' | 'Ő' => "O",
+            'ř' => "r",
+            'Ř' => "R",
+            'ś' | 'š' | 'ş' | 'ș' => "s",
+            'Ś' | 'Š' | 'Ş' | 'Ș' => "S",
+            'ť' | 'ţ' | 'ț' => "t",
+            'Ť' | 'Ţ' | 'Ț' => "T",
+            'ú' | 'û' | 'ū' | 'ů' | 'ű' => "u",
+            'Ú' | 'Ù' | 'Û' | 'Ū' | 'Ů' | 'Ű' => "U",
+            'ý' | 'ÿ' => "y",
+            'Ý' | 'Ÿ' => "Y",
+            'ź' | 'ż' | 'ž' => "z",
+            'Ź' | 'Ż' | 'Ž' => "Z",
+            c if strip && SmsEncoding::of(c.encode_utf8(&mut [0; 4])) == SmsEncoding::Ucs2 => "",
+            c => {
+                sanitized.push(c);
+                continue;
+            }
+        };
+        sanitized.push_str(replacement);
+        replaced += 1;
+    }
+    (sanitized, replaced)
+}
@@ -0,0 +1,109 @@
+//! How SMS bodies are encoded and split into segments, as carriers bill them.
+
+/// The characters of the GSM 03.38 default alphabet, one septet each.
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+
+/// The characters of the GSM 03.38 extension table, two septets each (an escape, then the character).
+const GSM7_EXTENSION: &str = "\u{0C}^{}\\[~]|€";
+
+/// How the body of an SMS is encoded over the air.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmsEncoding {
+    /// The GSM 03.38 7-bit alphabet: 160 septets in a single segment, 153 per segment of a longer message.
+    Gsm7,
+    /// UCS-2, as soon as one character is outside GSM-7 (emoji, most non-Latin scripts): 70 UTF-16 code units in a
+    /// single segment, 67 per segment of a longer message.
+    Ucs2,
+}
+
+impl SmsEncoding {
+    /// The encoding of `body`.
+    pub fn of(body: &str) -> Self {
+        if body.chars().all(|c| GSM7_BASIC.contains(c) || GSM7_EXTENSION.contains(c)) {
+            SmsEncoding::Gsm7
+        } else {
+            SmsEncoding::Ucs2
+        }
+    }
+
+    /// The units of a message sent in a single segment.
+    pub fn single_segment_capacity(self) -> usize {
+        match self {
+            SmsEncoding::Gsm7 => 160,
+            SmsEncoding::Ucs2 => 70,
+        }
+    }
+
+    /// The units of each segment of a longer message, the rest of the segment carrying the header that joins them.
+    pub fn multi_segment_capacity(self) -> usize {
+        match self {
+            SmsEncoding::Gsm7 => 153,
+            SmsEncoding::Ucs2 => 67,
+        }
+    }
+
+    /// The units `c` takes: two for the GSM-7 extension characters and the UCS-2 surrogate pairs (emoji), one otherwise.
+    fn units(self, c: char) -> usize {
+        match self {
+            SmsEncoding::Gsm7 if GSM7_EXTENSION.contains(c) => 2,
+            SmsEncoding::Gsm7 => 1,
+            SmsEncoding::Ucs2 => c.len_utf16(),
+        }
+    }
+}
+
+/// How a message body is encoded and split into segments, see `estimate_segments`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SegmentEstimate {
+    pub encoding: SmsEncoding,
+    /// The characters of the body, as a user counts them.
+    pub characters: usize,
+    /// The length of the body in the units of its encoding: septets for GSM-7, UTF-16 code units for UCS-2.
+    pub units: usize,
+    pub segments: usize,
+    /// The units each segment holds: the single segment capacity for a message sent in one, the multi-segment
+    /// capacity otherwise.
+    pub per_segment: usize,
+    /// The units left in the last segment before another one is needed, e.g. for a `12/160` counter.
+    pub remaining: usize,
+}
+
+/// The encoding and segments of a message body, as SignalWire bills it.
+///
+/// A character is never split across two segments: an extension character or an emoji that doesn't fit at the end
+/// of a segment starts the next one, so a segment may hold a unit less than its capacity.
+pub fn estimate_segments(body: &str) -> SegmentEstimate {
+    let encoding = SmsEncoding::of(body);
+    let units: usize = body.chars().map(|c| encoding.units(c)).sum();
+
+    let single = encoding.single_segment_capacity();
+    if units <= single {
+        return SegmentEstimate {
+            encoding,
+            characters: body.chars().count(),
+            units,
+            segments: 1,
+            per_segment: single,
+            remaining: single - units,
+        };
+    }
+
+    let per_segment = encoding.multi_segment_capacity();
+    let (mut segments, mut used) = (1, 0);
+    for c in body.chars() {
+        let units = encoding.units(c);
+        if used + units > per_segment {
+            segments += 1;
+            used = 0;
+        }
+        used += units;
+    }
+    SegmentEstimate {
+        encoding,
+        characters: body.chars().count(),
+        units,
+        segments,
+        per_segment,
+        remaining: per_segment - used,
+    }
+}
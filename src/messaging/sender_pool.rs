@@ -0,0 +1,143 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use async_trait::async_trait;
+
+use crate::{
+    errors::{SignalWireError, ValidationError},
+    types::SmsMessage,
+    validation::validate_e164,
+};
+
+/// Where the sender assigned to each recipient of a `SenderPool` is kept.
+///
+/// Implement this on top of your database for recipients to keep their sender across restarts; `InMemorySenderStore`
+/// is provided for tests and single-process services.
+#[async_trait]
+pub trait SenderStore: Send + Sync {
+    /// The number assigned to `recipient`, if any.
+    async fn sender(&self, recipient: &str) -> Result<Option<String>, SignalWireError>;
+
+    /// Assigns `sender` to `recipient`, replacing any previous assignment.
+    async fn assign(&self, recipient: &str, sender: &str) -> Result<(), SignalWireError>;
+}
+
+/// A `SenderStore` kept in memory, lost on restart.
+#[derive(Debug, Default)]
+pub struct InMemorySenderStore {
+    senders: Mutex<HashMap<String, String>>,
+}
+
+impl InMemorySenderStore {
+    pub fn new() -> Self {
+        InMemorySenderStore::default()
+    }
+}
+
+#[async_trait]
+impl SenderStore for InMemorySenderStore {
+    async fn sender(&self, recipient: &str) -> Result<Option<String>, SignalWireError> {
+        Ok(self.senders.lock().unwrap().get(recipient).cloned())
+    }
+
+    async fn assign(&self, recipient: &str, sender: &str) -> Result<(), SignalWireError> {
+        self.senders.lock().unwrap().insert(recipient.to_string(), sender.to_string());
+        Ok(())
+    }
+}
+
+/// Spreads high-volume traffic over several numbers, while every recipient keeps hearing from the same one.
+///
+/// A new recipient gets the next number of the pool in turn, and keeps it for as long as it stays in the pool;
+/// recipients of a number taken out of the pool move on to the next one in turn.
+///
+/// ```rust,no_run
+/// use signalwire::{
+///     messaging::{InMemorySenderStore, SenderPool},
+///     types::SmsMessage,
+/// };
+///
+/// # async fn example() -> Result<(), signalwire::errors::SignalWireError> {
+/// let pool = SenderPool::new(
+///     ["+15551230001", "+15551230002", "+15551230003"],
+///     InMemorySenderStore::new(),
+/// )?;
+///
+/// let mut message = SmsMessage {
+///     to: "+15557654321".to_string(),
+///     body: "Your order has shipped".to_string(),
+///     ..Default::default()
+/// };
+/// pool.fill_sender(&mut message).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SenderPool<S> {
+    numbers: Vec<String>,
+    store: S,
+    next: AtomicUsize,
+}
+
+impl<S: SenderStore> SenderPool<S> {
+    /// A pool of the E.164 `numbers`, duplicates ignored, assigning them through `store`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::EmptySenderPool` without numbers, or `ValidationError::InvalidPhoneNumber`.
+    pub fn new(numbers: impl IntoIterator<Item = impl Into<String>>, store: S) -> Result<Self, ValidationError> {
+        let mut pool: Vec<String> = Vec::new();
+        for number in numbers {
+            let number = number.into();
+            validate_e164("from", &number)?;
+            if !pool.contains(&number) {
+                pool.push(number);
+            }
+        }
+        if pool.is_empty() {
+            return Err(ValidationError::EmptySenderPool);
+        }
+
+        Ok(SenderPool {
+            numbers: pool,
+            store,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// The numbers of the pool.
+    pub fn numbers(&self) -> &[String] {
+        &self.numbers
+    }
+
+    /// The underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// The number to send to `recipient` from, assigning one if it has none in the pool yet.
+    ///
+    /// Two first messages to the same recipient sent at once may each pick a number; the last one assigned sticks.
+    pub async fn sender_for(&self, recipient: &str) -> Result<String, SignalWireError> {
+        if let Some(sender) = self.store.sender(recipient).await? {
+            if self.numbers.contains(&sender) {
+                return Ok(sender);
+            }
+        }
+
+        let sender = &self.numbers[self.next.fetch_add(1, Ordering::Relaxed) % self.numbers.len()];
+        self.store.assign(recipient, sender).await?;
+        Ok(sender.clone())
+    }
+
+    /// Sets the `from` of `message` to the number of its recipient, see `sender_for`.
+    pub async fn fill_sender(&self, message: &mut SmsMessage) -> Result<(), SignalWireError> {
+        message.from = self.sender_for(&message.to).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use crate::{
+    rt,
+    types::{MessageStatus, SmsResponse},
+    webhook::MessageStatusCallback,
+};
+
+/// The delivery outcomes of the messages of a campaign, see `DeliveryStats`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CampaignStats {
+    pub campaign: String,
+    /// The messages tracked.
+    pub total: usize,
+    pub delivered: usize,
+    pub failed: usize,
+    pub undelivered: usize,
+    /// The messages without a final status yet.
+    pub pending: usize,
+    /// The error codes reported, most frequent first, with their counts.
+    pub error_codes: Vec<(String, usize)>,
+    /// How long the delivered messages took to be reported delivered, `None` before the first one.
+    pub latency: Option<LatencyPercentiles>,
+}
+
+impl CampaignStats {
+    /// The share of the messages tracked that were delivered, from 0 to 1.
+    pub fn delivery_rate(&self) -> f64 {
+        self.rate(self.delivered)
+    }
+
+    /// The share of the messages tracked that failed to be sent, from 0 to 1.
+    pub fn failure_rate(&self) -> f64 {
+        self.rate(self.failed)
+    }
+
+    /// The share of the messages tracked that were sent but not delivered, from 0 to 1.
+    pub fn undelivered_rate(&self) -> f64 {
+        self.rate(self.undelivered)
+    }
+
+    fn rate(&self, count: usize) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            count as f64 / self.total as f64
+        }
+    }
+}
+
+/// Percentiles of the delivery latencies of a campaign, by nearest rank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyPercentiles {
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl LatencyPercentiles {
+    fn of(latencies: &mut [Duration]) -> Option<Self> {
+        let max = *latencies.iter().max()?;
+        latencies.sort_unstable();
+        let percentile = |p: usize| latencies[(latencies.len() * p).div_ceil(100) - 1];
+        Some(LatencyPercentiles {
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max,
+        })
+    }
+}
+
+/// A campaign being aggregated.
+struct Aggregate<'a> {
+    stats: CampaignStats,
+    error_codes: HashMap<&'a str, usize>,
+    latencies: Vec<Duration>,
+}
+
+#[derive(Debug)]
+struct Tracked {
+    campaign: String,
+    sent_at: Duration,
+    status: MessageStatus,
+    error_code: Option<String>,
+    latency: Option<Duration>,
+}
+
+/// Aggregates the delivery outcomes of messages per campaign, from status callbacks or polled statuses.
+///
+/// Messages are tracked by SID once sent; latencies run from then until they are reported delivered.
+///
+/// ```rust,no_run
+/// use signalwire::{client::SignalWireClient, messaging::DeliveryStats, types::SmsMessage, webhook::MessageStatusCallback};
+///
+/// # async fn example(client: SignalWireClient, message: SmsMessage, callback: MessageStatusCallback) -> Result<(), signalwire::errors::SignalWireError> {
+/// let stats = DeliveryStats::new();
+///
+/// let response = client.send_sms(&message).await?;
+/// stats.track("spring-sale", response.sid.as_str());
+///
+/// // In the status callback handler
+/// stats.record_callback(&callback);
+///
+/// if let Some(report) = stats.campaign("spring-sale") {
+///     println!("{:.1}% delivered, top errors {:?}", report.delivery_rate() * 100.0, report.error_codes);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct DeliveryStats {
+    messages: Mutex<HashMap<String, Tracked>>,
+}
+
+impl DeliveryStats {
+    pub fn new() -> Self {
+        DeliveryStats::default()
+    }
+
+    /// Starts tracking the message `message_sid` of `campaign`, sent now.
+    pub fn track(&self, campaign: &str, message_sid: &str) {
+        self.track_at(campaign, message_sid, rt::since_epoch());
+    }
+
+    pub(crate) fn track_at(&self, campaign: &str, message_sid: &str, sent_at: Duration) {
+        self.messages.lock().unwrap().insert(
+            message_sid.to_string(),
+            Tracked {
+                campaign: campaign.to_string(),
+                sent_at,
+                status: MessageStatus::Queued,
+                error_code: None,
+                latency: None,
+            },
+        );
+    }
+
+    /// Records the status of `message_sid`, returning whether the message is tracked.
+    pub fn record(&self, message_sid: &str, status: MessageStatus, error_code: Option<&str>) -> bool {
+        self.record_at(message_sid, status, error_code, rt::since_epoch())
+    }
+
+    pub(crate) fn record_at(&self, message_sid: &str, status: MessageStatus, error_code: Option<&str>, at: Duration) -> bool {
+        let mut messages = self.messages.lock().unwrap();
+        let Some(message) = messages.get_mut(message_sid) else {
+            return false;
+        };
+
+        // Callbacks may arrive out of order: a final status isn't overwritten by an earlier one
+        if message.status.is_final() && !status.is_final() {
+            return true;
+        }
+        if status == MessageStatus::Delivered && message.latency.is_none() {
+            message.latency = Some(at.saturating_sub(message.sent_at));
+        }
+        if let Some(error_code) = error_code {
+            message.error_code = Some(error_code.to_string());
+        }
+        message.status = status;
+        true
+    }
+
+    /// Records the status reported by a status callback, returning whether the message is tracked.
+    pub fn record_callback(&self, callback: &MessageStatusCallback) -> bool {
+        self.record(&callback.message_sid, callback.get_status(), callback.error_code.as_deref())
+    }
+
+    /// Records the status of a message polled with `get_message_status`, returning whether the message is tracked.
+    pub fn record_response(&self, response: &SmsResponse) -> bool {
+        self.record(response.sid.as_str(), response.get_status(), response.error_code.as_deref())
+    }
+
+    /// The statistics of `campaign`, `None` if no message of it is tracked.
+    pub fn campaign(&self, campaign: &str) -> Option<CampaignStats> {
+        self.campaigns().into_iter().find(|stats| stats.campaign == campaign)
+    }
+
+    /// The statistics of every campaign, by name.
+    pub fn campaigns(&self) -> Vec<CampaignStats> {
+        let messages = self.messages.lock().unwrap();
+        let mut campaigns: HashMap<&str, Aggregate> = HashMap::new();
+        for message in messages.values() {
+            let Aggregate { stats, error_codes, latencies } = campaigns.entry(&message.campaign).or_insert_with(|| Aggregate {
+                stats: CampaignStats {
+                    campaign: message.campaign.clone(),
+                    total: 0,
+                    delivered: 0,
+                    failed: 0,
+                    undelivered: 0,
+                    pending: 0,
+                    error_codes: Vec::new(),
+                    latency: None,
+                },
+                error_codes: HashMap::new(),
+                latencies: Vec::new(),
+            });
+
+            stats.total += 1;
+            match message.status {
+                MessageStatus::Delivered => stats.delivered += 1,
+                MessageStatus::Failed => stats.failed += 1,
+                MessageStatus::Undelivered => stats.undelivered += 1,
+                _ => stats.pending += 1,
+            }
+            if let Some(error_code) = &message.error_code {
+                *error_codes.entry(error_code).or_default() += 1;
+            }
+            latencies.extend(message.latency);
+        }
+
+        let mut campaigns: Vec<CampaignStats> = campaigns
+            .into_values()
+            .map(|Aggregate { mut stats, error_codes, mut latencies }| {
+                stats.error_codes = error_codes.into_iter().map(|(code, count)| (code.to_string(), count)).collect();
+                stats.error_codes.sort_by(|(a_code, a_count), (b_code, b_count)| b_count.cmp(a_count).then(a_code.cmp(b_code)));
+                stats.latency = LatencyPercentiles::of(&mut latencies);
+                stats
+            })
+            .collect();
+        campaigns.sort_by(|a, b| a.campaign.cmp(&b.campaign));
+        campaigns
+    }
+}
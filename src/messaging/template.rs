@@ -0,0 +1,147 @@
+use crate::{
+    errors::{SignalWireError, ValidationError},
+    messaging::{estimate_segments, SegmentEstimate},
+    types::SmsMessage,
+    validation::validate_sms_message,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Placeholder(String),
+}
+
+/// A message body with `{name}` placeholders, e.g. `"Hi {name}, your code is {code}"`.
+///
+/// Placeholder names are made of ASCII letters, digits and underscores; write `{{` and `}}` for literal braces.
+///
+/// ```rust
+/// use signalwire::messaging::{MessageTemplate, SmsEncoding};
+///
+/// let template = MessageTemplate::parse("Hi {name}, your code is {code}")?;
+/// assert_eq!(template.placeholders(), ["name", "code"]);
+///
+/// let values = [("name", "Ada"), ("code", "123456")];
+/// assert_eq!(template.render(&values)?, "Hi Ada, your code is 123456");
+///
+/// let estimate = template.estimate(&values)?;
+/// assert_eq!(
+///     (estimate.encoding, estimate.segments),
+///     (SmsEncoding::Gsm7, 1)
+/// );
+/// # Ok::<(), signalwire::errors::SignalWireError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageTemplate {
+    parts: Vec<Part>,
+}
+
+impl MessageTemplate {
+    /// Parses a template.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidTemplate` for an unclosed or empty placeholder, a name with other characters,
+    /// or a lone `}`.
+    pub fn parse(template: &str) -> Result<Self, ValidationError> {
+        let invalid = |reason: &str| ValidationError::InvalidTemplate(format!("{} in {:?}", reason, template));
+
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) if c.is_ascii_alphanumeric() || c == '_' => name.push(c),
+                            Some(c) => return Err(invalid(&format!("Unexpected {:?} in placeholder", c))),
+                            None => return Err(invalid("Unclosed placeholder")),
+                        }
+                    }
+                    if name.is_empty() {
+                        return Err(invalid("Empty placeholder"));
+                    }
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Placeholder(name));
+                }
+                '}' => return Err(invalid("Unmatched `}`")),
+                c => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+
+        Ok(MessageTemplate { parts })
+    }
+
+    /// The names of the placeholders, in order of first appearance.
+    pub fn placeholders(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for part in &self.parts {
+            if let Part::Placeholder(name) = part {
+                if !names.contains(&name.as_str()) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// The body with every placeholder replaced by its value in `values`; values without a placeholder are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::MissingTemplateValue` for a placeholder without a value.
+    pub fn render<K: AsRef<str>, V: AsRef<str>>(&self, values: &[(K, V)]) -> Result<String, ValidationError> {
+        let mut body = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => body.push_str(text),
+                Part::Placeholder(name) => {
+                    let (_, value) = values.iter().find(|(key, _)| key.as_ref() == name).ok_or_else(|| ValidationError::MissingTemplateValue(name.clone()))?;
+                    body.push_str(value.as_ref());
+                }
+            }
+        }
+        Ok(body)
+    }
+
+    /// The encoding and segment count of the body rendered with `values`, see `estimate_segments`.
+    pub fn estimate<K: AsRef<str>, V: AsRef<str>>(&self, values: &[(K, V)]) -> Result<SegmentEstimate, ValidationError> {
+        self.render(values).map(|body| estimate_segments(&body))
+    }
+
+    /// The message from `from` to `to` with the body rendered with `values`, checked with `validate_sms_message`.
+    pub fn message<K: AsRef<str>, V: AsRef<str>>(&self, from: &str, to: &str, values: &[(K, V)]) -> Result<SmsMessage, SignalWireError> {
+        let message = SmsMessage {
+            body: self.render(values)?,
+            from: from.to_string(),
+            to: to.to_string(),
+            ..Default::default()
+        };
+        validate_sms_message(&message)?;
+        Ok(message)
+    }
+}
+
+impl std::str::FromStr for MessageTemplate {
+    type Err = ValidationError;
+
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        MessageTemplate::parse(template)
+    }
+}
@@ -0,0 +1,61 @@
+//! OpenTelemetry instrumentation for outgoing SignalWire API calls.
+//!
+//! Spans are created through the globally registered tracer provider and the trace context is
+//! injected with the globally registered propagator, so the application stays in control of
+//! exporters and propagation formats.
+
+use opentelemetry::{
+    global,
+    trace::{SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+use opentelemetry_http::HeaderInjector;
+use reqwest::Body;
+
+use crate::{
+    errors::SignalWireError,
+    transport::{self, HttpTransport},
+};
+
+/// The instrumentation scope name reported on every span.
+pub const TRACER_NAME: &str = "signalwire";
+
+/// Executes `request` inside a client span and propagates the span context to SignalWire.
+pub(crate) async fn instrumented_send(transport: &dyn HttpTransport, operation: &'static str, mut request: http::Request<Body>) -> Result<http::Response<Body>, SignalWireError> {
+    let tracer = global::tracer(TRACER_NAME);
+
+    let span = tracer
+        .span_builder(format!("signalwire.{}", operation))
+        .with_kind(SpanKind::Client)
+        .with_attributes(vec![
+            KeyValue::new("rpc.system", "signalwire"),
+            KeyValue::new("rpc.method", operation),
+            KeyValue::new("http.request.method", request.method().to_string()),
+            KeyValue::new("server.address", request.uri().host().unwrap_or_default().to_string()),
+            KeyValue::new("url.path", request.uri().path().to_string()),
+        ])
+        .start(&tracer);
+    let cx = Context::current_with_span(span);
+
+    global::get_text_map_propagator(|propagator| propagator.inject_context(&cx, &mut HeaderInjector(request.headers_mut())));
+
+    let result = transport.execute(operation, request).await;
+    let span = cx.span();
+
+    match &result {
+        Ok(response) => {
+            let status = response.status();
+            span.set_attribute(KeyValue::new("http.response.status_code", i64::from(status.as_u16())));
+            if status.is_client_error() || status.is_server_error() {
+                span.set_status(Status::error(status.to_string()));
+            }
+        }
+        Err(e) => {
+            span.set_attribute(KeyValue::new("error.type", if transport::is_timeout(e) { "timeout" } else { "transport" }));
+            span.set_status(Status::error(e.to_string()));
+        }
+    }
+    span.end();
+
+    result
+}
@@ -0,0 +1,419 @@
+//! Walking paginated list responses.
+//!
+//! Both API families paginate their lists, the Compatibility API with `next_page_uri` and the others with `links`.
+//! `Page` hides the difference: turn any list response into one with `Paginated::into_page`, then follow it with
+//! `Page::next_page`, or read every item of the list from the `*_stream` variant of its client method.
+//!
+//! ```rust,no_run
+//! use signalwire::{client::SignalWireClient, errors::SignalWireError, pagination::Paginated};
+//!
+//! # async fn example(client: SignalWireClient) -> Result<(), SignalWireError> {
+//! let mut page = client.get_phone_numbers_owned(&[]).await?.into_page();
+//! loop {
+//!     for number in &page.items {
+//!         println!("{}", number.number);
+//!     }
+//!     match page.next_page(&client).await? {
+//!         Some(next) => page = next,
+//!         None => break,
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(any(test, feature = "reqwest"))]
+use std::future::Future;
+use std::{fmt, str::FromStr};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD as BASE64, Engine as _};
+#[cfg(any(test, feature = "reqwest"))]
+use futures_util::{stream, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(any(test, feature = "reqwest"))]
+use crate::{client::SignalWireClient, errors::CollectLimitError};
+use crate::{
+    errors::{SignalWireError, ValidationError},
+    sansio::{check_status, parse_json, GetPage, HttpResponse},
+    types::*,
+};
+
+/// How many items and pages the `*_all` methods collect at most, see `SignalWireClient::with_collect_limits`.
+///
+/// Reaching a limit fails with `SignalWireError::CollectLimit` rather than returning part of the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectLimits {
+    pub max_items: usize,
+    pub max_pages: usize,
+}
+
+impl Default for CollectLimits {
+    /// At most 10,000 items over 200 pages.
+    fn default() -> Self {
+        CollectLimits { max_items: 10_000, max_pages: 200 }
+    }
+}
+
+impl CollectLimits {
+    /// No limits: the whole list is collected, whatever its size.
+    pub fn unbounded() -> Self {
+        CollectLimits {
+            max_items: usize::MAX,
+            max_pages: usize::MAX,
+        }
+    }
+}
+
+/// One page of a list, with the links to its neighbours.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// The link to the next page, `None` on the last one.
+    pub next: Option<String>,
+    /// The link to the previous page, `None` on the first one.
+    pub prev: Option<String>,
+    /// Parses the neighbouring pages, which have the type of this one.
+    parse: fn(&HttpResponse) -> Result<Page<T>, SignalWireError>,
+}
+
+/// Where to resume a list, e.g. after a restart, see `Page::next_cursor` and `resume`.
+///
+/// The cursor is opaque: store it as a string (`Display`/`FromStr`) or with serde, which use the same form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct PageCursor {
+    link: String,
+}
+
+impl PageCursor {
+    /// The operation fetching the page of the cursor, for sansio users.
+    pub fn operation<R: Paginated>(&self) -> GetPage<'_, R::Item> {
+        GetPage { link: &self.link, parse: parse_page::<R> }
+    }
+}
+
+impl fmt::Display for PageCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&BASE64.encode(&self.link))
+    }
+}
+
+impl FromStr for PageCursor {
+    type Err = ValidationError;
+
+    fn from_str(cursor: &str) -> Result<Self, Self::Err> {
+        BASE64
+            .decode(cursor)
+            .ok()
+            .and_then(|link| String::from_utf8(link).ok())
+            .map(|link| PageCursor { link })
+            .ok_or_else(|| ValidationError::InvalidCursor(cursor.to_string()))
+    }
+}
+
+impl From<PageCursor> for String {
+    fn from(cursor: PageCursor) -> Self {
+        cursor.to_string()
+    }
+}
+
+impl TryFrom<String> for PageCursor {
+    type Error = ValidationError;
+
+    fn try_from(cursor: String) -> Result<Self, Self::Error> {
+        cursor.parse()
+    }
+}
+
+/// Fetches the page of `cursor`, from a list response of type `R`.
+///
+/// ```rust,no_run
+/// use signalwire::{
+///     client::SignalWireClient,
+///     errors::SignalWireError,
+///     pagination::{self, PageCursor},
+///     types::MessagesListResponse,
+/// };
+///
+/// # async fn example(client: SignalWireClient, saved: &str) -> Result<(), SignalWireError> {
+/// let cursor: PageCursor = saved.parse()?;
+/// let page = pagination::resume::<MessagesListResponse>(&client, &cursor).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Same as `Page::next_page`.
+#[cfg(any(test, feature = "reqwest"))]
+pub async fn resume<R: Paginated>(client: &SignalWireClient, cursor: &PageCursor) -> Result<Page<R::Item>, SignalWireError> {
+    client.execute(&cursor.operation::<R>()).await
+}
+
+/// A list response that can be turned into a `Page`.
+pub trait Paginated: DeserializeOwned {
+    type Item;
+
+    /// The field holding the items, which `streaming::ItemDecoder` decodes one by one.
+    const ITEMS_FIELD: &'static str = "data";
+
+    /// The JSON pointer to the link of the next page.
+    const NEXT_LINK_POINTER: &'static str = "/links/next";
+
+    fn next_link(&self) -> Option<&str>;
+
+    fn prev_link(&self) -> Option<&str>;
+
+    fn into_items(self) -> Vec<Self::Item>;
+
+    fn into_page(self) -> Page<Self::Item> {
+        Page {
+            next: self.next_link().map(str::to_string),
+            prev: self.prev_link().map(str::to_string),
+            items: self.into_items(),
+            parse: parse_page::<Self>,
+        }
+    }
+}
+
+/// Streams the items of the list whose first page is `first`, fetching the following pages as the stream is read.
+#[cfg(any(test, feature = "reqwest"))]
+pub(crate) fn stream_items<'a, T, F>(client: &'a SignalWireClient, first: F) -> impl Stream<Item = Result<T, SignalWireError>> + 'a
+where
+    T: 'a,
+    F: Future<Output = Result<Page<T>, SignalWireError>> + 'a,
+{
+    enum State<F, T> {
+        First(F),
+        Next(Page<T>),
+    }
+
+    stream::try_unfold(State::First(first), move |state| async move {
+        let mut page = match state {
+            State::First(first) => first.await?,
+            State::Next(page) => match page.next_page(client).await? {
+                Some(next) => next,
+                None => return Ok(None),
+            },
+        };
+
+        let items = std::mem::take(&mut page.items);
+        Ok::<_, SignalWireError>(Some((stream::iter(items.into_iter().map(Ok)), State::Next(page))))
+    })
+    .try_flatten()
+}
+
+/// Collects the items of the list whose first page is `first`, within the limits of `client`.
+#[cfg(any(test, feature = "reqwest"))]
+pub(crate) async fn collect_items<T, F>(client: &SignalWireClient, first: F) -> Result<Vec<T>, SignalWireError>
+where
+    F: Future<Output = Result<Page<T>, SignalWireError>>,
+{
+    let CollectLimits { max_items, max_pages } = client.collect_limits;
+    let mut page = first.await?;
+    let mut pages = 1;
+    let mut items = Vec::new();
+
+    loop {
+        items.append(&mut page.items);
+        if items.len() > max_items {
+            return Err(CollectLimitError::TooManyItems { max_items }.into());
+        }
+        if !page.has_next() {
+            return Ok(items);
+        }
+        // Fail before fetching a page beyond the limit
+        if pages >= max_pages {
+            return Err(CollectLimitError::TooManyPages { max_pages }.into());
+        }
+
+        page = match page.next_page(client).await? {
+            Some(next) => next,
+            None => return Ok(items),
+        };
+        pages += 1;
+    }
+}
+
+pub(crate) fn parse_page<R: Paginated>(response: &HttpResponse) -> Result<Page<R::Item>, SignalWireError> {
+    check_status(response, None)?;
+    parse_json::<R>(response).map(Paginated::into_page)
+}
+
+impl<T> Page<T> {
+    pub fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+
+    pub fn has_prev(&self) -> bool {
+        self.prev.is_some()
+    }
+
+    /// The cursor of the next page, to resume the list from later with `resume`. `None` on the last page.
+    pub fn next_cursor(&self) -> Option<PageCursor> {
+        self.next.clone().map(|link| PageCursor { link })
+    }
+
+    /// The cursor of the previous page. `None` on the first page.
+    pub fn prev_cursor(&self) -> Option<PageCursor> {
+        self.prev.clone().map(|link| PageCursor { link })
+    }
+
+    /// The operation fetching the next page, for sansio users. `None` on the last page.
+    pub fn next_page_operation(&self) -> Option<GetPage<'_, T>> {
+        self.next.as_deref().map(|link| GetPage { link, parse: self.parse })
+    }
+
+    /// The operation fetching the previous page, for sansio users. `None` on the first page.
+    pub fn prev_page_operation(&self) -> Option<GetPage<'_, T>> {
+        self.prev.as_deref().map(|link| GetPage { link, parse: self.parse })
+    }
+}
+
+#[cfg(any(test, feature = "reqwest"))]
+impl<T> Page<T> {
+    /// Streams the items of this page, then those of the following pages as the stream is read.
+    ///
+    /// Pin the stream to read it, e.g. with `futures::pin_mut!` or `Box::pin`.
+    pub fn into_stream<'a>(self, client: &'a SignalWireClient) -> impl Stream<Item = Result<T, SignalWireError>> + 'a
+    where
+        T: 'a,
+    {
+        stream_items(client, std::future::ready(Ok(self)))
+    }
+
+    /// Collects the items of this page and of the following ones, within the limits of `client`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::CollectLimit` if the list is larger than the limits, or the error of the first failing
+    /// page.
+    pub async fn collect_all(self, client: &SignalWireClient) -> Result<Vec<T>, SignalWireError> {
+        collect_items(client, std::future::ready(Ok(self))).await
+    }
+
+    /// Fetches the next page, or returns `None` on the last one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unauthorized` if authentication fails, `SignalWireError::Unexpected` if the page
+    /// can't be fetched or parsed.
+    pub async fn next_page(&self, client: &SignalWireClient) -> Result<Option<Page<T>>, SignalWireError> {
+        match self.next_page_operation() {
+            Some(operation) => client.execute(&operation).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the previous page, or returns `None` on the first one.
+    ///
+    /// # Errors
+    ///
+    /// Same as `next_page`.
+    pub async fn prev_page(&self, client: &SignalWireClient) -> Result<Option<Page<T>>, SignalWireError> {
+        match self.prev_page_operation() {
+            Some(operation) => client.execute(&operation).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Blocking version of `next_page`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `next_page`.
+    #[cfg(feature = "blocking")]
+    pub fn next_page_blocking(&self, client: &SignalWireClient) -> Result<Option<Page<T>>, SignalWireError> {
+        crate::blocking::block_on(self.next_page(client))
+    }
+
+    /// Blocking version of `prev_page`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `next_page`.
+    #[cfg(feature = "blocking")]
+    pub fn prev_page_blocking(&self, client: &SignalWireClient) -> Result<Option<Page<T>>, SignalWireError> {
+        crate::blocking::block_on(self.prev_page(client))
+    }
+}
+
+// The lists of the Compatibility API, linking their neighbours with `next_page_uri` and `previous_page_uri`.
+macro_rules! impl_paginated_uri {
+    ($($response:ident => $items:ident: $item:ty),* $(,)?) => {
+        $(
+            impl Paginated for $response {
+                type Item = $item;
+
+                const ITEMS_FIELD: &'static str = stringify!($items);
+
+                const NEXT_LINK_POINTER: &'static str = "/next_page_uri";
+
+                fn next_link(&self) -> Option<&str> {
+                    self.next_page_uri.as_deref()
+                }
+
+                fn prev_link(&self) -> Option<&str> {
+                    self.previous_page_uri.as_deref()
+                }
+
+                fn into_items(self) -> Vec<$item> {
+                    self.$items
+                }
+            }
+        )*
+    };
+}
+
+// The lists of the other APIs, linking their neighbours with `links`.
+macro_rules! impl_paginated_links {
+    ($($response:ident: $item:ty),* $(,)?) => {
+        $(
+            impl Paginated for $response {
+                type Item = $item;
+
+                fn next_link(&self) -> Option<&str> {
+                    self.links.next.as_deref()
+                }
+
+                fn prev_link(&self) -> Option<&str> {
+                    self.links.prev.as_deref()
+                }
+
+                fn into_items(self) -> Vec<$item> {
+                    self.data
+                }
+            }
+        )*
+    };
+}
+
+impl_paginated_uri!(
+    MessagesListResponse => messages: SmsResponse,
+    CallsListResponse => calls: CallResponse,
+    ConferenceRecordingsListResponse => recordings: ConferenceRecording,
+    ConferenceParticipantsListResponse => participants: ConferenceParticipant,
+    ShortCodesListResponse => short_codes: ShortCode,
+    SubprojectsListResponse => accounts: SubprojectResponse,
+    SubprojectPhoneNumbersResponse => incoming_phone_numbers: SubprojectPhoneNumber,
+);
+
+impl_paginated_links!(
+    PhoneNumbersOwnedResponse: OwnedPhoneNumber,
+    SwmlScriptsListResponse: SwmlScriptResponse,
+    RoomRecordingsListResponse: RoomRecording,
+    RoomSessionsListResponse: RoomSession,
+    RoomMembersListResponse: RoomMember,
+    FabricSubscribersListResponse: FabricSubscriberResponse,
+    FabricAddressesListResponse: FabricAddress,
+    FabricResourcesListResponse: FabricResource,
+    AiAgentsListResponse: AiAgentResponse,
+    DatasphereDocumentsListResponse: DatasphereDocument,
+    DatasphereChunksListResponse: DatasphereChunk,
+    MessageLogsListResponse: MessageLog,
+    VoiceLogsListResponse: VoiceLog,
+    SipEndpointsListResponse: SipEndpoint,
+    DomainApplicationsListResponse: DomainApplication,
+);
@@ -0,0 +1,204 @@
+//! Certificate pinning of the connections to SignalWire, enabled by the `tls-pinning` feature.
+//!
+//! On top of the usual validation of the chain against the Mozilla roots, a connection is only accepted when a
+//! certificate of the validated chain, its trust anchor included, matches one of the pins of the `HttpOptions`.
+//! Pin the key in use along with its successor, or a CA key, so a rotation doesn't cut the client off:
+//!
+//! ```rust
+//! use signalwire::{pinning::CertificatePin, transport::HttpOptions};
+//!
+//! let options = HttpOptions::new()
+//!     .pin_certificate(CertificatePin::spki_sha256(
+//!         "RkqKJTJKjMbya+tN9cQY4qXTP+hE+BvmV3r4LifFDBY=",
+//!     )?)
+//!     .pin_certificate(CertificatePin::spki_sha256(
+//!         "9Sz1WMtM2E/WmCq1yczu2+703Dao6mgcWDjjXH62ATw=",
+//!     )?);
+//! # Ok::<(), signalwire::errors::SignalWireError>(())
+//! ```
+//!
+//! With pins, connections go through rustls rather than the system's TLS library. A mismatch fails the handshake,
+//! before any request is sent, with `SignalWireError::HttpError`.
+
+use std::sync::Arc;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use ring::digest::{digest, SHA256};
+use rustls::{
+    client::{
+        danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+        WebPkiServerVerifier,
+    },
+    crypto::CryptoProvider,
+    pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer, ServerName, UnixTime},
+    CertificateError, ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme,
+};
+
+use crate::{
+    errors::{SignalWireError, ValidationError},
+    transport::{ClientIdentity, HttpVersion},
+};
+
+/// A SHA-256 hash a certificate of the chain must match, see the module documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CertificatePin {
+    /// The hash of a SubjectPublicKeyInfo, which outlives certificates renewed with the same key.
+    Spki([u8; 32]),
+    /// The hash of a whole certificate (DER), which changes with every renewal.
+    Certificate([u8; 32]),
+}
+
+impl CertificatePin {
+    /// A pin on the base64 SHA-256 of a SubjectPublicKeyInfo, as HPKP `pin-sha256` values and
+    /// `openssl x509 -pubkey -noout | openssl pkey -pubin -outform der | openssl dgst -sha256 -binary | base64` give it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidPin` if `hash` isn't a base64 SHA-256 hash.
+    pub fn spki_sha256(hash: &str) -> Result<Self, SignalWireError> {
+        Ok(CertificatePin::Spki(decode(hash)?))
+    }
+
+    /// A pin on the base64 SHA-256 of a whole certificate (DER).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidPin` if `hash` isn't a base64 SHA-256 hash.
+    pub fn certificate_sha256(hash: &str) -> Result<Self, SignalWireError> {
+        Ok(CertificatePin::Certificate(decode(hash)?))
+    }
+
+    /// Whether the certificate `certificate` (absent for a trust anchor) with the key `spki` matches.
+    fn matches(&self, certificate: Option<&[u8]>, spki: &[u8]) -> bool {
+        match self {
+            CertificatePin::Spki(hash) => digest(&SHA256, spki).as_ref() == hash,
+            CertificatePin::Certificate(hash) => certificate.is_some_and(|certificate| digest(&SHA256, certificate).as_ref() == hash),
+        }
+    }
+}
+
+fn decode(hash: &str) -> Result<[u8; 32], ValidationError> {
+    BASE64.decode(hash.trim()).ok().and_then(|bytes| bytes.try_into().ok()).ok_or_else(|| ValidationError::InvalidPin(hash.to_string()))
+}
+
+/// Validates the chain as usual, then checks it against the pins.
+#[derive(Debug)]
+pub(crate) struct PinnedVerifier {
+    roots: Arc<RootCertStore>,
+    pins: Vec<CertificatePin>,
+    provider: Arc<CryptoProvider>,
+    verifier: Arc<WebPkiServerVerifier>,
+}
+
+impl PinnedVerifier {
+    pub(crate) fn new(roots: RootCertStore, pins: Vec<CertificatePin>, provider: Arc<CryptoProvider>) -> Self {
+        let roots = Arc::new(roots);
+        let verifier = WebPkiServerVerifier::builder_with_provider(roots.clone(), provider.clone())
+            .build()
+            .expect("The trust store of a pinned verifier can't be empty");
+        PinnedVerifier { roots, pins, provider, verifier }
+    }
+
+    /// Whether a certificate of the path `end_entity` validates through matches a pin.
+    fn is_pinned(&self, end_entity: &CertificateDer<'_>, intermediates: &[CertificateDer<'_>], now: UnixTime) -> Result<bool, rustls::Error> {
+        // Only the path counts, not the other certificates the server may have sent along
+        let certificate = webpki::EndEntityCert::try_from(end_entity).map_err(|e| rustls::Error::General(e.to_string()))?;
+        let path = certificate
+            .verify_for_usage(
+                self.provider.signature_verification_algorithms.all,
+                &self.roots.roots,
+                intermediates,
+                now,
+                webpki::KeyUsage::server_auth(),
+                None,
+                None,
+            )
+            .map_err(|e| rustls::Error::General(e.to_string()))?;
+
+        let certificates: Vec<_> = std::iter::once(&**path.end_entity()).chain(path.intermediate_certificates()).collect();
+        let anchor = der_sequence(&path.anchor().subject_public_key_info);
+        Ok(self
+            .pins
+            .iter()
+            .any(|pin| pin.matches(None, &anchor) || certificates.iter().any(|certificate| pin.matches(Some(&certificate.der()), &certificate.subject_public_key_info()))))
+    }
+}
+
+/// `contents` wrapped in a DER `SEQUENCE`, as trust anchors keep their SubjectPublicKeyInfo without it.
+fn der_sequence(contents: &[u8]) -> Vec<u8> {
+    let mut sequence = vec![0x30];
+    match contents.len() {
+        length @ 0..0x80 => sequence.push(length as u8),
+        length => {
+            let bytes = length.to_be_bytes();
+            let significant = &bytes[bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len() - 1)..];
+            sequence.push(0x80 | significant.len() as u8);
+            sequence.extend_from_slice(significant);
+        }
+    }
+    sequence.extend_from_slice(contents);
+    sequence
+}
+
+impl ServerCertVerifier for PinnedVerifier {
+    fn verify_server_cert(&self, end_entity: &CertificateDer<'_>, intermediates: &[CertificateDer<'_>], server_name: &ServerName<'_>, ocsp_response: &[u8], now: UnixTime) -> Result<ServerCertVerified, rustls::Error> {
+        let verified = self.verifier.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)?;
+        if !self.is_pinned(end_entity, intermediates, now)? {
+            return Err(rustls::Error::InvalidCertificate(CertificateError::ApplicationVerificationFailure));
+        }
+        Ok(verified)
+    }
+
+    fn verify_tls12_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.verifier.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.verifier.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.verifier.supported_verify_schemes()
+    }
+}
+
+/// The certificate chain and private key of a `ClientIdentity`, as rustls takes them.
+pub(crate) fn load_identity(certificate_chain: &[u8], private_key: &[u8]) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), SignalWireError> {
+    let invalid = |e: rustls::pki_types::pem::Error| SignalWireError::HttpError(format!("Invalid client identity: {}", e));
+    let certificates = CertificateDer::pem_slice_iter(certificate_chain).collect::<Result<Vec<_>, _>>().map_err(invalid)?;
+    let key = PrivateKeyDer::from_pem_slice(private_key).map_err(invalid)?;
+    rustls::crypto::ring::default_provider()
+        .key_provider
+        .load_private_key(key.clone_key())
+        .map_err(|e| SignalWireError::HttpError(format!("Invalid client identity: {}", e)))?;
+    Ok((certificates, key))
+}
+
+/// The TLS settings of a client checking `pins` and presenting `identity`, offering the protocols of `version` over ALPN.
+pub(crate) fn client_config(pins: &[CertificatePin], version: HttpVersion, identity: Option<&ClientIdentity>) -> ClientConfig {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let roots = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    let verifier = PinnedVerifier::new(roots, pins.to_vec(), provider.clone());
+
+    let builder = ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .expect("The ring provider supports the default TLS versions")
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier));
+    let mut config = match identity {
+        Some(identity) => {
+            let (certificate_chain, private_key) = identity.pem();
+            let (certificates, key) = load_identity(certificate_chain, private_key).expect("The identity was checked when loaded");
+            builder.with_client_auth_cert(certificates, key).expect("The identity was checked when loaded")
+        }
+        None => builder.with_no_client_auth(),
+    };
+    config.alpn_protocols = match version {
+        HttpVersion::Auto if cfg!(feature = "http2") => vec![b"h2".to_vec(), b"http/1.1".to_vec()],
+        HttpVersion::Auto | HttpVersion::Http1Only => vec![b"http/1.1".to_vec()],
+        HttpVersion::Http2PriorKnowledge => vec![b"h2".to_vec()],
+    };
+    config
+}
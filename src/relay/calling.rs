@@ -0,0 +1,533 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{EventStream, RawEvent, RelayClient};
+use crate::errors::{RelayError, SignalWireError};
+
+/// An endpoint to dial or connect to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Device {
+    #[serde(rename = "type")]
+    pub device_type: String,
+    pub params: Value,
+}
+
+impl Device {
+    /// A phone number, called from `from_number`.
+    pub fn phone(to_number: &str, from_number: &str) -> Self {
+        Device {
+            device_type: "phone".to_string(),
+            params: json!({ "to_number": to_number, "from_number": from_number }),
+        }
+    }
+
+    /// A SIP URI, called from the SIP URI `from`.
+    pub fn sip(to: &str, from: &str) -> Self {
+        Device {
+            device_type: "sip".to_string(),
+            params: json!({ "to": to, "from": from }),
+        }
+    }
+
+    /// Seconds to ring before giving up.
+    pub fn timeout(mut self, seconds: u32) -> Self {
+        self.params["timeout"] = json!(seconds);
+        self
+    }
+}
+
+/// Something to play on a call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "params", rename_all = "lowercase")]
+pub enum Media {
+    Tts {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        gender: Option<String>,
+    },
+    Audio {
+        url: String,
+    },
+    /// Seconds of silence.
+    Silence {
+        duration: f64,
+    },
+    /// A country's ringback tone, e.g. `us`.
+    Ringtone {
+        name: String,
+    },
+}
+
+impl Media {
+    /// Text to speech with the default voice.
+    pub fn tts(text: &str) -> Self {
+        Media::Tts {
+            text: text.to_string(),
+            language: None,
+            gender: None,
+        }
+    }
+
+    pub fn audio(url: &str) -> Self {
+        Media::Audio { url: url.to_string() }
+    }
+
+    pub fn silence(duration: f64) -> Self {
+        Media::Silence { duration }
+    }
+}
+
+/// What `Call::prompt` collects.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Collect {
+    /// Seconds to wait for the caller to start.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_timeout: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digits: Option<CollectDigits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speech: Option<CollectSpeech>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectDigits {
+    pub max: u32,
+    /// Keys ending the input early, e.g. `#`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminators: Option<String>,
+    /// Seconds to wait between key presses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digit_timeout: Option<f64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectSpeech {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_silence_timeout: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hints: Vec<String>,
+}
+
+impl Collect {
+    /// Collects up to `max` digits.
+    pub fn digits(max: u32) -> Self {
+        Collect {
+            digits: Some(CollectDigits { max, ..Default::default() }),
+            ..Default::default()
+        }
+    }
+
+    /// Collects speech.
+    pub fn speech() -> Self {
+        Collect {
+            speech: Some(CollectSpeech::default()),
+            ..Default::default()
+        }
+    }
+
+    /// Ends digit input early on any of `terminators`.
+    pub fn terminators(mut self, terminators: &str) -> Self {
+        self.digits.get_or_insert_with(Default::default).terminators = Some(terminators.to_string());
+        self
+    }
+}
+
+/// The outcome of `Call::prompt`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CollectResult {
+    Digits { digits: String, terminator: Option<String> },
+    Speech { text: String, confidence: f64 },
+    NoInput,
+    NoMatch,
+    Error,
+}
+
+impl CollectResult {
+    fn from_value(result: &Value) -> Self {
+        let params = &result["params"];
+        match result["type"].as_str().unwrap_or_default() {
+            "digit" => CollectResult::Digits {
+                digits: params["digits"].as_str().unwrap_or_default().to_string(),
+                terminator: params["terminator"].as_str().filter(|terminator| !terminator.is_empty()).map(str::to_string),
+            },
+            "speech" => CollectResult::Speech {
+                text: params["text"].as_str().unwrap_or_default().to_string(),
+                confidence: params["confidence"].as_f64().unwrap_or_default(),
+            },
+            "no_input" => CollectResult::NoInput,
+            "no_match" => CollectResult::NoMatch,
+            _ => CollectResult::Error,
+        }
+    }
+}
+
+/// The state of a Relay call.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CallState {
+    Created,         // The call was created
+    Ringing,         // The call is ringing
+    Answered,        // The call was answered
+    Ending,          // The call is hanging up
+    Ended,           // The call has ended
+    Unknown(String), // A state this version doesn't know, as received
+}
+
+impl From<&str> for CallState {
+    fn from(state: &str) -> Self {
+        match state.to_lowercase().as_str() {
+            "created" => CallState::Created,
+            "ringing" => CallState::Ringing,
+            "answered" => CallState::Answered,
+            "ending" => CallState::Ending,
+            "ended" => CallState::Ended,
+            _ => CallState::Unknown(state.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for CallState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallState::Created => write!(f, "created"),
+            CallState::Ringing => write!(f, "ringing"),
+            CallState::Answered => write!(f, "answered"),
+            CallState::Ending => write!(f, "ending"),
+            CallState::Ended => write!(f, "ended"),
+            CallState::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// A state change of a call (`calling.call.state`).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallStateEvent {
+    pub call_id: String,
+    #[serde(default)]
+    pub node_id: String,
+    pub call_state: String,
+    #[serde(default)]
+    pub end_reason: Option<String>,
+}
+
+impl CallStateEvent {
+    pub fn get_state(&self) -> CallState {
+        CallState::from(self.call_state.as_str())
+    }
+}
+
+/// The call object of `calling.call.receive`, `calling.call.dial` and `calling.call.connect` events.
+///
+/// `Calling::call` turns it into a `Call` to control.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallInfo {
+    pub call_id: String,
+    pub node_id: String,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub context: Option<String>,
+    #[serde(default)]
+    pub direction: String,
+    #[serde(default)]
+    pub device: Option<Device>,
+}
+
+/// The progress of an outbound call (`calling.call.dial`).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallDialEvent {
+    pub tag: String,
+    /// `dialing`, `answered` or `failed`.
+    pub dial_state: String,
+    /// The answered call.
+    #[serde(default)]
+    pub call: Option<CallInfo>,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// The progress of a `Call::play` (`calling.call.play`).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallPlayEvent {
+    pub call_id: String,
+    pub control_id: String,
+    /// `playing`, `paused`, `finished` or `error`.
+    pub state: String,
+}
+
+/// The outcome of a `Call::prompt` (`calling.call.collect`).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallCollectEvent {
+    pub call_id: String,
+    pub control_id: String,
+    #[serde(default)]
+    pub result: Value,
+}
+
+impl CallCollectEvent {
+    pub fn get_result(&self) -> CollectResult {
+        CollectResult::from_value(&self.result)
+    }
+}
+
+/// The progress of a `Call::connect` (`calling.call.connect`).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallConnectEvent {
+    pub call_id: String,
+    /// `connecting`, `connected`, `failed` or `disconnected`.
+    pub connect_state: String,
+    /// The connected call.
+    #[serde(default)]
+    pub peer: Option<CallInfo>,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// A live call, inbound or outbound, controlled over Relay.
+///
+/// Actions resolve once Relay reports their outcome, e.g. `play` when the media finished playing.
+#[derive(Debug, Clone)]
+pub struct Call {
+    relay: RelayClient,
+    pub call_id: String,
+    pub node_id: String,
+    pub tag: Option<String>,
+    /// The context an inbound call was received on.
+    pub context: Option<String>,
+    /// `inbound` or `outbound`.
+    pub direction: String,
+    pub device: Option<Device>,
+}
+
+impl Call {
+    fn new(relay: RelayClient, params: CallInfo) -> Self {
+        Call {
+            relay,
+            call_id: params.call_id,
+            node_id: params.node_id,
+            tag: params.tag,
+            context: params.context,
+            direction: params.direction,
+            device: params.device,
+        }
+    }
+
+    /// The calling number, for phone calls.
+    pub fn from_number(&self) -> Option<&str> {
+        self.device.as_ref().and_then(|device| device.params["from_number"].as_str())
+    }
+
+    /// The called number, for phone calls.
+    pub fn to_number(&self) -> Option<&str> {
+        self.device.as_ref().and_then(|device| device.params["to_number"].as_str())
+    }
+
+    fn params(&self, extra: Value) -> Value {
+        let mut params = json!({ "node_id": self.node_id, "call_id": self.call_id });
+        if let (Some(params), Value::Object(extra)) = (params.as_object_mut(), extra) {
+            params.extend(extra);
+        }
+        params
+    }
+
+    /// Answers an inbound call.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `calling.answer`.
+    pub async fn answer(&self) -> Result<(), SignalWireError> {
+        self.relay.execute("calling.answer", self.params(json!({}))).await.map(|_| ())
+    }
+
+    /// Hangs up.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `calling.end`.
+    pub async fn hangup(&self) -> Result<(), SignalWireError> {
+        self.relay.execute("calling.end", self.params(json!({ "reason": "hangup" }))).await.map(|_| ())
+    }
+
+    /// Plays `media` in order and waits until it finished.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RelayError::CallEnded` if the call ends first, or `RelayError::Request` if playback fails.
+    pub async fn play(&self, media: &[Media]) -> Result<(), SignalWireError> {
+        let params = json!({ "play": media });
+        let state = self
+            .control("calling.play", params, "calling.call.play", |params| {
+                let state = params["state"].as_str().unwrap_or_default();
+                matches!(state, "finished" | "error").then(|| state.to_string())
+            })
+            .await?;
+
+        match state.as_str() {
+            "error" => Err(RelayError::Request {
+                method: "calling.play".to_string(),
+                code: "error".to_string(),
+                message: "Playback failed".to_string(),
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Plays `media` while collecting digits or speech, and waits for the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RelayError::CallEnded` if the call ends first.
+    pub async fn prompt(&self, media: &[Media], collect: &Collect) -> Result<CollectResult, SignalWireError> {
+        let params = json!({ "play": media, "collect": collect });
+        self.control("calling.play_and_collect", params, "calling.call.collect", |params| params.get("result").map(CollectResult::from_value))
+            .await
+    }
+
+    /// Dials `devices` in parallel and bridges the first to answer into this call. Returns the connected peer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RelayError::DialFailed` if no device answers, or `RelayError::CallEnded` if this call ends first.
+    pub async fn connect(&self, devices: &[Device]) -> Result<Call, SignalWireError> {
+        let params = json!({ "devices": [devices] });
+        let outcome = self
+            .control_without_id("calling.connect", params, "calling.call.connect", |params| match params["connect_state"].as_str() {
+                Some("connected") => Some(Ok(serde_json::from_value::<CallInfo>(params["peer"].clone()).unwrap_or_default())),
+                Some("failed") => Some(Err(RelayError::DialFailed(params["reason"].as_str().unwrap_or("connect failed").to_string()))),
+                _ => None,
+            })
+            .await?;
+
+        Ok(Call::new(self.relay.clone(), outcome?))
+    }
+
+    /// The state changes of this call from now on.
+    pub fn states(&self) -> EventStream<CallStateEvent> {
+        let call_id = self.call_id.clone();
+        self.relay.listen(move |event| call_state(event).filter(|state| state.call_id == call_id))
+    }
+
+    /// Waits until the call has ended.
+    pub async fn wait_for_ended(&self) {
+        let mut states = self.states();
+        while let Some(state) = states.recv().await {
+            if state.get_state() == CallState::Ended {
+                return;
+            }
+        }
+    }
+
+    /// Runs an action identified by a fresh `control_id` and waits for the event `done` returns `Some` for.
+    async fn control<T: Send + 'static>(&self, method: &str, params: Value, event_type: &'static str, done: impl Fn(&Value) -> Option<T> + Send + 'static) -> Result<T, SignalWireError> {
+        let control_id = uuid::Uuid::new_v4().to_string();
+        let mut params = params;
+        params["control_id"] = json!(control_id);
+        self.run(method, params, event_type, move |params| (params["control_id"] == control_id.as_str()).then(|| done(params)).flatten())
+            .await
+    }
+
+    /// Runs an action without a `control_id`, matching its events by call.
+    async fn control_without_id<T: Send + 'static>(&self, method: &str, params: Value, event_type: &'static str, done: impl Fn(&Value) -> Option<T> + Send + 'static) -> Result<T, SignalWireError> {
+        let call_id = self.call_id.clone();
+        self.run(method, params, event_type, move |params| (params["call_id"] == call_id.as_str()).then(|| done(params)).flatten()).await
+    }
+
+    async fn run<T: Send + 'static>(&self, method: &str, params: Value, event_type: &'static str, done: impl Fn(&Value) -> Option<T> + Send + 'static) -> Result<T, SignalWireError> {
+        let call_id = self.call_id.clone();
+        // Listen first, the outcome may be pushed before the request's own response.
+        let mut outcomes = self.relay.listen(move |event| {
+            if event.event_type == event_type {
+                return done(&event.params).map(Some);
+            }
+            call_state(event).filter(|state| state.call_id == call_id && state.get_state() == CallState::Ended).map(|_| None)
+        });
+
+        self.relay.execute(method, self.params(params)).await?;
+        match outcomes.recv().await {
+            Some(Some(outcome)) => Ok(outcome),
+            Some(None) => Err(RelayError::CallEnded(self.call_id.clone()).into()),
+            None => Err(RelayError::Closed.into()),
+        }
+    }
+}
+
+fn call_state(event: &RawEvent) -> Option<CallStateEvent> {
+    if event.event_type != "calling.call.state" {
+        return None;
+    }
+    serde_json::from_value(event.params.clone()).ok()
+}
+
+/// The voice API of a Relay connection, see `RelayClient::calling`.
+#[derive(Debug, Clone)]
+pub struct Calling {
+    relay: RelayClient,
+}
+
+impl RelayClient {
+    pub fn calling(&self) -> Calling {
+        Calling { relay: self.clone() }
+    }
+}
+
+impl Calling {
+    /// A handle to control a call known from an event, e.g. `RelayEvent::CallReceived`.
+    pub fn call(&self, info: CallInfo) -> Call {
+        Call::new(self.relay.clone(), info)
+    }
+
+    /// Dials `devices` in parallel and waits for one of them to answer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RelayError::DialFailed` if nobody answers, or the error of `calling.dial`.
+    pub async fn dial(&self, devices: &[Device]) -> Result<Call, SignalWireError> {
+        let tag = uuid::Uuid::new_v4().to_string();
+        let expected = tag.clone();
+        let mut outcomes = self.relay.listen(move |event| {
+            if event.event_type != "calling.call.dial" || event.params["tag"] != expected.as_str() {
+                return None;
+            }
+            match event.params["dial_state"].as_str() {
+                Some("answered") => Some(Ok(serde_json::from_value::<CallInfo>(event.params["call"].clone()).unwrap_or_default())),
+                Some("failed") => Some(Err(RelayError::DialFailed(event.params["reason"].as_str().unwrap_or("no answer").to_string()))),
+                _ => None,
+            }
+        });
+
+        self.relay.execute("calling.dial", json!({ "tag": tag, "devices": [devices] })).await?;
+        match outcomes.recv().await {
+            Some(Ok(params)) => Ok(Call::new(self.relay.clone(), params)),
+            Some(Err(error)) => Err(error.into()),
+            None => Err(RelayError::Closed.into()),
+        }
+    }
+
+    /// Subscribes to `contexts` and streams the calls received on them. Calls must be answered with `Call::answer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `RelayClient::subscribe`.
+    pub async fn receive(&self, contexts: &[&str]) -> Result<EventStream<Call>, SignalWireError> {
+        let wanted: Vec<String> = contexts.iter().map(|context| context.to_string()).collect();
+        let relay = self.relay.downgrade();
+        let calls = self.relay.listen(move |event| {
+            if event.event_type != "calling.call.receive" {
+                return None;
+            }
+            let params = serde_json::from_value::<CallInfo>(event.params.clone()).ok()?;
+            if !params.context.as_ref().is_some_and(|context| wanted.contains(context)) {
+                return None;
+            }
+            Some(Call::new(relay.upgrade()?, params))
+        });
+
+        self.relay.subscribe(contexts).await?;
+        Ok(calls)
+    }
+}
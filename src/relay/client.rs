@@ -0,0 +1,807 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    time::{Duration, Instant},
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::{
+    net::TcpStream,
+    sync::{mpsc, oneshot},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use super::{
+    protocol::{code_to_string, connect_params, Frame, RawEvent},
+    stream::{event_channel, Delivery, EventBuffer, EventSender, EventStream, OverflowPolicy},
+};
+use crate::{
+    client::SignalWireClient,
+    endpoint,
+    errors::{RelayError, SignalWireError},
+};
+
+/// How long `RelayClient::execute` waits for a response by default.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Fetches a fresh JWT for a reconnection.
+type JwtRefresh = Arc<dyn Fn() -> Pin<Box<dyn Future<Output = Result<String, SignalWireError>> + Send>> + Send + Sync>;
+
+/// Where and how to connect to Relay.
+#[derive(Clone)]
+pub struct RelayConfig {
+    /// The WebSocket URL, e.g. `wss://example.signalwire.com`.
+    pub url: String,
+    pub project_id: String,
+    /// A token from `SignalWireClient::get_jwt`.
+    pub jwt_token: String,
+    /// Contexts to receive inbound events for from the start.
+    pub contexts: Vec<String>,
+    pub request_timeout: Duration,
+    pub reconnect: ReconnectPolicy,
+    pub keepalive: Keepalive,
+    pub event_buffer: EventBuffer,
+    jwt_refresh: Option<JwtRefresh>,
+}
+
+impl RelayConfig {
+    pub fn new(url: &str, project_id: &str, jwt_token: &str) -> Self {
+        RelayConfig {
+            url: url.to_string(),
+            project_id: project_id.to_string(),
+            jwt_token: jwt_token.to_string(),
+            contexts: Vec::new(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            reconnect: ReconnectPolicy::default(),
+            keepalive: Keepalive::default(),
+            event_buffer: EventBuffer::default(),
+            jwt_refresh: None,
+        }
+    }
+
+    /// Fetches a JWT with `client` and targets the Relay endpoint of its space. Reconnections fetch a fresh JWT the
+    /// same way.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `get_jwt`.
+    pub async fn from_client(client: &SignalWireClient) -> Result<Self, SignalWireError> {
+        let jwt = client.get_jwt().await?;
+        let client = client.clone();
+        let config = RelayConfig::new(&endpoint::relay_url(&client.base_url), &client.project_id, &jwt.jwt_token).jwt_refresh(move || {
+            let client = client.clone();
+            async move { Ok(client.get_jwt().await?.jwt_token) }
+        });
+
+        Ok(config)
+    }
+
+    /// Adds a context to receive inbound events for.
+    pub fn context(mut self, context: &str) -> Self {
+        self.contexts.push(context.to_string());
+        self
+    }
+
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    pub fn reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = policy;
+        self
+    }
+
+    pub fn keepalive(mut self, keepalive: Keepalive) -> Self {
+        self.keepalive = keepalive;
+        self
+    }
+
+    /// Sets how many unread events each event stream holds, and what happens when a consumer falls further behind.
+    pub fn event_buffer(mut self, buffer: EventBuffer) -> Self {
+        self.event_buffer = buffer;
+        self
+    }
+
+    /// Sets how to get a fresh JWT when reconnecting. Without one, reconnections reuse `jwt_token`, which fails once it
+    /// expired.
+    pub fn jwt_refresh<F, Fut>(mut self, refresh: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, SignalWireError>> + Send + 'static,
+    {
+        self.jwt_refresh = Some(Arc::new(move || Box::pin(refresh())));
+        self
+    }
+
+    async fn fresh_jwt(&self) -> Result<String, SignalWireError> {
+        match &self.jwt_refresh {
+            Some(refresh) => refresh().await,
+            None => Ok(self.jwt_token.clone()),
+        }
+    }
+}
+
+impl fmt::Debug for RelayConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelayConfig")
+            .field("url", &self.url)
+            .field("project_id", &self.project_id)
+            .field("contexts", &self.contexts)
+            .field("request_timeout", &self.request_timeout)
+            .field("reconnect", &self.reconnect)
+            .field("keepalive", &self.keepalive)
+            .field("event_buffer", &self.event_buffer)
+            .field("jwt_refresh", &self.jwt_refresh.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// How `RelayClient` reconnects after the connection drops, with exponential backoff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    /// Gives up after this many failed attempts in a row, `None` to retry forever.
+    pub max_attempts: Option<u32>,
+    /// The delay before the first attempt.
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    /// The factor the delay grows by after each failed attempt.
+    pub multiplier: f64,
+}
+
+impl Default for ReconnectPolicy {
+    /// Retries forever, waiting 500 ms at first and up to 30 s between attempts.
+    fn default() -> Self {
+        ReconnectPolicy {
+            max_attempts: None,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Never reconnects: the client closes when the connection drops.
+    pub fn disabled() -> Self {
+        ReconnectPolicy {
+            max_attempts: Some(0),
+            ..Default::default()
+        }
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    pub fn delays(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_delay = initial;
+        self.max_delay = max;
+        self
+    }
+
+    /// The delay before attempt `attempt`, counted from 1.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        Duration::try_from_secs_f64(self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent)).map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+/// How `RelayClient` makes sure its connection is alive.
+///
+/// A connection that receives nothing, not even the answers to pings, for `idle_timeout` is considered dead: it's
+/// dropped and reconnected following the `ReconnectPolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keepalive {
+    /// How often to send a WebSocket ping, `None` to never ping.
+    pub ping_interval: Option<Duration>,
+    /// `None` to never consider the connection dead.
+    pub idle_timeout: Option<Duration>,
+}
+
+impl Default for Keepalive {
+    /// Pings every 15 s and gives up on the connection after 45 s without a pong.
+    fn default() -> Self {
+        Keepalive::new(Duration::from_secs(15), Duration::from_secs(45))
+    }
+}
+
+impl Keepalive {
+    pub fn new(ping_interval: Duration, idle_timeout: Duration) -> Self {
+        Keepalive {
+            ping_interval: Some(ping_interval),
+            idle_timeout: Some(idle_timeout),
+        }
+    }
+
+    /// Never pings and never drops an idle connection.
+    pub fn disabled() -> Self {
+        Keepalive { ping_interval: None, idle_timeout: None }
+    }
+}
+
+/// A snapshot of the liveness of a `RelayClient` connection, see `RelayClient::health`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelayHealth {
+    pub state: ConnectionState,
+    /// How long ago anything was last received from Relay.
+    pub idle: Duration,
+    /// The round trip time of the last answered ping.
+    pub latency: Option<Duration>,
+    /// How many times the connection was re-established.
+    pub reconnects: u32,
+    idle_timeout: Option<Duration>,
+}
+
+impl RelayHealth {
+    /// Whether the connection is up and not idle for longer than the keepalive allows.
+    pub fn is_healthy(&self) -> bool {
+        self.state == ConnectionState::Connected && self.idle_timeout.is_none_or(|idle_timeout| self.idle < idle_timeout)
+    }
+}
+
+/// The state of a `RelayClient` connection, see `RelayClient::connection_states`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connected,                     // Connected and authenticated, again after a reconnection
+    Disconnected,                  // The connection dropped, reconnection attempts follow unless disabled
+    Reconnecting { attempt: u32 }, // Waiting for, then making, reconnection attempt `attempt`
+    Closed,                        // Closed for good, by `disconnect` or because reconnecting gave up
+}
+
+/// What Relay returned from the `signalwire.connect` handshake.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct RelaySession {
+    #[serde(default)]
+    pub identity: String,
+    #[serde(default)]
+    pub protocol: String,
+    #[serde(default)]
+    pub authorization: Value,
+}
+
+/// Relay's acknowledgment of `RelayClient::subscribe` or `unsubscribe`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SubscriptionAck {
+    pub code: String,
+    pub message: String,
+    /// All the contexts subscribed to once the change applied.
+    pub contexts: Vec<String>,
+}
+
+impl SubscriptionAck {
+    fn new(result: &Value, contexts: &[String]) -> Self {
+        SubscriptionAck {
+            code: result.get("code").map(code_to_string).unwrap_or_default(),
+            message: result.get("message").and_then(Value::as_str).unwrap_or_default().to_string(),
+            contexts: contexts.to_vec(),
+        }
+    }
+}
+
+/// Called with every event, reports whether its stream took it.
+pub(crate) type Subscriber = Box<dyn Fn(&RawEvent) -> Delivery + Send>;
+
+/// The state shared by the client handles and the connection task.
+struct Shared {
+    pending: Mutex<HashMap<String, oneshot::Sender<Frame>>>,
+    subscribers: Mutex<Vec<Subscriber>>,
+    state: Mutex<ConnectionState>,
+    state_listeners: Mutex<Vec<EventSender<ConnectionState>>>,
+    contexts: Mutex<Vec<String>>,
+    session: Mutex<RelaySession>,
+    liveness: Mutex<Liveness>,
+    request_timeout: Duration,
+    keepalive: Keepalive,
+    event_buffer: EventBuffer,
+}
+
+/// What `RelayClient::health` reports on, updated by the connection task.
+struct Liveness {
+    last_received: Instant,
+    ping_sent: Option<Instant>,
+    latency: Option<Duration>,
+    reconnects: u32,
+}
+
+impl Liveness {
+    fn received(&mut self, message: &Message) {
+        self.last_received = Instant::now();
+        if let (Message::Pong(_), Some(ping_sent)) = (message, self.ping_sent.take()) {
+            self.latency = Some(ping_sent.elapsed());
+        }
+    }
+}
+
+/// A connection to SignalWire Relay, the realtime WebSocket API.
+///
+/// Cloning is cheap, all clones share the connection. The connection is closed with `disconnect` or once every
+/// clone is dropped.
+///
+/// When the connection drops, the client reconnects following `RelayConfig::reconnect`, with a fresh JWT, and resumes
+/// the session with the contexts subscribed so far. Event streams carry on across reconnections, requests in flight
+/// fail with `RelayError::Closed` and requests made meanwhile are sent once reconnected.
+#[derive(Clone)]
+pub struct RelayClient {
+    shared: Arc<Shared>,
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl RelayClient {
+    /// Opens the WebSocket and performs the `signalwire.connect` handshake.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RelayError::WebSocket` if the socket can't be opened and `RelayError::Request` if the handshake is
+    /// rejected, e.g. because of an expired JWT.
+    pub async fn connect(config: RelayConfig) -> Result<Self, SignalWireError> {
+        let (socket, _) = connect_async(config.url.as_str()).await.map_err(|e| RelayError::WebSocket(e.to_string()))?;
+        let (commands, receiver) = mpsc::unbounded_channel();
+        let shared = Arc::new(Shared {
+            pending: Mutex::new(HashMap::new()),
+            subscribers: Mutex::new(Vec::new()),
+            state: Mutex::new(ConnectionState::Connected),
+            state_listeners: Mutex::new(Vec::new()),
+            contexts: Mutex::new(config.contexts.clone()),
+            session: Mutex::new(RelaySession::default()),
+            liveness: Mutex::new(Liveness {
+                last_received: Instant::now(),
+                ping_sent: None,
+                latency: None,
+                reconnects: 0,
+            }),
+            request_timeout: config.request_timeout,
+            keepalive: config.keepalive,
+            event_buffer: config.event_buffer,
+        });
+        let params = connect_params(&config.project_id, &config.jwt_token, &config.contexts, None);
+        tokio::spawn(run(socket, receiver, shared.clone(), config));
+
+        let client = RelayClient { shared, commands };
+        let result = client.execute("signalwire.connect", params).await?;
+        let session = serde_json::from_value(result).map_err(|e| RelayError::Protocol(e.to_string()))?;
+        *client.shared.session.lock().unwrap() = session;
+
+        Ok(client)
+    }
+
+    /// Sends a request and waits for its result.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RelayError::Request` if Relay answers with an error, `RelayError::Timeout` if it doesn't answer within
+    /// the configured request timeout and `RelayError::Closed` if the connection is gone.
+    pub async fn execute(&self, method: &str, params: Value) -> Result<Value, SignalWireError> {
+        let frame = Frame::request(method, params);
+        let id = frame.id.clone().unwrap_or_default();
+        let (sender, receiver) = oneshot::channel();
+        self.shared.pending.lock().unwrap().insert(id.clone(), sender);
+
+        let command = Command::Request {
+            id: id.clone(),
+            message: Message::text(frame.to_text()),
+        };
+        if self.commands.send(command).is_err() {
+            self.shared.pending.lock().unwrap().remove(&id);
+            return Err(RelayError::Closed.into());
+        }
+
+        match tokio::time::timeout(self.shared.request_timeout, receiver).await {
+            Ok(Ok(response)) => Ok(response.into_result(method)?),
+            Ok(Err(_)) => Err(RelayError::Closed.into()),
+            Err(_) => {
+                self.shared.pending.lock().unwrap().remove(&id);
+                Err(RelayError::Timeout(method.to_string()).into())
+            }
+        }
+    }
+
+    /// Starts receiving inbound events (calls, messages, tasks) for `contexts`, on the live session.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `signalwire.receive`, the subscriptions are unchanged then.
+    pub async fn subscribe(&self, contexts: &[&str]) -> Result<SubscriptionAck, SignalWireError> {
+        let result = self.execute("signalwire.receive", json!({ "contexts": contexts })).await?;
+
+        let mut subscribed = self.shared.contexts.lock().unwrap();
+        for context in contexts {
+            if !subscribed.iter().any(|subscribed| subscribed == context) {
+                subscribed.push(context.to_string());
+            }
+        }
+
+        Ok(SubscriptionAck::new(&result, &subscribed))
+    }
+
+    /// Stops receiving inbound events for `contexts`, e.g. when a tenant is offboarded.
+    ///
+    /// Streams filtering on these contexts, like `Messaging::receive`, stay open but receive nothing more.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `signalwire.unreceive`, the subscriptions are unchanged then.
+    pub async fn unsubscribe(&self, contexts: &[&str]) -> Result<SubscriptionAck, SignalWireError> {
+        let result = self.execute("signalwire.unreceive", json!({ "contexts": contexts })).await?;
+
+        let mut subscribed = self.shared.contexts.lock().unwrap();
+        subscribed.retain(|subscribed| !contexts.contains(&subscribed.as_str()));
+
+        Ok(SubscriptionAck::new(&result, &subscribed))
+    }
+
+    /// Every event received from now on, untyped.
+    ///
+    /// The stream ends when the connection closes.
+    pub fn events(&self) -> EventStream<RawEvent> {
+        self.listen(|event| Some(event.clone()))
+    }
+
+    /// The events `map` returns `Some` for, converted.
+    pub(crate) fn listen<T: Send + 'static>(&self, map: impl Fn(&RawEvent) -> Option<T> + Send + 'static) -> EventStream<T> {
+        let (sender, stream) = event_channel(self.shared.event_buffer);
+        // Once closed nothing will be dispatched anymore, dropping `sender` ends the stream right away.
+        self.add_subscriber(Box::new(move |event| match map(event) {
+            Some(item) => sender.push(item),
+            None if sender.is_closed() => Delivery::Closed,
+            None => Delivery::Delivered,
+        }));
+
+        stream
+    }
+
+    /// Registers `subscriber` unless the connection is closed.
+    pub(crate) fn add_subscriber(&self, subscriber: Subscriber) {
+        let mut subscribers = self.shared.subscribers.lock().unwrap();
+        if self.connection_state() != ConnectionState::Closed {
+            subscribers.push(subscriber);
+        }
+    }
+
+    /// The contexts inbound events are received for.
+    pub fn contexts(&self) -> Vec<String> {
+        self.shared.contexts.lock().unwrap().clone()
+    }
+
+    pub fn session(&self) -> RelaySession {
+        self.shared.session.lock().unwrap().clone()
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connection_state() == ConnectionState::Connected
+    }
+
+    pub fn connection_state(&self) -> ConnectionState {
+        *self.shared.state.lock().unwrap()
+    }
+
+    /// The liveness of the connection, for orchestration to restart sessions that went stale.
+    pub fn health(&self) -> RelayHealth {
+        let liveness = self.shared.liveness.lock().unwrap();
+        RelayHealth {
+            state: self.connection_state(),
+            idle: liveness.last_received.elapsed(),
+            latency: liveness.latency,
+            reconnects: liveness.reconnects,
+            idle_timeout: self.shared.keepalive.idle_timeout,
+        }
+    }
+
+    /// The connection state changes from now on, to observe outages.
+    ///
+    /// The stream ends after `ConnectionState::Closed`.
+    ///
+    /// Never blocks the connection: past the event buffer capacity, the oldest states are dropped.
+    pub fn connection_states(&self) -> EventStream<ConnectionState> {
+        let (sender, stream) = event_channel(EventBuffer::new(self.shared.event_buffer.capacity, OverflowPolicy::DropOldest));
+        let mut listeners = self.shared.state_listeners.lock().unwrap();
+        if self.connection_state() != ConnectionState::Closed {
+            listeners.push(sender);
+        }
+
+        stream
+    }
+
+    /// Closes the connection for good. Pending requests fail with `RelayError::Closed` and event streams end.
+    pub fn disconnect(&self) {
+        let _ = self.commands.send(Command::Close);
+    }
+
+    /// A handle that doesn't keep the connection open, for use inside event subscribers.
+    pub(crate) fn downgrade(&self) -> WeakRelayClient {
+        WeakRelayClient {
+            shared: Arc::downgrade(&self.shared),
+            commands: self.commands.downgrade(),
+        }
+    }
+}
+
+impl fmt::Debug for RelayClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelayClient").field("session", &self.session()).field("connected", &self.is_connected()).finish()
+    }
+}
+
+/// A `RelayClient` that doesn't keep the connection alive.
+///
+/// Subscribers are owned by the connection, so a subscriber holding a strong `RelayClient` would never let it close.
+#[derive(Clone)]
+pub(crate) struct WeakRelayClient {
+    shared: Weak<Shared>,
+    commands: mpsc::WeakUnboundedSender<Command>,
+}
+
+impl WeakRelayClient {
+    pub fn upgrade(&self) -> Option<RelayClient> {
+        Some(RelayClient {
+            shared: self.shared.upgrade()?,
+            commands: self.commands.upgrade()?,
+        })
+    }
+}
+
+impl Shared {
+    /// Handles a text frame from Relay, returning the reply to send back and the event to dispatch, if any.
+    fn handle(&self, text: &str) -> (Option<Message>, Option<RawEvent>) {
+        // A malformed frame can't be answered, and isn't worth dropping the connection over.
+        let Ok(frame) = Frame::parse(text) else {
+            return (None, None);
+        };
+        match (frame.method.as_deref(), frame.id.clone()) {
+            (Some(method), id) => {
+                let event = (method == "signalwire.event").then_some(frame.params).flatten().and_then(|params| serde_json::from_value::<RawEvent>(params).ok());
+                // Events, pings and anything else Relay sends are acknowledged with an empty result.
+                (id.map(|id| Message::text(Frame::result(&id, json!({})).to_text())), event)
+            }
+            (None, Some(id)) => {
+                if let Some(sender) = self.pending.lock().unwrap().remove(&id) {
+                    let _ = sender.send(frame);
+                }
+                (None, None)
+            }
+            (None, None) => (None, None),
+        }
+    }
+
+    /// Offers `event` to every subscriber, waiting for room in the streams that block when full.
+    async fn dispatch(&self, event: &RawEvent) {
+        let mut index = 0;
+        loop {
+            let full = {
+                let mut subscribers = self.subscribers.lock().unwrap();
+                let mut full = None;
+                while index < subscribers.len() {
+                    match subscribers[index](event) {
+                        Delivery::Delivered => index += 1,
+                        Delivery::Closed => drop(subscribers.remove(index)),
+                        Delivery::Full(space) => {
+                            full = Some(space);
+                            break;
+                        }
+                    }
+                }
+                full
+            };
+
+            match full {
+                // Offered again once read from, or dropped.
+                Some(space) => space.notified().await,
+                None => return,
+            }
+        }
+    }
+
+    fn set_state(&self, state: ConnectionState) {
+        let mut listeners = self.state_listeners.lock().unwrap();
+        *self.state.lock().unwrap() = state;
+        listeners.retain(|listener| !matches!(listener.push(state), Delivery::Closed));
+    }
+
+    /// The request `message` if its caller still waits for it. Those that timed out, or were failed with
+    /// `RelayError::Closed` when the connection dropped, are never sent: a retrying caller would send them twice.
+    fn still_pending(&self, id: &str, message: Message) -> Option<Message> {
+        self.pending.lock().unwrap().contains_key(id).then_some(message)
+    }
+
+    fn close(&self) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        self.set_state(ConnectionState::Closed);
+        self.state_listeners.lock().unwrap().clear();
+        subscribers.clear();
+        self.pending.lock().unwrap().clear();
+    }
+}
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// What the handles ask of the connection.
+enum Command {
+    /// A request of `execute`, with the ID its caller waits on.
+    Request {
+        id: String,
+        message: Message,
+    },
+    Close,
+}
+
+/// Why `serve` returned.
+enum Ended {
+    /// `disconnect` was called, or every handle dropped.
+    Closed,
+    Dropped,
+}
+
+/// Owns the connection: serves the socket and replaces it when it drops, until closed.
+async fn run(mut socket: Socket, mut commands: mpsc::UnboundedReceiver<Command>, shared: Arc<Shared>, config: RelayConfig) {
+    let mut queued = Vec::new();
+    while let Ended::Dropped = serve(socket, &mut commands, &mut queued, &shared).await {
+        // Their responses were lost with the connection.
+        shared.pending.lock().unwrap().clear();
+        shared.set_state(ConnectionState::Disconnected);
+
+        match reconnect(&mut commands, &mut queued, &shared, &config).await {
+            Some(resumed) => {
+                socket = resumed;
+                shared.liveness.lock().unwrap().reconnects += 1;
+                shared.set_state(ConnectionState::Connected);
+            }
+            None => break,
+        }
+    }
+
+    shared.close();
+}
+
+/// Writes what the handles send, answers Relay's requests, routes responses and events, and keeps the connection alive.
+async fn serve(socket: Socket, commands: &mut mpsc::UnboundedReceiver<Command>, queued: &mut Vec<(String, Message)>, shared: &Shared) -> Ended {
+    let (mut sink, mut stream) = socket.split();
+    for (id, message) in queued.drain(..) {
+        let Some(message) = shared.still_pending(&id, message) else {
+            continue;
+        };
+        if sink.send(message).await.is_err() {
+            return Ended::Dropped;
+        }
+    }
+
+    // Disabled timers get a period they'll never reach, their branches are turned off anyway.
+    const NEVER: Duration = Duration::from_secs(24 * 60 * 60);
+    let Keepalive { ping_interval, idle_timeout } = shared.keepalive;
+    let mut pings = tokio::time::interval_at(tokio::time::Instant::now() + ping_interval.unwrap_or(NEVER), ping_interval.unwrap_or(NEVER));
+    let idle = tokio::time::sleep(idle_timeout.unwrap_or(NEVER));
+    tokio::pin!(idle);
+    {
+        let mut liveness = shared.liveness.lock().unwrap();
+        liveness.last_received = Instant::now();
+        liveness.ping_sent = None;
+    }
+
+    loop {
+        tokio::select! {
+            _ = pings.tick(), if ping_interval.is_some() => {
+                shared.liveness.lock().unwrap().ping_sent.get_or_insert_with(Instant::now);
+                if sink.send(Message::Ping(Default::default())).await.is_err() {
+                    return Ended::Dropped;
+                }
+            }
+            // Not even a pong for that long: the connection silently went away.
+            _ = &mut idle, if idle_timeout.is_some() => return Ended::Dropped,
+            command = commands.recv() => match command {
+                Some(Command::Close) => {
+                    let _ = sink.send(Message::Close(None)).await;
+                    return Ended::Closed;
+                }
+                Some(Command::Request { id, message }) => {
+                    let Some(message) = shared.still_pending(&id, message) else {
+                        continue;
+                    };
+                    if sink.send(message).await.is_err() {
+                        return Ended::Dropped;
+                    }
+                }
+                None => {
+                    let _ = sink.send(Message::Close(None)).await;
+                    return Ended::Closed;
+                }
+            },
+            message = stream.next() => match message {
+                Some(Ok(message)) if !matches!(message, Message::Close(_)) => {
+                    shared.liveness.lock().unwrap().received(&message);
+                    idle.as_mut().reset(tokio::time::Instant::now() + idle_timeout.unwrap_or(NEVER));
+                    let Message::Text(text) = message else {
+                        // Pings are answered by tungstenite itself.
+                        continue;
+                    };
+                    let (reply, event) = shared.handle(&text);
+                    if let Some(reply) = reply {
+                        if sink.send(reply).await.is_err() {
+                            return Ended::Dropped;
+                        }
+                    }
+                    if let Some(event) = event {
+                        shared.dispatch(&event).await;
+                    }
+                }
+                Some(Ok(_) | Err(_)) | None => return Ended::Dropped,
+            }
+        }
+    }
+}
+
+/// Retries `resume` following the reconnect policy. Requests made meanwhile are queued, and sent once resumed if
+/// their caller still waits for them.
+///
+/// Returns `None` once the policy gives up, or if the client is closed meanwhile.
+async fn reconnect(commands: &mut mpsc::UnboundedReceiver<Command>, queued: &mut Vec<(String, Message)>, shared: &Shared, config: &RelayConfig) -> Option<Socket> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        if config.reconnect.max_attempts.is_some_and(|max_attempts| attempt > max_attempts) {
+            return None;
+        }
+        shared.set_state(ConnectionState::Reconnecting { attempt });
+
+        let delay = tokio::time::sleep(config.reconnect.delay(attempt));
+        tokio::pin!(delay);
+        loop {
+            tokio::select! {
+                _ = &mut delay => break,
+                command = commands.recv() => match command {
+                    Some(Command::Close) | None => return None,
+                    Some(Command::Request { id, message }) => queued.push((id, message)),
+                },
+            }
+        }
+
+        if let Ok(socket) = resume(shared, config).await {
+            return Some(socket);
+        }
+    }
+}
+
+/// Opens a new socket and repeats the handshake with a fresh JWT, resuming the session and its subscriptions.
+async fn resume(shared: &Shared, config: &RelayConfig) -> Result<Socket, SignalWireError> {
+    let jwt = config.fresh_jwt().await?;
+    let (mut socket, _) = connect_async(config.url.as_str()).await.map_err(|e| RelayError::WebSocket(e.to_string()))?;
+    let contexts = shared.contexts.lock().unwrap().clone();
+    let protocol = shared.session.lock().unwrap().protocol.clone();
+    let request = Frame::request(
+        "signalwire.connect",
+        connect_params(&config.project_id, &jwt, &contexts, Some(protocol.as_str()).filter(|protocol| !protocol.is_empty())),
+    );
+    socket.send(Message::text(request.to_text())).await.map_err(|e| RelayError::WebSocket(e.to_string()))?;
+
+    let response = tokio::time::timeout(shared.request_timeout, async {
+        while let Some(Ok(message)) = socket.next().await {
+            let Message::Text(text) = message else {
+                continue;
+            };
+            match Frame::parse(&text) {
+                Ok(frame) if frame.method.is_none() && frame.id == request.id => return Ok(frame),
+                _ => {
+                    let (reply, event) = shared.handle(&text);
+                    if let Some(reply) = reply {
+                        socket.send(reply).await.map_err(|e| RelayError::WebSocket(e.to_string()))?;
+                    }
+                    if let Some(event) = event {
+                        shared.dispatch(&event).await;
+                    }
+                }
+            }
+        }
+        Err(RelayError::Closed)
+    })
+    .await
+    .map_err(|_| RelayError::Timeout("signalwire.connect".to_string()))??;
+
+    let result = response.into_result("signalwire.connect")?;
+    *shared.session.lock().unwrap() = serde_json::from_value(result).map_err(|e| RelayError::Protocol(e.to_string()))?;
+
+    Ok(socket)
+}
@@ -0,0 +1,112 @@
+use serde::{de::DeserializeOwned, Deserialize};
+
+use super::{stream::Delivery, CallCollectEvent, CallConnectEvent, CallDialEvent, CallInfo, CallPlayEvent, CallStateEvent, EventStream, InboundMessage, MessageStateEvent, RawEvent, RelayClient, RelayTask};
+
+/// A Relay event, typed after its `event_type`.
+///
+/// Deserializes from the `params` of a `signalwire.event` frame. Events of other types, or whose params don't match,
+/// are kept as `Unknown`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "RawEvent")]
+pub enum RelayEvent {
+    MessageReceived(InboundMessage), // messaging.receive
+    MessageState(MessageStateEvent), // messaging.state
+    CallReceived(CallInfo),          // calling.call.receive
+    CallState(CallStateEvent),       // calling.call.state
+    CallDial(CallDialEvent),         // calling.call.dial
+    CallPlay(CallPlayEvent),         // calling.call.play
+    CallCollect(CallCollectEvent),   // calling.call.collect
+    CallConnect(CallConnectEvent),   // calling.call.connect
+    Task(RelayTask),                 // queuing.relay.tasks
+    Unknown(RawEvent),
+}
+
+impl From<RawEvent> for RelayEvent {
+    fn from(event: RawEvent) -> Self {
+        fn params<T: DeserializeOwned>(event: &RawEvent) -> Option<T> {
+            serde_json::from_value(event.params.clone()).ok()
+        }
+
+        let typed = match event.event_type.as_str() {
+            "messaging.receive" => params(&event).map(RelayEvent::MessageReceived),
+            "messaging.state" => params(&event).map(RelayEvent::MessageState),
+            "calling.call.receive" => params(&event).map(RelayEvent::CallReceived),
+            "calling.call.state" => params(&event).map(RelayEvent::CallState),
+            "calling.call.dial" => params(&event).map(RelayEvent::CallDial),
+            "calling.call.play" => params(&event).map(RelayEvent::CallPlay),
+            "calling.call.collect" => params(&event).map(RelayEvent::CallCollect),
+            "calling.call.connect" => params(&event).map(RelayEvent::CallConnect),
+            "queuing.relay.tasks" => params(&event).map(RelayEvent::Task),
+            _ => None,
+        };
+
+        typed.unwrap_or(RelayEvent::Unknown(event))
+    }
+}
+
+/// The events of a connection split by kind, see `RelayClient::split_events`.
+///
+/// Every stream buffers until read, drop the ones you don't need.
+pub struct EventStreams {
+    pub messages: EventStream<InboundMessage>,
+    pub message_states: EventStream<MessageStateEvent>,
+    /// Inbound calls, `Calling::call` turns them into a `Call` to answer.
+    pub calls: EventStream<CallInfo>,
+    pub call_states: EventStream<CallStateEvent>,
+    pub tasks: EventStream<RelayTask>,
+    /// Everything else: the progress of call actions and unknown events.
+    pub other: EventStream<RelayEvent>,
+}
+
+impl RelayClient {
+    /// Every event received from now on, typed.
+    ///
+    /// The stream ends when the connection closes.
+    pub fn typed_events(&self) -> EventStream<RelayEvent> {
+        self.listen(|event| Some(RelayEvent::from(event.clone())))
+    }
+
+    /// Calls `handler` with every event received from now on, until the connection closes.
+    ///
+    /// The handler runs on the connection task: keep it short and spawn anything that waits.
+    pub fn on_event(&self, handler: impl Fn(RelayEvent) + Send + 'static) {
+        self.add_subscriber(Box::new(move |event| {
+            handler(RelayEvent::from(event.clone()));
+            Delivery::Delivered
+        }));
+    }
+
+    /// The events received from now on, in one stream per kind.
+    pub fn split_events(&self) -> EventStreams {
+        EventStreams {
+            messages: self.select(|event| match event {
+                RelayEvent::MessageReceived(message) => Some(message),
+                _ => None,
+            }),
+            message_states: self.select(|event| match event {
+                RelayEvent::MessageState(state) => Some(state),
+                _ => None,
+            }),
+            calls: self.select(|event| match event {
+                RelayEvent::CallReceived(call) => Some(call),
+                _ => None,
+            }),
+            call_states: self.select(|event| match event {
+                RelayEvent::CallState(state) => Some(state),
+                _ => None,
+            }),
+            tasks: self.select(|event| match event {
+                RelayEvent::Task(task) => Some(task),
+                _ => None,
+            }),
+            other: self.select(|event| match event {
+                RelayEvent::MessageReceived(_) | RelayEvent::MessageState(_) | RelayEvent::CallReceived(_) | RelayEvent::CallState(_) | RelayEvent::Task(_) => None,
+                other => Some(other),
+            }),
+        }
+    }
+
+    fn select<T: Send + 'static>(&self, pick: fn(RelayEvent) -> Option<T>) -> EventStream<T> {
+        self.listen(move |event| pick(RelayEvent::from(event.clone())))
+    }
+}
@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{EventStream, RelayClient};
+use crate::{errors::SignalWireError, types::MessageStatus};
+
+/// An inbound SMS or MMS received over Relay (`messaging.receive`).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InboundMessage {
+    pub message_id: String,
+    pub context: String,
+    #[serde(default)]
+    pub direction: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub from_number: String,
+    pub to_number: String,
+    #[serde(default)]
+    pub body: String,
+    /// The URLs of the attached media, in order.
+    #[serde(default)]
+    pub media: Vec<String>,
+    #[serde(default)]
+    pub segments: u32,
+    #[serde(default)]
+    pub message_state: String,
+}
+
+impl InboundMessage {
+    pub fn get_status(&self) -> MessageStatus {
+        MessageStatus::from(self.message_state.as_str())
+    }
+}
+
+/// A message to send over Relay.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboundMessage {
+    /// The context the message's state events are delivered on.
+    pub context: String,
+    pub from_number: String,
+    pub to_number: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Media URLs to attach, making this an MMS.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub media: Vec<String>,
+    /// Labels echoed back in the state events.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+impl OutboundMessage {
+    pub fn new(context: &str, from_number: &str, to_number: &str, body: &str) -> Self {
+        OutboundMessage {
+            context: context.to_string(),
+            from_number: from_number.to_string(),
+            to_number: to_number.to_string(),
+            body: Some(body.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn media(mut self, url: &str) -> Self {
+        self.media.push(url.to_string());
+        self
+    }
+
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+}
+
+/// A state change of an outbound message (`messaging.state`).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageStateEvent {
+    pub message_id: String,
+    #[serde(default)]
+    pub context: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub from_number: String,
+    #[serde(default)]
+    pub to_number: String,
+    #[serde(default)]
+    pub segments: u32,
+    pub message_state: String,
+    /// Why the message failed or wasn't delivered.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+impl MessageStateEvent {
+    pub fn get_status(&self) -> MessageStatus {
+        MessageStatus::from(self.message_state.as_str())
+    }
+
+    /// Whether no further state changes will follow.
+    pub fn is_final(&self) -> bool {
+        self.get_status().is_final()
+    }
+}
+
+/// A message accepted by Relay, see `Messaging::send`.
+pub struct SentMessage {
+    pub message_id: String,
+    states: EventStream<MessageStateEvent>,
+    done: bool,
+}
+
+impl SentMessage {
+    /// The next state change of this message, or `None` after its final state (or if the connection closes).
+    pub async fn recv(&mut self) -> Option<MessageStateEvent> {
+        if self.done {
+            return None;
+        }
+
+        while let Some(state) = self.states.recv().await {
+            if state.message_id == self.message_id {
+                self.done = state.is_final();
+                return Some(state);
+            }
+        }
+
+        None
+    }
+
+    /// Waits for the final state: delivered, undelivered or failed.
+    pub async fn wait(mut self) -> Option<MessageStateEvent> {
+        let mut last = None;
+        while let Some(state) = self.recv().await {
+            last = Some(state);
+        }
+
+        last.filter(MessageStateEvent::is_final)
+    }
+}
+
+/// The messaging API of a Relay connection, see `RelayClient::messaging`.
+#[derive(Debug, Clone)]
+pub struct Messaging {
+    relay: RelayClient,
+}
+
+impl RelayClient {
+    pub fn messaging(&self) -> Messaging {
+        Messaging { relay: self.clone() }
+    }
+}
+
+impl Messaging {
+    /// Subscribes to `contexts` and streams the messages received on them.
+    ///
+    /// Point the phone numbers' message handler at a Relay context to receive their messages here, without a public
+    /// webhook endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `RelayClient::subscribe`.
+    pub async fn receive(&self, contexts: &[&str]) -> Result<EventStream<InboundMessage>, SignalWireError> {
+        let wanted: Vec<String> = contexts.iter().map(|context| context.to_string()).collect();
+        // Listen first, so nothing received right after the subscription is missed.
+        let messages = self.relay.listen(move |event| {
+            if event.event_type != "messaging.receive" {
+                return None;
+            }
+            serde_json::from_value::<InboundMessage>(event.params.clone()).ok().filter(|message| wanted.contains(&message.context))
+        });
+
+        self.relay.subscribe(contexts).await?;
+        Ok(messages)
+    }
+
+    /// Sends a message over the Relay connection.
+    ///
+    /// Relay pushes its state changes (`initiated`, `sent`, `delivered`, ...) back on the socket, read them with
+    /// `SentMessage::recv` or `SentMessage::wait`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RelayError::Request` if Relay rejects the message, e.g. because of an invalid number.
+    pub async fn send(&self, message: &OutboundMessage) -> Result<SentMessage, SignalWireError> {
+        // The message ID is only known once Relay answers, but state events may already arrive before that.
+        let states = self.relay.listen(|event| {
+            if event.event_type != "messaging.state" {
+                return None;
+            }
+            serde_json::from_value::<MessageStateEvent>(event.params.clone()).ok()
+        });
+
+        let params = serde_json::to_value(message).map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+        let result = self.relay.execute("messaging.send", params).await?;
+        let message_id = result.get("message_id").and_then(Value::as_str).unwrap_or_default().to_string();
+
+        Ok(SentMessage { message_id, states, done: false })
+    }
+}
@@ -0,0 +1,42 @@
+//! Relay, SignalWire's realtime WebSocket API.
+//!
+//! `RelayClient` authenticates with a JWT (see `SignalWireClient::get_jwt`), performs the `signalwire.connect`
+//! handshake and keeps the socket running in a background task. Subscribe to contexts to receive inbound events.
+//!
+//! ```rust,no_run
+//! use signalwire::{
+//!     client::SignalWireClient,
+//!     relay::{RelayClient, RelayConfig},
+//! };
+//!
+//! # async fn example() -> Result<(), signalwire::errors::SignalWireError> {
+//! let client = SignalWireClient::new("example", "project-id", "api-key");
+//! let relay =
+//!     RelayClient::connect(RelayConfig::from_client(&client).await?.context("office")).await?;
+//! relay.subscribe(&["support"]).await?;
+//!
+//! let mut events = relay.events();
+//! while let Some(event) = events.recv().await {
+//!     println!("{}: {}", event.event_type, event.params);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod calling;
+mod client;
+mod event;
+mod messaging;
+pub mod protocol;
+mod stream;
+mod tasking;
+mod video;
+
+pub use calling::*;
+pub use client::*;
+pub use event::*;
+pub use messaging::*;
+pub use protocol::RawEvent;
+pub use stream::{EventBuffer, EventStream, OverflowPolicy};
+pub use tasking::*;
+pub use video::*;
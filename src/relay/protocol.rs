@@ -0,0 +1,133 @@
+//! The Relay wire protocol: JSON-RPC 2.0 frames over a WebSocket.
+//!
+//! Everything here is sans-IO, the connection task in `client` only moves text frames around.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::errors::RelayError;
+
+/// The protocol version announced in `signalwire.connect`.
+pub const RELAY_VERSION: (u32, u32, u32) = (3, 0, 0);
+
+/// The `agent` announced in `signalwire.connect`.
+pub const RELAY_AGENT: &str = concat!("signalwire-rust/", env!("CARGO_PKG_VERSION"));
+
+/// A JSON-RPC 2.0 frame, in either direction.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Frame {
+    pub jsonrpc: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+/// The `error` member of a failed JSON-RPC response.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: Value,
+    #[serde(default)]
+    pub message: String,
+}
+
+/// An event pushed by Relay through `signalwire.event`, before any typing.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RawEvent {
+    pub event_type: String,
+    #[serde(default)]
+    pub event_channel: Option<String>,
+    #[serde(default)]
+    pub timestamp: Option<f64>,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    #[serde(default)]
+    pub space_id: Option<String>,
+    #[serde(default)]
+    pub params: Value,
+}
+
+impl Frame {
+    /// A request with a fresh random ID.
+    pub fn request(method: &str, params: Value) -> Self {
+        Frame {
+            jsonrpc: "2.0".to_string(),
+            id: Some(uuid::Uuid::new_v4().to_string()),
+            method: Some(method.to_string()),
+            params: Some(params),
+            ..Default::default()
+        }
+    }
+
+    /// A successful response to the request `id`.
+    pub fn result(id: &str, result: Value) -> Self {
+        Frame {
+            jsonrpc: "2.0".to_string(),
+            id: Some(id.to_string()),
+            result: Some(result),
+            ..Default::default()
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, RelayError> {
+        serde_json::from_str(text).map_err(|e| RelayError::Protocol(format!("{}. Frame was: {}", e, text)))
+    }
+
+    pub fn to_text(&self) -> String {
+        serde_json::to_string(self).expect("frames always serialize")
+    }
+
+    /// The outcome of a response frame to a `method` request.
+    ///
+    /// Relay reports some failures as a successful JSON-RPC response whose result carries a non-2xx `code`,
+    /// those are errors too.
+    pub fn into_result(self, method: &str) -> Result<Value, RelayError> {
+        if let Some(error) = self.error {
+            return Err(RelayError::Request {
+                method: method.to_string(),
+                code: code_to_string(&error.code),
+                message: error.message,
+            });
+        }
+
+        let result = self.result.unwrap_or(Value::Null);
+        match result.get("code").map(code_to_string) {
+            Some(code) if !code.starts_with('2') => Err(RelayError::Request {
+                method: method.to_string(),
+                code,
+                message: result.get("message").and_then(Value::as_str).unwrap_or_default().to_string(),
+            }),
+            _ => Ok(result),
+        }
+    }
+}
+
+/// The params of `signalwire.connect`. Passing the `protocol` of a previous session resumes it.
+pub fn connect_params(project_id: &str, jwt_token: &str, contexts: &[String], protocol: Option<&str>) -> Value {
+    let (major, minor, revision) = RELAY_VERSION;
+    let mut params = json!({
+        "version": { "major": major, "minor": minor, "revision": revision },
+        "authentication": { "project": project_id, "jwt_token": jwt_token },
+        "contexts": contexts,
+        "agent": RELAY_AGENT,
+    });
+    if let Some(protocol) = protocol {
+        params["protocol"] = json!(protocol);
+    }
+
+    params
+}
+
+/// A response `code`, which Relay sends as a string or a number.
+pub(crate) fn code_to_string(code: &Value) -> String {
+    match code {
+        Value::String(code) => code.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures_util::Stream;
+use tokio::sync::Notify;
+
+/// What a stream does with an event when it already holds `EventBuffer::capacity` unread ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    DropOldest, // Discards the oldest unread event, see `EventStream::dropped`
+    Block,      // Pauses the connection, responses and pings included, until the consumer catches up
+    Error,      // Ends the stream, see `EventStream::overflowed`
+}
+
+/// How many unread events each `EventStream` holds, and what happens beyond that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventBuffer {
+    pub capacity: usize,
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for EventBuffer {
+    /// Holds 1024 events, then drops the oldest.
+    fn default() -> Self {
+        EventBuffer::new(1024, OverflowPolicy::DropOldest)
+    }
+}
+
+impl EventBuffer {
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn new(capacity: usize, overflow: OverflowPolicy) -> Self {
+        assert!(capacity > 0, "an event buffer needs room for at least one event");
+        EventBuffer { capacity, overflow }
+    }
+}
+
+/// The outcome of offering an event to a subscriber.
+pub(crate) enum Delivery {
+    Delivered,
+    /// The stream is gone, the subscriber can be removed.
+    Closed,
+    /// The stream is full and blocks: offer the event again once this is notified.
+    Full(Arc<Notify>),
+}
+
+struct Queue<T> {
+    items: VecDeque<T>,
+    waker: Option<Waker>,
+    /// No more items will be pushed.
+    closed: bool,
+    receiver_dropped: bool,
+    overflowed: bool,
+    dropped: u64,
+}
+
+struct Shared<T> {
+    queue: Mutex<Queue<T>>,
+    /// Notified when an item is read, for blocked senders.
+    space: Arc<Notify>,
+    buffer: EventBuffer,
+}
+
+/// The connection side of an `EventStream`. Dropping it ends the stream once its events are read.
+pub(crate) struct EventSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// A stream of Relay events, ending when the connection closes.
+///
+/// Holds at most `EventBuffer::capacity` unread events, see `RelayConfig::event_buffer`. Implements
+/// `futures::Stream`; `recv` reads it without extra imports.
+pub struct EventStream<T> {
+    shared: Arc<Shared<T>>,
+}
+
+pub(crate) fn event_channel<T>(buffer: EventBuffer) -> (EventSender<T>, EventStream<T>) {
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(Queue {
+            items: VecDeque::new(),
+            waker: None,
+            closed: false,
+            receiver_dropped: false,
+            overflowed: false,
+            dropped: 0,
+        }),
+        space: Arc::new(Notify::new()),
+        buffer,
+    });
+
+    (EventSender { shared: shared.clone() }, EventStream { shared })
+}
+
+impl<T> EventSender<T> {
+    pub fn push(&self, item: T) -> Delivery {
+        let mut queue = self.shared.queue.lock().unwrap();
+        if queue.receiver_dropped || queue.closed {
+            return Delivery::Closed;
+        }
+
+        if queue.items.len() >= self.shared.buffer.capacity {
+            match self.shared.buffer.overflow {
+                OverflowPolicy::DropOldest => {
+                    queue.items.pop_front();
+                    queue.dropped += 1;
+                }
+                OverflowPolicy::Block => return Delivery::Full(self.shared.space.clone()),
+                OverflowPolicy::Error => {
+                    queue.overflowed = true;
+                    queue.closed = true;
+                    wake(queue);
+                    return Delivery::Closed;
+                }
+            }
+        }
+
+        queue.items.push_back(item);
+        wake(queue);
+        Delivery::Delivered
+    }
+
+    pub fn is_closed(&self) -> bool {
+        let queue = self.shared.queue.lock().unwrap();
+        queue.receiver_dropped || queue.closed
+    }
+}
+
+impl<T> Drop for EventSender<T> {
+    fn drop(&mut self) {
+        let mut queue = self.shared.queue.lock().unwrap();
+        queue.closed = true;
+        wake(queue);
+    }
+}
+
+fn wake<T>(mut queue: std::sync::MutexGuard<'_, Queue<T>>) {
+    let waker = queue.waker.take();
+    drop(queue);
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+impl<T> EventStream<T> {
+    /// The next event, or `None` once the connection is closed.
+    pub async fn recv(&mut self) -> Option<T> {
+        std::future::poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// How many events were discarded because this stream was full, with `OverflowPolicy::DropOldest`.
+    pub fn dropped(&self) -> u64 {
+        self.shared.queue.lock().unwrap().dropped
+    }
+
+    /// Whether the stream ended because it was full, with `OverflowPolicy::Error`, rather than because the
+    /// connection closed.
+    pub fn overflowed(&self) -> bool {
+        self.shared.queue.lock().unwrap().overflowed
+    }
+
+    fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut queue = self.shared.queue.lock().unwrap();
+        if let Some(item) = queue.items.pop_front() {
+            self.shared.space.notify_one();
+            return Poll::Ready(Some(item));
+        }
+        if queue.closed {
+            return Poll::Ready(None);
+        }
+
+        queue.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T> Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.poll_recv(cx)
+    }
+}
+
+impl<T> Drop for EventStream<T> {
+    fn drop(&mut self) {
+        self.shared.queue.lock().unwrap().receiver_dropped = true;
+        // A sender blocked on this stream would wait forever otherwise.
+        self.shared.space.notify_one();
+    }
+}
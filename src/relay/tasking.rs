@@ -0,0 +1,61 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{EventStream, RelayClient};
+use crate::errors::{RelayError, SignalWireError};
+
+/// A task received over Relay (`queuing.relay.tasks`), as sent with `SignalWireClient::send_task`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelayTask {
+    pub context: String,
+    /// The JSON payload, as sent.
+    #[serde(default)]
+    pub message: Value,
+}
+
+impl RelayTask {
+    /// Deserializes the payload into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RelayError::Protocol` if the payload doesn't match `T`.
+    pub fn payload<T: DeserializeOwned>(&self) -> Result<T, SignalWireError> {
+        serde_json::from_value(self.message.clone()).map_err(|e| RelayError::Protocol(format!("{}. Task was: {}", e, self.message)).into())
+    }
+}
+
+/// Receives tasks over a `RelayClient`, see `RelayClient::tasking`.
+///
+/// Tasks are sent over REST, with `SignalWireClient::send_task`, so that any service holding API credentials can
+/// dispatch work to the ones connected to Relay.
+#[derive(Debug, Clone)]
+pub struct Tasking {
+    relay: RelayClient,
+}
+
+impl RelayClient {
+    pub fn tasking(&self) -> Tasking {
+        Tasking { relay: self.clone() }
+    }
+}
+
+impl Tasking {
+    /// Subscribes to `contexts` and streams the tasks sent to them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `RelayClient::subscribe`.
+    pub async fn receive(&self, contexts: &[&str]) -> Result<EventStream<RelayTask>, SignalWireError> {
+        let wanted: Vec<String> = contexts.iter().map(|context| context.to_string()).collect();
+        // Listen first, so nothing received right after the subscription is missed.
+        let tasks = self.relay.listen(move |event| {
+            if event.event_type != "queuing.relay.tasks" {
+                return None;
+            }
+            serde_json::from_value::<RelayTask>(event.params.clone()).ok().filter(|task| wanted.contains(&task.context))
+        });
+
+        self.relay.subscribe(contexts).await?;
+        Ok(tasks)
+    }
+}
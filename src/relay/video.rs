@@ -0,0 +1,90 @@
+use serde_json::{json, Value};
+
+use super::RelayClient;
+use crate::errors::{RelayError, SignalWireError};
+
+/// Controls live video room sessions over Relay, see `RelayClient::video`.
+///
+/// Finished recordings are managed over REST, with `SignalWireClient::get_room_recording` and friends.
+#[derive(Debug, Clone)]
+pub struct Video {
+    relay: RelayClient,
+}
+
+impl RelayClient {
+    pub fn video(&self) -> Video {
+        Video { relay: self.clone() }
+    }
+}
+
+impl Video {
+    /// Starts recording a room session in progress, returning the ID of the recording.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `video.recording.start`, e.g. because the session ended.
+    pub async fn start_recording(&self, room_session_id: &str) -> Result<String, SignalWireError> {
+        let result = self.relay.execute("video.recording.start", json!({ "room_session_id": room_session_id })).await?;
+        match result.get("recording_id").and_then(Value::as_str) {
+            Some(recording_id) => Ok(recording_id.to_string()),
+            None => Err(RelayError::Protocol(format!("`video.recording.start` returned no recording ID: {}", result)).into()),
+        }
+    }
+
+    /// Stops a recording started with `start_recording`. It can be downloaded once its status is `completed`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `video.recording.stop`.
+    pub async fn stop_recording(&self, room_session_id: &str, recording_id: &str) -> Result<(), SignalWireError> {
+        self.relay
+            .execute("video.recording.stop", json!({ "room_session_id": room_session_id, "recording_id": recording_id }))
+            .await
+            .map(|_| ())
+    }
+
+    /// Mutes the microphone of a member, see `SignalWireClient::list_room_session_members` for their IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `video.member.audio_mute`, e.g. because the member left.
+    pub async fn mute_audio(&self, room_session_id: &str, member_id: &str) -> Result<(), SignalWireError> {
+        self.member_action("video.member.audio_mute", room_session_id, member_id).await
+    }
+
+    /// # Errors
+    ///
+    /// Returns the error of `video.member.audio_unmute`.
+    pub async fn unmute_audio(&self, room_session_id: &str, member_id: &str) -> Result<(), SignalWireError> {
+        self.member_action("video.member.audio_unmute", room_session_id, member_id).await
+    }
+
+    /// Turns off the camera of a member.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `video.member.video_mute`.
+    pub async fn mute_video(&self, room_session_id: &str, member_id: &str) -> Result<(), SignalWireError> {
+        self.member_action("video.member.video_mute", room_session_id, member_id).await
+    }
+
+    /// # Errors
+    ///
+    /// Returns the error of `video.member.video_unmute`.
+    pub async fn unmute_video(&self, room_session_id: &str, member_id: &str) -> Result<(), SignalWireError> {
+        self.member_action("video.member.video_unmute", room_session_id, member_id).await
+    }
+
+    /// Removes a member from the session. They can join again with a new token.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `video.member.remove`.
+    pub async fn remove_member(&self, room_session_id: &str, member_id: &str) -> Result<(), SignalWireError> {
+        self.member_action("video.member.remove", room_session_id, member_id).await
+    }
+
+    async fn member_action(&self, method: &str, room_session_id: &str, member_id: &str) -> Result<(), SignalWireError> {
+        self.relay.execute(method, json!({ "room_session_id": room_session_id, "member_id": member_id })).await.map(|_| ())
+    }
+}
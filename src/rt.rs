@@ -0,0 +1,44 @@
+//! What the async client needs from its runtime: Tokio with the `tokio` feature, any executor without it, and the
+//! JavaScript host on `wasm32`, where futures aren't `Send` and `std::time::Instant` isn't available.
+
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::{SystemTime, UNIX_EPOCH};
+
+/// The time since the Unix epoch.
+pub(crate) fn since_epoch() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+/// Waits for `duration`.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+    tokio::time::sleep(duration).await;
+
+    // A timer thread of its own, outside of any runtime
+    #[cfg(all(not(feature = "tokio"), not(target_arch = "wasm32")))]
+    futures_timer::Delay::new(duration).await;
+
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// A boxed stream, `Send` but on `wasm32` where the host's streams aren't.
+#[cfg(all(any(test, feature = "reqwest"), not(target_arch = "wasm32")))]
+pub(crate) type BoxStream<'a, T> = futures_util::stream::BoxStream<'a, T>;
+
+#[cfg(all(any(test, feature = "reqwest"), target_arch = "wasm32"))]
+pub(crate) type BoxStream<'a, T> = futures_util::stream::LocalBoxStream<'a, T>;
+
+/// The body of `response`, chunk by chunk as received.
+#[cfg(any(test, feature = "reqwest"))]
+pub(crate) fn body_stream(response: reqwest::Response) -> BoxStream<'static, reqwest::Result<bytes::Bytes>> {
+    Box::pin(response.bytes_stream())
+}
@@ -0,0 +1,236 @@
+//! A sans-IO description of every SignalWire operation.
+//!
+//! Each operation (e.g. `SendSms`) knows how to describe its HTTP request and how to turn the
+//! HTTP response back into a typed result, but never performs any I/O itself. `SignalWireClient`
+//! executes operations with reqwest; users with a bespoke HTTP stack (or a test harness) can do
+//! the same with anything that can send a request and hand back the status, headers and body.
+//!
+//! ```rust
+//! use signalwire::{
+//!     sansio::{Credentials, HttpResponse, Operation, SendSms},
+//!     types::SmsMessage,
+//! };
+//!
+//! let credentials = Credentials::new("example", "project", "key");
+//! let message = SmsMessage {
+//!     from: "+15551234567".to_string(),
+//!     to: "+15557654321".to_string(),
+//!     body: "Hi".to_string(),
+//!     ..Default::default()
+//! };
+//! let operation = SendSms { message: &message };
+//!
+//! let request = operation.request(&credentials).unwrap();
+//! assert_eq!(
+//!     request.url,
+//!     "https://example.signalwire.com/api/laml/2010-04-01/Accounts/project/Messages"
+//! );
+//!
+//! // ... execute `request` with your HTTP client of choice, then:
+//! let response = HttpResponse {
+//!     status: 401,
+//!     headers: vec![],
+//!     body: String::new(),
+//! };
+//! assert!(operation.parse(response).is_err());
+//! ```
+
+mod operations;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+pub use http::Method;
+pub use operations::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{endpoint, errors::SignalWireError, types::ResponseMeta};
+
+/// The credentials and base URL an operation needs to describe its request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Credentials {
+    pub project_id: String,
+    pub api_key: String,
+    pub base_url: String,
+}
+
+impl Credentials {
+    /// Creates credentials for the space `space_name`, using its default base URL.
+    pub fn new(space_name: &str, project_id: &str, api_key: &str) -> Self {
+        Credentials {
+            project_id: project_id.to_string(),
+            api_key: api_key.to_string(),
+            base_url: endpoint::space_base_url(space_name),
+        }
+    }
+
+    /// Overrides the base URL, e.g. to target a mock server.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// The value of the `Authorization` header for HTTP basic authentication.
+    pub fn authorization_header(&self) -> String {
+        format!("Basic {}", BASE64.encode(format!("{}:{}", self.project_id, self.api_key)))
+    }
+}
+
+/// A fully described HTTP request, ready to be executed by any HTTP client.
+///
+/// `headers` always include `Authorization`, and `Content-Type` whenever there is a body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    /// Starts a request with basic authentication and no body.
+    pub fn new(method: Method, url: String, credentials: &Credentials) -> Self {
+        HttpRequest {
+            method,
+            url,
+            headers: vec![("Authorization".to_string(), credentials.authorization_header())],
+            body: None,
+        }
+    }
+
+    /// Appends `query_params` to the URL.
+    pub fn with_query(mut self, query_params: &[(String, String)]) -> Result<Self, SignalWireError> {
+        if !query_params.is_empty() {
+            self.url = url::Url::parse_with_params(&self.url, query_params).map_err(|e| SignalWireError::Unexpected(e.to_string()))?.to_string();
+        }
+        Ok(self)
+    }
+
+    /// Adds a header.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets a JSON body and the matching `Content-Type`.
+    pub fn with_json<T: Serialize + ?Sized>(self, body: &T) -> Result<Self, SignalWireError> {
+        let body = serde_json::to_string(body).map_err(|e| SignalWireError::Unexpected(format!("Failed to serialize request: {}", e)))?;
+        Ok(self.with_body("application/json", body))
+    }
+
+    /// Sets a form-encoded body and the matching `Content-Type`.
+    pub fn with_form<T: Serialize + ?Sized>(self, form: &T) -> Result<Self, SignalWireError> {
+        let body = serde_urlencoded::to_string(form).map_err(|e| SignalWireError::Unexpected(format!("Failed to encode form: {}", e)))?;
+        Ok(self.with_body("application/x-www-form-urlencoded", body))
+    }
+
+    /// Sets a raw body with the given `Content-Type`.
+    pub fn with_body(mut self, content_type: &str, body: String) -> Self {
+        self.headers.push(("Content-Type".to_string(), content_type.to_string()));
+        self.body = Some(body);
+        self
+    }
+}
+
+/// The parts of an HTTP response operations need for parsing.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// The response metadata (status, request ID, rate limit counters).
+    pub fn meta(&self) -> ResponseMeta {
+        ResponseMeta::new(self.status, self.headers.clone())
+    }
+
+    fn is_error(&self) -> bool {
+        self.status >= 400
+    }
+}
+
+/// A single SignalWire API operation, described without performing any I/O.
+pub trait Operation {
+    /// The typed result of the operation.
+    type Output;
+
+    /// A short, stable name for the operation, used for tracing and recording.
+    const NAME: &'static str;
+
+    /// Describes the HTTP request for this operation.
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError>;
+
+    /// Turns the HTTP response into the operation's result.
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError>;
+
+    /// The synthesized result returned in dry-run mode, for billable operations.
+    ///
+    /// Returns `None` (the default) for operations that should always be executed.
+    fn dry_run(&self, _credentials: &Credentials) -> Option<Result<Self::Output, SignalWireError>> {
+        None
+    }
+}
+
+/// An operation with its request built and signed, ready to be sent.
+///
+/// A prepared request can be inspected (e.g. logged or checked in a test), adjusted, queued or sent through a
+/// pipeline of your own before its response is handed back to `parse`. `SignalWireClient::execute_prepared` sends
+/// it as it is, without describing it again.
+#[derive(Debug, Clone)]
+pub struct PreparedRequest<O> {
+    operation: O,
+    request: HttpRequest,
+}
+
+impl<O: Operation> PreparedRequest<O> {
+    /// Describes the request of `operation` with `credentials`.
+    pub fn new(operation: O, credentials: &Credentials) -> Result<Self, SignalWireError> {
+        let request = operation.request(credentials)?;
+        Ok(PreparedRequest { operation, request })
+    }
+
+    /// The name of the operation, e.g. `send_sms`.
+    pub fn name(&self) -> &'static str {
+        O::NAME
+    }
+
+    pub fn operation(&self) -> &O {
+        &self.operation
+    }
+
+    pub fn request(&self) -> &HttpRequest {
+        &self.request
+    }
+
+    /// The request, to change it before it's sent, e.g. to add a header for a proxy.
+    pub fn request_mut(&mut self) -> &mut HttpRequest {
+        &mut self.request
+    }
+
+    pub fn into_parts(self) -> (O, HttpRequest) {
+        (self.operation, self.request)
+    }
+
+    /// Turns the response to the request into the operation's result.
+    pub fn parse(&self, response: HttpResponse) -> Result<O::Output, SignalWireError> {
+        self.operation.parse(response)
+    }
+}
+
+/// Maps error statuses to `SignalWireError`.
+///
+/// `401` becomes `Unauthorized`, `404` becomes `NotFound(not_found)` when a message is given,
+/// and any other error status becomes `Unexpected` with the response body.
+pub fn check_status(response: &HttpResponse, not_found: Option<String>) -> Result<(), SignalWireError> {
+    match (response.status, not_found) {
+        (401, _) => Err(SignalWireError::Unauthorized),
+        (404, Some(message)) => Err(SignalWireError::NotFound(message)),
+        _ if response.is_error() => Err(SignalWireError::Unexpected(response.body.clone())),
+        _ => Ok(()),
+    }
+}
+
+/// Deserializes a JSON response body.
+pub fn parse_json<T: DeserializeOwned>(response: &HttpResponse) -> Result<T, SignalWireError> {
+    serde_json::from_str(&response.body).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse response: {}. Response was: {}", e, response.body)))
+}
@@ -0,0 +1,2106 @@
+use super::{check_status, parse_json, Credentials, HttpRequest, HttpResponse, Method, Operation};
+use crate::{
+    dry_run,
+    endpoint::Endpoint,
+    errors::SignalWireError,
+    pagination::Page,
+    swml::{Ai, Swml},
+    types::*,
+    validation,
+};
+
+// ---------- Authentication ----------
+
+/// Retrieves a JWT and refresh token for Relay authentication.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GetJwt;
+
+impl Operation for GetJwt {
+    type Output = JwtResponse;
+
+    const NAME: &'static str = "get_jwt";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::POST, Endpoint::Jwt.url(&credentials.base_url), credentials).with_body("application/json", String::new()))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+// ---------- Phone Numbers ----------
+
+/// Searches phone numbers available for purchase.
+#[derive(Debug, Clone, Copy)]
+pub struct GetPhoneNumbersAvailable<'a> {
+    pub iso_country: &'a str,
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for GetPhoneNumbersAvailable<'_> {
+    type Output = PhoneNumbersAvailableResponse;
+
+    const NAME: &'static str = "get_phone_numbers_available";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let url = Endpoint::AvailablePhoneNumbers {
+            account_sid: &credentials.project_id,
+            iso_country: self.iso_country,
+        }
+        .url(&credentials.base_url);
+        HttpRequest::new(Method::GET, url, credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Lists phone numbers owned by the project.
+#[derive(Debug, Clone, Copy)]
+pub struct GetPhoneNumbersOwned<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for GetPhoneNumbersOwned<'_> {
+    type Output = PhoneNumbersOwnedResponse;
+
+    const NAME: &'static str = "get_phone_numbers_owned";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::PhoneNumbers.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Buys a phone number. Billable, so it is short-circuited in dry-run mode.
+#[derive(Debug, Clone, Copy)]
+pub struct BuyPhoneNumber<'a> {
+    pub phone_number: &'a str,
+}
+
+impl Operation for BuyPhoneNumber<'_> {
+    type Output = BuyPhoneNumberResponse;
+
+    const NAME: &'static str = "buy_phone_number";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::POST, Endpoint::PhoneNumbers.url(&credentials.base_url), credentials).with_json(&BuyPhoneNumberRequest { number: self.phone_number.to_string() })
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+
+    fn dry_run(&self, _credentials: &Credentials) -> Option<Result<Self::Output, SignalWireError>> {
+        Some(
+            validation::validate_e164("number", self.phone_number)
+                .map(|_| dry_run::buy_phone_number_response(self.phone_number))
+                .map_err(SignalWireError::from),
+        )
+    }
+}
+
+/// Updates the configuration of an owned phone number.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdatePhoneNumber<'a> {
+    pub id: &'a str,
+    pub request: &'a UpdatePhoneNumberRequest,
+}
+
+impl Operation for UpdatePhoneNumber<'_> {
+    type Output = BuyPhoneNumberResponse;
+
+    const NAME: &'static str = "update_phone_number";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::PUT, Endpoint::PhoneNumber { id: self.id }.url(&credentials.base_url), credentials)
+            .with_header("Accept", "application/json")
+            .with_json(self.request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+// ---------- Messaging ----------
+
+/// Sends an SMS message. Billable, so it is short-circuited in dry-run mode.
+#[derive(Debug, Clone, Copy)]
+pub struct SendSms<'a> {
+    pub message: &'a SmsMessage,
+}
+
+impl Operation for SendSms<'_> {
+    type Output = SmsResponse;
+
+    const NAME: &'static str = "send_sms";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let sender = match &self.message.number_group_id {
+            Some(group) => ("MessagingServiceSid", group),
+            None => ("From", &self.message.from),
+        };
+        let form = [sender, ("To", &self.message.to), ("Body", &self.message.body)];
+        HttpRequest::new(Method::POST, Endpoint::Messages { account_sid: &credentials.project_id }.url(&credentials.base_url), credentials).with_form(&form)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+
+    fn dry_run(&self, credentials: &Credentials) -> Option<Result<Self::Output, SignalWireError>> {
+        Some(
+            validation::validate_sms_message(self.message)
+                .map(|_| dry_run::sms_response(self.message, &credentials.project_id))
+                .map_err(SignalWireError::from),
+        )
+    }
+}
+
+/// Gets a previously sent message by SID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMessageStatus<'a> {
+    pub message_sid: &'a str,
+}
+
+impl Operation for GetMessageStatus<'_> {
+    type Output = SmsResponse;
+
+    const NAME: &'static str = "get_message_status";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(
+            Method::GET,
+            Endpoint::Message {
+                account_sid: &credentials.project_id,
+                message_sid: self.message_sid,
+            }
+            .url(&credentials.base_url),
+            credentials,
+        ))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Message with SID {} not found", self.message_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Lists the messages of the project, newest first.
+#[derive(Debug, Clone, Copy)]
+pub struct ListMessages<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListMessages<'_> {
+    type Output = MessagesListResponse;
+
+    const NAME: &'static str = "list_messages";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::Messages { account_sid: &credentials.project_id }.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Lists the short codes of the project.
+#[derive(Debug, Clone, Copy)]
+pub struct ListShortCodes<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListShortCodes<'_> {
+    type Output = ShortCodesListResponse;
+
+    const NAME: &'static str = "list_short_codes";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::ShortCodes { account_sid: &credentials.project_id }.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a short code by SID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetShortCode<'a> {
+    pub short_code_sid: &'a str,
+}
+
+impl Operation for GetShortCode<'_> {
+    type Output = ShortCode;
+
+    const NAME: &'static str = "get_short_code";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ShortCode {
+            account_sid: &credentials.project_id,
+            short_code_sid: self.short_code_sid,
+        };
+        Ok(HttpRequest::new(Method::GET, endpoint.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Short code with SID {} not found", self.short_code_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Updates the name and inbound message URLs of a short code.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateShortCode<'a> {
+    pub short_code_sid: &'a str,
+    pub update: &'a ShortCodeUpdate,
+}
+
+impl Operation for UpdateShortCode<'_> {
+    type Output = ShortCode;
+
+    const NAME: &'static str = "update_short_code";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ShortCode {
+            account_sid: &credentials.project_id,
+            short_code_sid: self.short_code_sid,
+        };
+        HttpRequest::new(Method::POST, endpoint.url(&credentials.base_url), credentials).with_form(self.update)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Short code with SID {} not found", self.short_code_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Lists the calls of the project, newest first.
+#[derive(Debug, Clone, Copy)]
+pub struct ListCalls<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListCalls<'_> {
+    type Output = CallsListResponse;
+
+    const NAME: &'static str = "list_calls";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::Calls { account_sid: &credentials.project_id }.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Places a call, to a phone number or a SIP URI. Billable, so it is short-circuited in dry-run mode.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateCall<'a> {
+    pub call: &'a NewCall,
+}
+
+impl Operation for CreateCall<'_> {
+    type Output = CallResponse;
+
+    const NAME: &'static str = "create_call";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::POST, Endpoint::Calls { account_sid: &credentials.project_id }.url(&credentials.base_url), credentials).with_form(&self.call.form())
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+
+    fn dry_run(&self, credentials: &Credentials) -> Option<Result<Self::Output, SignalWireError>> {
+        Some(
+            validation::validate_call(self.call)
+                .map(|_| dry_run::call_response(self.call, &credentials.project_id))
+                .map_err(SignalWireError::from),
+        )
+    }
+}
+
+/// Lists the recordings of a conference.
+#[derive(Debug, Clone, Copy)]
+pub struct ListConferenceRecordings<'a> {
+    pub conference_sid: &'a str,
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListConferenceRecordings<'_> {
+    type Output = ConferenceRecordingsListResponse;
+
+    const NAME: &'static str = "list_conference_recordings";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ConferenceRecordings {
+            account_sid: &credentials.project_id,
+            conference_sid: self.conference_sid,
+        };
+        HttpRequest::new(Method::GET, endpoint.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Conference with SID {} not found", self.conference_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Starts recording a conference in progress.
+#[derive(Debug, Clone, Copy)]
+pub struct StartConferenceRecording<'a> {
+    pub conference_sid: &'a str,
+    /// Where the status changes of the recording are posted.
+    pub status_callback: Option<&'a str>,
+}
+
+impl Operation for StartConferenceRecording<'_> {
+    type Output = ConferenceRecording;
+
+    const NAME: &'static str = "start_conference_recording";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ConferenceRecordings {
+            account_sid: &credentials.project_id,
+            conference_sid: self.conference_sid,
+        };
+        let form: Vec<_> = self.status_callback.map(|url| ("RecordingStatusCallback", url)).into_iter().collect();
+        HttpRequest::new(Method::POST, endpoint.url(&credentials.base_url), credentials).with_form(&form)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Conference with SID {} not found", self.conference_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a recording of a conference by SID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetConferenceRecording<'a> {
+    pub conference_sid: &'a str,
+    pub recording_sid: &'a str,
+}
+
+impl Operation for GetConferenceRecording<'_> {
+    type Output = ConferenceRecording;
+
+    const NAME: &'static str = "get_conference_recording";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ConferenceRecording {
+            account_sid: &credentials.project_id,
+            conference_sid: self.conference_sid,
+            recording_sid: self.recording_sid,
+        };
+        Ok(HttpRequest::new(Method::GET, endpoint.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Conference recording with SID {} not found", self.recording_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Pauses, resumes (`in-progress`) or stops a recording of a conference in progress.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateConferenceRecording<'a> {
+    pub conference_sid: &'a str,
+    pub recording_sid: &'a str,
+    pub status: &'a str,
+}
+
+impl Operation for UpdateConferenceRecording<'_> {
+    type Output = ConferenceRecording;
+
+    const NAME: &'static str = "update_conference_recording";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ConferenceRecording {
+            account_sid: &credentials.project_id,
+            conference_sid: self.conference_sid,
+            recording_sid: self.recording_sid,
+        };
+        HttpRequest::new(Method::POST, endpoint.url(&credentials.base_url), credentials).with_form(&[("Status", self.status)])
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Conference recording with SID {} not found", self.recording_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Deletes a recording of a conference.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteConferenceRecording<'a> {
+    pub conference_sid: &'a str,
+    pub recording_sid: &'a str,
+}
+
+impl Operation for DeleteConferenceRecording<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "delete_conference_recording";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ConferenceRecording {
+            account_sid: &credentials.project_id,
+            conference_sid: self.conference_sid,
+            recording_sid: self.recording_sid,
+        };
+        Ok(HttpRequest::new(Method::DELETE, endpoint.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Conference recording with SID {} not found", self.recording_sid)))
+    }
+}
+
+/// Lists the participants of a conference.
+#[derive(Debug, Clone, Copy)]
+pub struct ListConferenceParticipants<'a> {
+    pub conference_sid: &'a str,
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListConferenceParticipants<'_> {
+    type Output = ConferenceParticipantsListResponse;
+
+    const NAME: &'static str = "list_conference_participants";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ConferenceParticipants {
+            account_sid: &credentials.project_id,
+            conference_sid: self.conference_sid,
+        };
+        HttpRequest::new(Method::GET, endpoint.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Conference with SID {} not found", self.conference_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a participant of a conference by the SID of its call.
+#[derive(Debug, Clone, Copy)]
+pub struct GetConferenceParticipant<'a> {
+    pub conference_sid: &'a str,
+    pub call_sid: &'a str,
+}
+
+impl Operation for GetConferenceParticipant<'_> {
+    type Output = ConferenceParticipant;
+
+    const NAME: &'static str = "get_conference_participant";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ConferenceParticipant {
+            account_sid: &credentials.project_id,
+            conference_sid: self.conference_sid,
+            call_sid: self.call_sid,
+        };
+        Ok(HttpRequest::new(Method::GET, endpoint.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Participant {} of conference {} not found", self.call_sid, self.conference_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Mutes, holds, or makes a participant of a conference coach or barge in.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateConferenceParticipant<'a> {
+    pub conference_sid: &'a str,
+    pub call_sid: &'a str,
+    pub update: &'a ParticipantUpdate,
+}
+
+impl Operation for UpdateConferenceParticipant<'_> {
+    type Output = ConferenceParticipant;
+
+    const NAME: &'static str = "update_conference_participant";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = Endpoint::ConferenceParticipant {
+            account_sid: &credentials.project_id,
+            conference_sid: self.conference_sid,
+            call_sid: self.call_sid,
+        };
+        HttpRequest::new(Method::POST, endpoint.url(&credentials.base_url), credentials).with_form(&self.update.form())
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Participant {} of conference {} not found", self.call_sid, self.conference_sid)))?;
+        parse_json(&response)
+    }
+}
+
+// ---------- Subprojects ----------
+
+/// Lists the project and its subprojects.
+#[derive(Debug, Clone, Copy)]
+pub struct ListSubprojects<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListSubprojects<'_> {
+    type Output = SubprojectsListResponse;
+
+    const NAME: &'static str = "list_subprojects";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::Accounts.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a subproject by SID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetSubproject<'a> {
+    pub subproject_sid: &'a str,
+}
+
+impl Operation for GetSubproject<'_> {
+    type Output = SubprojectResponse;
+
+    const NAME: &'static str = "get_subproject";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::Account { account_sid: self.subproject_sid }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Subproject with SID {} not found", self.subproject_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Creates a subproject.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateSubproject<'a> {
+    pub friendly_name: &'a str,
+}
+
+impl Operation for CreateSubproject<'_> {
+    type Output = SubprojectResponse;
+
+    const NAME: &'static str = "create_subproject";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::POST, Endpoint::Accounts.url(&credentials.base_url), credentials).with_form(&[("FriendlyName", self.friendly_name)])
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Updates a subproject's name and, optionally, its status (`active` or `suspended`).
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateSubproject<'a> {
+    pub subproject_sid: &'a str,
+    pub friendly_name: &'a str,
+    pub status: Option<&'a str>,
+}
+
+impl Operation for UpdateSubproject<'_> {
+    type Output = SubprojectResponse;
+
+    const NAME: &'static str = "update_subproject";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let mut form = vec![("FriendlyName", self.friendly_name)];
+        if let Some(status) = self.status {
+            form.push(("Status", status));
+        }
+
+        HttpRequest::new(Method::POST, Endpoint::Account { account_sid: self.subproject_sid }.url(&credentials.base_url), credentials).with_form(&form)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Subproject with SID {} not found", self.subproject_sid)))?;
+        parse_json(&response)
+    }
+}
+
+/// Deletes a subproject.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteSubproject<'a> {
+    pub subproject_sid: &'a str,
+}
+
+impl Operation for DeleteSubproject<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "delete_subproject";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::DELETE, Endpoint::Account { account_sid: self.subproject_sid }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Subproject with SID {} not found", self.subproject_sid)))
+    }
+}
+
+/// Lists phone numbers owned by a subproject.
+#[derive(Debug, Clone, Copy)]
+pub struct GetSubprojectPhoneNumbers<'a> {
+    pub subproject_sid: &'a str,
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for GetSubprojectPhoneNumbers<'_> {
+    type Output = SubprojectPhoneNumbersResponse;
+
+    const NAME: &'static str = "get_subproject_phone_numbers";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::IncomingPhoneNumbers { account_sid: self.subproject_sid }.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Subproject with SID {} not found", self.subproject_sid)))?;
+        parse_json(&response)
+    }
+}
+
+// ---------- Lookup & Validation ----------
+
+/// Looks up a phone number, optionally with carrier or caller name data (see `PhoneLookupParams`).
+#[derive(Debug, Clone, Copy)]
+pub struct LookupPhoneNumber<'a> {
+    pub phone_number: &'a str,
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for LookupPhoneNumber<'_> {
+    type Output = PhoneLookupResponse;
+
+    const NAME: &'static str = "lookup_phone_number";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::PhoneNumberLookup { phone_number: self.phone_number }.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+// ---------- SWML Scripts ----------
+
+/// Lists the SWML scripts hosted in the space.
+#[derive(Debug, Clone, Copy)]
+pub struct ListSwmlScripts<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListSwmlScripts<'_> {
+    type Output = SwmlScriptsListResponse;
+
+    const NAME: &'static str = "list_swml_scripts";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::SwmlScripts.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a hosted SWML script by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetSwmlScript<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetSwmlScript<'_> {
+    type Output = SwmlScriptResponse;
+
+    const NAME: &'static str = "get_swml_script";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::SwmlScript { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("SWML script with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Uploads a SWML document as a hosted script.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateSwmlScript<'a> {
+    pub name: &'a str,
+    pub swml: &'a Swml,
+}
+
+impl Operation for CreateSwmlScript<'_> {
+    type Output = SwmlScriptResponse;
+
+    const NAME: &'static str = "create_swml_script";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = SwmlScriptRequest {
+            name: Some(self.name.to_string()),
+            contents: Some(self.swml.to_json()?),
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::SwmlScripts.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Renames a hosted SWML script and/or replaces its document.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateSwmlScript<'a> {
+    pub id: &'a str,
+    pub name: Option<&'a str>,
+    pub swml: Option<&'a Swml>,
+}
+
+impl Operation for UpdateSwmlScript<'_> {
+    type Output = SwmlScriptResponse;
+
+    const NAME: &'static str = "update_swml_script";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = SwmlScriptRequest {
+            name: self.name.map(str::to_string),
+            contents: self.swml.map(Swml::to_json).transpose()?,
+        };
+
+        HttpRequest::new(Method::PUT, Endpoint::SwmlScript { id: self.id }.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("SWML script with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Deletes a hosted SWML script.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteSwmlScript<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for DeleteSwmlScript<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "delete_swml_script";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::DELETE, Endpoint::SwmlScript { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("SWML script with ID {} not found", self.id)))
+    }
+}
+
+// ---------- Relay Tasks ----------
+
+/// Delivers a task to the Relay clients receiving on a context.
+#[derive(Debug, Clone, Copy)]
+pub struct SendRelayTask<'a> {
+    pub context: &'a str,
+    pub message: &'a serde_json::Value,
+}
+
+impl Operation for SendRelayTask<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "send_task";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = RelayTaskRequest {
+            context: self.context.to_string(),
+            message: self.message.clone(),
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::RelayTasks.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)
+    }
+}
+
+// ---------- Video ----------
+
+/// Creates a token for a participant to join a video room.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateRoomToken<'a> {
+    pub room_name: &'a str,
+    pub user_name: &'a str,
+    pub permissions: &'a RoomPermissions,
+}
+
+impl Operation for CreateRoomToken<'_> {
+    type Output = RoomTokenResponse;
+
+    const NAME: &'static str = "create_room_token";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = RoomTokenRequest {
+            room_name: self.room_name.to_string(),
+            user_name: self.user_name.to_string(),
+            permissions: self.permissions.scopes(),
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::RoomTokens.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Lists room recordings, of every session or of one.
+#[derive(Debug, Clone, Copy)]
+pub struct ListRoomRecordings<'a> {
+    /// Only the recordings of this room session.
+    pub room_session_id: Option<&'a str>,
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListRoomRecordings<'_> {
+    type Output = RoomRecordingsListResponse;
+
+    const NAME: &'static str = "list_room_recordings";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let endpoint = match self.room_session_id {
+            Some(id) => Endpoint::RoomSessionRecordings { id },
+            None => Endpoint::RoomRecordings,
+        };
+
+        HttpRequest::new(Method::GET, endpoint.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, self.room_session_id.map(|id| format!("Room session with ID {} not found", id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a room recording by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetRoomRecording<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetRoomRecording<'_> {
+    type Output = RoomRecording;
+
+    const NAME: &'static str = "get_room_recording";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::RoomRecording { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Room recording with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Deletes a room recording.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteRoomRecording<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for DeleteRoomRecording<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "delete_room_recording";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::DELETE, Endpoint::RoomRecording { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Room recording with ID {} not found", self.id)))
+    }
+}
+
+/// Lists video room sessions.
+#[derive(Debug, Clone, Copy)]
+pub struct ListRoomSessions<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListRoomSessions<'_> {
+    type Output = RoomSessionsListResponse;
+
+    const NAME: &'static str = "list_room_sessions";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::RoomSessions.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a video room session by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetRoomSession<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetRoomSession<'_> {
+    type Output = RoomSession;
+
+    const NAME: &'static str = "get_room_session";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::RoomSession { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Room session with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Lists the members of a video room session.
+#[derive(Debug, Clone, Copy)]
+pub struct ListRoomSessionMembers<'a> {
+    pub room_session_id: &'a str,
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListRoomSessionMembers<'_> {
+    type Output = RoomMembersListResponse;
+
+    const NAME: &'static str = "list_room_session_members";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::RoomSessionMembers { id: self.room_session_id }.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Room session with ID {} not found", self.room_session_id)))?;
+        parse_json(&response)
+    }
+}
+
+// ---------- Chat ----------
+
+/// Creates a token for a member to use chat channels.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateChatToken<'a> {
+    pub ttl: u32,
+    pub channels: &'a std::collections::BTreeMap<String, ChatChannelPermissions>,
+    pub member_id: Option<&'a str>,
+    pub state: Option<&'a serde_json::Value>,
+}
+
+impl Operation for CreateChatToken<'_> {
+    type Output = ChatTokenResponse;
+
+    const NAME: &'static str = "create_chat_token";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = ChatTokenRequest {
+            ttl: self.ttl,
+            channels: self.channels.clone(),
+            member_id: self.member_id.map(str::to_string),
+            state: self.state.cloned(),
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::ChatTokens.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+// ---------- Fabric Subscribers ----------
+
+/// Lists the subscribers of the space.
+#[derive(Debug, Clone, Copy)]
+pub struct ListFabricSubscribers<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListFabricSubscribers<'_> {
+    type Output = FabricSubscribersListResponse;
+
+    const NAME: &'static str = "list_fabric_subscribers";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::FabricSubscribers.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a subscriber by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetFabricSubscriber<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetFabricSubscriber<'_> {
+    type Output = FabricSubscriberResponse;
+
+    const NAME: &'static str = "get_fabric_subscriber";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::FabricSubscriber { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Subscriber with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Creates a subscriber.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateFabricSubscriber<'a> {
+    pub request: &'a FabricSubscriberRequest,
+}
+
+impl Operation for CreateFabricSubscriber<'_> {
+    type Output = FabricSubscriberResponse;
+
+    const NAME: &'static str = "create_fabric_subscriber";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::POST, Endpoint::FabricSubscribers.url(&credentials.base_url), credentials).with_json(self.request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Updates the fields of a subscriber that are set in the request.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateFabricSubscriber<'a> {
+    pub id: &'a str,
+    pub request: &'a FabricSubscriberRequest,
+}
+
+impl Operation for UpdateFabricSubscriber<'_> {
+    type Output = FabricSubscriberResponse;
+
+    const NAME: &'static str = "update_fabric_subscriber";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::PUT, Endpoint::FabricSubscriber { id: self.id }.url(&credentials.base_url), credentials).with_json(self.request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Subscriber with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Deletes a subscriber.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteFabricSubscriber<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for DeleteFabricSubscriber<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "delete_fabric_subscriber";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::DELETE, Endpoint::FabricSubscriber { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Subscriber with ID {} not found", self.id)))
+    }
+}
+
+// ---------- Fabric Addresses ----------
+
+/// Lists the addresses of the space.
+#[derive(Debug, Clone, Copy)]
+pub struct ListFabricAddresses<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListFabricAddresses<'_> {
+    type Output = FabricAddressesListResponse;
+
+    const NAME: &'static str = "list_fabric_addresses";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::FabricAddresses.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets an address by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetFabricAddress<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetFabricAddress<'_> {
+    type Output = FabricAddress;
+
+    const NAME: &'static str = "get_fabric_address";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::FabricAddress { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Fabric address with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+// ---------- Fabric Resources ----------
+
+fn resource_collection(resource_type: &FabricResourceType) -> Result<&'static str, SignalWireError> {
+    resource_type
+        .collection()
+        .ok_or_else(|| SignalWireError::Unexpected(format!("Fabric resources of type {} can't be created or updated", resource_type)))
+}
+
+/// Lists the resources of the space, of every type.
+#[derive(Debug, Clone, Copy)]
+pub struct ListFabricResources<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListFabricResources<'_> {
+    type Output = FabricResourcesListResponse;
+
+    const NAME: &'static str = "list_fabric_resources";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::FabricResources.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a resource by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetFabricResource<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetFabricResource<'_> {
+    type Output = FabricResource;
+
+    const NAME: &'static str = "get_fabric_resource";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::FabricResource { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Fabric resource with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Creates a resource of the given type from its type-specific fields.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateFabricResource<'a> {
+    pub resource_type: &'a FabricResourceType,
+    pub fields: &'a serde_json::Value,
+}
+
+impl Operation for CreateFabricResource<'_> {
+    type Output = FabricResource;
+
+    const NAME: &'static str = "create_fabric_resource";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let collection = resource_collection(self.resource_type)?;
+        HttpRequest::new(Method::POST, Endpoint::FabricResourcesOfType { collection }.url(&credentials.base_url), credentials).with_json(self.fields)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Updates the type-specific fields of a resource.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateFabricResource<'a> {
+    pub id: &'a str,
+    pub resource_type: &'a FabricResourceType,
+    pub fields: &'a serde_json::Value,
+}
+
+impl Operation for UpdateFabricResource<'_> {
+    type Output = FabricResource;
+
+    const NAME: &'static str = "update_fabric_resource";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let collection = resource_collection(self.resource_type)?;
+        HttpRequest::new(Method::PUT, Endpoint::FabricResourceOfType { collection, id: self.id }.url(&credentials.base_url), credentials).with_json(self.fields)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Fabric resource with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Deletes a resource, of any type, along with its addresses.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteFabricResource<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for DeleteFabricResource<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "delete_fabric_resource";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::DELETE, Endpoint::FabricResource { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Fabric resource with ID {} not found", self.id)))
+    }
+}
+
+/// Lists the addresses of a resource.
+#[derive(Debug, Clone, Copy)]
+pub struct ListFabricResourceAddresses<'a> {
+    pub id: &'a str,
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListFabricResourceAddresses<'_> {
+    type Output = FabricAddressesListResponse;
+
+    const NAME: &'static str = "list_fabric_resource_addresses";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::FabricResourceAddresses { id: self.id }.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Fabric resource with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Routes the calls or messages of a phone number to a resource.
+#[derive(Debug, Clone, Copy)]
+pub struct AssignFabricResourcePhoneNumber<'a> {
+    pub id: &'a str,
+    pub phone_number_id: &'a str,
+    pub handler: FabricHandler,
+}
+
+impl Operation for AssignFabricResourcePhoneNumber<'_> {
+    type Output = FabricAddress;
+
+    const NAME: &'static str = "assign_fabric_resource_phone_number";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = FabricPhoneRouteRequest {
+            phone_number_id: self.phone_number_id.to_string(),
+            handler: self.handler.to_string(),
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::FabricResourcePhoneRoutes { id: self.id }.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Fabric resource with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+// ---------- Fabric Tokens ----------
+
+/// Creates a token for a subscriber to register a client with.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateFabricSubscriberToken<'a> {
+    pub reference: &'a str,
+    /// Unix timestamp.
+    pub expire_at: i64,
+}
+
+impl Operation for CreateFabricSubscriberToken<'_> {
+    type Output = FabricSubscriberTokenResponse;
+
+    const NAME: &'static str = "create_fabric_subscriber_token";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = FabricSubscriberTokenRequest {
+            reference: self.reference.to_string(),
+            expire_at: self.expire_at,
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::FabricSubscriberTokens.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Subscriber {} not found", self.reference)))?;
+        parse_json(&response)
+    }
+}
+
+/// Creates a token for a guest, scoped to a set of addresses.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateFabricGuestToken<'a> {
+    pub allowed_addresses: &'a [&'a str],
+    /// Unix timestamp.
+    pub expire_at: i64,
+}
+
+impl Operation for CreateFabricGuestToken<'_> {
+    type Output = FabricGuestTokenResponse;
+
+    const NAME: &'static str = "create_fabric_guest_token";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = FabricGuestTokenRequest {
+            allowed_addresses: self.allowed_addresses.iter().map(|address| address.to_string()).collect(),
+            expire_at: self.expire_at,
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::FabricGuestTokens.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+// ---------- AI Agents ----------
+
+/// Lists the hosted AI agents of the space.
+#[derive(Debug, Clone, Copy)]
+pub struct ListAiAgents<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListAiAgents<'_> {
+    type Output = AiAgentsListResponse;
+
+    const NAME: &'static str = "list_ai_agents";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::AiAgents.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a hosted AI agent by resource ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetAiAgent<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetAiAgent<'_> {
+    type Output = AiAgentResponse;
+
+    const NAME: &'static str = "get_ai_agent";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::AiAgent { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("AI agent with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Creates a hosted AI agent.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateAiAgent<'a> {
+    pub name: &'a str,
+    pub agent: &'a Ai,
+}
+
+impl Operation for CreateAiAgent<'_> {
+    type Output = AiAgentResponse;
+
+    const NAME: &'static str = "create_ai_agent";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = AiAgentRequest {
+            name: Some(self.name.to_string()),
+            config: Some(self.agent.clone()),
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::AiAgents.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Renames a hosted AI agent and/or replaces its configuration.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateAiAgent<'a> {
+    pub id: &'a str,
+    pub name: Option<&'a str>,
+    pub agent: Option<&'a Ai>,
+}
+
+impl Operation for UpdateAiAgent<'_> {
+    type Output = AiAgentResponse;
+
+    const NAME: &'static str = "update_ai_agent";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = AiAgentRequest {
+            name: self.name.map(str::to_string),
+            config: self.agent.cloned(),
+        };
+
+        HttpRequest::new(Method::PUT, Endpoint::AiAgent { id: self.id }.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("AI agent with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Deletes a hosted AI agent.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteAiAgent<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for DeleteAiAgent<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "delete_ai_agent";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::DELETE, Endpoint::AiAgent { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("AI agent with ID {} not found", self.id)))
+    }
+}
+
+// ---------- Datasphere ----------
+
+/// Lists the documents of the Datasphere.
+#[derive(Debug, Clone, Copy)]
+pub struct ListDatasphereDocuments<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListDatasphereDocuments<'_> {
+    type Output = DatasphereDocumentsListResponse;
+
+    const NAME: &'static str = "list_datasphere_documents";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::DatasphereDocuments.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a Datasphere document by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDatasphereDocument<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetDatasphereDocument<'_> {
+    type Output = DatasphereDocument;
+
+    const NAME: &'static str = "get_datasphere_document";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::DatasphereDocument { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Datasphere document with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Lists the chunks of a Datasphere document.
+#[derive(Debug, Clone, Copy)]
+pub struct ListDatasphereChunks<'a> {
+    pub document_id: &'a str,
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListDatasphereChunks<'_> {
+    type Output = DatasphereChunksListResponse;
+
+    const NAME: &'static str = "list_datasphere_chunks";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::DatasphereDocumentChunks { id: self.document_id }.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Datasphere document with ID {} not found", self.document_id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a chunk of a Datasphere document.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDatasphereChunk<'a> {
+    pub document_id: &'a str,
+    pub id: &'a str,
+}
+
+impl Operation for GetDatasphereChunk<'_> {
+    type Output = DatasphereChunk;
+
+    const NAME: &'static str = "get_datasphere_chunk";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(
+            Method::GET,
+            Endpoint::DatasphereDocumentChunk {
+                document_id: self.document_id,
+                id: self.id,
+            }
+            .url(&credentials.base_url),
+            credentials,
+        ))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Datasphere chunk with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Searches the Datasphere for the chunks closest to a query.
+#[derive(Debug, Clone, Copy)]
+pub struct SearchDatasphere<'a> {
+    pub query: &'a str,
+    pub filters: &'a DatasphereSearchFilters,
+}
+
+impl Operation for SearchDatasphere<'_> {
+    type Output = DatasphereSearchResponse;
+
+    const NAME: &'static str = "datasphere_search";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = DatasphereSearchRequest {
+            query_string: self.query.to_string(),
+            filters: self.filters.clone(),
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::DatasphereSearch.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+// ---------- Space Logs ----------
+
+/// Lists the message logs of the space.
+#[derive(Debug, Clone, Copy)]
+pub struct ListMessageLogs<'a> {
+    pub query: &'a LogQuery,
+}
+
+impl Operation for ListMessageLogs<'_> {
+    type Output = MessageLogsListResponse;
+
+    const NAME: &'static str = "list_message_logs";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::MessageLogs.url(&credentials.base_url), credentials).with_query(&self.query.query_params())
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a message log by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMessageLog<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetMessageLog<'_> {
+    type Output = MessageLog;
+
+    const NAME: &'static str = "get_message_log";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::MessageLog { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Message log with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Lists the call logs of the space.
+#[derive(Debug, Clone, Copy)]
+pub struct ListVoiceLogs<'a> {
+    pub query: &'a LogQuery,
+}
+
+impl Operation for ListVoiceLogs<'_> {
+    type Output = VoiceLogsListResponse;
+
+    const NAME: &'static str = "list_voice_logs";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::VoiceLogs.url(&credentials.base_url), credentials).with_query(&self.query.query_params())
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a call log by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetVoiceLog<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetVoiceLog<'_> {
+    type Output = VoiceLog;
+
+    const NAME: &'static str = "get_voice_log";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::VoiceLog { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Voice log with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+// ---------- SIP Endpoints ----------
+
+/// Lists the SIP endpoints of the space.
+#[derive(Debug, Clone, Copy)]
+pub struct ListSipEndpoints<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListSipEndpoints<'_> {
+    type Output = SipEndpointsListResponse;
+
+    const NAME: &'static str = "list_sip_endpoints";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::SipEndpoints.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a SIP endpoint by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetSipEndpoint<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetSipEndpoint<'_> {
+    type Output = SipEndpoint;
+
+    const NAME: &'static str = "get_sip_endpoint";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::SipEndpoint { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("SIP endpoint with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Creates a SIP endpoint.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateSipEndpoint<'a> {
+    pub request: &'a SipEndpointRequest,
+}
+
+impl Operation for CreateSipEndpoint<'_> {
+    type Output = SipEndpoint;
+
+    const NAME: &'static str = "create_sip_endpoint";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::POST, Endpoint::SipEndpoints.url(&credentials.base_url), credentials).with_json(self.request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Updates the fields of a SIP endpoint that are set in the request.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateSipEndpoint<'a> {
+    pub id: &'a str,
+    pub request: &'a SipEndpointRequest,
+}
+
+impl Operation for UpdateSipEndpoint<'_> {
+    type Output = SipEndpoint;
+
+    const NAME: &'static str = "update_sip_endpoint";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::PUT, Endpoint::SipEndpoint { id: self.id }.url(&credentials.base_url), credentials).with_json(self.request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("SIP endpoint with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Deletes a SIP endpoint.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteSipEndpoint<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for DeleteSipEndpoint<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "delete_sip_endpoint";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::DELETE, Endpoint::SipEndpoint { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("SIP endpoint with ID {} not found", self.id)))
+    }
+}
+
+// ---------- Domain Applications ----------
+
+/// Lists the domain applications of the space.
+#[derive(Debug, Clone, Copy)]
+pub struct ListDomainApplications<'a> {
+    pub query_params: &'a [(String, String)],
+}
+
+impl Operation for ListDomainApplications<'_> {
+    type Output = DomainApplicationsListResponse;
+
+    const NAME: &'static str = "list_domain_applications";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::GET, Endpoint::DomainApplications.url(&credentials.base_url), credentials).with_query(self.query_params)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Gets a domain application by ID.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDomainApplication<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for GetDomainApplication<'_> {
+    type Output = DomainApplication;
+
+    const NAME: &'static str = "get_domain_application";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::GET, Endpoint::DomainApplication { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Domain application with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Creates a domain application.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateDomainApplication<'a> {
+    pub request: &'a DomainApplicationRequest,
+}
+
+impl Operation for CreateDomainApplication<'_> {
+    type Output = DomainApplication;
+
+    const NAME: &'static str = "create_domain_application";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::POST, Endpoint::DomainApplications.url(&credentials.base_url), credentials).with_json(self.request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+}
+
+/// Updates the fields of a domain application that are set in the request.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateDomainApplication<'a> {
+    pub id: &'a str,
+    pub request: &'a DomainApplicationRequest,
+}
+
+impl Operation for UpdateDomainApplication<'_> {
+    type Output = DomainApplication;
+
+    const NAME: &'static str = "update_domain_application";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        HttpRequest::new(Method::PUT, Endpoint::DomainApplication { id: self.id }.url(&credentials.base_url), credentials).with_json(self.request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Domain application with ID {} not found", self.id)))?;
+        parse_json(&response)
+    }
+}
+
+/// Deletes a domain application.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteDomainApplication<'a> {
+    pub id: &'a str,
+}
+
+impl Operation for DeleteDomainApplication<'_> {
+    type Output = ();
+
+    const NAME: &'static str = "delete_domain_application";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        Ok(HttpRequest::new(Method::DELETE, Endpoint::DomainApplication { id: self.id }.url(&credentials.base_url), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, Some(format!("Domain application with ID {} not found", self.id)))
+    }
+}
+
+// ---------- MFA ----------
+
+/// Sends a one-time code by SMS. Billable, so it is short-circuited in dry-run mode.
+#[derive(Debug, Clone, Copy)]
+pub struct SendMfaSms<'a> {
+    pub to: &'a str,
+    pub options: &'a MfaOptions,
+}
+
+impl Operation for SendMfaSms<'_> {
+    type Output = MfaResponse;
+
+    const NAME: &'static str = "mfa_send_sms";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = MfaRequest {
+            to: self.to.to_string(),
+            options: self.options.clone(),
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::MfaSms.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+
+    fn dry_run(&self, _credentials: &Credentials) -> Option<Result<Self::Output, SignalWireError>> {
+        Some(validation::validate_e164("to", self.to).map(|_| dry_run::mfa_response(self.to, "sms")).map_err(SignalWireError::from))
+    }
+}
+
+/// Sends a one-time code by voice call. Billable, so it is short-circuited in dry-run mode.
+#[derive(Debug, Clone, Copy)]
+pub struct SendMfaCall<'a> {
+    pub to: &'a str,
+    pub options: &'a MfaOptions,
+}
+
+impl Operation for SendMfaCall<'_> {
+    type Output = MfaResponse;
+
+    const NAME: &'static str = "mfa_send_call";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = MfaRequest {
+            to: self.to.to_string(),
+            options: self.options.clone(),
+        };
+
+        HttpRequest::new(Method::POST, Endpoint::MfaCall.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        check_status(&response, None)?;
+        parse_json(&response)
+    }
+
+    fn dry_run(&self, _credentials: &Credentials) -> Option<Result<Self::Output, SignalWireError>> {
+        Some(validation::validate_e164("to", self.to).map(|_| dry_run::mfa_response(self.to, "call")).map_err(SignalWireError::from))
+    }
+}
+
+/// Checks a one-time code. A wrong code is `MfaVerification::Failed`, not an error.
+#[derive(Debug, Clone, Copy)]
+pub struct VerifyMfa<'a> {
+    pub request_id: &'a str,
+    pub token: &'a str,
+}
+
+impl Operation for VerifyMfa<'_> {
+    type Output = MfaVerification;
+
+    const NAME: &'static str = "mfa_verify";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        let request = MfaVerifyRequest { token: self.token.to_string() };
+        HttpRequest::new(Method::POST, Endpoint::MfaVerify { id: self.request_id }.url(&credentials.base_url), credentials).with_json(&request)
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        // Expired requests are gone, or rejected with a message saying so.
+        let expired = matches!(response.status, 404 | 410) || (matches!(response.status, 400 | 403 | 422) && response.body.to_lowercase().contains("expired"));
+        if expired {
+            return Ok(MfaVerification::Expired);
+        }
+
+        check_status(&response, None)?;
+        let result: MfaVerifyResponse = parse_json(&response)?;
+        Ok(if result.success { MfaVerification::Verified } else { MfaVerification::Failed })
+    }
+}
+
+// ---------- Pagination ----------
+
+/// Fetches a neighbouring page of a list, see `Page::next_page_operation`.
+#[derive(Debug, Clone, Copy)]
+pub struct GetPage<'a, T> {
+    /// The link of the page, absolute or relative to the space.
+    pub link: &'a str,
+    pub(crate) parse: fn(&HttpResponse) -> Result<Page<T>, SignalWireError>,
+}
+
+impl<T> Operation for GetPage<'_, T> {
+    type Output = Page<T>;
+
+    const NAME: &'static str = "get_page";
+
+    fn request(&self, credentials: &Credentials) -> Result<HttpRequest, SignalWireError> {
+        // Links point at the space, requests go to the configured base URL (e.g. a mock server) all the same.
+        let path = match url::Url::parse(self.link) {
+            Ok(url) => match url.query() {
+                Some(query) => format!("{}?{}", url.path(), query),
+                None => url.path().to_string(),
+            },
+            Err(_) => self.link.to_string(),
+        };
+
+        Ok(HttpRequest::new(Method::GET, format!("{}/{}", credentials.base_url, path.trim_start_matches('/')), credentials))
+    }
+
+    fn parse(&self, response: HttpResponse) -> Result<Self::Output, SignalWireError> {
+        (self.parse)(&response)
+    }
+}
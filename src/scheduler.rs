@@ -0,0 +1,149 @@
+//! Client-side rate limiting with priorities, see `SignalWireClient::with_scheduler`.
+//!
+//! Once the limits are reached, requests queue up and start by priority, then in order of arrival: an OTP send
+//! tagged `Priority::High` goes ahead of a bulk export queued before it.
+
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures_util::future;
+use tokio::sync::Notify;
+
+use crate::rt::{self, Instant};
+
+/// How urgent a request is, see `SignalWireClient::with_priority`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Priority {
+    /// Bulk traffic, which can wait, e.g. exports and batch sends.
+    Low,
+    #[default]
+    Normal,
+    /// Interactive traffic, someone is waiting on it, e.g. OTP sends and status checks.
+    High,
+}
+
+/// The limits of a `SignalWireClient::with_scheduler` scheduler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchedulerOptions {
+    /// Requests in flight at once, at least one.
+    pub max_in_flight: usize,
+    /// Requests started per second, `None` for no limit.
+    pub max_per_second: Option<u32>,
+}
+
+impl Default for SchedulerOptions {
+    /// 16 requests in flight, no rate limit.
+    fn default() -> Self {
+        SchedulerOptions { max_in_flight: 16, max_per_second: None }
+    }
+}
+
+// The place of a queued request: by priority, highest first, then by arrival.
+type Ticket = (std::cmp::Reverse<Priority>, u64);
+
+#[derive(Debug)]
+struct State {
+    in_flight: usize,
+    next_start: Instant,
+    queued: BTreeSet<Ticket>,
+    arrivals: u64,
+}
+
+/// Holds requests back beyond the limits of its `SchedulerOptions`, letting them go by priority.
+#[derive(Debug)]
+pub(crate) struct RequestScheduler {
+    max_in_flight: usize,
+    interval: Duration,
+    state: Mutex<State>,
+    changed: Notify,
+}
+
+/// A started request, counted in flight until dropped.
+#[derive(Debug)]
+pub(crate) struct Permit {
+    scheduler: Arc<RequestScheduler>,
+}
+
+/// Leaves the queue if the request gives up while waiting.
+struct Queued<'a> {
+    scheduler: &'a RequestScheduler,
+    ticket: Ticket,
+}
+
+impl Drop for Queued<'_> {
+    fn drop(&mut self) {
+        if self.scheduler.state.lock().unwrap().queued.remove(&self.ticket) {
+            self.scheduler.changed.notify_waiters();
+        }
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.scheduler.state.lock().unwrap().in_flight -= 1;
+        self.scheduler.changed.notify_waiters();
+    }
+}
+
+impl RequestScheduler {
+    pub(crate) fn new(options: SchedulerOptions) -> Self {
+        RequestScheduler {
+            max_in_flight: options.max_in_flight.max(1),
+            interval: options.max_per_second.filter(|rate| *rate > 0).map_or(Duration::ZERO, |rate| Duration::from_secs(1) / rate),
+            state: Mutex::new(State {
+                in_flight: 0,
+                next_start: Instant::now(),
+                queued: BTreeSet::new(),
+                arrivals: 0,
+            }),
+            changed: Notify::new(),
+        }
+    }
+
+    /// Waits until a request of `priority` may start.
+    pub(crate) async fn acquire(self: &Arc<Self>, priority: Priority) -> Permit {
+        let queued = {
+            let mut state = self.state.lock().unwrap();
+            state.arrivals += 1;
+            let ticket = (std::cmp::Reverse(priority), state.arrivals);
+            state.queued.insert(ticket);
+            Queued { scheduler: self, ticket }
+        };
+
+        loop {
+            // Registered before checking, so a change in between still wakes us up
+            let mut changed = std::pin::pin!(self.changed.notified());
+            changed.as_mut().enable();
+
+            let start_at = {
+                let mut state = self.state.lock().unwrap();
+                let now = Instant::now();
+                let first = state.queued.first() == Some(&queued.ticket);
+                if first && state.in_flight < self.max_in_flight {
+                    if state.next_start <= now {
+                        state.in_flight += 1;
+                        state.next_start = now + self.interval;
+                        drop(state);
+                        // Leaving the queue lets the next request check its turn
+                        drop(queued);
+                        return Permit { scheduler: self.clone() };
+                    }
+                    Some(state.next_start)
+                } else {
+                    None
+                }
+            };
+
+            match start_at {
+                Some(start_at) => {
+                    let delay = std::pin::pin!(rt::sleep(start_at.saturating_duration_since(Instant::now())));
+                    future::select(delay, changed).await;
+                }
+                None => changed.await,
+            }
+        }
+    }
+}
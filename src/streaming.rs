@@ -0,0 +1,242 @@
+//! Decoding large list responses item by item.
+//!
+//! The list methods parse a page whole, which for an export of every owned number or message means holding the
+//! JSON of the page, and then all it parsed into, in memory at once. `ItemDecoder` instead decodes the items of a
+//! list response as its body arrives, buffering the JSON of one item at a time. The `*_streaming` client methods
+//! use it to walk every page of a list:
+//!
+//! ```rust,no_run
+//! use futures_util::{pin_mut, TryStreamExt};
+//! use signalwire::{client::SignalWireClient, errors::SignalWireError};
+//!
+//! # async fn example(client: SignalWireClient) -> Result<(), SignalWireError> {
+//! let messages = client.list_messages_streaming(&[]);
+//! pin_mut!(messages);
+//! while let Some(message) = messages.try_next().await? {
+//!     println!("{}: {:?}", message.sid, message.status);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Compressed responses (`gzip`, `brotli` features) are decompressed whole before they are decoded.
+
+use std::marker::PhantomData;
+
+#[cfg(any(test, feature = "reqwest"))]
+use futures_util::{stream, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::errors::SignalWireError;
+#[cfg(any(test, feature = "reqwest"))]
+use crate::{
+    client::SignalWireClient,
+    pagination::{parse_page, Paginated},
+    rt::{self, BoxStream},
+    sansio::{GetPage, HttpRequest, Operation},
+    transport,
+};
+
+/// Decodes the items of the array field of a JSON object as the object is fed in chunks.
+///
+/// The other fields of the object, e.g. the links to the neighbouring pages, are kept and returned by `finish`.
+///
+/// ```rust
+/// use signalwire::{streaming::ItemDecoder, types::OwnedPhoneNumber};
+///
+/// let mut decoder = ItemDecoder::<OwnedPhoneNumber>::new("data");
+/// let mut numbers = decoder.feed(br#"{"links": {"next": null}, "data": [{"id": "00000000-0000-0000-0000-000000000001", "#).unwrap();
+/// assert!(numbers.is_empty());
+///
+/// numbers.extend(decoder.feed(br#""number": "+15551234567", "capabilities": []}]}"#).unwrap());
+/// assert_eq!(numbers[0].number, "+15551234567");
+/// assert_eq!(decoder.finish().unwrap()["links"]["next"], serde_json::Value::Null);
+/// ```
+#[derive(Debug)]
+pub struct ItemDecoder<T> {
+    field: &'static str,
+    // Nesting of the current position, 1 inside the top-level object
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    in_items: bool,
+    done: bool,
+    // The JSON of the current top-level field, `"name":value`
+    member: Vec<u8>,
+    // The JSON of the current item
+    item: Vec<u8>,
+    rest: Map<String, Value>,
+    _item: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> ItemDecoder<T> {
+    /// A decoder of the items of `field`, e.g. `"data"`.
+    pub fn new(field: &'static str) -> Self {
+        ItemDecoder {
+            field,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            in_items: false,
+            done: false,
+            member: Vec::new(),
+            item: Vec::new(),
+            rest: Map::new(),
+            _item: PhantomData,
+        }
+    }
+
+    /// Feeds the next chunk of the body, returning the items it completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unexpected` if the body isn't a JSON object or an item can't be parsed.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Vec<T>, SignalWireError> {
+        let mut items = Vec::new();
+        for &byte in chunk {
+            self.push(byte, &mut items)?;
+        }
+        Ok(items)
+    }
+
+    /// Ends the body, returning its fields other than the items.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unexpected` if the body ended before the object did.
+    pub fn finish(self) -> Result<Map<String, Value>, SignalWireError> {
+        if !self.done {
+            return Err(SignalWireError::Unexpected("Failed to parse response: the body ended before the JSON object".to_string()));
+        }
+        Ok(self.rest)
+    }
+
+    fn push(&mut self, byte: u8, items: &mut Vec<T>) -> Result<(), SignalWireError> {
+        if self.in_string {
+            self.capture().push(byte);
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+            }
+            return Ok(());
+        }
+
+        match (byte, self.depth) {
+            (b' ' | b'\n' | b'\r' | b'\t', _) => {}
+            (b'{', 0) if !self.done => self.depth = 1,
+            (_, 0) => return Err(invalid(format!("unexpected '{}' outside the JSON object", byte as char))),
+            (b',' | b'}', 1) => {
+                self.finish_member()?;
+                if byte == b'}' {
+                    self.depth = 0;
+                    self.done = true;
+                }
+            }
+            (b'[', 1) if self.is_items_field() => {
+                self.member.clear();
+                self.in_items = true;
+                self.depth = 2;
+            }
+            (b',', 2) if self.in_items => self.finish_item(items)?,
+            (b']', 2) if self.in_items => {
+                self.finish_item(items)?;
+                self.in_items = false;
+                self.depth = 1;
+            }
+            _ => {
+                self.capture().push(byte);
+                match byte {
+                    b'"' => self.in_string = true,
+                    b'{' | b'[' => self.depth += 1,
+                    b'}' | b']' => self.depth -= 1,
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn capture(&mut self) -> &mut Vec<u8> {
+        if self.in_items && self.depth >= 2 {
+            &mut self.item
+        } else {
+            &mut self.member
+        }
+    }
+
+    fn is_items_field(&self) -> bool {
+        match self.member.split_last() {
+            Some((b':', name)) => serde_json::from_slice::<String>(name).is_ok_and(|name| name == self.field),
+            _ => false,
+        }
+    }
+
+    fn finish_member(&mut self) -> Result<(), SignalWireError> {
+        if self.member.is_empty() {
+            return Ok(());
+        }
+        let mut object = Vec::with_capacity(self.member.len() + 2);
+        object.push(b'{');
+        object.append(&mut self.member);
+        object.push(b'}');
+        let fields: Map<String, Value> = serde_json::from_slice(&object).map_err(|e| invalid(e.to_string()))?;
+        self.rest.extend(fields);
+        Ok(())
+    }
+
+    fn finish_item(&mut self, items: &mut Vec<T>) -> Result<(), SignalWireError> {
+        if self.item.is_empty() {
+            return Ok(());
+        }
+        let item = serde_json::from_slice(&self.item).map_err(|e| invalid(format!("{}. Item was: {}", e, String::from_utf8_lossy(&self.item))))?;
+        items.push(item);
+        self.item.clear();
+        Ok(())
+    }
+}
+
+fn invalid(reason: String) -> SignalWireError {
+    SignalWireError::Unexpected(format!("Failed to parse response: {}", reason))
+}
+
+/// Streams the items of the list response `R` whose first page the `request` of `operation` fetches, decoding each
+/// page as it arrives and fetching the following pages as the stream is read.
+#[cfg(any(test, feature = "reqwest"))]
+pub(crate) fn stream_list<'a, R>(client: &'a SignalWireClient, operation: &'static str, request: Result<HttpRequest, SignalWireError>) -> impl Stream<Item = Result<R::Item, SignalWireError>> + 'a
+where
+    R: Paginated,
+    R::Item: DeserializeOwned + 'a,
+{
+    enum State<T> {
+        Open(&'static str, Result<HttpRequest, SignalWireError>),
+        Body(BoxStream<'static, reqwest::Result<bytes::Bytes>>, ItemDecoder<T>),
+    }
+
+    stream::try_unfold(Some(State::Open(operation, request)), move |state| async move {
+        let (mut body, mut decoder) = match state {
+            Some(State::Open(operation, request)) => (rt::body_stream(client.open(operation, request?).await?), ItemDecoder::new(R::ITEMS_FIELD)),
+            Some(State::Body(body, decoder)) => (body, decoder),
+            None => return Ok::<_, SignalWireError>(None),
+        };
+
+        match body.try_next().await.map_err(|e| SignalWireError::HttpError(transport::body_error(&e)))? {
+            Some(chunk) => {
+                let items = decoder.feed(&chunk)?;
+                Ok(Some((stream::iter(items.into_iter().map(Ok)), Some(State::Body(body, decoder)))))
+            }
+            None => {
+                let rest = Value::Object(decoder.finish()?);
+                let next = rest.pointer(R::NEXT_LINK_POINTER).and_then(Value::as_str).filter(|link| !link.is_empty()).map(|link| {
+                    let page = GetPage::<R::Item> { link, parse: parse_page::<R> };
+                    State::Open(GetPage::<R::Item>::NAME, page.request(&client.credentials()))
+                });
+                Ok(Some((stream::iter(Vec::new().into_iter().map(Ok)), next)))
+            }
+        }
+    })
+    .try_flatten()
+}
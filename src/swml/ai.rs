@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The parameters of the `ai` instruction, which hands the call to an AI voice agent.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ai {
+    pub prompt: AiPrompt,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_prompt: Option<AiPrompt>,
+    /// Where the conversation summary produced by `post_prompt` is posted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_prompt_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<AiLanguage>,
+    /// Words and phrases to help speech recognition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hints: Vec<String>,
+    /// Agent tuning parameters (`end_of_speech_timeout`, `attention_timeout`, ...).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, Value>,
+    #[serde(rename = "SWAIG", skip_serializing_if = "Option::is_none")]
+    pub swaig: Option<Swaig>,
+}
+
+impl Ai {
+    /// Creates an agent driven by the system prompt `prompt`.
+    pub fn new(prompt: &str) -> Self {
+        Ai {
+            prompt: AiPrompt::new(prompt),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the prompt run after the call to summarize it.
+    pub fn post_prompt(mut self, text: &str) -> Self {
+        self.post_prompt = Some(AiPrompt::new(text));
+        self
+    }
+
+    /// Sets where the post-prompt summary is posted.
+    pub fn post_prompt_url(mut self, url: &str) -> Self {
+        self.post_prompt_url = Some(url.to_string());
+        self
+    }
+
+    /// Adds a language the agent can speak.
+    pub fn language(mut self, language: AiLanguage) -> Self {
+        self.languages.push(language);
+        self
+    }
+
+    /// Adds a speech recognition hint.
+    pub fn hint(mut self, hint: &str) -> Self {
+        self.hints.push(hint.to_string());
+        self
+    }
+
+    /// Sets an agent tuning parameter.
+    pub fn param(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.params.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// Adds a SWAIG function the agent can call.
+    pub fn function(mut self, function: SwaigFunction) -> Self {
+        self.swaig.get_or_insert_with(Swaig::default).functions.push(function);
+        self
+    }
+
+    /// Sets the webhook used by SWAIG functions without their own `web_hook_url`.
+    pub fn function_web_hook_url(mut self, url: &str) -> Self {
+        self.swaig.get_or_insert_with(Swaig::default).defaults = Some(SwaigDefaults { web_hook_url: Some(url.to_string()) });
+        self
+    }
+}
+
+/// A prompt and its model settings.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiPrompt {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f64>,
+}
+
+impl AiPrompt {
+    pub fn new(text: &str) -> Self {
+        AiPrompt {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A language the agent can understand and speak.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiLanguage {
+    /// A display name, e.g. `English`.
+    pub name: String,
+    /// A language code, e.g. `en-US`.
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<String>,
+}
+
+impl AiLanguage {
+    pub fn new(name: &str, code: &str) -> Self {
+        AiLanguage {
+            name: name.to_string(),
+            code: code.to_string(),
+            voice: None,
+        }
+    }
+
+    pub fn voice(mut self, voice: &str) -> Self {
+        self.voice = Some(voice.to_string());
+        self
+    }
+}
+
+/// The SignalWire AI Gateway settings: functions the agent can call during the conversation.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Swaig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<SwaigDefaults>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub functions: Vec<SwaigFunction>,
+}
+
+/// Settings shared by every SWAIG function.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwaigDefaults {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_hook_url: Option<String>,
+}
+
+/// A function the agent can call, described to the model by its name, purpose and parameters.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwaigFunction {
+    pub function: String,
+    pub description: String,
+    pub parameters: FunctionParameters,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_hook_url: Option<String>,
+}
+
+impl SwaigFunction {
+    pub fn new(function: &str, description: &str) -> Self {
+        SwaigFunction {
+            function: function.to_string(),
+            description: description.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a parameter, listing it as required if `required` is set.
+    pub fn parameter(mut self, name: &str, schema: ParameterSchema, required: bool) -> Self {
+        self.parameters.properties.insert(name.to_string(), schema);
+        if required {
+            self.parameters.required.push(name.to_string());
+        }
+        self
+    }
+
+    pub fn web_hook_url(mut self, url: &str) -> Self {
+        self.web_hook_url = Some(url.to_string());
+        self
+    }
+}
+
+/// The JSON schema of a function's arguments.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionParameters {
+    #[serde(rename = "type")]
+    pub schema_type: String,
+    #[serde(default)]
+    pub properties: BTreeMap<String, ParameterSchema>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+}
+
+impl Default for FunctionParameters {
+    fn default() -> Self {
+        FunctionParameters {
+            schema_type: "object".to_string(),
+            properties: BTreeMap::new(),
+            required: Vec::new(),
+        }
+    }
+}
+
+/// The JSON schema of a single function parameter.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterSchema {
+    #[serde(rename = "type")]
+    pub schema_type: String,
+    pub description: String,
+    #[serde(rename = "enum", default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_values: Vec<String>,
+}
+
+impl ParameterSchema {
+    fn new(schema_type: &str, description: &str) -> Self {
+        ParameterSchema {
+            schema_type: schema_type.to_string(),
+            description: description.to_string(),
+            allowed_values: Vec::new(),
+        }
+    }
+
+    pub fn string(description: &str) -> Self {
+        ParameterSchema::new("string", description)
+    }
+
+    pub fn integer(description: &str) -> Self {
+        ParameterSchema::new("integer", description)
+    }
+
+    pub fn number(description: &str) -> Self {
+        ParameterSchema::new("number", description)
+    }
+
+    pub fn boolean(description: &str) -> Self {
+        ParameterSchema::new("boolean", description)
+    }
+
+    /// A string restricted to `values`.
+    pub fn one_of(description: &str, values: &[&str]) -> Self {
+        ParameterSchema {
+            allowed_values: values.iter().map(|value| value.to_string()).collect(),
+            ..ParameterSchema::string(description)
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Typed SWML (SignalWire Markup Language) documents.
+//!
+//! A SWML document is a set of named sections, each a list of instructions executed in order
+//! starting with `main`. Typed builder methods cover the common instructions; anything else can be
+//! added with `Section::instruction`.
+//!
+//! ```rust
+//! use signalwire::swml::{Ai, Section, Swml};
+//!
+//! let swml = Swml::new().section("main", Section::new().answer().ai(Ai::new("You are a friendly receptionist.")).hangup());
+//!
+//! let json = serde_json::to_value(&swml).unwrap();
+//! assert_eq!(json["sections"]["main"][0], serde_json::json!({ "answer": {} }));
+//! ```
+
+mod ai;
+mod validate;
+
+use std::collections::BTreeMap;
+
+pub use ai::*;
+use serde::{de, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Map, Value};
+
+use crate::errors::SwmlError;
+
+/// The SWML version written by `Swml::new`.
+pub const SWML_VERSION: &str = "1.0.0";
+
+/// A SWML document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Swml {
+    pub version: String,
+    pub sections: BTreeMap<String, Section>,
+}
+
+impl Default for Swml {
+    fn default() -> Self {
+        Swml {
+            version: SWML_VERSION.to_string(),
+            sections: BTreeMap::new(),
+        }
+    }
+}
+
+impl Swml {
+    pub fn new() -> Self {
+        Swml::default()
+    }
+
+    /// Adds (or replaces) a section. Execution starts with the `main` section.
+    pub fn section(mut self, name: &str, section: Section) -> Self {
+        self.sections.insert(name.to_string(), section);
+        self
+    }
+
+    /// Checks the document structure: a `main` section, known method names and the fields each method requires.
+    ///
+    /// # Errors
+    ///
+    /// Returns every problem found, each with the path of the offending instruction (e.g. `sections.main[2]`).
+    pub fn validate(&self) -> Result<(), Vec<SwmlError>> {
+        let errors = validate::validate(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Renders the document as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, SwmlError> {
+        serde_json::to_string_pretty(self).map_err(|e| SwmlError::Serialization(e.to_string()))
+    }
+
+    /// Renders the document as YAML.
+    pub fn to_yaml(&self) -> Result<String, SwmlError> {
+        serde_yaml_ng::to_string(self).map_err(|e| SwmlError::Serialization(e.to_string()))
+    }
+
+    /// Parses a JSON document.
+    pub fn from_json(json: &str) -> Result<Self, SwmlError> {
+        serde_json::from_str(json).map_err(|e| SwmlError::Serialization(e.to_string()))
+    }
+
+    /// Parses a YAML document.
+    pub fn from_yaml(yaml: &str) -> Result<Self, SwmlError> {
+        serde_yaml_ng::from_str(yaml).map_err(|e| SwmlError::Serialization(e.to_string()))
+    }
+}
+
+/// An ordered list of instructions.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Section {
+    pub instructions: Vec<Instruction>,
+}
+
+impl Section {
+    pub fn new() -> Self {
+        Section::default()
+    }
+
+    /// Appends any instruction, e.g. one without a typed builder method.
+    pub fn instruction(mut self, method: &str, params: Value) -> Self {
+        self.instructions.push(Instruction::new(method, params));
+        self
+    }
+
+    /// Answers the call.
+    pub fn answer(self) -> Self {
+        self.instruction("answer", json!({}))
+    }
+
+    /// Ends the call.
+    pub fn hangup(self) -> Self {
+        self.instruction("hangup", json!({}))
+    }
+
+    /// Plays audio; `url` may also be `say:Some text` for text-to-speech or `silence:2` for a pause.
+    pub fn play(self, url: &str) -> Self {
+        self.instruction("play", json!({ "url": url }))
+    }
+
+    /// Waits for `duration_ms` milliseconds.
+    pub fn sleep(self, duration_ms: u64) -> Self {
+        self.instruction("sleep", json!({ "duration": duration_ms }))
+    }
+
+    /// Dials a phone number or SIP URI and bridges the call.
+    pub fn connect(self, to: &str) -> Self {
+        self.instruction("connect", json!({ "to": to }))
+    }
+
+    /// Continues with another section of this document, or the SWML at a URL.
+    pub fn transfer(self, dest: &str) -> Self {
+        self.instruction("transfer", json!({ "dest": dest }))
+    }
+
+    /// Hands the call to an AI agent.
+    pub fn ai(self, ai: Ai) -> Self {
+        let params = serde_json::to_value(ai).expect("AI parameters serialize to JSON");
+        self.instruction("ai", params)
+    }
+}
+
+/// A single SWML instruction: a method name and its parameters.
+///
+/// Serialized as a single-key object, `{ "method": params }`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instruction {
+    pub method: String,
+    pub params: Value,
+}
+
+impl Instruction {
+    pub fn new(method: &str, params: Value) -> Self {
+        Instruction { method: method.to_string(), params }
+    }
+}
+
+impl Serialize for Instruction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.method, &self.params)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Instruction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Value::deserialize(deserializer)? {
+            // `- hangup` is shorthand for `- hangup: {}`.
+            Value::String(method) => Ok(Instruction::new(&method, Value::Object(Map::new()))),
+            Value::Object(map) if map.len() == 1 => {
+                let (method, params) = map.into_iter().next().expect("map has one entry");
+                Ok(Instruction { method, params })
+            }
+            other => Err(de::Error::custom(format!("expected an instruction object with a single method, found {}", other))),
+        }
+    }
+}
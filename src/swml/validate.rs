@@ -0,0 +1,81 @@
+use serde_json::Value;
+
+use super::Swml;
+use crate::errors::SwmlError;
+
+/// Every SWML method with the fields it requires when its parameters are an object.
+///
+/// `a|b` means at least one of `a` and `b` must be present.
+const METHODS: &[(&str, &[&str])] = &[
+    ("ai", &["prompt"]),
+    ("amazon_bedrock", &["prompt"]),
+    ("answer", &[]),
+    ("cond", &[]),
+    ("connect", &["to|serial|parallel|serial_parallel"]),
+    ("denoise", &[]),
+    ("detect_machine", &[]),
+    ("enter_queue", &["queue_name"]),
+    ("execute", &["dest"]),
+    ("goto", &["label"]),
+    ("hangup", &[]),
+    ("join_conference", &["name"]),
+    ("join_room", &["name"]),
+    ("label", &[]),
+    ("live_transcribe", &["action"]),
+    ("live_translate", &["action"]),
+    ("pay", &["payment_connector_url"]),
+    ("play", &["url|urls"]),
+    ("prompt", &["play"]),
+    ("receive_fax", &[]),
+    ("record", &[]),
+    ("record_call", &[]),
+    ("request", &["url", "method"]),
+    ("return", &[]),
+    ("send_digits", &["digits"]),
+    ("send_fax", &["document"]),
+    ("send_sms", &["to_number", "from_number", "body|media"]),
+    ("set", &[]),
+    ("sip_refer", &["to_uri"]),
+    ("sleep", &["duration"]),
+    ("stop_denoise", &[]),
+    ("stop_record_call", &[]),
+    ("stop_tap", &[]),
+    ("switch", &["variable", "case"]),
+    ("tap", &["uri"]),
+    ("transfer", &["dest"]),
+    ("unset", &[]),
+    ("user_event", &["event"]),
+];
+
+/// Collects every structural problem of `swml`.
+pub(super) fn validate(swml: &Swml) -> Vec<SwmlError> {
+    let mut errors = Vec::new();
+
+    if !swml.sections.contains_key("main") {
+        errors.push(SwmlError::MissingMainSection);
+    }
+
+    for (name, section) in &swml.sections {
+        for (index, instruction) in section.instructions.iter().enumerate() {
+            let path = format!("sections.{}[{}]", name, index);
+            let Some((_, required)) = METHODS.iter().find(|(method, _)| *method == instruction.method) else {
+                errors.push(SwmlError::UnknownMethod { path, method: instruction.method.clone() });
+                continue;
+            };
+
+            // Scalar and array parameters are shorthands (e.g. `sleep: 1000`), only objects have fields to check.
+            let Value::Object(params) = &instruction.params else {
+                continue;
+            };
+            for field in required.iter().filter(|field| !field.split('|').any(|alternative| params.contains_key(alternative))) {
+                errors.push(SwmlError::MissingField {
+                    path: path.clone(),
+                    method: instruction.method.clone(),
+                    field: field.replace('|', "` or `"),
+                });
+            }
+        }
+    }
+
+    errors
+}
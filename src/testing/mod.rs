@@ -0,0 +1,238 @@
+//! Test utilities backed by an in-process [`wiremock`] server.
+//!
+//! `MockSignalWire` starts a local HTTP server, hands out a `SignalWireClient` pointed at it and
+//! offers canned responses for the most commonly used endpoints, so integration tests can run
+//! without real credentials or charges.
+//!
+//! With the `relay` feature, `MockRelay` does the same for the Relay WebSocket API.
+//!
+//! ```rust,no_run
+//! use signalwire::{
+//!     testing::{fixtures, MockSignalWire},
+//!     types::SmsMessage,
+//! };
+//!
+//! # async fn example() {
+//! let mock = MockSignalWire::start().await;
+//! let message = SmsMessage {
+//!     from: "+15551234567".to_string(),
+//!     to: "+15557654321".to_string(),
+//!     body: "Hi".to_string(),
+//!     ..Default::default()
+//! };
+//! mock.mock_send_sms(&fixtures::sms_response(&message)).await;
+//!
+//! let response = mock.client().send_sms(&message).await.unwrap();
+//! assert_eq!(response.status, "queued");
+//! # }
+//! ```
+
+#[cfg(any(test, feature = "relay"))]
+mod relay;
+
+#[cfg(any(test, feature = "relay"))]
+pub use relay::*;
+use wiremock::{
+    matchers::{method, path, path_regex},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::{client::SignalWireClient, endpoint::Endpoint, types::*};
+
+/// The space name used by clients created through `MockSignalWire::client`.
+pub const MOCK_SPACE_NAME: &str = "mock-space";
+/// The project ID used by clients created through `MockSignalWire::client`.
+pub const MOCK_PROJECT_ID: &str = "00000000-0000-0000-0000-000000000000";
+/// The API key used by clients created through `MockSignalWire::client`.
+pub const MOCK_API_KEY: &str = "PTmockapikey";
+
+/// Canned error responses that can be mounted on any endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorScenario {
+    /// `401 Unauthorized`.
+    Unauthorized,
+    /// `404 Not Found`.
+    NotFound,
+    /// `429 Too Many Requests` with a `Retry-After` header (in seconds).
+    RateLimited { retry_after: u64 },
+    /// `500 Internal Server Error`.
+    ServerError,
+}
+
+impl ErrorScenario {
+    fn response(&self) -> ResponseTemplate {
+        match self {
+            ErrorScenario::Unauthorized => ResponseTemplate::new(401).set_body_json(serde_json::json!({ "errors": [{ "code": "unauthorized", "message": "Unauthorized" }] })),
+            ErrorScenario::NotFound => ResponseTemplate::new(404).set_body_json(serde_json::json!({ "code": 20404, "message": "The requested resource was not found", "status": 404 })),
+            ErrorScenario::RateLimited { retry_after } => ResponseTemplate::new(429)
+                .insert_header("Retry-After", retry_after.to_string().as_str())
+                .insert_header("X-RateLimit-Remaining", "0")
+                .set_body_json(serde_json::json!({ "code": 20429, "message": "Too Many Requests", "status": 429 })),
+            ErrorScenario::ServerError => ResponseTemplate::new(500).set_body_json(serde_json::json!({ "code": 20500, "message": "Internal Server Error", "status": 500 })),
+        }
+    }
+}
+
+/// A local fake of the SignalWire REST API.
+pub struct MockSignalWire {
+    server: MockServer,
+}
+
+impl MockSignalWire {
+    /// Starts a new mock server on a random local port.
+    pub async fn start() -> Self {
+        MockSignalWire { server: MockServer::start().await }
+    }
+
+    /// Returns a client using the mock credentials and pointed at this server.
+    pub fn client(&self) -> SignalWireClient {
+        SignalWireClient::new(MOCK_SPACE_NAME, MOCK_PROJECT_ID, MOCK_API_KEY).with_base_url(&self.server.uri())
+    }
+
+    /// The base URI of the mock server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The underlying `wiremock` server, for mounting custom mocks and inspecting received requests.
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// Responds to `send_sms` with `response`.
+    pub async fn mock_send_sms(&self, response: &SmsResponse) {
+        Mock::given(method("POST"))
+            .and(path(Endpoint::Messages { account_sid: MOCK_PROJECT_ID }.path()))
+            .respond_with(ResponseTemplate::new(201).set_body_json(response))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Responds to `get_message_status` for `response.sid` with `response`.
+    pub async fn mock_message_status(&self, response: &SmsResponse) {
+        Mock::given(method("GET"))
+            .and(path(
+                Endpoint::Message {
+                    account_sid: MOCK_PROJECT_ID,
+                    message_sid: response.sid.as_str(),
+                }
+                .path(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Responds to every phone number lookup of `phone_number` (with or without carrier/CNAM) with `response`.
+    pub async fn mock_lookup(&self, phone_number: &str, response: &PhoneLookupResponse) {
+        Mock::given(method("GET"))
+            .and(path(Endpoint::PhoneNumberLookup { phone_number }.path()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Responds to `get_phone_numbers_owned` with `response`.
+    pub async fn mock_phone_numbers_owned(&self, response: &PhoneNumbersOwnedResponse) {
+        Mock::given(method("GET"))
+            .and(path(Endpoint::PhoneNumbers.path()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Responds to `get_phone_numbers_available` for `iso_country` with `response`.
+    pub async fn mock_phone_numbers_available(&self, iso_country: &str, response: &PhoneNumbersAvailableResponse) {
+        Mock::given(method("GET"))
+            .and(path(
+                Endpoint::AvailablePhoneNumbers {
+                    account_sid: MOCK_PROJECT_ID,
+                    iso_country,
+                }
+                .path(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Responds to requests with the given HTTP method whose path matches `path_pattern` (a regex) with an error.
+    ///
+    /// Error mocks are mounted like any other mock, so mount them before the success mocks of the
+    /// same endpoint when both are present.
+    pub async fn mock_error(&self, http_method: &str, path_pattern: &str, scenario: ErrorScenario) {
+        Mock::given(method(http_method)).and(path_regex(path_pattern)).respond_with(scenario.response()).mount(&self.server).await;
+    }
+}
+
+/// Ready-made response bodies for the mocked endpoints.
+pub mod fixtures {
+    use crate::{
+        endpoint::{Endpoint, LAML_API_VERSION},
+        types::*,
+    };
+
+    /// A queued message response echoing `message`.
+    pub fn sms_response(message: &SmsMessage) -> SmsResponse {
+        SmsResponse {
+            sid: "SM00000000000000000000000000000001".parse().unwrap(),
+            date_created: "Mon, 01 Jan 2024 00:00:00 +0000".to_string(),
+            date_updated: "Mon, 01 Jan 2024 00:00:00 +0000".to_string(),
+            account_sid: super::MOCK_PROJECT_ID.parse().unwrap(),
+            to: message.to.clone(),
+            from: message.from.clone(),
+            messaging_service_sid: message.number_group_id.clone(),
+            body: message.body.clone(),
+            status: "queued".to_string(),
+            num_segments: 1,
+            direction: "outbound-api".to_string(),
+            api_version: LAML_API_VERSION.to_string(),
+            uri: Endpoint::Message {
+                account_sid: super::MOCK_PROJECT_ID,
+                message_sid: "SM00000000000000000000000000000001",
+            }
+            .path(),
+            ..Default::default()
+        }
+    }
+
+    /// A successful lookup of a valid US mobile number.
+    pub fn lookup_response(phone_number: &str) -> PhoneLookupResponse {
+        PhoneLookupResponse {
+            country_code_number: Some(1),
+            national_number: Some(phone_number.trim_start_matches("+1").to_string()),
+            possible_number: Some(true),
+            valid_number: Some(true),
+            e164: Some(phone_number.to_string()),
+            country_code: "US".to_string(),
+            timezones: Some(vec!["America/New_York".to_string()]),
+            number_type: Some("mobile".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// An owned phone number with voice and SMS capabilities.
+    pub fn owned_phone_number(number: &str) -> OwnedPhoneNumber {
+        OwnedPhoneNumber {
+            id: "00000000-0000-0000-0000-000000000001".parse().unwrap(),
+            number: number.to_string(),
+            name: Some(number.to_string()),
+            capabilities: vec![NumberCapability::Voice, NumberCapability::Sms],
+            number_type: Some("toll-free".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// A single page of owned phone numbers.
+    pub fn phone_numbers_owned_response(numbers: &[&str]) -> PhoneNumbersOwnedResponse {
+        PhoneNumbersOwnedResponse {
+            links: Links {
+                self_field: "/api/relay/rest/phone_numbers?page_number=0&page_size=50".to_string(),
+                first: "/api/relay/rest/phone_numbers?page_size=50".to_string(),
+                next: None,
+                prev: None,
+            },
+            data: numbers.iter().map(|number| owned_phone_number(number)).collect(),
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+};
+use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+use super::MOCK_PROJECT_ID;
+use crate::relay::{
+    protocol::{Frame, RpcError},
+    RelayConfig,
+};
+
+/// The JWT used by configs created through `MockRelay::config`.
+pub const MOCK_JWT: &str = "mock-jwt-token";
+
+/// How long `MockRelay::next_request` and `next_response` wait before panicking.
+const WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What the test asks of the current connection.
+enum Command {
+    Send(Message),
+    Close,
+    Stall,
+}
+
+#[derive(Default)]
+struct State {
+    responses: HashMap<String, Result<Value, RpcError>>,
+    received: Vec<Frame>,
+    connection: Option<mpsc::UnboundedSender<Command>>,
+    connections: usize,
+}
+
+impl State {
+    fn reply(&self, method: &str, id: &str) -> Frame {
+        match self.responses.get(method) {
+            Some(Ok(result)) => Frame::result(id, result.clone()),
+            Some(Err(error)) => Frame {
+                error: Some(error.clone()),
+                result: None,
+                ..Frame::result(id, Value::Null)
+            },
+            None if method == "signalwire.connect" => Frame::result(id, json!({ "identity": "mock-identity", "protocol": "signalwire_mock", "authorization": { "project": MOCK_PROJECT_ID } })),
+            None => Frame::result(id, json!({ "code": "200", "message": "OK" })),
+        }
+    }
+}
+
+/// A local fake of the Relay WebSocket API.
+///
+/// Every request is answered: `signalwire.connect` with a mock session, anything else with `{"code": "200"}`
+/// unless overridden with `respond`. Frames sent by the client are recorded for `next_request`/`next_response`.
+///
+/// ```rust,no_run
+/// use serde_json::json;
+/// use signalwire::{relay::RelayClient, testing::MockRelay};
+///
+/// # async fn example() {
+/// let mock = MockRelay::start().await;
+/// let relay = RelayClient::connect(mock.config()).await.unwrap();
+/// let mut events = relay.events();
+///
+/// mock.push_event("queuing.relay.tasks", json!({ "context": "office", "message": { "job": 1 } }));
+/// let event = events.recv().await.unwrap();
+/// assert_eq!(event.event_type, "queuing.relay.tasks");
+/// # }
+/// ```
+pub struct MockRelay {
+    url: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockRelay {
+    /// Starts the server on a random local port.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("failed to bind the mock Relay server");
+        let url = format!("ws://{}", listener.local_addr().expect("the mock Relay server has an address"));
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let accepting = state.clone();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                tokio::spawn(serve(socket, accepting.clone()));
+            }
+        });
+
+        MockRelay { url, state }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// A config pointed at this server.
+    pub fn config(&self) -> RelayConfig {
+        RelayConfig::new(&self.url, MOCK_PROJECT_ID, MOCK_JWT)
+    }
+
+    /// Answers every `method` request with `result`.
+    pub fn respond(&self, method: &str, result: Value) {
+        self.state.lock().unwrap().responses.insert(method.to_string(), Ok(result));
+    }
+
+    /// Answers every `method` request with a JSON-RPC error.
+    pub fn respond_error(&self, method: &str, code: i64, message: &str) {
+        let error = RpcError {
+            code: json!(code),
+            message: message.to_string(),
+        };
+        self.state.lock().unwrap().responses.insert(method.to_string(), Err(error));
+    }
+
+    /// Sends a frame to the connected client.
+    pub fn send(&self, frame: &Frame) {
+        if let Some(connection) = &self.state.lock().unwrap().connection {
+            let _ = connection.send(Command::Send(Message::text(frame.to_text())));
+        }
+    }
+
+    /// Pushes a `signalwire.event` to the connected client, returning the frame ID it should acknowledge.
+    pub fn push_event(&self, event_type: &str, params: Value) -> String {
+        let frame = Frame::request("signalwire.event", json!({ "event_type": event_type, "timestamp": 1700000000.0, "project_id": MOCK_PROJECT_ID, "params": params }));
+        self.send(&frame);
+        frame.id.unwrap_or_default()
+    }
+
+    /// Closes the current connection, as a network drop would.
+    pub fn drop_connection(&self) {
+        if let Some(connection) = self.state.lock().unwrap().connection.take() {
+            let _ = connection.send(Command::Close);
+        }
+    }
+
+    /// Stops reading from and writing to the current connection without closing it, as a half-open connection
+    /// would: pings go unanswered.
+    pub fn stall(&self) {
+        if let Some(connection) = &self.state.lock().unwrap().connection {
+            let _ = connection.send(Command::Stall);
+        }
+    }
+
+    /// How many connections were accepted so far.
+    pub fn connections(&self) -> usize {
+        self.state.lock().unwrap().connections
+    }
+
+    /// How many `method` requests were received that `next_request` didn't return yet.
+    pub fn requests(&self, method: &str) -> usize {
+        self.state.lock().unwrap().received.iter().filter(|frame| frame.method.as_deref() == Some(method)).count()
+    }
+
+    /// Waits for the next `method` request from the client that wasn't returned yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if none arrives within 5 seconds.
+    pub async fn next_request(&self, method: &str) -> Frame {
+        self.wait_for(|frame| frame.method.as_deref() == Some(method)).await.unwrap_or_else(|| panic!("no `{}` request received", method))
+    }
+
+    /// Waits for the client's response to the frame `id`, e.g. the acknowledgment of a pushed event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if none arrives within 5 seconds.
+    pub async fn next_response(&self, id: &str) -> Frame {
+        self.wait_for(|frame| frame.method.is_none() && frame.id.as_deref() == Some(id))
+            .await
+            .unwrap_or_else(|| panic!("no response to `{}` received", id))
+    }
+
+    async fn wait_for(&self, matches: impl Fn(&Frame) -> bool) -> Option<Frame> {
+        let deadline = tokio::time::Instant::now() + WAIT_TIMEOUT;
+        while tokio::time::Instant::now() < deadline {
+            {
+                let mut state = self.state.lock().unwrap();
+                if let Some(index) = state.received.iter().position(&matches) {
+                    return Some(state.received.remove(index));
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        None
+    }
+}
+
+/// Runs one client connection.
+async fn serve(socket: TcpStream, state: Arc<Mutex<State>>) {
+    let Ok(socket) = accept_async(socket).await else {
+        return;
+    };
+    let (mut sink, mut stream) = socket.split();
+    let (sender, mut outgoing) = mpsc::unbounded_channel();
+    {
+        let mut state = state.lock().unwrap();
+        state.connection = Some(sender);
+        state.connections += 1;
+    }
+
+    loop {
+        tokio::select! {
+            command = outgoing.recv() => match command {
+                Some(Command::Send(message)) => {
+                    if sink.send(message).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Command::Close) | None => {
+                    let _ = sink.close().await;
+                    break;
+                }
+                Some(Command::Stall) => {
+                    // Hold the socket open until told otherwise or replaced by a new connection.
+                    while let Some(Command::Send(_) | Command::Stall) = outgoing.recv().await {}
+                    break;
+                }
+            },
+            message = stream.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let Ok(frame) = Frame::parse(&text) else {
+                        continue;
+                    };
+                    let reply = {
+                        let mut state = state.lock().unwrap();
+                        let reply = frame.method.as_deref().zip(frame.id.as_deref()).map(|(method, id)| state.reply(method, id));
+                        state.received.push(frame);
+                        reply
+                    };
+                    if let Some(reply) = reply {
+                        if sink.send(Message::text(reply.to_text())).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+                Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::Links;
+
+/// A hosted AI agent, as returned by the Fabric resources API.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiAgentResponse {
+    pub id: String,
+    pub project_id: Option<String>,
+    pub display_name: Option<String>,
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub ai_agent: AiAgent,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiAgent {
+    pub agent_id: Option<String>,
+    pub name: String,
+    /// The prompt, voice, functions and post-prompt webhook, as in the SWML `ai` instruction.
+    #[serde(flatten)]
+    pub config: crate::swml::Ai,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiAgentsListResponse {
+    pub data: Vec<AiAgentResponse>,
+    pub links: Links,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiAgentRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub config: Option<crate::swml::Ai>,
+}
@@ -0,0 +1,7 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JwtResponse {
+    pub jwt_token: String,
+    pub refresh_token: String,
+}
@@ -0,0 +1,274 @@
+use std::fmt;
+
+use serde_derive::{Deserialize, Serialize};
+
+use super::{AccountSid, CallSid};
+use crate::{
+    errors::ValidationError,
+    validation::{is_sip_uri, validate_call},
+    webhook::CallStatus,
+};
+
+/// A call to place with `SignalWireClient::create_call`, to a phone number or a SIP URI.
+#[derive(Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewCall {
+    pub from: String,
+    /// An E.164 number, or a `sip:` or `sips:` URI to bridge the call into a PBX or trunk.
+    pub to: String,
+    /// Where the LaML of the call is fetched from once answered.
+    pub url: Option<String>,
+    /// The LaML of the call, instead of a `url`.
+    pub laml: Option<String>,
+    pub status_callback: Option<String>,
+    /// How long to let the call ring, in seconds.
+    pub timeout: Option<u32>,
+    /// The `X-` headers sent along with the SIP `INVITE`, only to a SIP URI.
+    #[serde(default)]
+    pub sip_headers: Vec<(String, String)>,
+    /// The credentials to answer the SIP destination's authentication challenge with, only to a SIP URI.
+    pub sip_auth_username: Option<String>,
+    pub sip_auth_password: Option<String>,
+}
+
+impl NewCall {
+    /// Builds a call checked with `validate_call`. Build the struct directly to skip the checks.
+    pub fn builder() -> NewCallBuilder {
+        NewCallBuilder::default()
+    }
+
+    /// Whether the call is to a SIP URI.
+    pub fn is_sip(&self) -> bool {
+        is_sip_uri(&self.to)
+    }
+
+    /// The form the call is created with, the SIP headers appended to the `To` URI.
+    pub(crate) fn form(&self) -> Vec<(&'static str, String)> {
+        let mut to = self.to.clone();
+        for (index, (name, value)) in self.sip_headers.iter().enumerate() {
+            to.push(if index == 0 { '?' } else { '&' });
+            to.push_str(&escape_header(name));
+            to.push('=');
+            to.push_str(&escape_header(value));
+        }
+
+        let mut form = vec![("From", self.from.clone()), ("To", to)];
+        let optional = [
+            ("Url", self.url.clone()),
+            ("Twiml", self.laml.clone()),
+            ("StatusCallback", self.status_callback.clone()),
+            ("Timeout", self.timeout.map(|timeout| timeout.to_string())),
+            ("SipAuthUsername", self.sip_auth_username.clone()),
+            ("SipAuthPassword", self.sip_auth_password.clone()),
+        ];
+        form.extend(optional.into_iter().filter_map(|(name, value)| value.map(|value| (name, value))));
+        form
+    }
+}
+
+impl fmt::Debug for NewCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Keeps the SIP password out of logs
+        f.debug_struct("NewCall")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("url", &self.url)
+            .field("laml", &self.laml)
+            .field("status_callback", &self.status_callback)
+            .field("timeout", &self.timeout)
+            .field("sip_headers", &self.sip_headers)
+            .field("sip_auth_username", &self.sip_auth_username)
+            .field("sip_auth_password", &self.sip_auth_password.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+/// Percent-encodes everything but the unreserved characters of a SIP URI header (RFC 3261).
+fn escape_header(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'!' | b'~' | b'*' | b'\'' | b'(' | b')' => escaped.push(byte as char),
+            _ => escaped.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    escaped
+}
+
+/// Builds a `NewCall`, see `NewCall::builder`.
+#[derive(Default)]
+pub struct NewCallBuilder {
+    call: NewCall,
+}
+
+impl NewCallBuilder {
+    /// The E.164 number the call is from.
+    pub fn from(mut self, from: &str) -> Self {
+        self.call.from = from.to_string();
+        self
+    }
+
+    /// The E.164 number or SIP URI to call, e.g. `sip:alice@pbx.example.com`.
+    pub fn to(mut self, to: &str) -> Self {
+        self.call.to = to.to_string();
+        self
+    }
+
+    pub fn url(mut self, url: &str) -> Self {
+        self.call.url = Some(url.to_string());
+        self
+    }
+
+    pub fn laml(mut self, laml: &str) -> Self {
+        self.call.laml = Some(laml.to_string());
+        self
+    }
+
+    pub fn status_callback(mut self, url: &str) -> Self {
+        self.call.status_callback = Some(url.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, seconds: u32) -> Self {
+        self.call.timeout = Some(seconds);
+        self
+    }
+
+    /// Adds a header to the SIP `INVITE`, its name starting with `X-`.
+    pub fn sip_header(mut self, name: &str, value: &str) -> Self {
+        self.call.sip_headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn sip_auth(mut self, username: &str, password: &str) -> Self {
+        self.call.sip_auth_username = Some(username.to_string());
+        self.call.sip_auth_password = Some(password.to_string());
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns the `ValidationError` of `validate_call`.
+    pub fn build(self) -> Result<NewCall, ValidationError> {
+        validate_call(&self.call)?;
+        Ok(self.call)
+    }
+}
+
+/// A call of the Compatibility API, as listed by `SignalWireClient::list_calls`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallResponse {
+    pub sid: CallSid,
+    pub account_sid: AccountSid,
+    /// The call that created this one, e.g. with `<Dial>`.
+    pub parent_call_sid: Option<CallSid>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub to: String,
+    pub from: String,
+    pub phone_number_sid: Option<String>,
+    pub status: String,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    /// In seconds, as a string.
+    pub duration: Option<String>,
+    pub price: Option<f64>,
+    pub price_unit: Option<String>,
+    pub direction: String,
+    pub answered_by: Option<String>,
+    pub forwarded_from: Option<String>,
+    pub caller_name: Option<String>,
+    pub uri: Option<String>,
+}
+
+impl CallResponse {
+    pub fn get_status(&self) -> CallStatus {
+        CallStatus::from(self.status.as_str())
+    }
+
+    /// Whether the call has ended, see `CallStatus::is_final`.
+    pub fn is_final(&self) -> bool {
+        self.get_status().is_final()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallsListResponse {
+    pub uri: Option<String>,
+    pub first_page_uri: String,
+    pub next_page_uri: Option<String>,
+    pub previous_page_uri: Option<String>,
+    pub page: Option<i32>,
+    pub page_size: Option<i32>,
+    pub calls: Vec<CallResponse>,
+}
+
+/// The filters of `SignalWireClient::list_calls`.
+///
+/// Calls always come newest first: the Compatibility API has no sort parameter.
+#[derive(Default)]
+pub struct CallListParams {
+    params: Vec<(String, String)>,
+}
+
+impl CallListParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn to(mut self, to: &str) -> Self {
+        self.params.push(("To".to_string(), to.to_string()));
+        self
+    }
+
+    pub fn from(mut self, from: &str) -> Self {
+        self.params.push(("From".to_string(), from.to_string()));
+        self
+    }
+
+    pub fn status(mut self, status: CallStatus) -> Self {
+        self.params.push(("Status".to_string(), status.to_string()));
+        self
+    }
+
+    /// Only the calls created by `parent_call_sid`, e.g. the legs it dialed.
+    pub fn parent_call_sid(mut self, parent_call_sid: &CallSid) -> Self {
+        self.params.push(("ParentCallSid".to_string(), parent_call_sid.to_string()));
+        self
+    }
+
+    /// Only the calls started on `date`.
+    #[cfg(any(test, feature = "chrono"))]
+    pub fn start_time(mut self, date: chrono::NaiveDate) -> Self {
+        self.params.push(("StartTime".to_string(), date.format("%Y-%m-%d").to_string()));
+        self
+    }
+
+    /// Only the calls started on or before `date`.
+    #[cfg(any(test, feature = "chrono"))]
+    pub fn start_time_before(mut self, date: chrono::NaiveDate) -> Self {
+        self.params.push(("StartTime<".to_string(), date.format("%Y-%m-%d").to_string()));
+        self
+    }
+
+    /// Only the calls started on or after `date`.
+    #[cfg(any(test, feature = "chrono"))]
+    pub fn start_time_after(mut self, date: chrono::NaiveDate) -> Self {
+        self.params.push(("StartTime>".to_string(), date.format("%Y-%m-%d").to_string()));
+        self
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.params.push(("PageSize".to_string(), page_size.to_string()));
+        self
+    }
+
+    /// The page to start from, counting from 0. The following pages are linked by `next_page_uri`, see `Page`.
+    pub fn page(mut self, page: u32) -> Self {
+        self.params.push(("Page".to_string(), page.to_string()));
+        self
+    }
+
+    pub fn build(self) -> Vec<(String, String)> {
+        self.params
+    }
+}
@@ -0,0 +1,35 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// What a chat member can do on a channel.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatChannelPermissions {
+    pub read: bool,
+    pub write: bool,
+}
+
+impl ChatChannelPermissions {
+    pub fn read_only() -> Self {
+        ChatChannelPermissions { read: true, write: false }
+    }
+
+    pub fn read_write() -> Self {
+        ChatChannelPermissions { read: true, write: true }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatTokenRequest {
+    /// In minutes.
+    pub ttl: u32,
+    pub channels: std::collections::BTreeMap<String, ChatChannelPermissions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<serde_json::Value>,
+}
+
+/// A token for a browser to join chat channels with.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatTokenResponse {
+    pub token: String,
+}
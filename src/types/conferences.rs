@@ -0,0 +1,244 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::{AccountSid, CallSid, ConferenceSid, RecordingSid};
+
+/// A recording of a conference of the Compatibility API, as opposed to the recording of a single call.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConferenceRecording {
+    pub sid: RecordingSid,
+    pub account_sid: AccountSid,
+    pub conference_sid: ConferenceSid,
+    pub call_sid: Option<CallSid>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub start_time: Option<String>,
+    /// In seconds, as a string, `-1` while recording.
+    pub duration: Option<String>,
+    pub channels: Option<u32>,
+    /// What started the recording, e.g. `StartConferenceRecordingAPI` or `DialVerb`.
+    pub source: Option<String>,
+    pub status: String,
+    pub error_code: Option<String>,
+    pub price: Option<f64>,
+    pub price_unit: Option<String>,
+    pub uri: Option<String>,
+}
+
+impl ConferenceRecording {
+    pub fn get_status(&self) -> ConferenceRecordingStatus {
+        ConferenceRecordingStatus::from(self.status.as_str())
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConferenceRecordingsListResponse {
+    pub uri: Option<String>,
+    pub first_page_uri: String,
+    pub next_page_uri: Option<String>,
+    pub previous_page_uri: Option<String>,
+    pub page: Option<i32>,
+    pub page_size: Option<i32>,
+    pub recordings: Vec<ConferenceRecording>,
+}
+
+// Conference recording status values according to the Compatibility API
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ConferenceRecordingStatus {
+    InProgress,      // The conference is being recorded
+    Paused,          // The recording is paused, and can be resumed
+    Stopped,         // The recording was stopped, and is being processed
+    Processing,      // The recording is being processed
+    Completed,       // The recording is finished and can be downloaded
+    Absent,          // Nothing was recorded
+    Failed,          // The recording failed
+    Unknown(String), // A status this version doesn't know, as received
+}
+
+impl From<&str> for ConferenceRecordingStatus {
+    fn from(status: &str) -> Self {
+        match status.to_lowercase().as_str() {
+            "in-progress" => ConferenceRecordingStatus::InProgress,
+            "paused" => ConferenceRecordingStatus::Paused,
+            "stopped" => ConferenceRecordingStatus::Stopped,
+            "processing" => ConferenceRecordingStatus::Processing,
+            "completed" => ConferenceRecordingStatus::Completed,
+            "absent" => ConferenceRecordingStatus::Absent,
+            "failed" => ConferenceRecordingStatus::Failed,
+            _ => ConferenceRecordingStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for ConferenceRecordingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConferenceRecordingStatus::InProgress => write!(f, "in-progress"),
+            ConferenceRecordingStatus::Paused => write!(f, "paused"),
+            ConferenceRecordingStatus::Stopped => write!(f, "stopped"),
+            ConferenceRecordingStatus::Processing => write!(f, "processing"),
+            ConferenceRecordingStatus::Completed => write!(f, "completed"),
+            ConferenceRecordingStatus::Absent => write!(f, "absent"),
+            ConferenceRecordingStatus::Failed => write!(f, "failed"),
+            ConferenceRecordingStatus::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// A participant of a conference: one of the calls it bridges.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConferenceParticipant {
+    pub account_sid: AccountSid,
+    pub call_sid: CallSid,
+    pub conference_sid: ConferenceSid,
+    pub label: Option<String>,
+    /// Only this call hears the participant, while `coaching`.
+    pub call_sid_to_coach: Option<CallSid>,
+    #[serde(default)]
+    pub coaching: bool,
+    #[serde(default)]
+    pub muted: bool,
+    #[serde(default)]
+    pub hold: bool,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    /// E.g. `connected` or `complete`.
+    pub status: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConferenceParticipantsListResponse {
+    pub uri: Option<String>,
+    pub first_page_uri: String,
+    pub next_page_uri: Option<String>,
+    pub previous_page_uri: Option<String>,
+    pub page: Option<i32>,
+    pub page_size: Option<i32>,
+    pub participants: Vec<ConferenceParticipant>,
+}
+
+/// The changes to a participant of `SignalWireClient::update_conference_participant`; what isn't set isn't changed.
+///
+/// A supervisor joining an agent's conference can listen in (`monitor`), whisper to the agent alone (`coach`), then
+/// take part in the call (`barge`):
+///
+/// ```rust
+/// use signalwire::types::{CallSid, ParticipantUpdate};
+///
+/// let agent: CallSid = "CA0123456789abcdef0123456789abcdef".parse()?;
+/// let update = ParticipantUpdate::coach(&agent);
+/// assert_eq!(
+///     update.form(),
+///     [
+///         ("Coaching", "true".to_string()),
+///         ("CallSidToCoach", agent.to_string())
+///     ]
+/// );
+/// # Ok::<(), signalwire::errors::ValidationError>(())
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ParticipantUpdate {
+    muted: Option<bool>,
+    hold: Option<bool>,
+    hold_url: Option<String>,
+    announce_url: Option<String>,
+    end_conference_on_exit: Option<bool>,
+    coaching: Option<bool>,
+    call_sid_to_coach: Option<CallSid>,
+}
+
+impl ParticipantUpdate {
+    pub fn new() -> Self {
+        ParticipantUpdate::default()
+    }
+
+    /// Heard by the call `call_sid` alone, as a supervisor whispering to an agent.
+    pub fn coach(call_sid: &CallSid) -> Self {
+        ParticipantUpdate {
+            coaching: Some(true),
+            call_sid_to_coach: Some(call_sid.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Heard by everyone, as a supervisor taking over a call, unmuted and no longer coaching.
+    pub fn barge() -> Self {
+        ParticipantUpdate {
+            muted: Some(false),
+            coaching: Some(false),
+            ..Default::default()
+        }
+    }
+
+    /// Heard by no one, as a supervisor listening in.
+    pub fn monitor() -> Self {
+        ParticipantUpdate {
+            muted: Some(true),
+            coaching: Some(false),
+            ..Default::default()
+        }
+    }
+
+    pub fn muted(mut self, muted: bool) -> Self {
+        self.muted = Some(muted);
+        self
+    }
+
+    /// Puts the participant on hold, or back into the conference.
+    pub fn hold(mut self, hold: bool) -> Self {
+        self.hold = Some(hold);
+        self
+    }
+
+    /// The LaML or audio played to the participant while on hold.
+    pub fn hold_url(mut self, url: &str) -> Self {
+        self.hold_url = Some(url.to_string());
+        self
+    }
+
+    /// The LaML or audio played to the participant alone, right away.
+    pub fn announce_url(mut self, url: &str) -> Self {
+        self.announce_url = Some(url.to_string());
+        self
+    }
+
+    pub fn end_conference_on_exit(mut self, end: bool) -> Self {
+        self.end_conference_on_exit = Some(end);
+        self
+    }
+
+    /// The form the participant is updated with.
+    pub fn form(&self) -> Vec<(&'static str, String)> {
+        let fields = [
+            ("Muted", self.muted.map(|muted| muted.to_string())),
+            ("Hold", self.hold.map(|hold| hold.to_string())),
+            ("HoldUrl", self.hold_url.clone()),
+            ("AnnounceUrl", self.announce_url.clone()),
+            ("EndConferenceOnExit", self.end_conference_on_exit.map(|end| end.to_string())),
+            ("Coaching", self.coaching.map(|coaching| coaching.to_string())),
+            ("CallSidToCoach", self.call_sid_to_coach.as_ref().map(CallSid::to_string)),
+        ];
+        fields.into_iter().filter_map(|(name, value)| value.map(|value| (name, value))).collect()
+    }
+}
+
+/// The audio format of a recording download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RecordingFormat {
+    #[default]
+    Wav,
+    Mp3,
+}
+
+impl RecordingFormat {
+    /// The extension appended to the recording's path.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RecordingFormat::Wav => "wav",
+            RecordingFormat::Mp3 => "mp3",
+        }
+    }
+}
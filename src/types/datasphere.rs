@@ -0,0 +1,181 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::Links;
+
+/// How a Datasphere document is split into the chunks search results are made of.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChunkingStrategy {
+    /// Groups of sentences, also broken at new lines if `split_newlines` is set.
+    Sentence {
+        max_sentences_per_chunk: Option<u32>,
+        split_newlines: bool,
+    },
+    /// Windows of `chunk_size` words, overlapping by `overlap_size` words.
+    Sliding {
+        chunk_size: u32,
+        overlap_size: u32,
+    },
+    Paragraph,
+    Page,
+}
+
+impl Default for ChunkingStrategy {
+    fn default() -> Self {
+        ChunkingStrategy::Sentence {
+            max_sentences_per_chunk: None,
+            split_newlines: false,
+        }
+    }
+}
+
+impl ChunkingStrategy {
+    /// The form fields describing the strategy.
+    #[cfg(any(test, feature = "reqwest"))]
+    pub(crate) fn fields(&self) -> Vec<(&'static str, String)> {
+        match self {
+            ChunkingStrategy::Sentence { max_sentences_per_chunk, split_newlines } => {
+                let mut fields = vec![("chunking_strategy", "sentence".to_string()), ("split_newlines", split_newlines.to_string())];
+                if let Some(max_sentences_per_chunk) = max_sentences_per_chunk {
+                    fields.push(("max_sentences_per_chunk", max_sentences_per_chunk.to_string()));
+                }
+                fields
+            }
+            ChunkingStrategy::Sliding { chunk_size, overlap_size } => vec![("chunking_strategy", "sliding".to_string()), ("chunk_size", chunk_size.to_string()), ("overlap_size", overlap_size.to_string())],
+            ChunkingStrategy::Paragraph => vec![("chunking_strategy", "paragraph".to_string())],
+            ChunkingStrategy::Page => vec![("chunking_strategy", "page".to_string())],
+        }
+    }
+}
+
+/// Options of `SignalWireClient::upload_datasphere_document`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct DatasphereUploadOptions {
+    pub chunking: ChunkingStrategy,
+    /// Searches can be restricted to documents with given tags.
+    pub tags: Vec<String>,
+}
+
+/// How much of an upload was handed to the connection so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    pub sent: u64,
+    pub total: u64,
+}
+
+/// A document of the Datasphere, the corpus searched by AI agents.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasphereDocument {
+    pub id: String,
+    pub filename: Option<String>,
+    pub status: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub chunking_strategy: Option<String>,
+    pub number_of_chunks: Option<u64>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl DatasphereDocument {
+    pub fn get_status(&self) -> DatasphereDocumentStatus {
+        DatasphereDocumentStatus::from(self.status.as_str())
+    }
+}
+
+// Datasphere document status values according to the Datasphere API
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DatasphereDocumentStatus {
+    Submitted,       // Uploaded, waiting to be processed
+    InProgress,      // Being split into chunks
+    Completed,       // Searchable
+    Failed,          // Could not be processed
+    Unknown(String), // A status this version doesn't know, as received
+}
+
+impl From<&str> for DatasphereDocumentStatus {
+    fn from(status: &str) -> Self {
+        match status.to_lowercase().as_str() {
+            "submitted" => DatasphereDocumentStatus::Submitted,
+            "in_progress" => DatasphereDocumentStatus::InProgress,
+            "completed" => DatasphereDocumentStatus::Completed,
+            "failed" => DatasphereDocumentStatus::Failed,
+            _ => DatasphereDocumentStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for DatasphereDocumentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DatasphereDocumentStatus::Submitted => write!(f, "submitted"),
+            DatasphereDocumentStatus::InProgress => write!(f, "in_progress"),
+            DatasphereDocumentStatus::Completed => write!(f, "completed"),
+            DatasphereDocumentStatus::Failed => write!(f, "failed"),
+            DatasphereDocumentStatus::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasphereDocumentsListResponse {
+    pub data: Vec<DatasphereDocument>,
+    pub links: Links,
+}
+
+/// A chunk of a Datasphere document, as made by its `ChunkingStrategy`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasphereChunk {
+    pub id: String,
+    pub datasphere_document_id: String,
+    pub status: Option<String>,
+    pub content: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasphereChunksListResponse {
+    pub data: Vec<DatasphereChunk>,
+    pub links: Links,
+}
+
+/// What a Datasphere search is restricted to. The default searches every document.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasphereSearchFilters {
+    /// Only this document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<String>,
+    /// Only documents with any of these tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// How many chunks to return at most.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    /// How far from the query a chunk can be, between 0 and 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasphereSearchRequest {
+    pub query_string: String,
+    #[serde(flatten)]
+    pub filters: DatasphereSearchFilters,
+}
+
+/// The chunks matching a query, most relevant first.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasphereSearchResponse {
+    pub chunks: Vec<DatasphereSearchResult>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasphereSearchResult {
+    pub text: String,
+    pub document_id: String,
+    /// How far the chunk is from the query: the lower, the more relevant.
+    pub distance: Option<f64>,
+}
@@ -0,0 +1,283 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::Links;
+
+/// A subscriber: a user who signs in to a calling app with an email and password, without a SIP endpoint.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricSubscriberResponse {
+    pub id: String,
+    pub project_id: Option<String>,
+    pub display_name: Option<String>,
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub subscriber: FabricSubscriber,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricSubscriber {
+    pub id: String,
+    pub email: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub display_name: Option<String>,
+    pub job_title: Option<String>,
+    pub timezone: Option<String>,
+    pub country: Option<String>,
+    pub region: Option<String>,
+    pub company_name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricSubscribersListResponse {
+    pub data: Vec<FabricSubscriberResponse>,
+    pub links: Links,
+}
+
+/// The fields of a subscriber to set. `email` is required on creation; fields left `None` are not changed.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricSubscriberRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Generated by SignalWire on creation if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+}
+
+// ---------- Fabric Address Types ----------
+
+/// A callable address of a Fabric resource, e.g. `/public/standup`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricAddress {
+    pub id: String,
+    pub resource_id: Option<String>,
+    pub name: String,
+    pub display_name: Option<String>,
+    #[serde(rename = "type")]
+    pub address_type: String,
+    pub cover_url: Option<String>,
+    pub preview_url: Option<String>,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub channels: FabricAddressChannels,
+    pub created_at: Option<String>,
+}
+
+impl FabricAddress {
+    pub fn get_type(&self) -> FabricAddressType {
+        FabricAddressType::from(self.address_type.as_str())
+    }
+}
+
+/// The URIs to dial an address on, per media.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricAddressChannels {
+    pub audio: Option<String>,
+    pub video: Option<String>,
+    pub messaging: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricAddressesListResponse {
+    pub data: Vec<FabricAddress>,
+    pub links: Links,
+}
+
+// Fabric address types according to the Fabric API
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum FabricAddressType {
+    App,             // A SWML script, AI agent or LaML application
+    Room,            // A video room
+    Subscriber,      // A subscriber
+    SipEndpoint,     // A SIP endpoint
+    Unknown(String), // A type this version doesn't know, as received
+}
+
+impl From<&str> for FabricAddressType {
+    fn from(address_type: &str) -> Self {
+        match address_type.to_lowercase().as_str() {
+            "app" => FabricAddressType::App,
+            "room" => FabricAddressType::Room,
+            "subscriber" => FabricAddressType::Subscriber,
+            "sip_endpoint" => FabricAddressType::SipEndpoint,
+            _ => FabricAddressType::Unknown(address_type.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for FabricAddressType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FabricAddressType::App => write!(f, "app"),
+            FabricAddressType::Room => write!(f, "room"),
+            FabricAddressType::Subscriber => write!(f, "subscriber"),
+            FabricAddressType::SipEndpoint => write!(f, "sip_endpoint"),
+            FabricAddressType::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+// ---------- Fabric Resource Types ----------
+
+/// A Fabric resource of any type: the building block calls, rooms and subscribers are routed to.
+///
+/// The fields specific to the type are kept as they are, see `details`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricResource {
+    pub id: String,
+    pub project_id: Option<String>,
+    pub display_name: Option<String>,
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl FabricResource {
+    pub fn get_type(&self) -> FabricResourceType {
+        FabricResourceType::from(self.resource_type.as_str())
+    }
+
+    /// The fields specific to the type, e.g. the `swml_script` object of a SWML script.
+    pub fn details(&self) -> Option<&serde_json::Value> {
+        self.extra.get(&self.resource_type)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricResourcesListResponse {
+    pub data: Vec<FabricResource>,
+    pub links: Links,
+}
+
+// Fabric resource types according to the Fabric API
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum FabricResourceType {
+    SwmlScript,      // A hosted SWML script
+    AiAgent,         // A hosted AI agent
+    LamlApplication, // A LaML (cXML) application
+    VideoRoom,       // A video room
+    Subscriber,      // A subscriber
+    SipEndpoint,     // A SIP endpoint
+    Unknown(String), // A type this version doesn't know, as received
+}
+
+impl FabricResourceType {
+    /// The path segment resources of this type are created under, `None` for `Unknown`.
+    pub(crate) fn collection(&self) -> Option<&'static str> {
+        match self {
+            FabricResourceType::SwmlScript => Some("swml_scripts"),
+            FabricResourceType::AiAgent => Some("ai_agents"),
+            FabricResourceType::LamlApplication => Some("laml_applications"),
+            FabricResourceType::VideoRoom => Some("video_rooms"),
+            FabricResourceType::Subscriber => Some("subscribers"),
+            FabricResourceType::SipEndpoint => Some("sip_endpoints"),
+            FabricResourceType::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<&str> for FabricResourceType {
+    fn from(resource_type: &str) -> Self {
+        match resource_type.to_lowercase().as_str() {
+            "swml_script" => FabricResourceType::SwmlScript,
+            "ai_agent" => FabricResourceType::AiAgent,
+            "laml_application" => FabricResourceType::LamlApplication,
+            "video_room" => FabricResourceType::VideoRoom,
+            "subscriber" => FabricResourceType::Subscriber,
+            "sip_endpoint" => FabricResourceType::SipEndpoint,
+            _ => FabricResourceType::Unknown(resource_type.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for FabricResourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FabricResourceType::SwmlScript => write!(f, "swml_script"),
+            FabricResourceType::AiAgent => write!(f, "ai_agent"),
+            FabricResourceType::LamlApplication => write!(f, "laml_application"),
+            FabricResourceType::VideoRoom => write!(f, "video_room"),
+            FabricResourceType::Subscriber => write!(f, "subscriber"),
+            FabricResourceType::SipEndpoint => write!(f, "sip_endpoint"),
+            FabricResourceType::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+// What a phone number routes to a resource
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FabricHandler {
+    Calling,   // Inbound calls
+    Messaging, // Inbound messages
+}
+
+impl std::fmt::Display for FabricHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FabricHandler::Calling => write!(f, "calling"),
+            FabricHandler::Messaging => write!(f, "messaging"),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricPhoneRouteRequest {
+    pub phone_number_id: String,
+    pub handler: String,
+}
+
+// ---------- Fabric Token Types ----------
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricSubscriberTokenRequest {
+    /// The email or ID of the subscriber.
+    pub reference: String,
+    /// When the token expires, as a Unix timestamp.
+    pub expire_at: i64,
+}
+
+/// A token for a subscriber to register a web or mobile client with.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricSubscriberTokenResponse {
+    pub token: String,
+    /// Exchanged for a new token before this one expires, by the client SDKs.
+    pub refresh_token: Option<String>,
+    pub subscriber_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricGuestTokenRequest {
+    /// The IDs of the addresses the guest can call.
+    pub allowed_addresses: Vec<String>,
+    /// When the token expires, as a Unix timestamp.
+    pub expire_at: i64,
+}
+
+/// A token for a guest, who can only call the addresses it was scoped to.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FabricGuestTokenResponse {
+    pub token: String,
+}
@@ -0,0 +1,120 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::Links;
+
+/// Filters and cursor of the space logs. The default lists the most recent entries.
+///
+/// Entries always come newest first: the logs API has no sort parameter.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct LogQuery {
+    #[cfg(any(test, feature = "chrono"))]
+    pub created_after: Option<chrono::DateTime<chrono::Utc>>,
+    #[cfg(any(test, feature = "chrono"))]
+    pub created_before: Option<chrono::DateTime<chrono::Utc>>,
+    pub include_deleted: bool,
+    pub page_size: Option<u32>,
+    /// Where to resume from, see `Links::next_page_token`.
+    pub page_token: Option<String>,
+}
+
+impl LogQuery {
+    /// The entries created between `after` and `before`.
+    #[cfg(any(test, feature = "chrono"))]
+    pub fn between(after: chrono::DateTime<chrono::Utc>, before: chrono::DateTime<chrono::Utc>) -> Self {
+        LogQuery {
+            created_after: Some(after),
+            created_before: Some(before),
+            ..Default::default()
+        }
+    }
+
+    /// The same query, resumed at the page after `links`, or `None` on the last page.
+    pub fn next_page(&self, links: &Links) -> Option<Self> {
+        links.next_page_token().map(|page_token| LogQuery {
+            page_token: Some(page_token),
+            ..self.clone()
+        })
+    }
+
+    pub fn query_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        #[cfg(any(test, feature = "chrono"))]
+        if let Some(created_after) = self.created_after {
+            params.push(("created_after".to_string(), created_after.to_rfc3339()));
+        }
+        #[cfg(any(test, feature = "chrono"))]
+        if let Some(created_before) = self.created_before {
+            params.push(("created_before".to_string(), created_before.to_rfc3339()));
+        }
+        if self.include_deleted {
+            params.push(("include_deleted".to_string(), "true".to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            params.push(("page_size".to_string(), page_size.to_string()));
+        }
+        if let Some(page_token) = &self.page_token {
+            params.push(("page_token".to_string(), page_token.clone()));
+        }
+        params
+    }
+}
+
+/// A line of the bill of a log entry.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogChargeDetail {
+    pub description: String,
+    pub charge: f64,
+}
+
+/// A message, as logged for the whole space (Compatibility, Relay and Fabric alike).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageLog {
+    pub id: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub direction: Option<String>,
+    pub status: Option<String>,
+    /// `sms` or `mms`.
+    pub kind: Option<String>,
+    /// The API the message went through, e.g. `laml` or `relay`.
+    pub source: Option<String>,
+    pub segments: Option<u32>,
+    /// In USD.
+    pub charge: Option<f64>,
+    #[serde(default)]
+    pub charge_details: Vec<LogChargeDetail>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageLogsListResponse {
+    pub data: Vec<MessageLog>,
+    pub links: Links,
+}
+
+/// A call, as logged for the whole space (Compatibility, Relay and Fabric alike).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoiceLog {
+    pub id: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub direction: Option<String>,
+    pub status: Option<String>,
+    /// The API the call went through, e.g. `laml` or `relay`.
+    pub source: Option<String>,
+    /// In seconds.
+    pub duration: Option<u64>,
+    /// The billed duration, in milliseconds.
+    pub billing_ms: Option<u64>,
+    /// In USD.
+    pub charge: Option<f64>,
+    #[serde(default)]
+    pub charge_details: Vec<LogChargeDetail>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoiceLogsListResponse {
+    pub data: Vec<VoiceLog>,
+    pub links: Links,
+}
@@ -0,0 +1,281 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::{AccountSid, MessageSid};
+use crate::{errors::ValidationError, validation::validate_sms_message};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmsMessage {
+    pub body: String,
+    /// The E.164 number the message is sent from, left empty to send from `number_group_id`.
+    pub from: String,
+    pub to: String,
+    /// The number group (messaging profile) SignalWire picks the sender from, instead of `from`. This is how
+    /// high-throughput traffic is meant to be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_group_id: Option<String>,
+}
+
+impl SmsMessage {
+    /// Builds a message checked with `validate_sms_message`. Build the struct directly to skip the checks.
+    pub fn builder() -> SmsMessageBuilder {
+        SmsMessageBuilder::default()
+    }
+}
+
+/// Builds an `SmsMessage`, see `SmsMessage::builder`.
+#[derive(Default)]
+pub struct SmsMessageBuilder {
+    message: SmsMessage,
+}
+
+impl SmsMessageBuilder {
+    /// The E.164 number the message is sent from.
+    pub fn from(mut self, from: &str) -> Self {
+        self.message.from = from.to_string();
+        self
+    }
+
+    /// Lets SignalWire pick the sender among the numbers of the number group `number_group_id`, rather than sending
+    /// from a fixed number.
+    pub fn number_group(mut self, number_group_id: &str) -> Self {
+        self.message.number_group_id = Some(number_group_id.to_string());
+        self
+    }
+
+    /// The E.164 number the message is sent to.
+    pub fn to(mut self, to: &str) -> Self {
+        self.message.to = to.to_string();
+        self
+    }
+
+    pub fn body(mut self, body: &str) -> Self {
+        self.message.body = body.to_string();
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns the `ValidationError` of `validate_sms_message`: a missing or malformed number, both a number and a
+    /// number group to send from, an empty body or one longer than `MAX_SMS_BODY_LENGTH`.
+    pub fn build(self) -> Result<SmsMessage, ValidationError> {
+        validate_sms_message(&self.message)?;
+        Ok(self.message)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmsResponse {
+    pub sid: MessageSid,
+    pub date_created: String,
+    pub date_updated: String,
+    pub date_sent: Option<String>,
+    pub account_sid: AccountSid,
+    pub to: String,
+    pub from: String,
+    pub messaging_service_sid: Option<String>,
+    pub body: String,
+    pub status: String,
+    pub num_segments: i32,
+    pub num_media: i32,
+    pub direction: String,
+    pub api_version: String,
+    pub price: Option<f64>,
+    pub price_unit: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub uri: String,
+    #[serde(default)]
+    pub subresource_uris: SubresourceUris,
+}
+
+impl SmsResponse {
+    /// Get the message status as an enum value.
+    ///
+    /// This method converts the string status field to a more
+    /// programmer-friendly enum variant.
+    ///
+    /// # Returns
+    ///
+    /// A `MessageStatus` enum representing the current status of the message.
+    pub fn get_status(&self) -> MessageStatus {
+        MessageStatus::from(self.status.as_str())
+    }
+
+    /// Whether the carrier confirmed delivery to the recipient.
+    pub fn is_delivered(&self) -> bool {
+        self.get_status() == MessageStatus::Delivered
+    }
+
+    /// Whether the message failed to be sent or wasn't delivered.
+    pub fn has_failed(&self) -> bool {
+        self.get_status().is_failure()
+    }
+
+    /// Whether no further status changes will follow, see `MessageStatus::is_final`.
+    pub fn is_final(&self) -> bool {
+        self.get_status().is_final()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubresourceUris {
+    #[serde(default)]
+    pub media: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessagesListResponse {
+    pub uri: Option<String>,
+    pub first_page_uri: String,
+    pub next_page_uri: Option<String>,
+    pub previous_page_uri: Option<String>,
+    pub page: Option<i32>,
+    pub page_size: Option<i32>,
+    pub messages: Vec<SmsResponse>,
+}
+
+/// The filters of `SignalWireClient::list_messages`.
+///
+/// Messages always come newest first: the Compatibility API has no sort parameter.
+#[derive(Default)]
+pub struct MessageListParams {
+    params: Vec<(String, String)>,
+}
+
+impl MessageListParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn to(mut self, to: &str) -> Self {
+        self.params.push(("To".to_string(), to.to_string()));
+        self
+    }
+
+    pub fn from(mut self, from: &str) -> Self {
+        self.params.push(("From".to_string(), from.to_string()));
+        self
+    }
+
+    /// Only the messages sent on `date`.
+    #[cfg(any(test, feature = "chrono"))]
+    pub fn date_sent(mut self, date: chrono::NaiveDate) -> Self {
+        self.params.push(("DateSent".to_string(), date.format("%Y-%m-%d").to_string()));
+        self
+    }
+
+    /// Only the messages sent on or before `date`.
+    #[cfg(any(test, feature = "chrono"))]
+    pub fn date_sent_before(mut self, date: chrono::NaiveDate) -> Self {
+        self.params.push(("DateSent<".to_string(), date.format("%Y-%m-%d").to_string()));
+        self
+    }
+
+    /// Only the messages sent on or after `date`.
+    #[cfg(any(test, feature = "chrono"))]
+    pub fn date_sent_after(mut self, date: chrono::NaiveDate) -> Self {
+        self.params.push(("DateSent>".to_string(), date.format("%Y-%m-%d").to_string()));
+        self
+    }
+
+    pub fn status(mut self, status: MessageStatus) -> Self {
+        self.params.push(("Status".to_string(), status.to_string()));
+        self
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.params.push(("PageSize".to_string(), page_size.to_string()));
+        self
+    }
+
+    /// The page to start from, counting from 0. The following pages are linked by `next_page_uri`, see `Page`.
+    pub fn page(mut self, page: u32) -> Self {
+        self.params.push(("Page".to_string(), page.to_string()));
+        self
+    }
+
+    pub fn build(self) -> Vec<(String, String)> {
+        self.params
+    }
+}
+
+// Message status values according to SignalWire API
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum MessageStatus {
+    Queued,          // The message is queued and waiting to be sent
+    Initiated,       // The message was accepted by Relay and is about to be sent
+    Sending,         // The message is in the process of being sent
+    Sent,            // The message has been sent to the carrier
+    Delivered,       // The message has been delivered to the recipient
+    Failed,          // The message failed to be sent
+    Undelivered,     // The message was sent but not delivered
+    Received,        // The message is inbound and was received
+    Unknown(String), // A status this version doesn't know, as received
+}
+
+impl From<&str> for MessageStatus {
+    fn from(status: &str) -> Self {
+        match status.to_lowercase().as_str() {
+            "queued" => MessageStatus::Queued,
+            "initiated" => MessageStatus::Initiated,
+            "sending" => MessageStatus::Sending,
+            "sent" => MessageStatus::Sent,
+            "delivered" => MessageStatus::Delivered,
+            "failed" => MessageStatus::Failed,
+            "undelivered" => MessageStatus::Undelivered,
+            "received" => MessageStatus::Received,
+            _ => MessageStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl From<String> for MessageStatus {
+    fn from(status: String) -> Self {
+        MessageStatus::from(status.as_str())
+    }
+}
+
+impl From<MessageStatus> for String {
+    fn from(status: MessageStatus) -> Self {
+        status.to_string()
+    }
+}
+
+// Never fails, statuses this version doesn't know become `Unknown`
+impl std::str::FromStr for MessageStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(MessageStatus::from(s))
+    }
+}
+
+impl MessageStatus {
+    /// Whether no further status changes will follow. Received messages are final too.
+    pub fn is_final(&self) -> bool {
+        matches!(self, MessageStatus::Delivered | MessageStatus::Undelivered | MessageStatus::Failed | MessageStatus::Received)
+    }
+
+    /// Whether the message failed to be sent or wasn't delivered.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, MessageStatus::Failed | MessageStatus::Undelivered)
+    }
+}
+
+impl std::fmt::Display for MessageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessageStatus::Queued => write!(f, "queued"),
+            MessageStatus::Initiated => write!(f, "initiated"),
+            MessageStatus::Sending => write!(f, "sending"),
+            MessageStatus::Sent => write!(f, "sent"),
+            MessageStatus::Delivered => write!(f, "delivered"),
+            MessageStatus::Failed => write!(f, "failed"),
+            MessageStatus::Undelivered => write!(f, "undelivered"),
+            MessageStatus::Received => write!(f, "received"),
+            MessageStatus::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
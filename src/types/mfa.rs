@@ -0,0 +1,59 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Options of a one-time code sent with `SignalWireClient::mfa_send_sms`. The default lets SignalWire decide.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MfaOptions {
+    /// The number the code is sent from, one of the space's.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// The text sent before the code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_length: Option<u8>,
+    /// How many wrong codes are accepted before the request fails.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+    /// Whether the code can contain letters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_alphas: Option<bool>,
+    /// In seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_for: Option<u32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MfaRequest {
+    pub to: String,
+    #[serde(flatten)]
+    pub options: MfaOptions,
+}
+
+/// A one-time code sent, to be checked with `SignalWireClient::mfa_verify`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MfaResponse {
+    /// The ID of the request, to verify the code against.
+    pub id: String,
+    pub success: bool,
+    pub to: String,
+    /// `sms` or `call`.
+    pub channel: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MfaVerifyRequest {
+    pub token: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MfaVerifyResponse {
+    pub success: bool,
+}
+
+// The outcome of checking a one-time code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MfaVerification {
+    Verified, // The code is right
+    Failed,   // The code is wrong, another can be tried until `max_attempts`
+    Expired,  // The request expired or ran out of attempts, a new code must be sent
+}
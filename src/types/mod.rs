@@ -0,0 +1,69 @@
+//! Request and response types of the REST APIs, grouped by domain.
+//!
+//! Everything is re-exported here, so `types::SmsMessage` and `types::messaging::SmsMessage` name the same type.
+//! The payloads of incoming webhooks live in `crate::webhook`.
+
+// `supports_voice`, `supports_sms`, ... on the types with a `supports` method
+macro_rules! impl_capability_helpers {
+    ($($number:ident),*) => {
+        $(
+            impl $number {
+                pub fn supports_voice(&self) -> bool {
+                    self.supports($crate::types::NumberCapability::Voice)
+                }
+
+                pub fn supports_sms(&self) -> bool {
+                    self.supports($crate::types::NumberCapability::Sms)
+                }
+
+                pub fn supports_mms(&self) -> bool {
+                    self.supports($crate::types::NumberCapability::Mms)
+                }
+
+                pub fn supports_fax(&self) -> bool {
+                    self.supports($crate::types::NumberCapability::Fax)
+                }
+            }
+        )*
+    };
+}
+
+pub mod ai_agents;
+pub mod auth;
+pub mod calls;
+pub mod chat;
+pub mod conferences;
+pub mod datasphere;
+pub mod fabric;
+pub mod logs;
+pub mod lookup;
+pub mod messaging;
+pub mod mfa;
+pub mod numbers;
+pub mod response;
+pub mod short_codes;
+pub mod sids;
+pub mod sip;
+pub mod subprojects;
+pub mod swml_scripts;
+pub mod video;
+
+pub use ai_agents::*;
+pub use auth::*;
+pub use calls::*;
+pub use chat::*;
+pub use conferences::*;
+pub use datasphere::*;
+pub use fabric::*;
+pub use logs::*;
+pub use lookup::*;
+pub use messaging::*;
+pub use mfa::*;
+pub use numbers::*;
+pub use response::*;
+pub use short_codes::*;
+pub use sids::*;
+pub use sip::*;
+pub use subprojects::*;
+pub use swml_scripts::*;
+pub use video::*;
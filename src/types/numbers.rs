@@ -0,0 +1,362 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::{Links, PhoneNumberId};
+
+#[derive(Default)]
+pub struct PhoneNumberAvailableQueryParams {
+    params: Vec<(String, String)>,
+}
+
+impl PhoneNumberAvailableQueryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn area_code(mut self, code: &str) -> Self {
+        self.params.push(("AreaCode".to_string(), code.to_string()));
+        self
+    }
+
+    pub fn beta(mut self, beta: bool) -> Self {
+        self.params.push(("Beta".to_string(), beta.to_string()));
+        self
+    }
+
+    pub fn contains(mut self, value: &str) -> Self {
+        self.params.push(("Contains".to_string(), value.to_string()));
+        self
+    }
+
+    pub fn exclude_all_address_required(mut self, value: bool) -> Self {
+        self.params.push(("ExcludeAllAddressRequired".to_string(), value.to_string()));
+        self
+    }
+
+    pub fn exclude_foreign_address_required(mut self, value: bool) -> Self {
+        self.params.push(("ExcludeForeignAddressRequired".to_string(), value.to_string()));
+        self
+    }
+
+    pub fn exclude_local_address_required(mut self, value: bool) -> Self {
+        self.params.push(("ExcludeLocalAddressRequired".to_string(), value.to_string()));
+        self
+    }
+
+    pub fn fax_enabled(mut self, enabled: bool) -> Self {
+        self.params.push(("FaxEnabled".to_string(), enabled.to_string()));
+        self
+    }
+
+    pub fn in_region(mut self, region: &str) -> Self {
+        self.params.push(("InRegion".to_string(), region.to_string()));
+        self
+    }
+
+    pub fn mms_enabled(mut self, enabled: bool) -> Self {
+        self.params.push(("MmsEnabled".to_string(), enabled.to_string()));
+        self
+    }
+
+    pub fn sms_enabled(mut self, enabled: bool) -> Self {
+        self.params.push(("SmsEnabled".to_string(), enabled.to_string()));
+        self
+    }
+
+    pub fn voice_enabled(mut self, enabled: bool) -> Self {
+        self.params.push(("VoiceEnabled".to_string(), enabled.to_string()));
+        self
+    }
+
+    pub fn build(self) -> Vec<(String, String)> {
+        self.params
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhoneNumbersAvailableResponse {
+    pub uri: String,
+    #[serde(rename = "available_phone_numbers")]
+    pub phone_numbers_available: Vec<PhoneNumberAvailable>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhoneNumberAvailable {
+    pub beta: bool,
+    pub capabilities: Capabilities,
+    pub friendly_name: String,
+    pub iso_country: String,
+    pub lata: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub phone_number: String,
+    pub postal_code: Option<String>,
+    pub rate_center: String,
+    pub region: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Capabilities {
+    pub voice: Option<bool>,
+    #[serde(rename = "SMS")]
+    pub sms: Option<bool>,
+    #[serde(rename = "MMS")]
+    pub mms: Option<bool>,
+    pub fax: Option<bool>,
+}
+
+impl Capabilities {
+    pub fn supports(&self, capability: NumberCapability) -> bool {
+        let flag = match capability {
+            NumberCapability::Voice => self.voice,
+            NumberCapability::Sms => self.sms,
+            NumberCapability::Mms => self.mms,
+            NumberCapability::Fax => self.fax,
+            NumberCapability::Unknown(_) => None,
+        };
+        flag.unwrap_or(false)
+    }
+
+    /// The capabilities supported, as listed for owned numbers.
+    pub fn to_vec(&self) -> Vec<NumberCapability> {
+        NumberCapability::ALL.into_iter().filter(|capability| self.supports(capability.clone())).collect()
+    }
+}
+
+impl PhoneNumberAvailable {
+    pub fn supports(&self, capability: NumberCapability) -> bool {
+        self.capabilities.supports(capability)
+    }
+}
+
+// What a phone number can be used for
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum NumberCapability {
+    Voice,           // Calls
+    Sms,             // Text messages
+    Mms,             // Picture and media messages
+    Fax,             // Faxes
+    Unknown(String), // A capability this version doesn't know, as received
+}
+
+impl NumberCapability {
+    /// Every known capability.
+    pub const ALL: [NumberCapability; 4] = [NumberCapability::Voice, NumberCapability::Sms, NumberCapability::Mms, NumberCapability::Fax];
+}
+
+impl From<&str> for NumberCapability {
+    fn from(capability: &str) -> Self {
+        match capability.to_lowercase().as_str() {
+            "voice" => NumberCapability::Voice,
+            "sms" => NumberCapability::Sms,
+            "mms" => NumberCapability::Mms,
+            "fax" => NumberCapability::Fax,
+            _ => NumberCapability::Unknown(capability.to_string()),
+        }
+    }
+}
+
+impl From<String> for NumberCapability {
+    fn from(capability: String) -> Self {
+        NumberCapability::from(capability.as_str())
+    }
+}
+
+impl From<NumberCapability> for String {
+    fn from(capability: NumberCapability) -> Self {
+        capability.to_string()
+    }
+}
+
+impl std::fmt::Display for NumberCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberCapability::Voice => write!(f, "voice"),
+            NumberCapability::Sms => write!(f, "sms"),
+            NumberCapability::Mms => write!(f, "mms"),
+            NumberCapability::Fax => write!(f, "fax"),
+            NumberCapability::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct PhoneNumberOwnedFilterParams {
+    params: Vec<(String, String)>,
+}
+
+impl PhoneNumberOwnedFilterParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn filter_name(mut self, name: &str) -> Self {
+        self.params.push(("filter_name".to_string(), name.to_string()));
+        self
+    }
+
+    pub fn filter_number(mut self, number: &str) -> Self {
+        self.params.push(("filter_number".to_string(), number.to_string()));
+        self
+    }
+
+    /// How many numbers per page, 50 by default and 1000 at most.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.params.push(("page_size".to_string(), page_size.to_string()));
+        self
+    }
+
+    /// Starts at the page of `page_token`, see `Links::next_page_token`.
+    pub fn page_token(mut self, page_token: &str) -> Self {
+        self.params.push(("page_token".to_string(), page_token.to_string()));
+        self
+    }
+
+    pub fn build(self) -> Vec<(String, String)> {
+        self.params
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhoneNumbersOwnedResponse {
+    pub links: Links,
+    pub data: Vec<OwnedPhoneNumber>,
+}
+
+/// Former name of `OwnedPhoneNumber`.
+#[deprecated(note = "renamed to `OwnedPhoneNumber`")]
+pub type Daum = OwnedPhoneNumber;
+
+/// A phone number of the project, as listed by `SignalWireClient::get_phone_numbers_owned`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnedPhoneNumber {
+    pub id: PhoneNumberId,
+    pub number: String,
+    pub name: Option<String>,
+    pub call_handler: Option<String>,
+    pub call_receive_mode: Option<String>,
+    pub call_request_url: Option<String>,
+    pub call_request_method: Option<String>,
+    pub call_fallback_url: Option<String>,
+    pub call_fallback_method: Option<String>,
+    pub call_status_callback_url: Option<String>,
+    pub call_status_callback_method: Option<String>,
+    pub call_laml_application_id: Option<String>,
+    pub call_dialogflow_agent_id: Option<String>,
+    pub call_relay_topic: Option<String>,
+    pub call_relay_topic_status_callback_url: Option<String>,
+    pub call_relay_context: Option<String>,
+    pub call_relay_context_status_callback_url: Option<String>,
+    pub call_relay_application: Option<String>,
+    pub call_relay_connector_id: Option<String>,
+    pub call_sip_endpoint_id: Option<String>,
+    pub call_verto_resource: Option<String>,
+    pub call_video_room_id: Option<String>,
+    pub message_handler: Option<String>,
+    pub message_request_url: Option<String>,
+    pub message_request_method: Option<String>,
+    pub message_fallback_url: Option<String>,
+    pub message_fallback_method: Option<String>,
+    pub message_laml_application_id: Option<String>,
+    pub message_relay_topic: Option<String>,
+    pub message_relay_context: Option<String>,
+    pub message_relay_application: Option<String>,
+    pub capabilities: Vec<NumberCapability>,
+    pub number_type: Option<String>,
+    pub e911_address_id: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub next_billed_at: Option<String>,
+}
+
+impl OwnedPhoneNumber {
+    pub fn supports(&self, capability: NumberCapability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuyPhoneNumberRequest {
+    pub number: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdatePhoneNumberRequest {
+    pub name: Option<String>,
+    pub call_handler: Option<String>,
+    pub call_receive_mode: Option<String>,
+    pub call_request_url: Option<String>,
+    pub call_request_method: Option<String>,
+    pub call_fallback_url: Option<String>,
+    pub call_fallback_method: Option<String>,
+    pub call_status_callback_url: Option<String>,
+    pub call_status_callback_method: Option<String>,
+    pub call_laml_application_id: Option<String>,
+    pub call_dialogflow_agent_id: Option<String>,
+    pub call_relay_topic: Option<String>,
+    pub call_relay_topic_status_callback_url: Option<String>,
+    pub call_relay_script_url: Option<String>,
+    pub call_relay_application: Option<String>,
+    pub call_relay_connector_id: Option<String>,
+    pub call_sip_endpoint_id: Option<String>,
+    pub call_verto_resource: Option<String>,
+    pub call_video_room_id: Option<String>,
+    pub message_handler: Option<String>,
+    pub message_request_url: Option<String>,
+    pub message_request_method: Option<String>,
+    pub message_fallback_url: Option<String>,
+    pub message_fallback_method: Option<String>,
+    pub message_laml_application_id: Option<String>,
+    pub message_relay_topic: Option<String>,
+    pub message_relay_application: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuyPhoneNumberResponse {
+    pub id: PhoneNumberId,
+    pub number: String,
+    pub name: Option<String>,
+    pub call_handler: Option<String>,
+    pub call_receive_mode: Option<String>,
+    pub call_request_url: Option<String>,
+    pub call_request_method: Option<String>,
+    pub call_fallback_url: Option<String>,
+    pub call_fallback_method: Option<String>,
+    pub call_status_callback_url: Option<String>,
+    pub call_status_callback_method: Option<String>,
+    pub call_laml_application_id: Option<String>,
+    pub call_dialogflow_agent_id: Option<String>,
+    pub call_relay_topic: Option<String>,
+    pub call_relay_topic_status_callback_url: Option<String>,
+    pub call_relay_context: Option<String>,
+    pub call_relay_context_status_callback_url: Option<String>,
+    pub call_relay_application: Option<String>,
+    pub call_relay_connector_id: Option<String>,
+    pub call_sip_endpoint_id: Option<String>,
+    pub call_verto_resource: Option<String>,
+    pub call_video_room_id: Option<String>,
+    pub message_handler: Option<String>,
+    pub message_request_url: Option<String>,
+    pub message_request_method: Option<String>,
+    pub message_fallback_url: Option<String>,
+    pub message_fallback_method: Option<String>,
+    pub message_laml_application_id: Option<String>,
+    pub message_relay_topic: Option<String>,
+    pub message_relay_context: Option<String>,
+    pub message_relay_application: Option<String>,
+    pub capabilities: Vec<NumberCapability>,
+    pub number_type: Option<String>,
+    pub e911_address_id: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub next_billed_at: Option<String>,
+}
+
+impl BuyPhoneNumberResponse {
+    pub fn supports(&self, capability: NumberCapability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+}
+
+impl_capability_helpers!(Capabilities, PhoneNumberAvailable, OwnedPhoneNumber, BuyPhoneNumberResponse);
@@ -0,0 +1,70 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// HTTP-level details of a SignalWire API response.
+///
+/// SignalWire support usually asks for the request ID of a failing or surprising call, so every
+/// `*_with_meta` client method returns this alongside the parsed body.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseMeta {
+    pub status: u16,
+    pub request_id: Option<String>,
+    pub rate_limit_limit: Option<u64>,
+    pub rate_limit_remaining: Option<u64>,
+    pub rate_limit_reset: Option<u64>,
+    /// How the body was compressed in transit (`gzip`, `br`), see the `gzip` and `brotli` features. The body is decoded either way.
+    pub content_encoding: Option<String>,
+    pub headers: Vec<(String, String)>,
+}
+
+impl ResponseMeta {
+    /// Builds the metadata from a status code and the raw response headers.
+    ///
+    /// Header names are matched case-insensitively when extracting the request ID and
+    /// rate limit counters.
+    pub fn new(status: u16, headers: Vec<(String, String)>) -> Self {
+        let mut meta = ResponseMeta { status, headers, ..Default::default() };
+        meta.request_id = meta.header("x-request-id").map(str::to_string);
+        meta.rate_limit_limit = meta.header("x-ratelimit-limit").and_then(|v| v.trim().parse().ok());
+        meta.rate_limit_remaining = meta.header("x-ratelimit-remaining").and_then(|v| v.trim().parse().ok());
+        meta.rate_limit_reset = meta.header("x-ratelimit-reset").and_then(|v| v.trim().parse().ok());
+        meta.content_encoding = meta.header("content-encoding").map(|v| v.trim().to_ascii_lowercase());
+        meta
+    }
+
+    /// Returns the value of the first header named `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+    }
+}
+
+/// A parsed response body together with its `ResponseMeta`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ResponseEnvelope<T> {
+    pub data: T,
+    pub meta: ResponseMeta,
+}
+
+impl<T> ResponseEnvelope<T> {
+    /// Discards the metadata and returns the parsed body.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Links {
+    #[serde(rename = "self")]
+    pub self_field: String,
+    pub first: String,
+    pub next: Option<String>,
+    pub prev: Option<String>,
+}
+
+impl Links {
+    /// The cursor of the next page, for the endpoints paginated with a `page_token`.
+    pub fn next_page_token(&self) -> Option<String> {
+        let (_, query) = self.next.as_deref()?.split_once('?')?;
+        let params: Vec<(String, String)> = serde_urlencoded::from_str(query).ok()?;
+        params.into_iter().find(|(name, _)| name == "page_token").map(|(_, value)| value)
+    }
+}
@@ -0,0 +1,94 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::{AccountSid, ShortCodeSid};
+
+/// A short code provisioned on the project, as listed by `SignalWireClient::list_short_codes`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShortCode {
+    pub sid: ShortCodeSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: Option<String>,
+    /// The short code itself, e.g. `55555`.
+    pub short_code: String,
+    /// Where inbound messages are posted to.
+    pub sms_url: Option<String>,
+    /// `GET` or `POST`.
+    pub sms_method: Option<String>,
+    /// Where inbound messages are posted to when `sms_url` fails.
+    pub sms_fallback_url: Option<String>,
+    pub sms_fallback_method: Option<String>,
+    pub api_version: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShortCodesListResponse {
+    pub uri: Option<String>,
+    pub first_page_uri: String,
+    pub next_page_uri: Option<String>,
+    pub previous_page_uri: Option<String>,
+    pub page: Option<i32>,
+    pub page_size: Option<i32>,
+    pub short_codes: Vec<ShortCode>,
+}
+
+/// The filters of `SignalWireClient::list_short_codes`.
+#[derive(Default)]
+pub struct ShortCodeListParams {
+    params: Vec<(String, String)>,
+}
+
+impl ShortCodeListParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn friendly_name(mut self, friendly_name: &str) -> Self {
+        self.params.push(("FriendlyName".to_string(), friendly_name.to_string()));
+        self
+    }
+
+    /// Only the short codes matching `short_code`, which may be partial.
+    pub fn short_code(mut self, short_code: &str) -> Self {
+        self.params.push(("ShortCode".to_string(), short_code.to_string()));
+        self
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.params.push(("PageSize".to_string(), page_size.to_string()));
+        self
+    }
+
+    /// The page to start from, counting from 0. The following pages are linked by `next_page_uri`, see `Page`.
+    pub fn page(mut self, page: u32) -> Self {
+        self.params.push(("Page".to_string(), page.to_string()));
+        self
+    }
+
+    pub fn build(self) -> Vec<(String, String)> {
+        self.params
+    }
+}
+
+/// The settings of a short code to change with `SignalWireClient::update_short_code`; fields left `None` are not
+/// changed.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShortCodeUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_url: Option<String>,
+    /// `GET` or `POST`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_fallback_url: Option<String>,
+    /// `GET` or `POST`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_fallback_method: Option<String>,
+}
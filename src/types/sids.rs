@@ -0,0 +1,155 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::errors::ValidationError;
+
+// SignalWire identifies resources by UUID, while SIDs copied over from Twilio-compatible
+// integrations are a two letter prefix followed by 32 hex digits. Both are accepted.
+fn is_sid(value: &str, prefixes: &[&str]) -> bool {
+    let is_uuid = value.len() == 36
+        && value.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        });
+    let is_prefixed = value.len() == 34 && prefixes.iter().any(|prefix| value.starts_with(prefix)) && value[2..].chars().all(|c| c.is_ascii_hexdigit());
+    is_uuid || is_prefixed
+}
+
+// A validated string identifier. Parsing checks the format, while deserializing trusts whatever
+// the API returned so new ID formats never break response parsing.
+macro_rules! sid_type {
+    ($(#[$doc:meta])* $name:ident, $kind:literal, [$($prefix:literal),*]) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Checks the format of `value`.
+            pub fn new(value: impl Into<String>) -> Result<Self, ValidationError> {
+                let value = value.into();
+                if is_sid(&value, &[$($prefix),*]) {
+                    Ok($name(value))
+                } else {
+                    Err(ValidationError::InvalidSid { kind: $kind, value })
+                }
+            }
+
+            // For IDs the crate synthesizes itself, such as dry-run responses.
+            #[allow(dead_code)]
+            pub(crate) fn unchecked(value: impl Into<String>) -> Self {
+                $name(value.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name(String::new())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = ValidationError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $name::new(s)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = ValidationError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                $name::new(value)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = ValidationError;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                $name::new(value)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+sid_type!(
+    /// The SID of an SMS or MMS message, such as `SmsResponse::sid`.
+    MessageSid,
+    "message",
+    ["SM", "MM"]
+);
+
+sid_type!(
+    /// The SID of a project or subproject, such as `SubprojectResponse::sid`.
+    AccountSid,
+    "account",
+    ["AC"]
+);
+
+sid_type!(
+    /// The SID of a call, such as `CallResponse::sid`.
+    CallSid,
+    "call",
+    ["CA"]
+);
+
+sid_type!(
+    /// The SID of a conference, such as `ConferenceRecording::conference_sid`.
+    ConferenceSid,
+    "conference",
+    ["CF"]
+);
+
+sid_type!(
+    /// The SID of a call or conference recording, such as `ConferenceRecording::sid`.
+    RecordingSid,
+    "recording",
+    ["RE"]
+);
+
+sid_type!(
+    /// The SID of a short code, such as `ShortCode::sid`.
+    ShortCodeSid,
+    "short code",
+    ["SC"]
+);
+
+sid_type!(
+    /// The ID of a phone number owned by the project, such as `OwnedPhoneNumber::id`.
+    PhoneNumberId,
+    "phone number",
+    ["PN"]
+);
@@ -0,0 +1,179 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::Links;
+
+/// A SIP endpoint softphones and PBXs register with, see `UpdatePhoneNumberRequest::call_sip_endpoint_id`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SipEndpoint {
+    pub id: String,
+    pub username: String,
+    pub caller_id: Option<String>,
+    /// The number presented on calls to the PSTN.
+    pub send_as: Option<String>,
+    #[serde(default)]
+    pub ciphers: Vec<String>,
+    #[serde(default)]
+    pub codecs: Vec<String>,
+    pub encryption: Option<String>,
+}
+
+impl SipEndpoint {
+    pub fn get_encryption(&self) -> SipEncryption {
+        SipEncryption::from(self.encryption.as_deref().unwrap_or_default())
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SipEndpointsListResponse {
+    pub data: Vec<SipEndpoint>,
+    pub links: Links,
+}
+
+/// The fields of a SIP endpoint to set. `username` and `password` are required on creation; fields left `None` are
+/// not changed.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SipEndpointRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caller_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_as: Option<String>,
+    /// E.g. `AEAD_AES_256_GCM_8`, in order of preference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ciphers: Option<Vec<String>>,
+    /// E.g. `OPUS`, `PCMU`, in order of preference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub codecs: Option<Vec<String>>,
+    /// See `SipEncryption`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<String>,
+}
+
+// SIP media encryption settings according to the Relay REST API
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum SipEncryption {
+    Default,         // Encrypted if the other side supports it
+    Required,        // Calls without encryption are rejected
+    Optional,        // Encrypted if asked for
+    Unknown(String), // A setting this version doesn't know, as received
+}
+
+impl From<&str> for SipEncryption {
+    fn from(encryption: &str) -> Self {
+        match encryption.to_lowercase().as_str() {
+            "default" => SipEncryption::Default,
+            "required" => SipEncryption::Required,
+            "optional" => SipEncryption::Optional,
+            _ => SipEncryption::Unknown(encryption.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for SipEncryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SipEncryption::Default => write!(f, "default"),
+            SipEncryption::Required => write!(f, "required"),
+            SipEncryption::Optional => write!(f, "optional"),
+            SipEncryption::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+// ---------- Domain Application Types ----------
+
+/// A SIP domain application: calls to `<identifier>-<space>.sip.signalwire.com` are handled as set here, e.g. from an
+/// external SIP trunk.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainApplication {
+    pub id: String,
+    pub name: Option<String>,
+    pub identifier: String,
+    /// The full SIP domain.
+    pub domain: Option<String>,
+    /// Whether calls are only accepted from `ip_auth`.
+    #[serde(default)]
+    pub ip_auth_enabled: bool,
+    #[serde(default)]
+    pub ip_auth: Vec<String>,
+    pub encryption: Option<String>,
+    #[serde(default)]
+    pub codecs: Vec<String>,
+    #[serde(default)]
+    pub ciphers: Vec<String>,
+    pub call_handler: Option<String>,
+    pub call_request_url: Option<String>,
+    pub call_request_method: Option<String>,
+    pub call_fallback_url: Option<String>,
+    pub call_fallback_method: Option<String>,
+    pub call_status_callback_url: Option<String>,
+    pub call_status_callback_method: Option<String>,
+    pub call_laml_application_id: Option<String>,
+    pub call_relay_topic: Option<String>,
+    pub call_relay_script_url: Option<String>,
+    pub call_relay_application: Option<String>,
+    pub call_video_room_id: Option<String>,
+}
+
+impl DomainApplication {
+    pub fn get_encryption(&self) -> SipEncryption {
+        SipEncryption::from(self.encryption.as_deref().unwrap_or_default())
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainApplicationsListResponse {
+    pub data: Vec<DomainApplication>,
+    pub links: Links,
+}
+
+/// The fields of a domain application to set. `identifier` and `call_handler` are required on creation; fields left
+/// `None` are not changed.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainApplicationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_auth_enabled: Option<bool>,
+    /// IP addresses or CIDR ranges calls are accepted from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_auth: Option<Vec<String>>,
+    /// See `SipEncryption`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub codecs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ciphers: Option<Vec<String>>,
+    /// E.g. `laml_webhooks`, `relay_context` or `video_room`, with the matching `call_*` fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_handler: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_request_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_request_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_fallback_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_fallback_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_status_callback_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_status_callback_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_laml_application_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_relay_topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_relay_script_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_relay_application: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_video_room_id: Option<String>,
+}
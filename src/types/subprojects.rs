@@ -0,0 +1,221 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::{AccountSid, NumberCapability};
+
+// Subproject (Account) related types
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubprojectResponse {
+    pub sid: AccountSid,
+    pub friendly_name: String,
+    pub status: String,
+    pub auth_token: String,
+    pub date_created: String,
+    pub date_updated: String,
+    #[serde(rename = "type")]
+    pub account_type: Option<String>,
+    pub owner_account_sid: Option<String>,
+    pub uri: Option<String>,
+    pub subproject: Option<bool>,
+    pub signing_key: Option<String>,
+    pub subresource_uris: SubprojectResourceUris,
+}
+
+impl SubprojectResponse {
+    /// Whether the subproject is active, as opposed to suspended or closed.
+    pub fn is_active(&self) -> bool {
+        self.status.eq_ignore_ascii_case("active")
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubprojectResourceUris {
+    pub addresses: Option<String>,
+    pub available_phone_numbers: Option<String>,
+    pub applications: Option<String>,
+    pub authorized_connect_apps: Option<String>,
+    pub calls: Option<String>,
+    pub conferences: Option<String>,
+    pub connect_apps: Option<String>,
+    pub incoming_phone_numbers: Option<String>,
+    pub keys: Option<String>,
+    pub notifications: Option<String>,
+    pub outgoing_caller_ids: Option<String>,
+    pub queues: Option<String>,
+    pub recordings: Option<String>,
+    pub sandbox: Option<String>,
+    pub sip: Option<String>,
+    pub short_codes: Option<String>,
+    pub messages: Option<String>,
+    pub transcriptions: Option<String>,
+    pub usage: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubprojectsListResponse {
+    pub uri: Option<String>,
+    pub first_page_uri: String,
+    pub next_page_uri: Option<String>,
+    pub previous_page_uri: Option<String>,
+    pub page: Option<i32>,
+    pub page_size: Option<i32>,
+    pub accounts: Vec<SubprojectResponse>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateSubprojectRequest {
+    pub friendly_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateSubprojectRequest {
+    pub friendly_name: String,
+    pub status: Option<String>, // "active" or "suspended"
+}
+
+#[derive(Default)]
+pub struct SubprojectQueryParams {
+    params: Vec<(String, String)>,
+}
+
+impl SubprojectQueryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn friendly_name(mut self, friendly_name: &str) -> Self {
+        self.params.push(("FriendlyName".to_string(), friendly_name.to_string()));
+        self
+    }
+
+    pub fn status(mut self, status: &str) -> Self {
+        self.params.push(("Status".to_string(), status.to_string()));
+        self
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.params.push(("PageSize".to_string(), page_size.to_string()));
+        self
+    }
+    /// The page to start from, counting from 0. The following pages are linked by `next_page_uri`, see `Page`.
+    pub fn page(mut self, page: u32) -> Self {
+        self.params.push(("Page".to_string(), page.to_string()));
+        self
+    }
+
+    pub fn build(self) -> Vec<(String, String)> {
+        self.params
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubprojectPhoneNumbersResponse {
+    pub uri: String,
+    pub first_page_uri: String,
+    pub next_page_uri: Option<String>,
+    pub previous_page_uri: Option<String>,
+    pub page: i32,
+    pub page_size: i32,
+    pub incoming_phone_numbers: Vec<SubprojectPhoneNumber>,
+}
+
+/// The filters of `SignalWireClient::get_subproject_phone_numbers`.
+#[derive(Default)]
+pub struct SubprojectPhoneNumberParams {
+    params: Vec<(String, String)>,
+}
+
+impl SubprojectPhoneNumberParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only the numbers matching `phone_number`, which may be partial.
+    pub fn phone_number(mut self, phone_number: &str) -> Self {
+        self.params.push(("PhoneNumber".to_string(), phone_number.to_string()));
+        self
+    }
+
+    pub fn friendly_name(mut self, friendly_name: &str) -> Self {
+        self.params.push(("FriendlyName".to_string(), friendly_name.to_string()));
+        self
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.params.push(("PageSize".to_string(), page_size.to_string()));
+        self
+    }
+
+    /// The page to start from, counting from 0. The following pages are linked by `next_page_uri`, see `Page`.
+    pub fn page(mut self, page: u32) -> Self {
+        self.params.push(("Page".to_string(), page.to_string()));
+        self
+    }
+
+    pub fn build(self) -> Vec<(String, String)> {
+        self.params
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubprojectPhoneNumber {
+    pub sid: String,
+    pub account_sid: String,
+    pub friendly_name: String,
+    pub phone_number: String,
+    pub voice_url: Option<String>,
+    pub voice_method: Option<String>,
+    pub voice_fallback_url: Option<String>,
+    pub voice_fallback_method: Option<String>,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<String>,
+    pub voice_caller_id_lookup: Option<bool>,
+    pub voice_application_sid: Option<String>,
+    pub date_created: String,
+    pub date_updated: String,
+    pub sms_url: Option<String>,
+    pub sms_method: Option<String>,
+    pub sms_fallback_url: Option<String>,
+    pub sms_fallback_method: Option<String>,
+    pub sms_application_sid: Option<String>,
+    pub capabilities: PhoneNumberCapabilities,
+    pub beta: bool,
+    pub uri: String,
+    pub trunk_sid: Option<String>,
+    pub emergency_status: Option<String>,
+    pub emergency_address_sid: Option<String>,
+    pub emergency_address_status: Option<String>,
+    pub status: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhoneNumberCapabilities {
+    pub voice: bool,
+    pub sms: bool,
+    pub mms: bool,
+    pub fax: bool,
+}
+
+impl PhoneNumberCapabilities {
+    pub fn supports(&self, capability: NumberCapability) -> bool {
+        match capability {
+            NumberCapability::Voice => self.voice,
+            NumberCapability::Sms => self.sms,
+            NumberCapability::Mms => self.mms,
+            NumberCapability::Fax => self.fax,
+            NumberCapability::Unknown(_) => false,
+        }
+    }
+
+    /// The capabilities supported, as listed for owned numbers.
+    pub fn to_vec(&self) -> Vec<NumberCapability> {
+        NumberCapability::ALL.into_iter().filter(|capability| self.supports(capability.clone())).collect()
+    }
+}
+
+impl SubprojectPhoneNumber {
+    pub fn supports(&self, capability: NumberCapability) -> bool {
+        self.capabilities.supports(capability)
+    }
+}
+
+impl_capability_helpers!(PhoneNumberCapabilities, SubprojectPhoneNumber);
@@ -0,0 +1,47 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::Links;
+
+/// A SWML script hosted in the space, as returned by the Fabric resources API.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwmlScriptResponse {
+    pub id: String,
+    pub project_id: Option<String>,
+    pub display_name: Option<String>,
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub swml_script: SwmlScript,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwmlScript {
+    pub id: String,
+    pub display_name: Option<String>,
+    /// The script, as JSON or YAML text.
+    pub contents: String,
+    /// The URL SignalWire serves the script from; set it as a phone number's `call_relay_script_url`.
+    pub request_url: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwmlScriptsListResponse {
+    pub data: Vec<SwmlScriptResponse>,
+    pub links: Links,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwmlScriptRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contents: Option<String>,
+}
+
+/// A task for the Relay consumers of `context`, see `SignalWireClient::send_task`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelayTaskRequest {
+    pub context: String,
+    pub message: serde_json::Value,
+}
@@ -0,0 +1,236 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::Links;
+
+// What a video room participant may do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RoomPermission {
+    PublishAudio, // Mute and unmute their own microphone
+    PublishVideo, // Mute and unmute their own camera
+    ScreenShare,  // Share their screen
+    Moderate,     // Mute, unmute and remove other members
+    Record,       // Start and stop recordings
+    SetLayout,    // Change the room layout
+}
+
+impl RoomPermission {
+    /// The permission scopes the Video API expects for this permission.
+    pub fn scopes(&self) -> &'static [&'static str] {
+        match self {
+            RoomPermission::PublishAudio => &["room.self.audio_mute", "room.self.audio_unmute"],
+            RoomPermission::PublishVideo => &["room.self.video_mute", "room.self.video_unmute"],
+            RoomPermission::ScreenShare => &["room.self.screenshare", "room.self.additional_source"],
+            RoomPermission::Moderate => &["room.member.audio_mute", "room.member.audio_unmute", "room.member.video_mute", "room.member.video_unmute", "room.member.remove"],
+            RoomPermission::Record => &["room.recording"],
+            RoomPermission::SetLayout => &["room.list_available_layouts", "room.set_layout"],
+        }
+    }
+}
+
+/// A set of `RoomPermission`s granted by a room token.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RoomPermissions {
+    permissions: std::collections::BTreeSet<RoomPermission>,
+}
+
+impl RoomPermissions {
+    pub fn new() -> Self {
+        RoomPermissions::default()
+    }
+
+    /// Publishing audio and video, what a regular participant needs.
+    pub fn participant() -> Self {
+        RoomPermissions::new().with(RoomPermission::PublishAudio).with(RoomPermission::PublishVideo)
+    }
+
+    /// Every permission.
+    pub fn moderator() -> Self {
+        RoomPermissions::participant()
+            .with(RoomPermission::ScreenShare)
+            .with(RoomPermission::Moderate)
+            .with(RoomPermission::Record)
+            .with(RoomPermission::SetLayout)
+    }
+
+    pub fn with(mut self, permission: RoomPermission) -> Self {
+        self.permissions.insert(permission);
+        self
+    }
+
+    pub fn contains(&self, permission: RoomPermission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RoomPermission> + '_ {
+        self.permissions.iter().copied()
+    }
+
+    /// The permission scopes the Video API expects, in a stable order.
+    pub fn scopes(&self) -> Vec<String> {
+        self.iter().flat_map(|permission| permission.scopes()).map(|scope| scope.to_string()).collect()
+    }
+}
+
+impl FromIterator<RoomPermission> for RoomPermissions {
+    fn from_iter<I: IntoIterator<Item = RoomPermission>>(iter: I) -> Self {
+        RoomPermissions { permissions: iter.into_iter().collect() }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomTokenRequest {
+    pub room_name: String,
+    pub user_name: String,
+    pub permissions: Vec<String>,
+}
+
+/// A token for a browser participant to join a video room with.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomTokenResponse {
+    pub token: String,
+}
+
+/// A recording of a video room session.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomRecording {
+    pub id: String,
+    pub room_session_id: String,
+    pub status: String,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    /// In seconds.
+    pub duration: Option<f64>,
+    pub size_in_bytes: Option<u64>,
+    pub format: Option<String>,
+    pub cost_in_dollars: Option<f64>,
+    /// Where to download the recording from, once completed.
+    pub uri: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl RoomRecording {
+    pub fn get_status(&self) -> RecordingStatus {
+        RecordingStatus::from(self.status.as_str())
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomRecordingsListResponse {
+    pub data: Vec<RoomRecording>,
+    pub links: Links,
+}
+
+// Room recording status values according to the Video API
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RecordingStatus {
+    Recording,       // The session is being recorded
+    Completed,       // The recording is finished and can be downloaded
+    Failed,          // The recording failed
+    Unknown(String), // A status this version doesn't know, as received
+}
+
+impl From<&str> for RecordingStatus {
+    fn from(status: &str) -> Self {
+        match status.to_lowercase().as_str() {
+            "recording" => RecordingStatus::Recording,
+            "completed" => RecordingStatus::Completed,
+            "failed" => RecordingStatus::Failed,
+            _ => RecordingStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for RecordingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordingStatus::Recording => write!(f, "recording"),
+            RecordingStatus::Completed => write!(f, "completed"),
+            RecordingStatus::Failed => write!(f, "failed"),
+            RecordingStatus::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// A session of a video room: from the first member joining to the last one leaving.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomSession {
+    pub id: String,
+    pub room_id: Option<String>,
+    pub name: Option<String>,
+    pub display_name: Option<String>,
+    pub status: String,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    /// In seconds.
+    pub duration: Option<f64>,
+    pub cost_in_dollars: Option<f64>,
+}
+
+impl RoomSession {
+    pub fn get_status(&self) -> RoomSessionStatus {
+        RoomSessionStatus::from(self.status.as_str())
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomSessionsListResponse {
+    pub data: Vec<RoomSession>,
+    pub links: Links,
+}
+
+// Room session status values according to the Video API
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RoomSessionStatus {
+    InProgress,      // Members are in the room
+    Completed,       // Everybody left
+    Unknown(String), // A status this version doesn't know, as received
+}
+
+impl From<&str> for RoomSessionStatus {
+    fn from(status: &str) -> Self {
+        match status.to_lowercase().as_str() {
+            "in-progress" => RoomSessionStatus::InProgress,
+            "completed" => RoomSessionStatus::Completed,
+            _ => RoomSessionStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for RoomSessionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoomSessionStatus::InProgress => write!(f, "in-progress"),
+            RoomSessionStatus::Completed => write!(f, "completed"),
+            RoomSessionStatus::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// A member of a video room session, past or present.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomMember {
+    pub id: String,
+    pub room_session_id: String,
+    pub name: Option<String>,
+    pub join_time: Option<String>,
+    pub leave_time: Option<String>,
+    /// In seconds.
+    pub duration: Option<f64>,
+    pub cost_in_dollars: Option<f64>,
+}
+
+impl RoomMember {
+    /// Whether the member is still in the session.
+    pub fn is_present(&self) -> bool {
+        self.leave_time.is_none()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomMembersListResponse {
+    pub data: Vec<RoomMember>,
+    pub links: Links,
+}
@@ -0,0 +1,129 @@
+//! A synchronous client on `ureq`, enabled by the `ureq` feature.
+//!
+//! Unlike `blocking::BlockingSignalWireClient`, which drives the async client on a background Tokio runtime,
+//! `UreqClient` makes plain blocking calls on the calling thread, for CLI tools and scripts that only need a few
+//! requests. It runs any `sansio::Operation`; the most common calls also have methods of their own:
+//!
+//! ```rust,no_run
+//! use signalwire::{types::SmsMessage, ureq_client::UreqClient};
+//!
+//! let client = UreqClient::new("your-space", "your-project-id", "your-api-key");
+//! let message = SmsMessage {
+//!     from: "+15551234567".to_string(),
+//!     to: "+15557654321".to_string(),
+//!     body: "Deploy finished".to_string(),
+//!     ..Default::default()
+//! };
+//! let sent = client.send_sms(&message)?;
+//! println!("{:?}", client.get_message_status(&sent.sid)?.status);
+//! # Ok::<(), signalwire::errors::SignalWireError>(())
+//! ```
+//!
+//! The client-side features of `SignalWireClient` (dry-run, cassettes, caching, coalescing, scheduling) aren't
+//! available here.
+
+use ureq::Agent;
+
+use crate::{errors::SignalWireError, sansio::*, types::*};
+
+/// A synchronous SignalWire client on `ureq`, see the module documentation.
+#[derive(Debug, Clone)]
+pub struct UreqClient {
+    credentials: Credentials,
+    agent: Agent,
+}
+
+impl UreqClient {
+    /// Creates a client for the space `space_name`, with a default `ureq` agent.
+    pub fn new(space_name: &str, project_id: &str, api_key: &str) -> Self {
+        UreqClient {
+            credentials: Credentials::new(space_name, project_id, api_key),
+            agent: Agent::config_builder().http_status_as_error(false).build().new_agent(),
+        }
+    }
+
+    /// Overrides the base URL, e.g. to target a mock server.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.credentials = self.credentials.with_base_url(base_url);
+        self
+    }
+
+    /// Sends the requests through `agent`, e.g. one with timeouts or a proxy.
+    ///
+    /// The agent must be configured with `http_status_as_error(false)`, for error responses to be mapped to
+    /// `SignalWireError` like with the other clients.
+    pub fn with_agent(mut self, agent: Agent) -> Self {
+        self.agent = agent;
+        self
+    }
+
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+
+    /// Executes any `sansio::Operation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever error the operation maps the response to, or `SignalWireError::HttpError`
+    /// if the request can't be sent.
+    pub fn execute<O: Operation>(&self, operation: &O) -> Result<O::Output, SignalWireError> {
+        self.execute_with_meta(operation).map(ResponseEnvelope::into_inner)
+    }
+
+    /// Same as `execute`, but also returns the HTTP status and response headers (request ID, rate limit counters).
+    pub fn execute_with_meta<O: Operation>(&self, operation: &O) -> Result<ResponseEnvelope<O::Output>, SignalWireError> {
+        let response = self.send(operation.request(&self.credentials)?)?;
+        let meta = response.meta();
+
+        Ok(ResponseEnvelope { data: operation.parse(response)?, meta })
+    }
+
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, SignalWireError> {
+        let mut builder = http::Request::builder().method(request.method).uri(&request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        let response = match request.body {
+            Some(body) => builder.body(body).map(|request| self.agent.run(request)),
+            None => builder.body(()).map(|request| self.agent.run(request)),
+        }
+        .map_err(|e| SignalWireError::HttpError(e.to_string()))?
+        .map_err(|e| SignalWireError::HttpError(e.to_string()))?;
+
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.as_str().to_string(), String::from_utf8_lossy(value.as_bytes()).into_owned()))
+            .collect();
+        let body = response.into_body().read_to_string().map_err(|e| SignalWireError::Unexpected(e.to_string()))?;
+
+        Ok(HttpResponse { status, headers, body })
+    }
+
+    /// Same as `SignalWireClient::get_jwt`.
+    pub fn get_jwt(&self) -> Result<JwtResponse, SignalWireError> {
+        self.execute(&GetJwt)
+    }
+
+    /// Same as `SignalWireClient::send_sms`.
+    pub fn send_sms(&self, message: &SmsMessage) -> Result<SmsResponse, SignalWireError> {
+        self.execute(&SendSms { message })
+    }
+
+    /// Same as `SignalWireClient::get_message_status`.
+    pub fn get_message_status(&self, message_sid: &MessageSid) -> Result<SmsResponse, SignalWireError> {
+        self.execute(&GetMessageStatus { message_sid: message_sid.as_str() })
+    }
+
+    /// Same as `SignalWireClient::lookup_phone_number`.
+    pub fn lookup_phone_number(&self, phone_number: &str) -> Result<PhoneLookupResponse, SignalWireError> {
+        self.execute(&LookupPhoneNumber { phone_number, query_params: &[] })
+    }
+
+    /// Same as `SignalWireClient::get_phone_numbers_owned`.
+    pub fn get_phone_numbers_owned(&self, query_params: &[(String, String)]) -> Result<PhoneNumbersOwnedResponse, SignalWireError> {
+        self.execute(&GetPhoneNumbersOwned { query_params })
+    }
+}
@@ -0,0 +1,110 @@
+//! Local input validation performed before a request is sent.
+
+use crate::{
+    errors::ValidationError,
+    types::{NewCall, SmsMessage},
+};
+
+/// The longest message body SignalWire accepts, in characters.
+pub const MAX_SMS_BODY_LENGTH: usize = 1600;
+
+/// Checks that `value` is an E.164 phone number: a `+`, a non-zero digit and up to 14 more digits.
+///
+/// # Arguments
+///
+/// * `field` - The name of the field being validated, reported in the error.
+/// * `value` - The phone number to check.
+pub fn validate_e164(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    let valid = match value.strip_prefix('+') {
+        Some(digits) => (2..=15).contains(&digits.len()) && digits.bytes().all(|b| b.is_ascii_digit()) && !digits.starts_with('0'),
+        None => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidPhoneNumber { field, value: value.to_string() })
+    }
+}
+
+/// Checks the numbers and body of an outgoing SMS.
+///
+/// Both `from` and `to` must be E.164 numbers and the body must be non-empty and at most
+/// `MAX_SMS_BODY_LENGTH` characters long. A message sent from a number group has an empty `from` instead.
+pub fn validate_sms_message(message: &SmsMessage) -> Result<(), ValidationError> {
+    match &message.number_group_id {
+        Some(_) if !message.from.is_empty() => return Err(ValidationError::SenderAndNumberGroup),
+        Some(group) if group.trim().is_empty() || group.chars().any(|c| c.is_whitespace() || c.is_control()) => {
+            return Err(ValidationError::InvalidNumberGroup(group.clone()));
+        }
+        Some(_) => {}
+        None => validate_e164("from", &message.from)?,
+    }
+    validate_e164("to", &message.to)?;
+
+    if message.body.trim().is_empty() {
+        return Err(ValidationError::EmptyBody);
+    }
+
+    let length = message.body.chars().count();
+    if length > MAX_SMS_BODY_LENGTH {
+        return Err(ValidationError::BodyTooLong { length, max: MAX_SMS_BODY_LENGTH });
+    }
+
+    Ok(())
+}
+
+/// Whether `value` is meant as a SIP URI rather than a phone number, starting with `sip:` or `sips:`.
+pub fn is_sip_uri(value: &str) -> bool {
+    let scheme = value.split(':').next().unwrap_or_default();
+    value.contains(':') && (scheme.eq_ignore_ascii_case("sip") || scheme.eq_ignore_ascii_case("sips"))
+}
+
+/// Checks that `value` is a SIP URI: `sip:` or `sips:`, an optional `user@`, then a host and an optional port,
+/// without headers, which are set apart.
+pub fn validate_sip_uri(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    let invalid = || ValidationError::InvalidSipUri { field, value: value.to_string() };
+    if !is_sip_uri(value) || value.contains('?') || value.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(invalid());
+    }
+
+    let (_, address) = value.split_once(':').ok_or_else(invalid)?;
+    let host = address.rsplit_once('@').map_or(address, |(_, host)| host);
+    let host = host.split(';').next().unwrap_or_default();
+    if host.is_empty() || host.starts_with(':') {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// Checks an outgoing call.
+///
+/// `from` must be an E.164 number, and `to` one too or a SIP URI; SIP headers, whose names start with `X-`, and SIP
+/// credentials are only allowed to a SIP URI. The call needs either a `url` or `laml`.
+pub fn validate_call(call: &NewCall) -> Result<(), ValidationError> {
+    validate_e164("from", &call.from)?;
+
+    if call.is_sip() {
+        validate_sip_uri("to", &call.to)?;
+    } else {
+        validate_e164("to", &call.to)?;
+        if !call.sip_headers.is_empty() || call.sip_auth_username.is_some() || call.sip_auth_password.is_some() {
+            return Err(ValidationError::SipOptionsWithoutSipUri);
+        }
+    }
+
+    for (name, _) in &call.sip_headers {
+        let token = name.len() > 2 && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-');
+        if !token || !name[..2].eq_ignore_ascii_case("x-") {
+            return Err(ValidationError::InvalidSipHeader(name.clone()));
+        }
+    }
+    if call.sip_auth_username.is_some() != call.sip_auth_password.is_some() {
+        return Err(ValidationError::IncompleteSipAuth);
+    }
+
+    if call.url.is_some() == call.laml.is_some() {
+        return Err(ValidationError::InvalidCallInstructions);
+    }
+    Ok(())
+}
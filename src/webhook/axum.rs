@@ -0,0 +1,119 @@
+//! Axum extractors for verified SignalWire webhooks.
+//!
+//! Add the signing key to the router with `WebhookSigningKey::layer`, then take
+//! `SignalWireWebhook<T>` in any handler: the signature is checked and the form body parsed into
+//! `T` before the handler runs.
+//!
+//! ```rust,no_run
+//! use axum::{routing::post, Router};
+//! use signalwire::webhook::{
+//!     axum::{SignalWireWebhook, WebhookSigningKey},
+//!     IncomingSmsWebhook,
+//! };
+//!
+//! async fn incoming_sms(
+//!     SignalWireWebhook(message): SignalWireWebhook<IncomingSmsWebhook>,
+//! ) -> &'static str {
+//!     println!("{} says {}", message.from, message.body);
+//!     "<Response/>"
+//! }
+//!
+//! let app: Router = Router::new()
+//!     .route("/sms", post(incoming_sms))
+//!     .layer(WebhookSigningKey::new("PSK...").layer());
+//! ```
+
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Extension,
+};
+use serde::de::DeserializeOwned;
+
+use super::{parse_form, request_url, validate_webhook_signature, SIGNATURE_HEADER};
+
+/// The signing key used to verify webhooks, shared with extractors through a request extension.
+#[derive(Debug, Clone)]
+pub struct WebhookSigningKey {
+    key: Arc<str>,
+    public_url: Option<Arc<str>>,
+}
+
+impl WebhookSigningKey {
+    /// Creates a key from your space's signing key.
+    pub fn new(signing_key: &str) -> Self {
+        WebhookSigningKey {
+            key: signing_key.into(),
+            public_url: None,
+        }
+    }
+
+    /// Sets the scheme and host SignalWire uses to reach this service (e.g. `https://hooks.example.com`).
+    ///
+    /// Signatures cover the full URL SignalWire requested. By default it is rebuilt from the
+    /// `X-Forwarded-Proto`, `X-Forwarded-Host` and `Host` headers, which may not match behind some proxies.
+    pub fn with_public_url(mut self, public_url: &str) -> Self {
+        self.public_url = Some(public_url.trim_end_matches('/').into());
+        self
+    }
+
+    /// The layer making this key available to `SignalWireWebhook` extractors.
+    pub fn layer(self) -> Extension<Self> {
+        Extension(self)
+    }
+}
+
+/// Why a request was rejected by `SignalWireWebhook`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookRejection {
+    /// No `WebhookSigningKey` layer was added to the router (`500`).
+    MissingSigningKey,
+    /// The request has no `X-SignalWire-Signature` header (`401`).
+    MissingSignature,
+    /// The signature doesn't match the request (`403`).
+    InvalidSignature,
+    /// The body couldn't be read or parsed (`400`).
+    InvalidBody(String),
+}
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        match self {
+            WebhookRejection::MissingSigningKey => (StatusCode::INTERNAL_SERVER_ERROR, "Missing WebhookSigningKey layer").into_response(),
+            WebhookRejection::MissingSignature => (StatusCode::UNAUTHORIZED, "Missing signature").into_response(),
+            WebhookRejection::InvalidSignature => (StatusCode::FORBIDDEN, "Invalid signature").into_response(),
+            WebhookRejection::InvalidBody(message) => (StatusCode::BAD_REQUEST, message).into_response(),
+        }
+    }
+}
+
+/// Extracts a form-encoded webhook of type `T` after verifying its signature.
+#[derive(Debug, Clone)]
+pub struct SignalWireWebhook<T>(pub T);
+
+impl<T, S> FromRequest<S> for SignalWireWebhook<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let signing_key = request.extensions().get::<WebhookSigningKey>().cloned().ok_or(WebhookRejection::MissingSigningKey)?;
+        let signature = request.headers().get(SIGNATURE_HEADER).and_then(|value| value.to_str().ok()).ok_or(WebhookRejection::MissingSignature)?.to_string();
+        let url = request_url(request.uri(), request.headers(), signing_key.public_url.as_deref());
+
+        let bytes = Bytes::from_request(request, state).await.map_err(|e| WebhookRejection::InvalidBody(e.body_text()))?;
+        let body = std::str::from_utf8(&bytes).map_err(|e| WebhookRejection::InvalidBody(e.to_string()))?;
+
+        if !validate_webhook_signature(&signing_key.key, &url, body, &signature) {
+            return Err(WebhookRejection::InvalidSignature);
+        }
+
+        parse_form(body).map(SignalWireWebhook).map_err(|e| WebhookRejection::InvalidBody(e.to_string()))
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+use crate::errors::SignalWireError;
+
+/// Any webhook SignalWire can send, for routing every callback through a single endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    /// An SMS or MMS sent to one of your numbers.
+    InboundMessage(IncomingSmsWebhook),
+    /// A delivery receipt for a message you sent.
+    MessageStatus(MessageStatusCallback),
+    /// An incoming call or a call status callback.
+    CallStatus(VoiceWebhook),
+    /// The input collected by `<Gather>`.
+    GatherInput(GatherWebhook),
+    /// A finished recording.
+    RecordingReady(RecordingWebhook),
+    /// An inbound fax or a fax status callback.
+    FaxReceived(FaxWebhook),
+    /// A JSON callback (e.g. from SWML or Relay), left untyped.
+    Json(serde_json::Value),
+    /// A form-encoded callback that matched none of the above.
+    Unknown(Vec<(String, String)>),
+}
+
+impl WebhookEvent {
+    /// Parses a webhook body into the matching event.
+    ///
+    /// Form-encoded bodies are told apart by their parameters (`FaxSid`, `RecordingSid`,
+    /// `MessageSid`, `Digits`/`SpeechResult`, `CallSid`); JSON bodies are returned as `Json`.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_type` - The value of the `Content-Type` header. An empty string is treated as form-encoded.
+    /// * `body` - The raw request body.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SignalWireError::Unexpected` for unsupported content types and malformed bodies.
+    pub fn parse(content_type: &str, body: &str) -> Result<Self, SignalWireError> {
+        let mime = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+
+        match mime.as_str() {
+            "" | "application/x-www-form-urlencoded" => Self::parse_form(body),
+            mime if mime == "application/json" || mime.ends_with("+json") => serde_json::from_str(body)
+                .map(WebhookEvent::Json)
+                .map_err(|e| SignalWireError::Unexpected(format!("Failed to parse webhook: {}. Body was: {}", e, body))),
+            _ => Err(SignalWireError::Unexpected(format!("Unsupported webhook content type: {}", content_type))),
+        }
+    }
+
+    fn parse_form(body: &str) -> Result<Self, SignalWireError> {
+        let params: Vec<(String, String)> = parse_form(body)?;
+        let get = |name: &str| params.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str());
+
+        if get("FaxSid").is_some() {
+            FaxWebhook::from_form(body).map(WebhookEvent::FaxReceived)
+        } else if get("RecordingSid").is_some() {
+            RecordingWebhook::from_form(body).map(WebhookEvent::RecordingReady)
+        } else if get("MessageSid").is_some() {
+            // Inbound messages report `received`; anything else is a delivery receipt.
+            match get("MessageStatus").or(get("SmsStatus")) {
+                Some(status) if !status.eq_ignore_ascii_case("received") => MessageStatusCallback::from_form(body).map(WebhookEvent::MessageStatus),
+                _ => IncomingSmsWebhook::from_form(body).map(WebhookEvent::InboundMessage),
+            }
+        } else if get("CallSid").is_some() && (get("Digits").is_some() || get("SpeechResult").is_some()) {
+            GatherWebhook::from_form(body).map(WebhookEvent::GatherInput)
+        } else if get("CallSid").is_some() {
+            VoiceWebhook::from_form(body).map(WebhookEvent::CallStatus)
+        } else {
+            Ok(WebhookEvent::Unknown(params))
+        }
+    }
+}
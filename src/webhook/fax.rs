@@ -0,0 +1,28 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::parse_form;
+use crate::errors::SignalWireError;
+
+/// An inbound fax, or a fax status callback.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FaxWebhook {
+    pub fax_sid: String,
+    pub account_sid: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub fax_status: Option<String>,
+    pub num_pages: Option<String>,
+    /// Where the received document (PDF) can be downloaded.
+    pub media_url: Option<String>,
+    pub remote_station_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl FaxWebhook {
+    /// Parses a form-encoded fax webhook body.
+    pub fn from_form(body: &str) -> Result<Self, SignalWireError> {
+        parse_form(body)
+    }
+}
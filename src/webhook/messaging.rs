@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use serde_derive::{Deserialize, Serialize};
+
+use super::parse_form;
+use crate::{errors::SignalWireError, types::MessageStatus};
+
+/// An inbound SMS or MMS, posted to the messaging webhook of the receiving number.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct IncomingSmsWebhook {
+    pub message_sid: String,
+    pub account_sid: String,
+    pub from: String,
+    pub to: String,
+    #[serde(default)]
+    pub body: String,
+    pub num_media: Option<String>,
+    pub num_segments: Option<String>,
+    /// Every other parameter, including `MediaUrl{n}` and `MediaContentType{n}` for MMS.
+    #[serde(flatten)]
+    pub extra: HashMap<String, String>,
+}
+
+impl IncomingSmsWebhook {
+    /// Parses a form-encoded inbound message body.
+    pub fn from_form(body: &str) -> Result<Self, SignalWireError> {
+        parse_form(body)
+    }
+
+    /// The URLs of the attached media, in order.
+    pub fn media_urls(&self) -> Vec<&str> {
+        (0..).map_while(|i| self.extra.get(&format!("MediaUrl{}", i)).map(String::as_str)).collect()
+    }
+}
+
+/// The delivery receipt SignalWire posts to a message's `StatusCallback` URL.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageStatusCallback {
+    pub message_sid: String,
+    pub account_sid: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub message_status: String,
+    /// The carrier or SignalWire error code, for `failed` and `undelivered` messages.
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl MessageStatusCallback {
+    /// Parses a form-encoded status callback body.
+    pub fn from_form(body: &str) -> Result<Self, SignalWireError> {
+        parse_form(body)
+    }
+
+    /// A `MessageStatus` enum representing the reported status of the message.
+    pub fn get_status(&self) -> MessageStatus {
+        MessageStatus::from(self.message_status.as_str())
+    }
+}
+
+impl From<&MessageStatusCallback> for MessageStatus {
+    fn from(callback: &MessageStatusCallback) -> Self {
+        callback.get_status()
+    }
+}
+
+impl From<MessageStatusCallback> for MessageStatus {
+    fn from(callback: MessageStatusCallback) -> Self {
+        callback.get_status()
+    }
+}
@@ -0,0 +1,51 @@
+//! Helpers for handling the webhooks SignalWire sends to your application.
+//!
+//! Every webhook is signed with your space's signing key in the `X-SignalWire-Signature` header.
+//! Check it with `validate_webhook_signature` (`webhook` feature) before trusting anything in the request.
+
+#[cfg(any(test, feature = "axum"))]
+pub mod axum;
+mod event;
+mod fax;
+mod messaging;
+#[cfg(any(test, feature = "webhook"))]
+mod signature;
+#[cfg(any(test, feature = "tower"))]
+pub mod tower;
+mod voice;
+
+pub use event::*;
+pub use fax::*;
+pub use messaging::*;
+use serde::de::DeserializeOwned;
+#[cfg(any(test, feature = "webhook"))]
+pub use signature::*;
+pub use voice::*;
+
+use crate::errors::SignalWireError;
+
+/// Deserializes a form-encoded (`application/x-www-form-urlencoded`) webhook body.
+pub fn parse_form<T: DeserializeOwned>(body: &str) -> Result<T, SignalWireError> {
+    serde_urlencoded::from_str(body).map_err(|e| SignalWireError::Unexpected(format!("Failed to parse webhook: {}. Body was: {}", e, body)))
+}
+
+/// Rebuilds the URL SignalWire requested, which is what the signature covers.
+///
+/// `public_url` (scheme and host) wins when set; otherwise the `X-Forwarded-Proto`,
+/// `X-Forwarded-Host` and `Host` headers are used.
+#[cfg(any(test, feature = "axum", feature = "tower"))]
+pub(crate) fn request_url(uri: &http::Uri, headers: &http::HeaderMap, public_url: Option<&str>) -> String {
+    let path = uri.path_and_query().map(|path| path.as_str()).unwrap_or("/");
+    if let Some(public_url) = public_url {
+        return format!("{}{}", public_url, path);
+    }
+
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    let scheme = header("x-forwarded-proto").or(uri.scheme_str()).unwrap_or("https");
+    let host = header("x-forwarded-host")
+        .or(header(http::header::HOST.as_str()))
+        .or(uri.authority().map(|authority| authority.as_str()))
+        .unwrap_or_default();
+
+    format!("{}://{}{}", scheme, host, path)
+}
@@ -0,0 +1,208 @@
+//! A `tower` middleware verifying SignalWire webhooks, for any hyper-based stack.
+//!
+//! `VerifyWebhookLayer` buffers the request body, up to 2 MiB by default, checks the signature and
+//! only then calls the inner service, with the body as `Full<Bytes>`. Rejected requests get an empty
+//! `401`, `403`, `400` or `413` response.
+//!
+//! ```rust,no_run
+//! use signalwire::webhook::tower::VerifyWebhookLayer;
+//!
+//! let layer = VerifyWebhookLayer::new("PSK...").with_public_url("https://hooks.example.com");
+//! // ServiceBuilder::new().layer(layer).service(app)
+//! ```
+//!
+//! The signature doesn't cover any timestamp, so it doesn't protect against replays: a captured
+//! request verifies again later. `with_max_age` only rejects requests by a timestamp header that
+//! isn't signed either, which anyone replaying a request can set; it's only of use behind a trusted
+//! proxy that sets the header itself, overwriting the client's.
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use ::tower::{Layer, Service};
+use bytes::Bytes;
+use http::{HeaderMap, Request, Response, StatusCode};
+use http_body::Body;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+
+use super::{request_url, validate_webhook_signature, SIGNATURE_HEADER};
+
+/// The default header read for the request timestamp, in Unix seconds. SignalWire doesn't sign it,
+/// see `VerifyWebhookLayer::with_max_age`.
+pub const TIMESTAMP_HEADER: &str = "X-SignalWire-Timestamp";
+
+/// The largest body buffered by default, in bytes, as axum's `DefaultBodyLimit`.
+pub const DEFAULT_MAX_BODY_SIZE: usize = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
+struct Config {
+    signing_key: String,
+    public_url: Option<String>,
+    max_age: Option<Duration>,
+    timestamp_header: String,
+    max_body_size: usize,
+}
+
+impl Config {
+    /// Rejects requests whose timestamp is missing, malformed or further than `max_age` from now.
+    fn check_timestamp(&self, headers: &HeaderMap) -> Result<(), StatusCode> {
+        let Some(max_age) = self.max_age else {
+            return Ok(());
+        };
+
+        let timestamp = headers
+            .get(self.timestamp_header.as_str())
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+        if now.abs_diff(timestamp) > max_age.as_secs() {
+            return Err(StatusCode::FORBIDDEN);
+        }
+
+        Ok(())
+    }
+
+    fn check_signature(&self, headers: &HeaderMap, url: &str, body: &[u8]) -> Result<(), StatusCode> {
+        let signature = headers.get(SIGNATURE_HEADER).and_then(|value| value.to_str().ok()).ok_or(StatusCode::UNAUTHORIZED)?;
+        let body = std::str::from_utf8(body).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+        if !validate_webhook_signature(&self.signing_key, url, body, signature) {
+            return Err(StatusCode::FORBIDDEN);
+        }
+
+        Ok(())
+    }
+}
+
+/// Applies `VerifyWebhook` to a service.
+#[derive(Debug, Clone)]
+pub struct VerifyWebhookLayer {
+    config: Arc<Config>,
+}
+
+impl VerifyWebhookLayer {
+    /// Creates a layer verifying signatures with your space's signing key.
+    pub fn new(signing_key: &str) -> Self {
+        VerifyWebhookLayer {
+            config: Arc::new(Config {
+                signing_key: signing_key.to_string(),
+                public_url: None,
+                max_age: None,
+                timestamp_header: TIMESTAMP_HEADER.to_string(),
+                max_body_size: DEFAULT_MAX_BODY_SIZE,
+            }),
+        }
+    }
+
+    /// Sets the scheme and host SignalWire uses to reach this service (e.g. `https://hooks.example.com`).
+    ///
+    /// By default the signed URL is rebuilt from the `X-Forwarded-Proto`, `X-Forwarded-Host` and `Host` headers.
+    pub fn with_public_url(mut self, public_url: &str) -> Self {
+        Arc::make_mut(&mut self.config).public_url = Some(public_url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Rejects requests whose timestamp header is missing or more than `max_age` away from the current time.
+    ///
+    /// This is not replay protection: the header isn't covered by the signature, so a replayed request
+    /// can carry a fresh one. Only enable it behind a trusted proxy that sets the header itself,
+    /// overwriting whatever the client sent, e.g. to drop requests queued upstream for too long.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        Arc::make_mut(&mut self.config).max_age = Some(max_age);
+        self
+    }
+
+    /// Rejects bodies larger than `max_body_size` bytes with `413 Payload Too Large`, instead of
+    /// `DEFAULT_MAX_BODY_SIZE`. Bodies are buffered before their signature is checked, so this bounds
+    /// what unauthenticated clients can make the service hold in memory.
+    pub fn with_max_body_size(mut self, max_body_size: usize) -> Self {
+        Arc::make_mut(&mut self.config).max_body_size = max_body_size;
+        self
+    }
+
+    /// Reads the request timestamp from `name` instead of `X-SignalWire-Timestamp`.
+    pub fn with_timestamp_header(mut self, name: &str) -> Self {
+        Arc::make_mut(&mut self.config).timestamp_header = name.to_string();
+        self
+    }
+}
+
+impl<S> Layer<S> for VerifyWebhookLayer {
+    type Service = VerifyWebhook<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        VerifyWebhook { inner, config: self.config.clone() }
+    }
+}
+
+/// Verifies SignalWire webhooks before passing them to the inner service.
+#[derive(Clone)]
+pub struct VerifyWebhook<S> {
+    inner: S,
+    config: Arc<Config>,
+}
+
+impl<S: fmt::Debug> fmt::Debug for VerifyWebhook<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerifyWebhook").field("inner", &self.inner).finish_non_exhaustive()
+    }
+}
+
+impl<S, B, ResBody> Service<Request<B>> for VerifyWebhook<S>
+where
+    S: Service<Request<Full<Bytes>>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send,
+    B: Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    ResBody: Default,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        // The service that was polled ready is the one that must be called.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let config = self.config.clone();
+
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            if let Err(status) = config.check_timestamp(&parts.headers) {
+                return Ok(reject(status));
+            }
+
+            let bytes = match Limited::new(body, config.max_body_size).collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(error) if error.is::<LengthLimitError>() => return Ok(reject(StatusCode::PAYLOAD_TOO_LARGE)),
+                Err(_) => return Ok(reject(StatusCode::BAD_REQUEST)),
+            };
+
+            let url = request_url(&parts.uri, &parts.headers, config.public_url.as_deref());
+            if let Err(status) = config.check_signature(&parts.headers, &url, &bytes) {
+                return Ok(reject(status));
+            }
+
+            inner.call(Request::from_parts(parts, Full::new(bytes))).await
+        })
+    }
+}
+
+fn reject<ResBody: Default>(status: StatusCode) -> Response<ResBody> {
+    let mut response = Response::new(ResBody::default());
+    *response.status_mut() = status;
+    response
+}
@@ -0,0 +1,171 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::parse_form;
+use crate::errors::SignalWireError;
+
+/// The parameters SignalWire sends with every voice webhook (incoming calls, status callbacks, etc.).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VoiceWebhook {
+    pub call_sid: String,
+    pub account_sid: String,
+    pub from: String,
+    pub to: String,
+    pub call_status: String,
+    pub direction: String,
+    pub api_version: Option<String>,
+    pub forwarded_from: Option<String>,
+    pub caller_name: Option<String>,
+    pub parent_call_sid: Option<String>,
+}
+
+impl VoiceWebhook {
+    /// Parses a form-encoded voice webhook body.
+    pub fn from_form(body: &str) -> Result<Self, SignalWireError> {
+        parse_form(body)
+    }
+
+    /// A `CallStatus` enum representing the current status of the call.
+    pub fn get_status(&self) -> CallStatus {
+        CallStatus::from(self.call_status.as_str())
+    }
+
+    /// A `CallDirection` enum representing who started the call.
+    pub fn get_direction(&self) -> CallDirection {
+        CallDirection::from(self.direction.as_str())
+    }
+}
+
+/// The action callback of a `<Gather>`: the call parameters plus the collected input.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GatherWebhook {
+    #[serde(flatten)]
+    pub call: VoiceWebhook,
+    /// The DTMF digits pressed, if any.
+    pub digits: Option<String>,
+    /// The transcribed speech, if speech input was enabled.
+    pub speech_result: Option<String>,
+    /// The confidence of `speech_result`, between 0.0 and 1.0.
+    pub confidence: Option<String>,
+}
+
+impl GatherWebhook {
+    /// Parses a form-encoded `<Gather>` callback body.
+    pub fn from_form(body: &str) -> Result<Self, SignalWireError> {
+        parse_form(body)
+    }
+}
+
+/// The action callback of `<Record>` or a `recordingStatusCallback`, of a call or of a conference.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RecordingWebhook {
+    /// The recorded call, absent for the recording of a conference.
+    pub call_sid: Option<String>,
+    /// The recorded conference, e.g. for `SignalWireClient::start_conference_recording`.
+    pub conference_sid: Option<String>,
+    pub account_sid: String,
+    pub recording_sid: String,
+    pub recording_url: String,
+    pub recording_status: Option<String>,
+    /// The length of the recording, in seconds.
+    pub recording_duration: Option<String>,
+    pub recording_channels: Option<String>,
+    pub recording_source: Option<String>,
+    /// The key that ended the recording, for `<Record finishOnKey>`.
+    pub digits: Option<String>,
+}
+
+impl RecordingWebhook {
+    /// Parses a form-encoded recording callback body.
+    pub fn from_form(body: &str) -> Result<Self, SignalWireError> {
+        parse_form(body)
+    }
+}
+
+// Call status values according to SignalWire API
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CallStatus {
+    Queued,          // The call is waiting to be placed
+    Ringing,         // The destination is ringing
+    InProgress,      // The call was answered and is ongoing
+    Completed,       // The call was answered and has ended
+    Busy,            // The destination returned a busy signal
+    Failed,          // The call could not be completed
+    NoAnswer,        // The destination did not answer
+    Canceled,        // The call was canceled before it was answered
+    Unknown(String), // A status this version doesn't know, as received
+}
+
+impl From<&str> for CallStatus {
+    fn from(status: &str) -> Self {
+        match status.to_lowercase().as_str() {
+            "queued" => CallStatus::Queued,
+            "ringing" => CallStatus::Ringing,
+            "in-progress" => CallStatus::InProgress,
+            "completed" => CallStatus::Completed,
+            "busy" => CallStatus::Busy,
+            "failed" => CallStatus::Failed,
+            "no-answer" => CallStatus::NoAnswer,
+            "canceled" => CallStatus::Canceled,
+            _ => CallStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for CallStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallStatus::Queued => write!(f, "queued"),
+            CallStatus::Ringing => write!(f, "ringing"),
+            CallStatus::InProgress => write!(f, "in-progress"),
+            CallStatus::Completed => write!(f, "completed"),
+            CallStatus::Busy => write!(f, "busy"),
+            CallStatus::Failed => write!(f, "failed"),
+            CallStatus::NoAnswer => write!(f, "no-answer"),
+            CallStatus::Canceled => write!(f, "canceled"),
+            CallStatus::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl CallStatus {
+    /// Whether the call has ended, answered or not.
+    pub fn is_final(&self) -> bool {
+        matches!(self, CallStatus::Completed | CallStatus::Busy | CallStatus::Failed | CallStatus::NoAnswer | CallStatus::Canceled)
+    }
+}
+
+// Call direction values according to SignalWire API
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CallDirection {
+    Inbound,         // The call was placed to one of your numbers
+    OutboundApi,     // The call was created through the REST API
+    OutboundDial,    // The call was created by <Dial>
+    Unknown(String), // A direction this version doesn't know, as received
+}
+
+impl From<&str> for CallDirection {
+    fn from(direction: &str) -> Self {
+        match direction.to_lowercase().as_str() {
+            "inbound" => CallDirection::Inbound,
+            "outbound-api" => CallDirection::OutboundApi,
+            "outbound-dial" => CallDirection::OutboundDial,
+            _ => CallDirection::Unknown(direction.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for CallDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallDirection::Inbound => write!(f, "inbound"),
+            CallDirection::OutboundApi => write!(f, "outbound-api"),
+            CallDirection::OutboundDial => write!(f, "outbound-dial"),
+            CallDirection::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}